@@ -1,16 +1,53 @@
 use std::path::Path;
 
-use crate::core::{DetectionResult, ParserInfo, ParserRegistry, ProjectDetector, PARSER_REGISTRY};
-use crate::models::SourceFile;
-use crate::parsers::delphi::DelphiParser;
-use crate::parsers::laravel::LaravelParser;
-use crate::parsers::ProjectParser;
+use crate::core::{
+    DetectionResult, ParserInfo, ParserRegistry, ProjectDetector, ProjectManifest,
+    PARSER_REGISTRY,
+};
+use crate::export::{write_graph, ExportFormat};
+use crate::graph::{
+    annotate_file_metrics, default_laravel_rules, duplicate_classes, fat_controllers,
+    force_directed, layout_warnings, merge_subprojects, model_cycles, node_details, route_slice,
+    view_layouts, ArchitectureReport, DuplicateClass, FatController, GraphFilter, Layer, LayerRule,
+    ModelCycle, NodeDetails, RuleViolation, ScoreWeights, ViewLayout, DEFAULT_LAYOUT_ITERATIONS,
+    DEFAULT_LAYOUT_SEED, DEFAULT_MAX_CONTROLLER_ACTIONS, DEFAULT_SEARCH_LIMIT, ROOT_PROJECT,
+};
+use crate::models::{
+    GraphDiff, GraphMetadata, ParseResult, ProjectAnalysis, SourceFile, UnifiedEdgeType,
+    UnifiedGraph, UnifiedNode, UnifiedNodeType,
+};
+use crate::parsers::{ParserConfig, ProjectParser};
+
+mod watch;
+
+pub use watch::*;
 
 /// Detect project type from a directory path
 #[tauri::command]
 pub async fn detect_project_type(path: String) -> Result<DetectionResult, String> {
-    let path = Path::new(&path);
+    detect(Path::new(&path))
+}
+
+/// Detect the project type of several directories at once
+///
+/// Paths are detected concurrently; results come in the order of `paths`,
+/// each path failing on its own.
+#[tauri::command]
+pub async fn detect_project_types(paths: Vec<String>) -> Vec<Result<DetectionResult, String>> {
+    let detections: Vec<_> = paths
+        .into_iter()
+        .map(|path| tokio::task::spawn_blocking(move || detect(Path::new(&path))))
+        .collect();
 
+    let mut results = Vec::with_capacity(detections.len());
+    for detection in detections {
+        results.push(detection.await.unwrap_or_else(|e| Err(e.to_string())));
+    }
+    results
+}
+
+/// Project type of a directory, as pinned by its manifest if any
+fn detect(path: &Path) -> Result<DetectionResult, String> {
     if !path.exists() {
         return Err("Path does not exist".to_string());
     }
@@ -19,7 +56,13 @@ pub async fn detect_project_type(path: String) -> Result<DetectionResult, String
         return Err("Path is not a directory".to_string());
     }
 
-    Ok(ProjectDetector::detect(path))
+    let detection = ProjectDetector::detect(path);
+
+    // A manifest pins the parser, whatever the markers say
+    Ok(match ProjectManifest::load(path)? {
+        Some(manifest) => manifest.pin_detection(detection),
+        None => detection,
+    })
 }
 
 /// List all available parsers
@@ -41,31 +84,521 @@ pub async fn scan_directory(
     }
 
     // Detect or use specified parser
-    let parser_id = parser_id.unwrap_or_else(|| {
-        let detection = ProjectDetector::detect(root_path);
-        detection.parser_id
-    });
+    let manifest = ProjectManifest::load(root_path)?.unwrap_or_default();
+    let parser_id = resolve_parser_id(root_path, parser_id, &manifest);
 
     // Get appropriate parser and scan
-    let files = match parser_id.as_str() {
-        "delphi" => {
-            let parser = DelphiParser::new();
-            let config = parser.default_config();
-            parser
+    let parser = find_parser(&parser_id)?;
+    let mut config = parser.default_config();
+    manifest.apply_to_config(&mut config);
+    let files = parser
+        .scan_files(root_path, &config, None)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(files)
+}
+
+/// Parse a project and build its graph
+///
+/// Returns the parse result along with the graph, so that `rebuild_graph`
+/// can regenerate the graph later without scanning the disk again.
+#[tauri::command]
+pub async fn analyze(path: String, parser_id: Option<String>) -> Result<ProjectAnalysis, String> {
+    run_analysis(Path::new(&path), parser_id, None).await
+}
+
+/// Regenerate a graph from the parse result returned by `analyze`
+///
+/// Nodes and edges come from the parser's `build_graph` as during `analyze`,
+/// but no source file is read again: line counts and complexity aren't
+/// recomputed, so file nodes have no metrics and `total_lines` is unset.
+#[tauri::command]
+pub async fn rebuild_graph(
+    parse_result: ParseResult,
+    parser_id: String,
+) -> Result<UnifiedGraph, String> {
+    let parser = find_parser(&parser_id)?;
+    if parse_result.root_path.is_empty() {
+        return Err("Parse result has no root path".to_string());
+    }
+    let manifest = ProjectManifest::load(Path::new(&parse_result.root_path))?.unwrap_or_default();
+
+    Ok(build_project_graph(parser, &parse_result, &manifest))
+}
+
+/// Analyze every project of a monorepo and merge them into one graph
+///
+/// Projects are found by `ProjectDetector::detect_all`, a manifest in a
+/// project's directory pins its parser. Projects without a parser (Node.js,
+/// plain PHP, ...) are skipped. Nodes carry their project's directory,
+/// relative to `path`, in `extra["project"]`. With `layout`, nodes get
+/// force-directed positions (see `graph::force_directed`).
+#[tauri::command]
+pub async fn scan_monorepo(path: String, layout: Option<bool>) -> Result<UnifiedGraph, String> {
+    let root_path = Path::new(&path);
+
+    if !root_path.is_dir() {
+        return Err("Path is not a directory".to_string());
+    }
+
+    let mut projects = Vec::new();
+    for (project_path, detection) in ProjectDetector::detect_all(root_path) {
+        let detection = match ProjectManifest::load(&project_path)? {
+            Some(manifest) => manifest.pin_detection(detection),
+            None => detection,
+        };
+        if PARSER_REGISTRY.parser(&detection.parser_id).is_none() {
+            continue;
+        }
+
+        let graph = analyze_project(&project_path, Some(detection.parser_id)).await?;
+        let project = project_path
+            .strip_prefix(root_path)
+            .map(|p| p.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_default();
+        let project = if project.is_empty() {
+            ROOT_PROJECT.to_string()
+        } else {
+            project
+        };
+        projects.push((project, graph));
+    }
+
+    if projects.is_empty() {
+        return Err("No supported project found".to_string());
+    }
+
+    let mut graph = merge_subprojects(root_path, projects);
+    if layout.unwrap_or(false) {
+        force_directed(&mut graph, DEFAULT_LAYOUT_ITERATIONS, DEFAULT_LAYOUT_SEED);
+    }
+
+    Ok(graph)
+}
+
+/// Compare two saved graph snapshots (JSON-serialized `UnifiedGraph`)
+#[tauri::command]
+pub async fn diff_graphs(old_path: String, new_path: String) -> Result<GraphDiff, String> {
+    let old_graph = load_graph(Path::new(&old_path))?;
+    let new_graph = load_graph(Path::new(&new_path))?;
+
+    Ok(old_graph.diff(&new_graph))
+}
+
+fn load_graph(path: &Path) -> Result<UnifiedGraph, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Invalid graph file {}: {}", path.display(), e))
+}
+
+/// Compute the architecture health report of a project
+#[tauri::command]
+pub async fn architecture_report(
+    path: String,
+    parser_id: Option<String>,
+    weights: Option<ScoreWeights>,
+) -> Result<ArchitectureReport, String> {
+    let graph = analyze_project(Path::new(&path), parser_id).await?;
+
+    Ok(ArchitectureReport::compute(&graph, &weights.unwrap_or_default()))
+}
+
+/// Find controllers with more actions than `max_actions` (default 15)
+#[tauri::command]
+pub async fn find_fat_controllers(
+    path: String,
+    parser_id: Option<String>,
+    max_actions: Option<usize>,
+) -> Result<Vec<FatController>, String> {
+    let graph = analyze_project(Path::new(&path), parser_id).await?;
+
+    Ok(fat_controllers(
+        &graph,
+        max_actions.unwrap_or(DEFAULT_MAX_CONTROLLER_ACTIONS),
+    ))
+}
+
+/// Find fully qualified class names declared in more than one file
+#[tauri::command]
+pub async fn find_duplicate_classes(
+    path: String,
+    parser_id: Option<String>,
+) -> Result<Vec<DuplicateClass>, String> {
+    let graph = analyze_project(Path::new(&path), parser_id).await?;
+
+    Ok(duplicate_classes(&graph))
+}
+
+/// Find the cycles of Eloquent relationships, ownership loops first
+#[tauri::command]
+pub async fn find_model_cycles(
+    path: String,
+    parser_id: Option<String>,
+) -> Result<Vec<ModelCycle>, String> {
+    let graph = analyze_project(Path::new(&path), parser_id).await?;
+
+    Ok(model_cycles(&graph))
+}
+
+/// Check a project against layering rules, the Laravel defaults when none are given
+///
+/// Each rule forbids edges between two node types or layers (see
+/// `graph::check_layering`).
+#[tauri::command]
+pub async fn check_layering(
+    path: String,
+    parser_id: Option<String>,
+    rules: Option<Vec<LayerRule>>,
+) -> Result<Vec<RuleViolation>, String> {
+    let rules = rules.unwrap_or_else(default_laravel_rules);
+    for rule in &rules {
+        if let Some(key) = [&rule.from, &rule.to].into_iter().find(|key| {
+            match key.strip_prefix("layer:") {
+                Some(layer) => Layer::from_name(layer).is_none(),
+                None => UnifiedNodeType::from_key(key).is_none(),
+            }
+        }) {
+            return Err(format!("Unknown node type or layer: {}", key));
+        }
+        if let Some(key) = rule
+            .edge_types
+            .iter()
+            .find(|key| UnifiedEdgeType::from_key(key).is_none())
+        {
+            return Err(format!("Unknown edge type: {}", key));
+        }
+    }
+
+    let graph = analyze_project(Path::new(&path), parser_id).await?;
+
+    Ok(crate::graph::check_layering(&graph, &rules))
+}
+
+/// Resolve Blade views against their full `@extends` layout chain
+#[tauri::command]
+pub async fn check_blade_layouts(
+    path: String,
+    parser_id: Option<String>,
+) -> Result<Vec<ViewLayout>, String> {
+    let graph = analyze_project(Path::new(&path), parser_id).await?;
+
+    Ok(view_layouts(&graph))
+}
+
+/// Analyze a project and export its graph to a file
+///
+/// `format` is one of json, dot, mermaid, graphml, sarif, cytoscape, plantuml
+/// (class diagram) or csv (written as `<stem>.nodes.csv` and `<stem>.edges.csv`
+/// next to `out`).
+/// With `layout`, nodes get force-directed positions (see `graph::force_directed`).
+#[tauri::command]
+pub async fn export_graph(
+    path: String,
+    parser_id: Option<String>,
+    format: String,
+    out: String,
+    layout: Option<bool>,
+) -> Result<(), String> {
+    let format = ExportFormat::from_name(&format).map_err(|e| e.to_string())?;
+    let mut graph = analyze_project(Path::new(&path), parser_id).await?;
+    if layout.unwrap_or(false) {
+        force_directed(&mut graph, DEFAULT_LAYOUT_ITERATIONS, DEFAULT_LAYOUT_SEED);
+    }
+
+    write_graph(&graph, format, Path::new(&out)).map_err(|e| e.to_string())
+}
+
+/// Export the slice of a project graph serving one named route
+///
+/// The slice holds the route file, the route's controller and what the
+/// controller depends on, rendered in `format` (see `export_graph`).
+#[tauri::command]
+pub async fn export_route_slice(
+    path: String,
+    parser_id: Option<String>,
+    route_name: String,
+    format: String,
+) -> Result<String, String> {
+    let format = ExportFormat::from_name(&format).map_err(|e| e.to_string())?;
+    let graph = analyze_project(Path::new(&path), parser_id).await?;
+
+    let slice = route_slice(&graph, &route_name)
+        .ok_or_else(|| format!("No controller route named {}", route_name))?;
+
+    crate::export::export_graph(&slice, format).map_err(|e| e.to_string())
+}
+
+/// Search the nodes of a graph by name, qualified name or file path
+///
+/// Fuzzy and case-insensitive, best matches first (see `graph::search_nodes`).
+/// `node_type` is a type key (`controller`, `custom:policy`), `limit`
+/// defaults to 50.
+#[tauri::command]
+pub fn search_nodes(
+    graph: UnifiedGraph,
+    query: String,
+    node_type: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<UnifiedNode>, String> {
+    let node_type = match node_type {
+        Some(key) => Some(
+            UnifiedNodeType::from_key(&key).ok_or_else(|| format!("Unknown node type: {}", key))?,
+        ),
+        None => None,
+    };
+
+    Ok(crate::graph::search_nodes(
+        &graph,
+        &query,
+        node_type.as_ref(),
+        limit.unwrap_or(DEFAULT_SEARCH_LIMIT),
+    ))
+}
+
+/// Extract the subgraph of some node/edge types or projects, or around some focus nodes
+///
+/// See `graph::filter_graph`. Type keys are checked so a typo doesn't
+/// silently empty the graph.
+#[tauri::command]
+pub fn filter_graph(graph: UnifiedGraph, options: GraphFilter) -> Result<UnifiedGraph, String> {
+    if let Some(key) = options
+        .node_types
+        .iter()
+        .find(|key| UnifiedNodeType::from_key(key).is_none())
+    {
+        return Err(format!("Unknown node type: {}", key));
+    }
+    if let Some(key) = options
+        .edge_types
+        .iter()
+        .find(|key| UnifiedEdgeType::from_key(key).is_none())
+    {
+        return Err(format!("Unknown edge type: {}", key));
+    }
+
+    Ok(crate::graph::filter_graph(&graph, &options))
+}
+
+/// A node with its edges, neighbors and source snippet, for the details panel
+#[tauri::command]
+pub fn get_node_details(graph: UnifiedGraph, node_id: String) -> Result<NodeDetails, String> {
+    node_details(&graph, &node_id).ok_or_else(|| format!("No node with ID {}", node_id))
+}
+
+/// Node IDs along a shortest directed path from one node to another
+///
+/// `None` when `to_id` can't be reached from `from_id`.
+#[tauri::command]
+pub fn find_path(
+    graph: UnifiedGraph,
+    from_id: String,
+    to_id: String,
+) -> Result<Option<Vec<String>>, String> {
+    if let Some(id) = [&from_id, &to_id]
+        .into_iter()
+        .find(|id| graph.find_node(id).is_none())
+    {
+        return Err(format!("No node with ID {}", id));
+    }
+
+    Ok(graph.shortest_path(&from_id, &to_id))
+}
+
+/// Registered parser implementation by ID
+fn find_parser(parser_id: &str) -> Result<&'static dyn ProjectParser, String> {
+    PARSER_REGISTRY
+        .parser(parser_id)
+        .ok_or_else(|| format!("Unknown parser: {}", parser_id))
+}
+
+/// Parser to use: the explicit one, then the manifest's, then detection
+fn resolve_parser_id(
+    root_path: &Path,
+    parser_id: Option<String>,
+    manifest: &ProjectManifest,
+) -> String {
+    parser_id
+        .or_else(|| manifest.parser.clone())
+        .unwrap_or_else(|| ProjectDetector::detect(root_path).parser_id)
+}
+
+/// Scan, parse and build the graph of a project
+async fn analyze_project(
+    root_path: &Path,
+    parser_id: Option<String>,
+) -> Result<UnifiedGraph, String> {
+    Ok(run_analysis(root_path, parser_id, None).await?.graph)
+}
+
+/// Scan and parse a project, then build its graph, keeping the parse result
+///
+/// With a `previous` parse result only the files whose content hash changed
+/// since are parsed again (see `ProjectParser::incremental_parse`), when the
+/// parser supports it. Other parsers parse every file again.
+async fn run_analysis(
+    root_path: &Path,
+    parser_id: Option<String>,
+    previous: Option<ParseResult>,
+) -> Result<ProjectAnalysis, String> {
+    let (mut analysis, config) = parse_and_build(root_path, parser_id, previous).await?;
+    // Line counts and complexity of every file, summed into total_lines
+    annotate_file_metrics(&mut analysis.graph, &config.encoding);
+
+    Ok(analysis)
+}
+
+/// Scan and parse a project and build its graph, like `run_analysis` but
+/// without the file metrics, returning the configuration it parsed with
+async fn parse_and_build(
+    root_path: &Path,
+    parser_id: Option<String>,
+    previous: Option<ParseResult>,
+) -> Result<(ProjectAnalysis, ParserConfig), String> {
+    if !root_path.is_dir() {
+        return Err("Path is not a directory".to_string());
+    }
+
+    let manifest = ProjectManifest::load(root_path)?.unwrap_or_default();
+    let parser_id = resolve_parser_id(root_path, parser_id, &manifest);
+    let parser = find_parser(&parser_id)?;
+    let mut config = parser.default_config();
+    manifest.apply_to_config(&mut config);
+
+    let parse_result = match previous {
+        Some(previous) => parser
+            .incremental_parse(root_path, previous, &config, None)
+            .await
+            .map_err(|e| e.to_string())?,
+        None => {
+            let files = parser
                 .scan_files(root_path, &config, None)
                 .await
-                .map_err(|e| e.to_string())?
-        }
-        "laravel" => {
-            let parser = LaravelParser::new();
-            let config = parser.default_config();
+                .map_err(|e| e.to_string())?;
             parser
-                .scan_files(root_path, &config, None)
+                .parse_project(root_path, &files, &config, None)
                 .await
                 .map_err(|e| e.to_string())?
         }
-        _ => return Err(format!("Unknown parser: {}", parser_id)),
     };
 
-    Ok(files)
+    let graph = build_project_graph(parser, &parse_result, &manifest);
+    let analysis = ProjectAnalysis {
+        parser_id,
+        parse_result,
+        graph,
+    };
+
+    Ok((analysis, config))
+}
+
+/// Build a project's graph from its parse result, without reading its files
+fn build_project_graph(
+    parser: &dyn ProjectParser,
+    parse_result: &ParseResult,
+    manifest: &ProjectManifest,
+) -> UnifiedGraph {
+    let root_path = Path::new(&parse_result.root_path);
+    let info = parser.info();
+    let mut graph = parser.build_graph(parse_result).with_metadata(GraphMetadata {
+        project_name: root_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default()
+            .to_string(),
+        root_path: parse_result.root_path.clone(),
+        language: info.id,
+        total_files: parse_result.total_processed,
+        total_lines: None,
+        scanned_at: Some(chrono::Utc::now().to_rfc3339()),
+        parser_version: info.version,
+        framework_version: parser.framework_version(root_path),
+        warnings: parse_result.all_warnings(),
+    });
+    // Missing layouts and unfilled yields only show once the graph is built
+    let warnings = layout_warnings(&graph);
+    graph.metadata.warnings.extend(warnings);
+    // Namespace or directory of every node, for the UI to group them
+    graph.annotate_clusters();
+    manifest.apply_to_graph(&mut graph);
+
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ProjectType;
+    use crate::parsers::common::fixtures::project_dir;
+
+    #[tokio::test]
+    async fn project_types_come_in_the_order_of_the_paths() {
+        let laravel = project_dir(&[
+            (
+                "composer.json",
+                r#"{"require": {"laravel/framework": "^11.0"}}"#,
+            ),
+            ("artisan", "#!/usr/bin/env php"),
+            ("app/Http/Controllers/Controller.php", "<?php"),
+        ]);
+        let delphi = project_dir(&[("Main.dpr", "program Main;"), ("Unit1.pas", "unit Unit1;")]);
+        let missing = laravel.path().join("missing");
+        let path = |path: &Path| path.to_string_lossy().to_string();
+
+        let results = detect_project_types(vec![
+            path(delphi.path()),
+            path(&missing),
+            path(laravel.path()),
+        ])
+        .await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].as_ref().unwrap().project_type,
+            ProjectType::Delphi
+        );
+        assert_eq!(results[1].as_ref().unwrap_err(), "Path does not exist");
+        assert_eq!(
+            results[2].as_ref().unwrap().project_type,
+            ProjectType::Laravel
+        );
+    }
+
+    #[tokio::test]
+    async fn export_graph_writes_known_formats_only() {
+        let project = project_dir(&[
+            (
+                "composer.json",
+                r#"{"require": {"laravel/framework": "^11.0"}}"#,
+            ),
+            ("artisan", "#!/usr/bin/env php"),
+            (
+                "app/Models/User.php",
+                "<?php\nnamespace App\\Models;\nclass User extends Model {}\n",
+            ),
+        ]);
+        let path = project.path().to_string_lossy().to_string();
+        let out = project.path().join("graph.dot");
+        let export = |format: &str, out: &Path| {
+            export_graph(
+                path.clone(),
+                None,
+                format.to_string(),
+                out.to_string_lossy().to_string(),
+                None,
+            )
+        };
+
+        export("dot", &out).await.unwrap();
+        let dot = std::fs::read_to_string(&out).unwrap();
+        assert!(dot.starts_with("digraph "));
+        assert!(dot.contains("User"));
+
+        let bogus = project.path().join("graph.bogus");
+        let error = export("bogus", &bogus).await.unwrap_err();
+        assert_eq!(error, "Unknown export format: bogus");
+        assert!(!bogus.exists());
+    }
 }
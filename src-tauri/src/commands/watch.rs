@@ -0,0 +1,249 @@
+// Watch commands - Live re-analysis of a project on file changes
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+
+use crate::core::ProjectManifest;
+use crate::graph::{carry_file_metrics, refresh_file_metrics};
+use crate::models::{ParseResult, ProjectAnalysis, UnifiedGraph};
+use crate::parsers::ParserConfig;
+
+use super::{find_parser, parse_and_build, run_analysis};
+
+/// Event emitted with a `GraphUpdate` after a watched project changed
+pub const GRAPH_UPDATED_EVENT: &str = "graph-updated";
+
+/// Quiet time after the last file event before a project is re-analyzed, so
+/// that a save or a checkout touching many files makes a single update
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(300);
+
+lazy_static::lazy_static! {
+    /// Running watchers by watched path, dropping one stops its updates
+    static ref WATCHERS: Mutex<HashMap<String, RecommendedWatcher>> = Mutex::new(HashMap::new());
+}
+
+/// Payload of the `graph-updated` event
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphUpdate {
+    /// Watched project path, as given to `watch_directory`
+    pub path: String,
+
+    /// IDs of the nodes added, removed, moved or changed since the last
+    /// update, and of the nodes whose edges changed
+    pub changed_node_ids: Vec<String>,
+
+    /// Graph of the project after the change
+    pub graph: UnifiedGraph,
+}
+
+/// Watch a project and re-analyze it whenever one of its source files changes
+///
+/// Each change emits `graph-updated` with a `GraphUpdate`. Events are
+/// debounced, and only files whose content hash changed are parsed again.
+/// The whole directory is watched rather than single files, so editors saving
+/// through a temporary file renamed over the original are followed too.
+/// Watching a path again replaces its watcher.
+#[tauri::command]
+pub async fn watch_directory(
+    app: AppHandle,
+    path: String,
+    parser_id: Option<String>,
+) -> Result<(), String> {
+    let root_path = PathBuf::from(&path);
+
+    // Started before the first analysis, so no change made meanwhile is missed
+    let (sender, receiver) = unbounded_channel();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+        if let Ok(event) = event {
+            let _ = sender.send(event);
+        }
+    })
+    .map_err(|e| e.to_string())?;
+    watcher
+        .watch(&root_path, RecursiveMode::Recursive)
+        .map_err(|e| e.to_string())?;
+
+    // An empty previous result parses every file, hashing it for the next runs
+    let previous = ParseResult {
+        root_path: path.clone(),
+        ..ParseResult::new()
+    };
+    let analysis = run_analysis(&root_path, parser_id, Some(previous)).await?;
+
+    WATCHERS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .insert(path.clone(), watcher);
+    tauri::async_runtime::spawn(reanalyze_on_changes(app, path, analysis, receiver));
+
+    Ok(())
+}
+
+/// Stop watching a project
+#[tauri::command]
+pub fn stop_watch(path: String) -> Result<(), String> {
+    WATCHERS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .remove(&path)
+        .map(|_| ())
+        .ok_or_else(|| format!("Not watching {}", path))
+}
+
+/// Re-analyze the project after every burst of relevant file events
+///
+/// Only the changed files are read again for their metrics, the others keep
+/// the ones of the previous graph. Ends once the watcher is dropped, which
+/// closes the channel.
+async fn reanalyze_on_changes(
+    app: AppHandle,
+    path: String,
+    mut analysis: ProjectAnalysis,
+    mut events: UnboundedReceiver<Event>,
+) {
+    let root_path = PathBuf::from(&path);
+    let config = match watched_config(&root_path, &analysis.parser_id) {
+        Ok(config) => config,
+        Err(_) => return,
+    };
+
+    while let Some(event) = events.recv().await {
+        let mut paths = changed_paths(event);
+
+        // Wait for the burst to end, a checkout or "save all" comes as many events
+        loop {
+            match tokio::time::timeout(WATCH_DEBOUNCE, events.recv()).await {
+                Ok(Some(event)) => paths.extend(changed_paths(event)),
+                Ok(None) => return,
+                Err(_) => break,
+            }
+        }
+        if !affects_graph(&paths, &root_path, &analysis, &config) {
+            continue;
+        }
+
+        let parser_id = Some(analysis.parser_id.clone());
+        let previous = analysis.parse_result.clone();
+        let mut updated = match parse_and_build(&root_path, parser_id, Some(previous)).await {
+            Ok((updated, _)) => updated,
+            Err(_) => continue,
+        };
+        carry_file_metrics(&analysis.graph, &mut updated.graph);
+        for relative in paths.iter().filter_map(|p| p.strip_prefix(&root_path).ok()) {
+            if let Some(relative) = relative.to_str() {
+                let _ = refresh_file_metrics(&mut updated.graph, relative, &config.encoding);
+            }
+        }
+
+        let changed_node_ids = changed_node_ids(&analysis.graph, &updated.graph);
+        analysis = updated;
+        if changed_node_ids.is_empty() {
+            continue;
+        }
+
+        let _ = app.emit(
+            GRAPH_UPDATED_EVENT,
+            GraphUpdate {
+                path: path.clone(),
+                changed_node_ids,
+                graph: analysis.graph.clone(),
+            },
+        );
+    }
+}
+
+/// Configuration the watched project is scanned with, for its excluded directories
+fn watched_config(root_path: &Path, parser_id: &str) -> Result<ParserConfig, String> {
+    let manifest = ProjectManifest::load(root_path)?.unwrap_or_default();
+    let mut config = find_parser(parser_id)?.default_config();
+    manifest.apply_to_config(&mut config);
+    Ok(config)
+}
+
+/// Paths an event may have changed, none for reads
+///
+/// Renames list both paths, so a file renamed over a source file (editors
+/// saving through a temporary file) is there.
+fn changed_paths(event: Event) -> Vec<PathBuf> {
+    match event.kind {
+        EventKind::Access(_) => Vec::new(),
+        _ => event.paths,
+    }
+}
+
+/// Whether changes to these paths may change the graph
+///
+/// Files in excluded directories don't. Other files do when the parser
+/// handles them or their extension is one of a file it parsed, which leaves
+/// out editor backups and swap files (`User.php~`, `.User.php.swp`).
+fn affects_graph(
+    paths: &[PathBuf],
+    root_path: &Path,
+    analysis: &ProjectAnalysis,
+    config: &ParserConfig,
+) -> bool {
+    let parser = match find_parser(&analysis.parser_id) {
+        Ok(parser) => parser,
+        Err(_) => return false,
+    };
+    let extensions: HashSet<String> = analysis
+        .parse_result
+        .files
+        .iter()
+        .map(|f| f.source.extension.to_lowercase())
+        .collect();
+
+    paths.iter().any(|path| {
+        let relative = path.strip_prefix(root_path).unwrap_or(path);
+        let excluded = relative
+            .parent()
+            .into_iter()
+            .flat_map(|dir| dir.components())
+            .any(|c| {
+                config
+                    .exclude_dirs
+                    .iter()
+                    .any(|d| c.as_os_str() == d.as_str())
+            });
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        !excluded && (parser.can_handle_file(path) || extensions.contains(&extension))
+    })
+}
+
+/// IDs of the nodes that differ between two graphs of the project, or whose
+/// edges do
+fn changed_node_ids(before: &UnifiedGraph, after: &UnifiedGraph) -> Vec<String> {
+    let diff = before.diff(after);
+
+    let ids = diff
+        .added_nodes
+        .iter()
+        .chain(&diff.removed_nodes)
+        .map(|n| &n.id)
+        .chain(
+            diff.moved_nodes
+                .iter()
+                .flat_map(|c| [&c.before.id, &c.after.id]),
+        )
+        .chain(diff.changed_nodes.iter().map(|c| &c.id))
+        .chain(
+            diff.added_edges
+                .iter()
+                .chain(&diff.removed_edges)
+                .flat_map(|e| [&e.source, &e.target]),
+        );
+
+    let mut seen = HashSet::new();
+    ids.filter(|id| seen.insert(*id)).cloned().collect()
+}
@@ -1,6 +1,20 @@
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use super::ProjectType;
+use super::PARSER_REGISTRY;
+use crate::parsers::common::has_files_with_extension;
+
+/// How many directory levels below the root `detect_all` looks for projects
+const MONOREPO_MAX_DEPTH: usize = 2;
+
+/// Confidence a directory needs to count as a project in `detect_all`
+///
+/// Markers alone (composer.json, package.json + sources, *.dpr + *.pas) reach it,
+/// a few loose source files one level down don't.
+const MONOREPO_MIN_CONFIDENCE: f32 = 0.5;
+
+/// Dependency directories, never a project of the repository
+const DEPENDENCY_DIRS: [&str; 2] = ["vendor", "node_modules"];
 
 /// Result of project type detection
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +36,10 @@ pub struct DetectionResult {
 
     /// Secondary types detected (for multi-language projects)
     pub secondary_types: Vec<(ProjectType, f32)>,
+
+    /// Major version of the framework (Laravel 10 is 10), when the parser reads it
+    #[serde(default)]
+    pub framework_version: Option<u32>,
 }
 
 impl Default for DetectionResult {
@@ -33,6 +51,7 @@ impl Default for DetectionResult {
             marker_files_found: Vec::new(),
             is_multi_language: false,
             secondary_types: Vec::new(),
+            framework_version: None,
         }
     }
 }
@@ -42,31 +61,52 @@ pub struct ProjectDetector;
 
 impl ProjectDetector {
     /// Detect project type from directory
+    ///
+    /// Every registered parser scores the directory with its own
+    /// `detect_markers`. Node.js and plain PHP, which have no parser yet,
+    /// are scored here so they still show up as (secondary) types.
     pub fn detect(root_path: &Path) -> DetectionResult {
-        let mut scores: Vec<(ProjectType, f32, Vec<String>)> = Vec::new();
+        let mut scores: Vec<(ProjectType, f32, Vec<String>, String)> = Vec::new();
 
-        // Check for Delphi
-        let (delphi_score, delphi_markers) = Self::detect_delphi(root_path);
-        if delphi_score > 0.0 {
-            scores.push((ProjectType::Delphi, delphi_score, delphi_markers));
+        for parser in PARSER_REGISTRY.parsers() {
+            let (score, markers) = parser.detect_markers(root_path);
+            if score > 0.0 {
+                let info = parser.info();
+                scores.push((info.project_type, score, markers, info.id));
+            }
         }
 
-        // Check for Laravel
-        let (laravel_score, laravel_markers) = Self::detect_laravel(root_path);
-        if laravel_score > 0.0 {
-            scores.push((ProjectType::Laravel, laravel_score, laravel_markers));
+        // Lumen projects go through the Laravel parser, but are labelled as Lumen
+        if Self::is_lumen(root_path) {
+            for (project_type, ..) in scores.iter_mut() {
+                if *project_type == ProjectType::Laravel {
+                    *project_type = ProjectType::Lumen;
+                }
+            }
         }
 
         // Check for Node.js
         let (nodejs_score, nodejs_markers) = Self::detect_nodejs(root_path);
         if nodejs_score > 0.0 {
-            scores.push((ProjectType::NodeJs, nodejs_score, nodejs_markers));
+            scores.push((
+                ProjectType::NodeJs,
+                nodejs_score,
+                nodejs_markers,
+                "nodejs".to_string(),
+            ));
         }
 
-        // Check for generic PHP
+        // Check for generic PHP, unless it's surely a Laravel (or Lumen) project
+        let laravel_score = scores
+            .iter()
+            .find(|(project_type, ..)| {
+                matches!(project_type, ProjectType::Laravel | ProjectType::Lumen)
+            })
+            .map(|(_, score, ..)| *score)
+            .unwrap_or(0.0);
         let (php_score, php_markers) = Self::detect_php(root_path);
         if php_score > 0.0 && laravel_score < 0.5 {
-            scores.push((ProjectType::Php, php_score, php_markers));
+            scores.push((ProjectType::Php, php_score, php_markers, "php".to_string()));
         }
 
         // Sort by confidence descending
@@ -76,16 +116,19 @@ impl ProjectDetector {
             return DetectionResult::default();
         }
 
-        let (project_type, confidence, markers) = scores.remove(0);
-        let parser_id = Self::get_parser_id(&project_type);
+        let (project_type, confidence, markers, parser_id) = scores.remove(0);
 
         // Check for multi-language
         let secondary_types: Vec<(ProjectType, f32)> = scores
             .iter()
-            .filter(|(_, conf, _)| *conf > 0.3)
-            .map(|(pt, conf, _)| (pt.clone(), *conf))
+            .filter(|(_, conf, ..)| *conf > 0.3)
+            .map(|(pt, conf, ..)| (pt.clone(), *conf))
             .collect();
 
+        let framework_version = PARSER_REGISTRY
+            .parser(&parser_id)
+            .and_then(|parser| parser.framework_version(root_path));
+
         DetectionResult {
             project_type,
             confidence,
@@ -93,74 +136,78 @@ impl ProjectDetector {
             marker_files_found: markers,
             is_multi_language: !secondary_types.is_empty(),
             secondary_types,
+            framework_version,
         }
     }
 
-    fn detect_delphi(root_path: &Path) -> (f32, Vec<String>) {
-        let mut score = 0.0f32;
-        let mut markers = Vec::new();
-
-        // Check for project files
-        for ext in ["dpr", "dproj", "groupproj"] {
-            if Self::has_files_with_extension(root_path, ext) {
-                score += 0.4;
-                markers.push(format!("*.{}", ext));
-            }
-        }
-
-        // Check for .pas files
-        if Self::has_files_with_extension(root_path, "pas") {
-            score += 0.3;
-            markers.push("*.pas".to_string());
-        }
-
-        // Check for form files
-        if Self::has_files_with_extension(root_path, "dfm")
-            || Self::has_files_with_extension(root_path, "fmx")
-        {
-            score += 0.2;
-            markers.push("*.dfm/*.fmx".to_string());
-        }
-
-        (score.min(1.0), markers)
+    /// Detect every project of a monorepo / multi-root directory
+    ///
+    /// Detection runs on `root_path` and its subdirectories, up to two levels
+    /// deep. Descent stops at the first directory detected as a project, so a
+    /// project's own subfolders are never reported. Hidden and dependency
+    /// directories (`vendor`, `node_modules`) are skipped. Sorted by path.
+    pub fn detect_all(root_path: &Path) -> Vec<(PathBuf, DetectionResult)> {
+        let mut projects = Vec::new();
+        Self::detect_below(root_path, 0, &mut projects);
+        projects.sort_by(|a, b| a.0.cmp(&b.0));
+        projects
     }
 
-    fn detect_laravel(root_path: &Path) -> (f32, Vec<String>) {
-        let mut score = 0.0f32;
-        let mut markers = Vec::new();
+    fn detect_below(dir: &Path, depth: usize, projects: &mut Vec<(PathBuf, DetectionResult)>) {
+        let subdirectories = Self::candidate_subdirectories(dir);
+        let detection = Self::detect(dir);
 
-        // Check composer.json for laravel/framework
-        let composer_path = root_path.join("composer.json");
-        if composer_path.exists() {
-            if let Ok(content) = std::fs::read_to_string(&composer_path) {
-                if content.contains("laravel/framework") {
-                    score += 0.6;
-                    markers.push("composer.json (laravel/framework)".to_string());
-                }
+        if detection.project_type != ProjectType::Unknown
+            && detection.confidence >= MONOREPO_MIN_CONFIDENCE
+        {
+            // Source files are looked for two levels down, so a directory holding
+            // a project detects as that project too: it only counts when none of
+            // its subdirectories detects as the same project at least as surely
+            let is_container = depth < MONOREPO_MAX_DEPTH
+                && subdirectories.iter().any(|sub| {
+                    let sub_detection = Self::detect(sub);
+                    sub_detection.project_type == detection.project_type
+                        && sub_detection.confidence >= detection.confidence
+                });
+            if !is_container {
+                projects.push((dir.to_path_buf(), detection));
+                return;
             }
         }
 
-        // Check for artisan
-        if root_path.join("artisan").exists() {
-            score += 0.2;
-            markers.push("artisan".to_string());
-        }
-
-        // Check for Laravel directories
-        let laravel_dirs = [
-            "app/Http/Controllers",
-            "resources/views",
-            "routes",
-            "database/migrations",
-        ];
-        for dir in laravel_dirs {
-            if root_path.join(dir).is_dir() {
-                score += 0.05;
-                markers.push(dir.to_string());
+        if depth < MONOREPO_MAX_DEPTH {
+            for sub in subdirectories {
+                Self::detect_below(&sub, depth + 1, projects);
             }
         }
+    }
 
-        (score.min(1.0), markers)
+    /// Subdirectories that may hold a project (not hidden, not dependencies)
+    fn candidate_subdirectories(dir: &Path) -> Vec<PathBuf> {
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) => return Vec::new(),
+        };
+
+        entries
+            .flatten()
+            .filter(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                !name.starts_with('.') && !DEPENDENCY_DIRS.contains(&name.as_str())
+            })
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .collect()
+    }
+
+    /// Whether composer.json requires Lumen rather than the full Laravel framework
+    fn is_lumen(root_path: &Path) -> bool {
+        std::fs::read_to_string(root_path.join("composer.json"))
+            .map(|content| {
+                content.contains("laravel/lumen-framework")
+                    && !content.contains("laravel/framework")
+            })
+            .unwrap_or(false)
     }
 
     fn detect_nodejs(root_path: &Path) -> (f32, Vec<String>) {
@@ -180,16 +227,12 @@ impl ProjectDetector {
         }
 
         // Check for TypeScript/JavaScript files
-        if Self::has_files_with_extension(root_path, "ts")
-            || Self::has_files_with_extension(root_path, "tsx")
-        {
+        if has_files_with_extension(root_path, "ts") || has_files_with_extension(root_path, "tsx") {
             score += 0.2;
             markers.push("*.ts/*.tsx".to_string());
         }
 
-        if Self::has_files_with_extension(root_path, "js")
-            || Self::has_files_with_extension(root_path, "jsx")
-        {
+        if has_files_with_extension(root_path, "js") || has_files_with_extension(root_path, "jsx") {
             score += 0.1;
         }
 
@@ -207,51 +250,109 @@ impl ProjectDetector {
         }
 
         // Check for PHP files
-        if Self::has_files_with_extension(root_path, "php") {
+        if has_files_with_extension(root_path, "php") {
             score += 0.4;
             markers.push("*.php".to_string());
         }
 
         (score.min(1.0), markers)
     }
+}
 
-    fn has_files_with_extension(root_path: &Path, ext: &str) -> bool {
-        if let Ok(entries) = std::fs::read_dir(root_path) {
-            for entry in entries.flatten() {
-                let path = entry.path();
-                if path.is_file() {
-                    if let Some(file_ext) = path.extension() {
-                        if file_ext.to_str().unwrap_or("").eq_ignore_ascii_case(ext) {
-                            return true;
-                        }
-                    }
-                } else if path.is_dir() {
-                    // Check one level deep
-                    if let Ok(subentries) = std::fs::read_dir(&path) {
-                        for subentry in subentries.flatten() {
-                            let subpath = subentry.path();
-                            if subpath.is_file() {
-                                if let Some(file_ext) = subpath.extension() {
-                                    if file_ext.to_str().unwrap_or("").eq_ignore_ascii_case(ext) {
-                                        return true;
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-        false
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::common::fixtures::project_dir;
+
+    const LARAVEL: &[(&str, &str)] = &[
+        (
+            "composer.json",
+            r#"{"require": {"laravel/framework": "^11.0"}}"#,
+        ),
+        ("artisan", "#!/usr/bin/env php"),
+        ("app/Http/Controllers/Controller.php", "<?php"),
+        ("resources/views/welcome.blade.php", "<h1>Hi</h1>"),
+    ];
+
+    const DELPHI: &[(&str, &str)] = &[
+        ("Main.dpr", "program Main;"),
+        ("Main.dproj", "<Project/>"),
+        ("src/Unit1.pas", "unit Unit1;"),
+        ("src/Unit1.dfm", "object Form1: TForm1\nend"),
+    ];
+
+    /// Detection agrees with the detecting parser's own `detect_markers`
+    fn assert_agrees(root_path: &Path) -> DetectionResult {
+        let detection = ProjectDetector::detect(root_path);
+        let parser = PARSER_REGISTRY.parser(&detection.parser_id).unwrap();
+        let (confidence, markers) = parser.detect_markers(root_path);
+
+        assert_eq!(detection.confidence, confidence);
+        assert_eq!(detection.confidence, parser.detect_confidence(root_path));
+        assert_eq!(detection.marker_files_found, markers);
+        detection
     }
 
-    fn get_parser_id(project_type: &ProjectType) -> String {
-        match project_type {
-            ProjectType::Delphi => "delphi".to_string(),
-            ProjectType::Laravel => "laravel".to_string(),
-            ProjectType::NodeJs => "nodejs".to_string(),
-            ProjectType::Php => "php".to_string(),
-            _ => "unknown".to_string(),
-        }
+    #[test]
+    fn detection_agrees_with_each_parser() {
+        let laravel = project_dir(LARAVEL);
+        assert_eq!(
+            assert_agrees(laravel.path()).project_type,
+            ProjectType::Laravel
+        );
+
+        let delphi = project_dir(DELPHI);
+        assert_eq!(
+            assert_agrees(delphi.path()).project_type,
+            ProjectType::Delphi
+        );
+    }
+
+    #[test]
+    fn other_parsers_are_secondary_types() {
+        let files: Vec<(&str, &str)> = LARAVEL.iter().chain(DELPHI).copied().collect();
+        let mixed = project_dir(&files);
+
+        let detection = assert_agrees(mixed.path());
+
+        assert!(detection.is_multi_language);
+        let secondary: Vec<&ProjectType> =
+            detection.secondary_types.iter().map(|(t, _)| t).collect();
+        let expected = match detection.project_type {
+            ProjectType::Laravel => ProjectType::Delphi,
+            _ => ProjectType::Laravel,
+        };
+        assert!(
+            secondary.contains(&&expected),
+            "{:?}",
+            detection.secondary_types
+        );
+    }
+
+    #[test]
+    fn lumen_projects_are_parsed_as_laravel() {
+        let lumen = project_dir(&[
+            (
+                "composer.json",
+                r#"{"require": {"laravel/lumen-framework": "^10.0"}}"#,
+            ),
+            ("artisan", "#!/usr/bin/env php"),
+            ("app/Http/Controllers/Controller.php", "<?php"),
+            ("routes/web.php", "<?php"),
+        ]);
+
+        let detection = assert_agrees(lumen.path());
+        assert_eq!(detection.project_type, ProjectType::Lumen);
+        assert_eq!(detection.parser_id, "laravel");
+        assert!(!detection
+            .secondary_types
+            .iter()
+            .any(|(t, _)| *t == ProjectType::Php));
+
+        let laravel = project_dir(LARAVEL);
+        assert_eq!(
+            ProjectDetector::detect(laravel.path()).project_type,
+            ProjectType::Laravel
+        );
     }
 }
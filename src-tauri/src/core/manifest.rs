@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::{DetectionResult, PARSER_REGISTRY};
+use crate::graph::Layer;
+use crate::models::UnifiedGraph;
+use crate::parsers::ParserConfig;
+
+/// Name of the project manifest, at the project root
+pub const MANIFEST_FILE: &str = ".sft.json";
+
+/// Project manifest (`.sft.json`) pinning how a repository is analyzed
+///
+/// Every field is optional, so a team only writes down what it wants pinned.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProjectManifest {
+    /// Parser ID to use instead of detection
+    pub parser: Option<String>,
+
+    /// Directory names to exclude, on top of the parser's defaults
+    pub exclude: Vec<String>,
+
+    /// File extensions to scan (empty = all the parser supports)
+    pub include: Vec<String>,
+
+    /// Globs of paths to scan, relative to the root (empty = everything)
+    pub include_globs: Vec<String>,
+
+    /// Globs of paths to exclude, on top of the parser's defaults
+    pub exclude_globs: Vec<String>,
+
+    /// Skip paths ignored by `.gitignore` files (parser default when unset)
+    pub respect_gitignore: Option<bool>,
+
+    /// Don't parse files larger than this many bytes
+    pub max_file_size_bytes: Option<u64>,
+
+    /// Language-specific parser options
+    pub options: HashMap<String, serde_json::Value>,
+
+    /// Layer of node types, by type key (`"custom:policy": "domain"`)
+    pub layers: HashMap<String, Layer>,
+
+    /// Notes attached to the nodes of a file or directory, by relative path
+    pub annotations: HashMap<String, String>,
+}
+
+impl ProjectManifest {
+    /// Load the manifest of a project, if it has one
+    pub fn load(root_path: &Path) -> Result<Option<Self>, String> {
+        let path = root_path.join(MANIFEST_FILE);
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+
+        serde_json::from_str(&content)
+            .map(Some)
+            .map_err(|e| format!("Invalid manifest {}: {}", path.display(), e))
+    }
+
+    /// Override a detection result with the pinned parser
+    pub fn pin_detection(&self, mut detection: DetectionResult) -> DetectionResult {
+        if let Some(ref parser_id) = self.parser {
+            detection.parser_id = parser_id.clone();
+            detection.confidence = 1.0;
+            detection.marker_files_found.push(MANIFEST_FILE.to_string());
+            if let Some(info) = PARSER_REGISTRY.get(parser_id) {
+                detection.project_type = info.project_type.clone();
+            }
+        }
+        detection
+    }
+
+    /// Add the manifest's excludes, includes and options to a parser config
+    pub fn apply_to_config(&self, config: &mut ParserConfig) {
+        for dir in &self.exclude {
+            if !config.exclude_dirs.contains(dir) {
+                config.exclude_dirs.push(dir.clone());
+            }
+        }
+        if !self.include.is_empty() {
+            config.include_extensions = self.include.clone();
+        }
+        if !self.include_globs.is_empty() {
+            config.include_globs = self.include_globs.clone();
+        }
+        for glob in &self.exclude_globs {
+            if !config.exclude_globs.contains(glob) {
+                config.exclude_globs.push(glob.clone());
+            }
+        }
+        if let Some(respect_gitignore) = self.respect_gitignore {
+            config.respect_gitignore = respect_gitignore;
+        }
+        if self.max_file_size_bytes.is_some() {
+            config.max_file_size_bytes = self.max_file_size_bytes;
+        }
+        for (key, value) in &self.options {
+            config.language_options.insert(key.clone(), value.clone());
+        }
+    }
+
+    /// Tag graph nodes with their pinned `layer` and `annotation`
+    ///
+    /// An annotation applies to the file at its path and to everything below
+    /// it when the path is a directory. The most specific path wins.
+    pub fn apply_to_graph(&self, graph: &mut UnifiedGraph) {
+        for node in &mut graph.nodes {
+            if let Some(layer) = self.layers.get(&node.node_type.as_key()) {
+                node.metadata.extra.insert(
+                    "layer".to_string(),
+                    serde_json::to_value(layer).unwrap_or_default(),
+                );
+            }
+
+            let annotation = node.file_path.as_deref().and_then(|file| {
+                self.annotations
+                    .iter()
+                    .filter(|(path, _)| covers(path, file))
+                    .max_by_key(|(path, _)| path.len())
+                    .map(|(_, note)| note)
+            });
+            if let Some(note) = annotation {
+                node.metadata.extra.insert(
+                    "annotation".to_string(),
+                    serde_json::Value::String(note.clone()),
+                );
+            }
+        }
+    }
+}
+
+/// Check if `path` is `file` or one of its parent directories
+fn covers(path: &str, file: &str) -> bool {
+    file.strip_prefix(path.trim_end_matches('/'))
+        .map(|rest| rest.is_empty() || rest.starts_with('/'))
+        .unwrap_or(false)
+}
@@ -1,9 +1,11 @@
-// Core module - Project types, detection, and registry
+// Core module - Project types, detection, registry and manifest
 
 mod project_type;
 mod detection;
 mod registry;
+mod manifest;
 
 pub use project_type::*;
 pub use detection::*;
 pub use registry::*;
+pub use manifest::*;
@@ -10,6 +10,9 @@ pub enum ProjectType {
     /// Laravel PHP framework
     Laravel,
 
+    /// Lumen, the Laravel micro-framework (parsed as Laravel)
+    Lumen,
+
     /// Node.js / TypeScript projects
     NodeJs,
 
@@ -46,6 +49,7 @@ impl std::fmt::Display for ProjectType {
         match self {
             ProjectType::Delphi => write!(f, "Delphi"),
             ProjectType::Laravel => write!(f, "Laravel (PHP)"),
+            ProjectType::Lumen => write!(f, "Lumen (PHP)"),
             ProjectType::NodeJs => write!(f, "Node.js / TypeScript"),
             ProjectType::Php => write!(f, "PHP"),
             ProjectType::CSharp => write!(f, "C# (.NET)"),
@@ -63,7 +67,7 @@ impl ProjectType {
     pub fn file_extensions(&self) -> Vec<&'static str> {
         match self {
             ProjectType::Delphi => vec!["pas", "dfm", "fmx", "dpr", "dpk", "dproj"],
-            ProjectType::Laravel | ProjectType::Php => vec!["php"],
+            ProjectType::Laravel | ProjectType::Lumen | ProjectType::Php => vec!["php"],
             ProjectType::NodeJs => vec!["js", "ts", "jsx", "tsx", "mjs", "cjs"],
             ProjectType::CSharp => vec!["cs", "csx"],
             ProjectType::Java => vec!["java"],
@@ -79,6 +83,7 @@ impl ProjectType {
         match self {
             ProjectType::Delphi => "#E31D1D",
             ProjectType::Laravel => "#FF2D20",
+            ProjectType::Lumen => "#E74430",
             ProjectType::Php => "#777BB4",
             ProjectType::NodeJs => "#339933",
             ProjectType::CSharp => "#512BD4",
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 use super::ProjectType;
+use crate::parsers::delphi::DelphiParser;
+use crate::parsers::laravel::LaravelParser;
+use crate::parsers::ProjectParser;
 
 /// Information about a registered parser
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,9 +38,11 @@ pub struct ParserInfo {
     pub is_available: bool,
 }
 
-/// Parser registry - stores information about available parsers
+/// Parser registry - stores information about available parsers and the
+/// implementations of those that are
 pub struct ParserRegistry {
     parsers: Vec<ParserInfo>,
+    implementations: Vec<(String, Box<dyn ProjectParser>)>,
 }
 
 impl ParserRegistry {
@@ -45,6 +50,7 @@ impl ParserRegistry {
     pub fn new() -> Self {
         Self {
             parsers: Vec::new(),
+            implementations: Vec::new(),
         }
     }
 
@@ -52,42 +58,9 @@ impl ParserRegistry {
     pub fn with_defaults() -> Self {
         let mut registry = Self::new();
 
-        // Register Delphi parser
-        registry.register(ParserInfo {
-            id: "delphi".to_string(),
-            display_name: "Delphi / Object Pascal".to_string(),
-            description: "Parser for Delphi and Lazarus projects".to_string(),
-            version: "1.0.0".to_string(),
-            file_extensions: vec![
-                "pas".to_string(),
-                "dfm".to_string(),
-                "fmx".to_string(),
-                "dpr".to_string(),
-                "dpk".to_string(),
-            ],
-            marker_files: vec!["*.dpr".to_string(), "*.dproj".to_string()],
-            marker_dirs: vec![],
-            project_type: ProjectType::Delphi,
-            primary_color: "#E31D1D".to_string(),
-            is_available: true,
-        });
-
-        // Register Laravel parser
-        registry.register(ParserInfo {
-            id: "laravel".to_string(),
-            display_name: "Laravel (PHP)".to_string(),
-            description: "Parser for Laravel PHP framework projects".to_string(),
-            version: "1.0.0".to_string(),
-            file_extensions: vec!["php".to_string()],
-            marker_files: vec!["composer.json".to_string(), "artisan".to_string()],
-            marker_dirs: vec![
-                "app/Http/Controllers".to_string(),
-                "resources/views".to_string(),
-            ],
-            project_type: ProjectType::Laravel,
-            primary_color: "#FF2D20".to_string(),
-            is_available: true,
-        });
+        // Implemented parsers, listed with the info they report
+        registry.register_parser(Box::new(DelphiParser::new()));
+        registry.register_parser(Box::new(LaravelParser::new()));
 
         // Register Node.js parser (placeholder)
         registry.register(ParserInfo {
@@ -116,13 +89,37 @@ impl ParserRegistry {
         self.parsers.push(info);
     }
 
+    /// Register a parser implementation, under the info it reports
+    pub fn register_parser(&mut self, parser: Box<dyn ProjectParser>) {
+        let info = parser.info();
+        self.implementations.push((info.id.clone(), parser));
+        self.register(info);
+    }
+
+    /// Get the implementation of a parser by ID
+    pub fn parser(&self, id: &str) -> Option<&dyn ProjectParser> {
+        self.implementations
+            .iter()
+            .find(|(parser_id, _)| parser_id == id)
+            .map(|(_, parser)| parser.as_ref())
+    }
+
+    /// All registered parser implementations
+    pub fn parsers(&self) -> impl Iterator<Item = &dyn ProjectParser> {
+        self.implementations.iter().map(|(_, parser)| parser.as_ref())
+    }
+
     /// Get parser by ID
     pub fn get(&self, id: &str) -> Option<&ParserInfo> {
         self.parsers.iter().find(|p| p.id == id)
     }
 
-    /// Get parser for a project type
+    /// Get parser for a project type (Lumen projects use the Laravel parser)
     pub fn get_for_type(&self, project_type: &ProjectType) -> Option<&ParserInfo> {
+        let project_type = match project_type {
+            ProjectType::Lumen => &ProjectType::Laravel,
+            other => other,
+        };
         self.parsers.iter().find(|p| p.project_type == *project_type)
     }
 
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use crate::models::UnifiedGraph;
+
+/// Render a graph as two CSV sheets, `(nodes, edges)`
+///
+/// Nodes: id, name, qualified_name, type, language, file_path, size,
+/// in_degree, out_degree. Edges: id, source, target, type, weight, label.
+/// Fields are quoted per RFC 4180, rows end with CRLF.
+pub fn export_csv(graph: &UnifiedGraph) -> (String, String) {
+    let mut in_degrees: HashMap<&str, usize> = HashMap::new();
+    let mut out_degrees: HashMap<&str, usize> = HashMap::new();
+    for edge in &graph.edges {
+        *in_degrees.entry(edge.target.as_str()).or_insert(0) += 1;
+        *out_degrees.entry(edge.source.as_str()).or_insert(0) += 1;
+    }
+
+    let mut nodes = String::new();
+    push_row(
+        &mut nodes,
+        &[
+            "id",
+            "name",
+            "qualified_name",
+            "type",
+            "language",
+            "file_path",
+            "size",
+            "in_degree",
+            "out_degree",
+        ],
+    );
+    for node in &graph.nodes {
+        let in_degree = in_degrees.get(node.id.as_str()).copied().unwrap_or(0);
+        let out_degree = out_degrees.get(node.id.as_str()).copied().unwrap_or(0);
+        push_row(
+            &mut nodes,
+            &[
+                &node.id,
+                &node.name,
+                &node.qualified_name,
+                &node.node_type.as_key(),
+                &node.language,
+                node.file_path.as_deref().unwrap_or(""),
+                &node.size.to_string(),
+                &in_degree.to_string(),
+                &out_degree.to_string(),
+            ],
+        );
+    }
+
+    let mut edges = String::new();
+    push_row(
+        &mut edges,
+        &["id", "source", "target", "type", "weight", "label"],
+    );
+    for edge in &graph.edges {
+        push_row(
+            &mut edges,
+            &[
+                &edge.id,
+                &edge.source,
+                &edge.target,
+                &edge.edge_type.as_key(),
+                &edge.weight.to_string(),
+                edge.label.as_deref().unwrap_or(""),
+            ],
+        );
+    }
+
+    (nodes, edges)
+}
+
+fn push_row(out: &mut String, fields: &[&str]) {
+    let fields: Vec<String> = fields.iter().map(|field| quote(field)).collect();
+    out.push_str(&fields.join(","));
+    out.push_str("\r\n");
+}
+
+/// Quote a field holding a comma, quote or line break, doubling its quotes
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{UnifiedEdge, UnifiedEdgeType, UnifiedNode, UnifiedNodeType};
+
+    #[test]
+    fn fields_with_commas_quotes_and_line_breaks_are_quoted() {
+        let mut graph = UnifiedGraph::new();
+        graph.add_node(
+            UnifiedNode::new(
+                "a".to_string(),
+                UnifiedNodeType::Class,
+                "Money, Inc".to_string(),
+            )
+            .with_file("app/Money.php".to_string()),
+        );
+        graph.add_node(UnifiedNode::new(
+            "b".to_string(),
+            UnifiedNodeType::Class,
+            "Say \"hi\"".to_string(),
+        ));
+        let edge = UnifiedEdge::new("a".to_string(), "b".to_string(), UnifiedEdgeType::Uses)
+            .with_label("line\nbreak");
+        let edge_id = edge.id.clone();
+        graph.add_edge(edge);
+
+        let (nodes, edges) = export_csv(&graph);
+        let nodes: Vec<&str> = nodes.split("\r\n").collect();
+        assert_eq!(
+            nodes[0],
+            "id,name,qualified_name,type,language,file_path,size,in_degree,out_degree"
+        );
+        assert!(nodes[1].starts_with("a,\"Money, Inc\",\"Money, Inc\",class,"));
+        let size = graph.nodes[0].size;
+        assert!(nodes[1].ends_with(&format!(",app/Money.php,{},0,1", size)));
+        assert!(nodes[2].starts_with("b,\"Say \"\"hi\"\"\",\"Say \"\"hi\"\"\",class,"));
+        assert!(nodes[2].ends_with(",1,0"));
+        assert_eq!(nodes[3], "");
+
+        assert_eq!(
+            edges,
+            format!(
+                "id,source,target,type,weight,label\r\n{},a,b,uses,1,\"line\nbreak\"\r\n",
+                edge_id
+            )
+        );
+    }
+}
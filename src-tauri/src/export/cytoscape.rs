@@ -0,0 +1,54 @@
+use crate::models::UnifiedGraph;
+
+/// Render a graph as Cytoscape.js elements JSON
+///
+/// `{ elements: { nodes: [{ data }], edges: [{ data }] } }`, loadable with
+/// `cy.add(json.elements)`. Node and edge types are their keys (`controller`,
+/// `custom:policy`), stored positions become Cytoscape positions.
+pub fn export_cytoscape(graph: &UnifiedGraph) -> serde_json::Value {
+    let nodes: Vec<serde_json::Value> = graph
+        .nodes
+        .iter()
+        .map(|node| {
+            let mut element = serde_json::json!({
+                "data": {
+                    "id": node.id,
+                    "label": node.label,
+                    "type": node.node_type.as_key(),
+                    "qualified_name": node.qualified_name,
+                    "size": node.size,
+                    "language": node.language,
+                    "file_path": node.file_path,
+                },
+            });
+            if let Some(ref position) = node.position {
+                element["position"] = serde_json::json!({ "x": position.x, "y": position.y });
+            }
+            element
+        })
+        .collect();
+
+    let edges: Vec<serde_json::Value> = graph
+        .edges
+        .iter()
+        .map(|edge| {
+            serde_json::json!({
+                "data": {
+                    "id": edge.id,
+                    "source": edge.source,
+                    "target": edge.target,
+                    "type": edge.edge_type.as_key(),
+                    "label": edge.label,
+                    "weight": edge.weight,
+                },
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "elements": {
+            "nodes": nodes,
+            "edges": edges,
+        },
+    })
+}
@@ -0,0 +1,47 @@
+use crate::models::UnifiedGraph;
+
+use super::edge_caption;
+
+/// Render a graph in Graphviz DOT format
+pub fn export_dot(graph: &UnifiedGraph) -> String {
+    let mut out = String::new();
+
+    out.push_str(&format!("digraph \"{}\" {{\n", escape(&graph.metadata.project_name)));
+    out.push_str("    rankdir=LR;\n");
+    out.push_str("    node [shape=box, style=rounded];\n\n");
+
+    for node in &graph.nodes {
+        out.push_str(&format!(
+            "    \"{}\" [label=\"{}\", tooltip=\"{}\", type=\"{}\"];\n",
+            escape(&node.id),
+            escape(&node.label),
+            escape(&node.qualified_name),
+            escape(&node.node_type.as_key()),
+        ));
+    }
+
+    if !graph.edges.is_empty() {
+        out.push('\n');
+    }
+
+    for edge in &graph.edges {
+        out.push_str(&format!(
+            "    \"{}\" -> \"{}\" [label=\"{}\", type=\"{}\"];\n",
+            escape(&edge.source),
+            escape(&edge.target),
+            escape(&edge_caption(edge)),
+            escape(&edge.edge_type.as_key()),
+        ));
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// Escape a DOT double-quoted string
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
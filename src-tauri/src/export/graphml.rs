@@ -0,0 +1,81 @@
+use crate::models::UnifiedGraph;
+
+/// Render a graph in GraphML (yEd, Gephi, Cytoscape desktop)
+pub fn export_graphml(graph: &UnifiedGraph) -> String {
+    let mut out = String::new();
+
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+
+    let keys = [
+        ("label", "node"),
+        ("type", "node"),
+        ("qualified_name", "node"),
+        ("file_path", "node"),
+        ("language", "node"),
+        ("edge_type", "edge"),
+        ("edge_label", "edge"),
+        ("detail", "edge"),
+        ("weight", "edge"),
+    ];
+    for (name, domain) in keys {
+        let attr_type = if name == "weight" { "double" } else { "string" };
+        out.push_str(&format!(
+            "  <key id=\"{}\" for=\"{}\" attr.name=\"{}\" attr.type=\"{}\"/>\n",
+            name, domain, name, attr_type
+        ));
+    }
+
+    out.push_str(&format!(
+        "  <graph id=\"{}\" edgedefault=\"directed\">\n",
+        escape(&graph.metadata.project_name)
+    ));
+
+    for node in &graph.nodes {
+        out.push_str(&format!("    <node id=\"{}\">\n", escape(&node.id)));
+        push_data(&mut out, "label", &node.label);
+        push_data(&mut out, "type", &node.node_type.as_key());
+        push_data(&mut out, "qualified_name", &node.qualified_name);
+        if let Some(ref path) = node.file_path {
+            push_data(&mut out, "file_path", path);
+        }
+        push_data(&mut out, "language", &node.language);
+        out.push_str("    </node>\n");
+    }
+
+    for edge in &graph.edges {
+        out.push_str(&format!(
+            "    <edge id=\"{}\" source=\"{}\" target=\"{}\">\n",
+            escape(&edge.id),
+            escape(&edge.source),
+            escape(&edge.target)
+        ));
+        push_data(&mut out, "edge_type", &edge.edge_type.as_key());
+        if let Some(ref label) = edge.label {
+            push_data(&mut out, "edge_label", label);
+        }
+        if let Some(ref detail) = edge.detail {
+            push_data(&mut out, "detail", detail);
+        }
+        push_data(&mut out, "weight", &edge.weight.to_string());
+        out.push_str("    </edge>\n");
+    }
+
+    out.push_str("  </graph>\n");
+    out.push_str("</graphml>\n");
+    out
+}
+
+fn push_data(out: &mut String, key: &str, value: &str) {
+    out.push_str(&format!("      <data key=\"{}\">{}</data>\n", key, escape(value)));
+}
+
+/// Escape XML text and attribute values
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
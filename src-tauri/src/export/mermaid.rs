@@ -0,0 +1,40 @@
+use crate::models::UnifiedGraph;
+
+use super::edge_caption;
+
+/// Render a graph as a Mermaid flowchart
+pub fn export_mermaid(graph: &UnifiedGraph) -> String {
+    let mut out = String::from("flowchart LR\n");
+
+    for node in &graph.nodes {
+        out.push_str(&format!("    {}[\"{}\"]\n", node_ref(&node.id), escape(&node.label)));
+    }
+
+    for edge in &graph.edges {
+        out.push_str(&format!(
+            "    {} -->|\"{}\"| {}\n",
+            node_ref(&edge.source),
+            escape(&edge_caption(edge)),
+            node_ref(&edge.target),
+        ));
+    }
+
+    out
+}
+
+/// Mermaid node identifier (IDs must be plain word characters)
+fn node_ref(id: &str) -> String {
+    let cleaned: String = id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("n{}", cleaned)
+}
+
+/// Escape text inside a quoted Mermaid label
+fn escape(value: &str) -> String {
+    value
+        .replace('"', "#quot;")
+        .replace('|', "#124;")
+        .replace('\n', " ")
+}
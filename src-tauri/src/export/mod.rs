@@ -0,0 +1,129 @@
+// Export module - Graph serialization to external formats
+
+mod dot;
+mod mermaid;
+mod graphml;
+mod sarif;
+mod cytoscape;
+mod csv;
+mod plantuml;
+
+pub use dot::*;
+pub use mermaid::*;
+pub use graphml::*;
+pub use sarif::*;
+pub use cytoscape::*;
+pub use csv::*;
+pub use plantuml::*;
+
+use std::path::Path;
+use thiserror::Error;
+
+use crate::models::{UnifiedEdge, UnifiedGraph};
+
+/// Export error types
+#[derive(Error, Debug)]
+pub enum ExportError {
+    #[error("Unknown export format: {0}")]
+    UnknownFormat(String),
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+pub type ExportResult<T> = Result<T, ExportError>;
+
+/// Supported export formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Dot,
+    Mermaid,
+    GraphMl,
+    Sarif,
+    Cytoscape,
+    Csv,
+    PlantUml,
+}
+
+impl ExportFormat {
+    /// Parse a format name (case-insensitive)
+    pub fn from_name(name: &str) -> ExportResult<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "json" => Ok(ExportFormat::Json),
+            "dot" | "graphviz" => Ok(ExportFormat::Dot),
+            "mermaid" | "mmd" => Ok(ExportFormat::Mermaid),
+            "graphml" => Ok(ExportFormat::GraphMl),
+            "sarif" => Ok(ExportFormat::Sarif),
+            "cytoscape" | "cyjs" => Ok(ExportFormat::Cytoscape),
+            "csv" => Ok(ExportFormat::Csv),
+            "plantuml" | "puml" => Ok(ExportFormat::PlantUml),
+            _ => Err(ExportError::UnknownFormat(name.to_string())),
+        }
+    }
+
+    /// Conventional file extension
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Dot => "dot",
+            ExportFormat::Mermaid => "mmd",
+            ExportFormat::GraphMl => "graphml",
+            ExportFormat::Sarif => "sarif",
+            ExportFormat::Cytoscape => "cyjs",
+            ExportFormat::Csv => "csv",
+            ExportFormat::PlantUml => "puml",
+        }
+    }
+}
+
+/// Render a graph in the given format
+///
+/// CSV renders the nodes sheet, a blank line, then the edges sheet; use
+/// `write_graph` for one file per sheet.
+pub fn export_graph(graph: &UnifiedGraph, format: ExportFormat) -> ExportResult<String> {
+    match format {
+        ExportFormat::Json => Ok(serde_json::to_string_pretty(graph)?),
+        ExportFormat::Dot => Ok(export_dot(graph)),
+        ExportFormat::Mermaid => Ok(export_mermaid(graph)),
+        ExportFormat::GraphMl => Ok(export_graphml(graph)),
+        ExportFormat::Sarif => Ok(serde_json::to_string_pretty(&export_sarif(graph))?),
+        ExportFormat::Cytoscape => Ok(serde_json::to_string_pretty(&export_cytoscape(graph))?),
+        ExportFormat::Csv => {
+            let (nodes, edges) = export_csv(graph);
+            Ok(format!("{}\r\n{}", nodes, edges))
+        }
+        ExportFormat::PlantUml => Ok(export_plantuml(graph)),
+    }
+}
+
+/// Render a graph and write it to a file
+///
+/// CSV is written as two files next to `out`, `<stem>.nodes.csv` and
+/// `<stem>.edges.csv`.
+pub fn write_graph(graph: &UnifiedGraph, format: ExportFormat, out: &Path) -> ExportResult<()> {
+    if format == ExportFormat::Csv {
+        let (nodes, edges) = export_csv(graph);
+        let stem = out.file_stem().and_then(|s| s.to_str()).unwrap_or("graph");
+        std::fs::write(out.with_file_name(format!("{}.nodes.csv", stem)), nodes)?;
+        std::fs::write(out.with_file_name(format!("{}.edges.csv", stem)), edges)?;
+        return Ok(());
+    }
+
+    let content = export_graph(graph, format)?;
+    std::fs::write(out, content)?;
+    Ok(())
+}
+
+/// Text shown on an edge: HTTP method and URI for routes, otherwise the edge type
+fn edge_caption(edge: &UnifiedEdge) -> String {
+    match (&edge.label, &edge.detail) {
+        (Some(label), Some(detail)) => format!("{} {}", label, detail),
+        (Some(label), None) => label.clone(),
+        (None, Some(detail)) => detail.clone(),
+        (None, None) => edge.edge_type.as_key(),
+    }
+}
@@ -0,0 +1,238 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::graph::is_file_node;
+use crate::models::{UnifiedEdgeType, UnifiedGraph, UnifiedNode, UnifiedNodeType};
+
+/// Render the classes, interfaces and traits of a graph as a PlantUML class diagram
+///
+/// Controllers and models count as classes. Declarations are grouped into
+/// packages by namespace; inheritance and realization arrows come from
+/// `Extends`/`Implements` edges and from the nodes' `parent_class` and
+/// `implements` metadata, for parents declared in the graph.
+pub fn export_plantuml(graph: &UnifiedGraph) -> String {
+    let types: Vec<&UnifiedNode> = graph.nodes.iter().filter(|n| is_type(n)).collect();
+
+    let mut by_qualified: HashMap<&str, &UnifiedNode> = HashMap::new();
+    let mut by_name: HashMap<&str, Vec<&UnifiedNode>> = HashMap::new();
+    for node in &types {
+        by_qualified.insert(node.qualified_name.as_str(), node);
+        by_name.entry(node.name.as_str()).or_default().push(node);
+    }
+
+    // Parent written as in the source: qualified, relative to the node's
+    // namespace or a short name declared once
+    let resolve = |node: &UnifiedNode, parent: &str| -> Option<&UnifiedNode> {
+        let parent = parent.trim().trim_start_matches('\\');
+        let relative = namespace(node).map(|ns| format!("{}\\{}", ns, parent));
+        by_qualified
+            .get(parent)
+            .or_else(|| relative.and_then(|q| by_qualified.get(q.as_str())))
+            .copied()
+            .or_else(|| {
+                let short_name = parent.rsplit('\\').next().unwrap_or(parent);
+                match by_name.get(short_name).map(|n| n.as_slice()) {
+                    Some([single]) => Some(*single),
+                    _ => None,
+                }
+            })
+    };
+
+    let mut out = String::from("@startuml\n");
+    // Namespaces are quoted strings, not `.`-separated package paths
+    out.push_str("set separator none\n");
+    out.push_str("hide empty members\n\n");
+
+    let mut packages: BTreeMap<&str, Vec<&UnifiedNode>> = BTreeMap::new();
+    for node in &types {
+        packages
+            .entry(namespace(node).unwrap_or(""))
+            .or_default()
+            .push(node);
+    }
+    for (package, nodes) in &packages {
+        let indent = if package.is_empty() { "" } else { "  " };
+        if !package.is_empty() {
+            out.push_str(&format!("package \"{}\" {{\n", escape(package)));
+        }
+        for node in nodes {
+            out.push_str(indent);
+            out.push_str(&declaration(node));
+            out.push('\n');
+        }
+        if !package.is_empty() {
+            out.push_str("}\n");
+        }
+    }
+    out.push('\n');
+
+    let ids: HashSet<&str> = types.iter().map(|n| n.id.as_str()).collect();
+    let mut arrows: Vec<(&str, &str, &str)> = Vec::new();
+    for edge in &graph.edges {
+        let arrow = match edge.edge_type {
+            UnifiedEdgeType::Extends => "--|>",
+            UnifiedEdgeType::Implements => "..|>",
+            _ => continue,
+        };
+        if ids.contains(edge.source.as_str()) && ids.contains(edge.target.as_str()) {
+            arrows.push((edge.source.as_str(), arrow, edge.target.as_str()));
+        }
+    }
+    for node in &types {
+        let metadata = &node.metadata;
+        // Interfaces may extend several: `extends Countable, ArrayAccess`
+        let parents = metadata.parent_class.iter().flat_map(|p| p.split(','));
+        for parent in parents.filter_map(|p| resolve(node, p)) {
+            arrows.push((node.id.as_str(), "--|>", parent.id.as_str()));
+        }
+        for interface in metadata.implements.iter().flatten() {
+            if let Some(parent) = resolve(node, interface) {
+                arrows.push((node.id.as_str(), "..|>", parent.id.as_str()));
+            }
+        }
+    }
+
+    let mut seen = HashSet::new();
+    for (source, arrow, target) in arrows {
+        if source != target && seen.insert((source, arrow, target)) {
+            out.push_str(&format!("{} {} {}\n", alias(source), arrow, alias(target)));
+        }
+    }
+
+    out.push_str("@enduml\n");
+    out
+}
+
+fn is_type(node: &UnifiedNode) -> bool {
+    let is_class_like = matches!(
+        node.node_type,
+        UnifiedNodeType::Class
+            | UnifiedNodeType::Interface
+            | UnifiedNodeType::Trait
+            | UnifiedNodeType::Controller
+            | UnifiedNodeType::Model
+    );
+
+    // File nodes take the type of the class they declare, skip them
+    is_class_like && !is_file_node(node)
+}
+
+/// `App\Models` for `App\Models\User`, None outside a namespace
+fn namespace(node: &UnifiedNode) -> Option<&str> {
+    node.qualified_name
+        .rsplit_once('\\')
+        .map(|(ns, _)| ns)
+        .filter(|ns| !ns.is_empty())
+}
+
+/// `abstract class "User" as n_ab12 <<private>>`
+fn declaration(node: &UnifiedNode) -> String {
+    let keyword = match node.node_type {
+        UnifiedNodeType::Interface => "interface",
+        UnifiedNodeType::Trait => "class",
+        _ if node.metadata.is_abstract == Some(true) => "abstract class",
+        _ => "class",
+    };
+
+    let mut stereotypes = Vec::new();
+    if node.node_type == UnifiedNodeType::Trait {
+        stereotypes.push("<< (T,#FF7700) trait >>".to_string());
+    }
+    if let Some(visibility) = node.metadata.visibility.as_deref() {
+        if visibility != "public" {
+            stereotypes.push(format!("<<{}>>", escape(visibility)));
+        }
+    }
+
+    let mut line = format!(
+        "{} \"{}\" as {}",
+        keyword,
+        escape(&node.name),
+        alias(&node.id)
+    );
+    for stereotype in stereotypes {
+        line.push(' ');
+        line.push_str(&stereotype);
+    }
+    line
+}
+
+/// PlantUML element alias (word characters only)
+fn alias(id: &str) -> String {
+    let cleaned: String = id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("n_{}", cleaned)
+}
+
+/// Text inside a quoted PlantUML name, which can't hold quotes or line breaks
+fn escape(value: &str) -> String {
+    value.replace('"', "'").replace(['\r', '\n'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::UnifiedEdge;
+    use crate::parsers::common::generate_id;
+
+    fn node(id: &str, node_type: UnifiedNodeType, qualified_name: &str) -> UnifiedNode {
+        let name = qualified_name.rsplit('\\').next().unwrap().to_string();
+        let mut node = UnifiedNode::new(id.to_string(), node_type, name);
+        node.qualified_name = qualified_name.to_string();
+        node
+    }
+
+    #[test]
+    fn classes_are_grouped_by_namespace_with_their_parents() {
+        let mut base = node("base", UnifiedNodeType::Class, "App\\Models\\Base");
+        base.metadata.is_abstract = Some(true);
+        let mut user = node("user", UnifiedNodeType::Model, "App\\Models\\User");
+        user.metadata.parent_class = Some("Base".to_string());
+        user.metadata.implements = Some(vec!["\\App\\Contracts\\HasName".to_string()]);
+        let has_name = node(
+            "has-name",
+            UnifiedNodeType::Interface,
+            "App\\Contracts\\HasName",
+        );
+        let mut loggable = node("loggable", UnifiedNodeType::Trait, "Loggable");
+        loggable.metadata.visibility = Some("internal".to_string());
+        let file = node(
+            &generate_id("app/Models/User.php"),
+            UnifiedNodeType::Model,
+            "User",
+        )
+        .with_file("app/Models/User.php".to_string());
+
+        let mut graph = UnifiedGraph::new();
+        for node in [base, user, has_name, loggable, file] {
+            graph.add_node(node);
+        }
+        graph.add_edge(UnifiedEdge::new(
+            "user".to_string(),
+            "base".to_string(),
+            UnifiedEdgeType::Extends,
+        ));
+
+        assert_eq!(
+            export_plantuml(&graph),
+            "@startuml
+set separator none
+hide empty members
+
+class \"Loggable\" as n_loggable << (T,#FF7700) trait >> <<internal>>
+package \"App\\Contracts\" {
+  interface \"HasName\" as n_has_name
+}
+package \"App\\Models\" {
+  abstract class \"Base\" as n_base
+  class \"User\" as n_user
+}
+
+n_user --|> n_base
+n_user ..|> n_has_name
+@enduml
+"
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use crate::graph::{check_layering, default_laravel_rules, file_dependencies, find_cycles};
+use crate::models::UnifiedGraph;
+
+const CYCLE_RULE: &str = "dependency-cycle";
+const LAYERING_RULE: &str = "layering-violation";
+
+/// Render architecture findings (dependency cycles, layering violations) as SARIF 2.1.0
+///
+/// Code scanning tools (GitHub, VS Code SARIF viewer) can then show them
+/// next to the offending files.
+pub fn export_sarif(graph: &UnifiedGraph) -> serde_json::Value {
+    let mut results = Vec::new();
+
+    for cycle in find_cycles(&file_dependencies(graph)) {
+        let locations: Vec<serde_json::Value> = cycle.iter().map(|file| location(file)).collect();
+        results.push(serde_json::json!({
+            "ruleId": CYCLE_RULE,
+            "level": "warning",
+            "message": {
+                "text": format!("Files form a dependency cycle: {}", cycle.join(" -> ")),
+            },
+            "locations": locations,
+        }));
+    }
+
+    for violation in check_layering(graph, &default_laravel_rules()) {
+        let mut result = serde_json::json!({
+            "ruleId": LAYERING_RULE,
+            "level": "warning",
+            "message": {
+                "text": format!(
+                    "{} depends on {}: {}",
+                    violation.source.label,
+                    violation.target.label,
+                    violation.rule.reason.as_deref().unwrap_or("forbidden dependency"),
+                ),
+            },
+        });
+        if let Some(path) = violation.source.file_path.as_ref() {
+            result["locations"] = serde_json::json!([location(path)]);
+        }
+        results.push(result);
+    }
+
+    serde_json::json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "DevpWareSoft",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": [
+                        {
+                            "id": CYCLE_RULE,
+                            "shortDescription": { "text": "Files depend on each other in a cycle" },
+                        },
+                        {
+                            "id": LAYERING_RULE,
+                            "shortDescription": { "text": "Dependency breaks a layering rule" },
+                        },
+                    ],
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+fn location(path: &str) -> serde_json::Value {
+    serde_json::json!({
+        "physicalLocation": {
+            "artifactLocation": { "uri": path.replace('\\', "/") },
+        },
+    })
+}
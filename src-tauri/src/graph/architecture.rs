@@ -0,0 +1,238 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::UnifiedGraph;
+
+use super::{
+    average_coupling, check_layering, default_laravel_rules, file_dependencies, find_cycles,
+    is_dependency_edge, orphan_files, testable_files, untested_files,
+};
+
+/// Average coupling at or below which the coupling score is 100
+const COUPLING_IDEAL: f64 = 2.0;
+
+/// Average coupling at or above which the coupling score is 0
+const COUPLING_WORST: f64 = 12.0;
+
+/// Relative weight of each sub-score in the overall score
+///
+/// Weights don't need to sum to 1, they are normalized.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreWeights {
+    pub cycles: f64,
+    pub coupling: f64,
+    pub orphans: f64,
+    pub layering: f64,
+    pub untested: f64,
+}
+
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        Self {
+            cycles: 0.25,
+            coupling: 0.20,
+            orphans: 0.15,
+            layering: 0.20,
+            untested: 0.20,
+        }
+    }
+}
+
+/// Sub-scores, each from 0 (worst) to 100 (best)
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ArchitectureScores {
+    pub cycles: f64,
+    pub coupling: f64,
+    pub orphans: f64,
+    pub layering: f64,
+    pub untested: f64,
+}
+
+/// One-glance architecture health summary
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchitectureReport {
+    /// Number of files analyzed
+    pub file_count: usize,
+
+    /// Number of file dependency cycles
+    pub cycle_count: usize,
+
+    /// Number of files involved in at least one cycle
+    pub files_in_cycles: usize,
+
+    /// Average distinct files each file is coupled with
+    pub average_coupling: f64,
+
+    /// Share of files without any dependency (0.0 - 1.0)
+    pub orphan_ratio: f64,
+
+    /// Number of edges breaking the default layering rules
+    pub layering_violations: usize,
+
+    /// Share of class files without a matching test (0.0 - 1.0)
+    pub untested_ratio: f64,
+
+    /// Individual scores
+    pub scores: ArchitectureScores,
+
+    /// Weighted overall score (0 - 100)
+    pub overall: f64,
+
+    /// Letter grade (A - F)
+    pub grade: String,
+}
+
+impl ArchitectureReport {
+    /// Compute the report for a graph
+    ///
+    /// Scoring (each sub-score is 0 - 100):
+    /// - cycles:   100 * (1 - files_in_cycles / files)
+    /// - coupling: 100 at COUPLING_IDEAL or less, linearly down to 0 at COUPLING_WORST
+    /// - orphans:  100 * (1 - orphan_ratio)
+    /// - layering: 100 * (1 - violations / dependency_edges)
+    /// - untested: 100 * (1 - untested_ratio)
+    ///
+    /// The overall score is the weighted mean of the sub-scores, graded
+    /// A (>= 90), B (>= 80), C (>= 70), D (>= 60), F otherwise.
+    pub fn compute(graph: &UnifiedGraph, weights: &ScoreWeights) -> Self {
+        let deps = file_dependencies(graph);
+        let file_count = deps.len();
+
+        let cycles = find_cycles(&deps);
+        let files_in_cycles: usize = cycles.iter().map(|c| c.len()).sum();
+        let average_coupling = average_coupling(&deps);
+        let orphan_ratio = ratio(orphan_files(&deps).len(), file_count);
+
+        let dependency_edges = graph
+            .edges
+            .iter()
+            .filter(|e| is_dependency_edge(&e.edge_type))
+            .count();
+        let layering_violations = check_layering(graph, &default_laravel_rules()).len();
+
+        let untested_ratio = ratio(
+            untested_files(graph, &deps).len(),
+            testable_files(graph).len(),
+        );
+
+        let scores = ArchitectureScores {
+            cycles: 100.0 * (1.0 - ratio(files_in_cycles, file_count)),
+            coupling: 100.0
+                * (1.0
+                    - ((average_coupling - COUPLING_IDEAL) / (COUPLING_WORST - COUPLING_IDEAL))
+                        .clamp(0.0, 1.0)),
+            orphans: 100.0 * (1.0 - orphan_ratio),
+            layering: 100.0 * (1.0 - ratio(layering_violations, dependency_edges)),
+            untested: 100.0 * (1.0 - untested_ratio),
+        };
+
+        let overall = weighted_score(&scores, weights);
+
+        Self {
+            file_count,
+            cycle_count: cycles.len(),
+            files_in_cycles,
+            average_coupling,
+            orphan_ratio,
+            layering_violations,
+            untested_ratio,
+            scores,
+            overall,
+            grade: grade(overall).to_string(),
+        }
+    }
+}
+
+fn ratio(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        (part as f64 / total as f64).min(1.0)
+    }
+}
+
+fn weighted_score(scores: &ArchitectureScores, weights: &ScoreWeights) -> f64 {
+    let pairs = [
+        (scores.cycles, weights.cycles),
+        (scores.coupling, weights.coupling),
+        (scores.orphans, weights.orphans),
+        (scores.layering, weights.layering),
+        (scores.untested, weights.untested),
+    ];
+
+    let total_weight: f64 = pairs.iter().map(|(_, w)| w.max(0.0)).sum();
+    if total_weight == 0.0 {
+        return 0.0;
+    }
+
+    pairs.iter().map(|(s, w)| s * w.max(0.0)).sum::<f64>() / total_weight
+}
+
+fn grade(score: f64) -> &'static str {
+    match score {
+        s if s >= 90.0 => "A",
+        s if s >= 80.0 => "B",
+        s if s >= 70.0 => "C",
+        s if s >= 60.0 => "D",
+        _ => "F",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{UnifiedEdge, UnifiedEdgeType, UnifiedNode, UnifiedNodeType};
+    use crate::parsers::common::generate_id;
+
+    /// Services a -> b -> c, plus c -> a when `cyclic`
+    fn services(cyclic: bool) -> UnifiedGraph {
+        let mut graph = UnifiedGraph::new();
+        for name in ["A", "B", "C"] {
+            let path = format!("app/Services/{}.php", name);
+            graph.add_node(
+                UnifiedNode::new(generate_id(&path), UnifiedNodeType::Class, name.to_string())
+                    .with_file(path)
+                    .with_language("php"),
+            );
+        }
+        let mut uses = vec![("A", "B"), ("B", "C")];
+        if cyclic {
+            uses.push(("C", "A"));
+        }
+        for (source, target) in uses {
+            let id = |name: &str| generate_id(&format!("app/Services/{}.php", name));
+            graph.add_edge(UnifiedEdge::new(
+                id(source),
+                id(target),
+                UnifiedEdgeType::Uses,
+            ));
+        }
+        graph
+    }
+
+    #[test]
+    fn report_fields_are_populated() {
+        let report = ArchitectureReport::compute(&services(false), &ScoreWeights::default());
+
+        assert_eq!(report.file_count, 3);
+        assert_eq!(report.cycle_count, 0);
+        assert_eq!(report.files_in_cycles, 0);
+        assert!(report.average_coupling > 0.0);
+        assert_eq!(report.orphan_ratio, 0.0);
+        assert_eq!(report.layering_violations, 0);
+        assert_eq!(report.scores.cycles, 100.0);
+        assert!(report.overall > 0.0 && report.overall <= 100.0);
+        assert_eq!(report.grade, grade(report.overall));
+    }
+
+    #[test]
+    fn cycles_lower_the_score() {
+        let weights = ScoreWeights::default();
+        let acyclic = ArchitectureReport::compute(&services(false), &weights);
+        let cyclic = ArchitectureReport::compute(&services(true), &weights);
+
+        assert_eq!(cyclic.cycle_count, 1);
+        assert_eq!(cyclic.files_in_cycles, 3);
+        assert_eq!(cyclic.scores.cycles, 0.0);
+        assert!(cyclic.overall < acyclic.overall);
+    }
+}
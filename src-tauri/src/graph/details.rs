@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::models::{UnifiedEdge, UnifiedGraph, UnifiedNode};
+
+/// Longest source snippet `node_details` returns, in lines
+pub const MAX_SNIPPET_LINES: usize = 200;
+
+/// An edge of a node, with the node at its other end
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeNeighbor {
+    pub edge: UnifiedEdge,
+
+    /// `None` when the edge dangles (external unit, unresolved class, ...)
+    pub node: Option<UnifiedNode>,
+}
+
+/// Everything the UI shows about a selected node
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeDetails {
+    pub node: UnifiedNode,
+
+    /// Absolute path of the node's file, resolved against the graph root
+    pub file_path: Option<String>,
+
+    /// Edges leaving the node
+    pub outgoing: Vec<NodeNeighbor>,
+
+    /// Edges reaching the node
+    pub incoming: Vec<NodeNeighbor>,
+
+    /// Source lines `line_start..=line_end`, when the node has them and the
+    /// file is still readable
+    pub snippet: Option<String>,
+}
+
+/// Details of the node `node_id`, `None` if the graph has no such node
+pub fn node_details(graph: &UnifiedGraph, node_id: &str) -> Option<NodeDetails> {
+    let node = graph.find_node(node_id)?;
+    let nodes: HashMap<&str, &UnifiedNode> =
+        graph.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+    let neighbor = |edge: &UnifiedEdge, id: &str| NodeNeighbor {
+        edge: edge.clone(),
+        node: nodes.get(id).map(|n| (*n).clone()),
+    };
+
+    let file_path = node.file_path.as_deref().map(|path| {
+        Path::new(&graph.metadata.root_path)
+            .join(path)
+            .to_string_lossy()
+            .to_string()
+    });
+    let snippet = file_path
+        .as_deref()
+        .and_then(|path| source_snippet(Path::new(path), node));
+
+    Some(NodeDetails {
+        node: node.clone(),
+        file_path,
+        outgoing: graph
+            .edges_from(node_id)
+            .into_iter()
+            .map(|e| neighbor(e, &e.target))
+            .collect(),
+        incoming: graph
+            .edges_to(node_id)
+            .into_iter()
+            .map(|e| neighbor(e, &e.source))
+            .collect(),
+        snippet,
+    })
+}
+
+/// Lines of the node's declaration, capped to `MAX_SNIPPET_LINES`
+fn source_snippet(path: &Path, node: &UnifiedNode) -> Option<String> {
+    let start = node.line_start? as usize;
+    let end = node.line_end.map(|l| l as usize).unwrap_or(start).max(start);
+    let count = (end - start + 1).min(MAX_SNIPPET_LINES);
+
+    let bytes = std::fs::read(path).ok()?;
+    let content = String::from_utf8_lossy(&bytes);
+    let lines: Vec<&str> = content
+        .lines()
+        .skip(start.saturating_sub(1))
+        .take(count)
+        .collect();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
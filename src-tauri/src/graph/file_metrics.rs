@@ -0,0 +1,264 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use crate::models::{UnifiedGraph, UnifiedNode};
+use crate::parsers::common::{count_lines, decode_source, generate_id, CommentSyntax};
+
+lazy_static::lazy_static! {
+    // Match: one decision point (if, elseif, for, foreach, while, case, catch, &&, ||, and, or)
+    static ref DECISION_REGEX: Regex = Regex::new(
+        r"(?i)\b(?:if|elseif|for|foreach|while|case|catch|and|or)\b|&&|\|\|"
+    ).unwrap();
+
+    // Match: one Pascal decision point (if, for, while, until, case, except, and, or)
+    static ref PASCAL_DECISION_REGEX: Regex = Regex::new(
+        r"(?i)\b(?:if|for|while|until|case|except|and|or)\b"
+    ).unwrap();
+}
+
+/// Size and complexity of one source file
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FileMetrics {
+    /// Physical lines
+    pub total_lines: u64,
+
+    /// Lines with code on them (not blank, not only comments)
+    pub lines_of_code: u64,
+
+    /// Lines holding only comments
+    pub comment_lines: u64,
+
+    pub blank_lines: u64,
+
+    /// 1 + decision points, keyword based so it works for every language
+    pub cyclomatic_complexity: u32,
+}
+
+impl FileMetrics {
+    /// Compute the metrics of a file's content
+    ///
+    /// Comments are recognized by the syntax of `path`'s language, decision
+    /// points by its keywords outside comments and strings.
+    pub fn compute(path: &str, content: &str) -> Self {
+        let syntax = CommentSyntax::from_path(path);
+        let lines = count_lines(content, syntax);
+        let code = syntax.blank_comments_and_strings(content);
+        let decisions = match syntax {
+            CommentSyntax::Pascal => PASCAL_DECISION_REGEX.find_iter(&code).count(),
+            _ => DECISION_REGEX.find_iter(&code).count(),
+        };
+        Self {
+            total_lines: lines.total,
+            lines_of_code: lines.code,
+            comment_lines: lines.comment,
+            blank_lines: lines.blank,
+            cyclomatic_complexity: 1 + decisions as u32,
+        }
+    }
+
+    /// Metrics stored on a file node, if it has any
+    pub fn of_node(node: &UnifiedNode) -> Option<Self> {
+        let count = |key: &str| node.metadata.extra.get(key)?.as_u64();
+
+        Some(Self {
+            total_lines: count("total_lines")?,
+            lines_of_code: count("lines_of_code")?,
+            comment_lines: count("comment_lines")?,
+            blank_lines: count("blank_lines")?,
+            cyclomatic_complexity: count("cyclomatic_complexity")? as u32,
+        })
+    }
+
+    fn store(&self, node: &mut UnifiedNode) {
+        let counts = [
+            ("total_lines", self.total_lines),
+            ("lines_of_code", self.lines_of_code),
+            ("comment_lines", self.comment_lines),
+            ("blank_lines", self.blank_lines),
+        ];
+        for (key, count) in counts {
+            node.metadata.extra.insert(key.to_string(), serde_json::json!(count));
+        }
+        node.metadata.extra.insert(
+            "cyclomatic_complexity".to_string(),
+            serde_json::json!(self.cyclomatic_complexity),
+        );
+    }
+}
+
+/// Check if a node is the node of its file (rather than a symbol in it)
+pub(crate) fn is_file_node(node: &UnifiedNode) -> bool {
+    node.file_path.as_deref().map(generate_id).as_deref() == Some(node.id.as_str())
+}
+
+/// Store the metrics of every file node and set `total_lines` to the sum of
+/// their physical lines
+///
+/// Files are read under `metadata.root_path` and decoded from `encoding`.
+/// Unreadable files get no metrics.
+pub fn annotate_file_metrics(graph: &mut UnifiedGraph, encoding: &str) {
+    let root = Path::new(&graph.metadata.root_path).to_path_buf();
+    let mut total_lines = 0;
+
+    for node in graph.nodes.iter_mut().filter(|n| is_file_node(n)) {
+        let path = node.file_path.clone().unwrap_or_default();
+        if let Ok(bytes) = std::fs::read(root.join(&path)) {
+            let (content, _) = decode_source(&bytes, encoding, &path);
+            let metrics = FileMetrics::compute(&path, &content);
+            total_lines += metrics.total_lines;
+            metrics.store(node);
+        }
+    }
+
+    graph.metadata.total_lines = Some(total_lines);
+}
+
+/// Copy the metrics of the file nodes `graph` shares with `previous`, and set
+/// `total_lines` to the sum of the copied ones
+///
+/// Followed by [`refresh_file_metrics`] on the changed files, this updates a
+/// rebuilt graph without reading the unchanged files again.
+pub fn carry_file_metrics(previous: &UnifiedGraph, graph: &mut UnifiedGraph) {
+    let metrics: HashMap<&str, FileMetrics> = previous
+        .nodes
+        .iter()
+        .filter(|n| is_file_node(n))
+        .filter_map(|n| Some((n.id.as_str(), FileMetrics::of_node(n)?)))
+        .collect();
+    let mut total_lines = 0;
+
+    for node in graph.nodes.iter_mut().filter(|n| is_file_node(n)) {
+        if let Some(metrics) = metrics.get(node.id.as_str()) {
+            total_lines += metrics.total_lines;
+            metrics.store(node);
+        }
+    }
+
+    graph.metadata.total_lines = Some(total_lines);
+}
+
+/// Recompute the metrics of one file after it changed, without a full re-scan
+///
+/// Only the file's node is updated and `total_lines` moves by the line delta,
+/// which is returned. A deleted file counts as empty. Returns `None` when the
+/// graph has no node for `path`.
+pub fn refresh_file_metrics(
+    graph: &mut UnifiedGraph,
+    path: &str,
+    encoding: &str,
+) -> std::io::Result<Option<i64>> {
+    let file_id = generate_id(path);
+    let content = match std::fs::read(Path::new(&graph.metadata.root_path).join(path)) {
+        Ok(bytes) => decode_source(&bytes, encoding, path).0,
+        Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+
+    let node = match graph.nodes.iter_mut().find(|n| n.id == file_id) {
+        Some(node) => node,
+        None => return Ok(None),
+    };
+
+    let previous = FileMetrics::of_node(node).unwrap_or_default();
+    let metrics = FileMetrics::compute(path, &content);
+    metrics.store(node);
+
+    let delta = metrics.total_lines as i64 - previous.total_lines as i64;
+    let total_lines = graph.metadata.total_lines.unwrap_or(0) as i64 + delta;
+    graph.metadata.total_lines = Some(total_lines.max(0) as u64);
+
+    Ok(Some(delta))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::UnifiedNodeType;
+
+    #[test]
+    fn decisions_in_comments_and_strings_dont_count() {
+        let content = "<?php\n// if this, or else\n$a = 'for each while';\nif ($a && $b) {}\n";
+
+        let metrics = FileMetrics::compute("app/Example.php", content);
+
+        assert_eq!(metrics.cyclomatic_complexity, 3);
+        assert_eq!(metrics.comment_lines, 1);
+    }
+
+    #[test]
+    fn pascal_decisions_use_pascal_keywords() {
+        let content = "begin\n  { if it fails }\n  if (A > 0) and (B > 0) or C then\n    \
+Writeln('if or and');\n  repeat Dec(A) until A = 0;\nend.\n";
+
+        let metrics = FileMetrics::compute("Unit1.pas", content);
+
+        // if, and, or, until
+        assert_eq!(metrics.cyclomatic_complexity, 5);
+    }
+
+    #[test]
+    fn files_are_decoded_with_the_configured_encoding() {
+        let dir = tempfile::tempdir().unwrap();
+        // "{ Café }" in Windows-1252, not valid UTF-8
+        std::fs::write(
+            dir.path().join("Unit1.pas"),
+            b"unit Unit1;\n{ Caf\xe9 }\nend.\n",
+        )
+        .unwrap();
+        let mut graph = UnifiedGraph::new();
+        graph.metadata.root_path = dir.path().to_string_lossy().to_string();
+        graph.add_node(
+            UnifiedNode::new(
+                generate_id("Unit1.pas"),
+                UnifiedNodeType::Module,
+                "Unit1".to_string(),
+            )
+            .with_file("Unit1.pas".to_string()),
+        );
+
+        annotate_file_metrics(&mut graph, "windows-1252");
+
+        let metrics = FileMetrics::of_node(&graph.nodes[0]).unwrap();
+        assert_eq!(metrics.total_lines, 3);
+        assert_eq!(metrics.comment_lines, 1);
+        assert_eq!(graph.metadata.total_lines, Some(3));
+    }
+
+    #[test]
+    fn carried_metrics_are_refreshed_for_changed_files_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let write =
+            |path: &str, content: &str| std::fs::write(dir.path().join(path), content).unwrap();
+        let graph_of = |paths: &[&str]| {
+            let mut graph = UnifiedGraph::new();
+            graph.metadata.root_path = dir.path().to_string_lossy().to_string();
+            for path in paths {
+                graph.add_node(
+                    UnifiedNode::new(generate_id(path), UnifiedNodeType::Class, path.to_string())
+                        .with_file(path.to_string()),
+                );
+            }
+            graph
+        };
+        write("A.php", "<?php\n");
+        write("B.php", "<?php\n\n");
+        let mut previous = graph_of(&["A.php", "B.php"]);
+        annotate_file_metrics(&mut previous, "utf-8");
+
+        // A changes on disk without being reported, B is deleted and C added
+        write("A.php", "<?php\n\n\n\n");
+        std::fs::remove_file(dir.path().join("B.php")).unwrap();
+        write("C.php", "<?php\n\n\n");
+        let mut graph = graph_of(&["A.php", "C.php"]);
+        carry_file_metrics(&previous, &mut graph);
+        refresh_file_metrics(&mut graph, "C.php", "utf-8").unwrap();
+
+        let lines = |i: usize| FileMetrics::of_node(&graph.nodes[i]).unwrap().total_lines;
+        assert_eq!(lines(0), 1);
+        assert_eq!(lines(1), 3);
+        assert_eq!(graph.metadata.total_lines, Some(4));
+    }
+}
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::models::{UnifiedGraph, UnifiedNode};
+
+use super::{is_file_node, FileMetrics};
+
+/// What `filter_graph` keeps of a graph
+///
+/// Every field is optional: an empty filter keeps the whole graph.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GraphFilter {
+    /// Node type keys to keep (`controller`, `custom:policy`), empty = all
+    pub node_types: Vec<String>,
+
+    /// Edge type keys to keep (`uses`, `custom:routes_to`), empty = all
+    pub edge_types: Vec<String>,
+
+    /// Projects to keep, empty = all: monorepo sub-project directories
+    /// (`extra["project"]`) or Delphi projects of a group (`extra["projects"]`)
+    pub projects: Vec<String>,
+
+    /// Node IDs to focus on, empty = no focus
+    pub focus: Vec<String>,
+
+    /// Hops from the focus nodes to keep (0 = the focus nodes only)
+    pub depth: usize,
+}
+
+/// Extract the subgraph selected by `filter`
+///
+/// Nodes and edges are first filtered by type and nodes by project, edges
+/// losing an endpoint are dropped. With focus nodes, only the nodes within `depth` hops of one of
+/// them are kept, following the remaining edges in both directions; focus
+/// nodes filtered out by type don't count. The metadata is kept, with
+/// `total_files` and `total_lines` recounted over the remaining file nodes.
+pub fn filter_graph(graph: &UnifiedGraph, filter: &GraphFilter) -> UnifiedGraph {
+    let node_types: HashSet<&str> = filter.node_types.iter().map(|t| t.as_str()).collect();
+    let edge_types: HashSet<&str> = filter.edge_types.iter().map(|t| t.as_str()).collect();
+
+    let mut kept: HashSet<&str> = graph
+        .nodes
+        .iter()
+        .filter(|n| node_types.is_empty() || node_types.contains(n.node_type.as_key().as_str()))
+        .filter(|n| filter.projects.is_empty() || in_projects(n, &filter.projects))
+        .map(|n| n.id.as_str())
+        .collect();
+    let edges: Vec<_> = graph
+        .edges
+        .iter()
+        .filter(|e| edge_types.is_empty() || edge_types.contains(e.edge_type.as_key().as_str()))
+        .filter(|e| kept.contains(e.source.as_str()) && kept.contains(e.target.as_str()))
+        .collect();
+
+    if !filter.focus.is_empty() {
+        let mut neighbors: HashMap<&str, Vec<&str>> = HashMap::new();
+        for edge in &edges {
+            neighbors.entry(edge.source.as_str()).or_default().push(edge.target.as_str());
+            neighbors.entry(edge.target.as_str()).or_default().push(edge.source.as_str());
+        }
+
+        let mut reached: HashSet<&str> = HashSet::new();
+        let mut queue: VecDeque<(&str, usize)> = filter
+            .focus
+            .iter()
+            .filter(|id| kept.contains(id.as_str()))
+            .map(|id| (id.as_str(), 0))
+            .collect();
+        while let Some((id, distance)) = queue.pop_front() {
+            if !reached.insert(id) || distance == filter.depth {
+                continue;
+            }
+            for &next in neighbors.get(id).map(|n| n.as_slice()).unwrap_or(&[]) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+        kept = reached;
+    }
+
+    let mut filtered = UnifiedGraph::new().with_metadata(graph.metadata.clone());
+    for node in graph.nodes.iter().filter(|n| kept.contains(n.id.as_str())) {
+        filtered.add_node(node.clone());
+    }
+    for edge in edges {
+        if kept.contains(edge.source.as_str()) && kept.contains(edge.target.as_str()) {
+            filtered.add_edge(edge.clone());
+        }
+    }
+
+    let files: Vec<_> = filtered.nodes.iter().filter(|n| is_file_node(n)).collect();
+    filtered.metadata.total_files = files.len();
+    if filtered.metadata.total_lines.is_some() {
+        let lines = files
+            .iter()
+            .filter_map(|n| FileMetrics::of_node(n))
+            .map(|m| m.total_lines)
+            .sum();
+        filtered.metadata.total_lines = Some(lines);
+    }
+
+    filtered
+}
+
+/// Whether `node` belongs to one of `projects`
+fn in_projects(node: &UnifiedNode, projects: &[String]) -> bool {
+    let extra = &node.metadata.extra;
+    let project = extra.get("project").and_then(|p| p.as_str());
+    let grouped = extra
+        .get("projects")
+        .and_then(|p| p.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|p| p.as_str());
+
+    project
+        .into_iter()
+        .chain(grouped)
+        .any(|p| projects.iter().any(|wanted| wanted == p))
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+use crate::models::{Position3D, UnifiedGraph};
+
+/// Iterations run when a command asks for a layout
+pub const DEFAULT_LAYOUT_ITERATIONS: usize = 100;
+
+/// Seed of the initial positions used by commands, so a project always lays out the same way
+pub const DEFAULT_LAYOUT_SEED: u64 = 0x5EED;
+
+/// Ideal distance between two connected nodes, in frontend units
+const SPACING: f64 = 30.0;
+
+/// Fill every node's `position` with a Fruchterman-Reingold layout in 3D
+///
+/// Nodes start at random positions drawn from `seed`, then connected nodes
+/// attract and all nodes repel while the temperature (the largest move per
+/// iteration) cools down to zero. Repulsion only acts within `2 * SPACING`
+/// (grid variant), so large graphs stay fast. Same graph and seed, same
+/// positions.
+pub fn force_directed(graph: &mut UnifiedGraph, iterations: usize, seed: u64) {
+    let count = graph.nodes.len();
+    if count == 0 {
+        return;
+    }
+
+    let side = SPACING * (count as f64).cbrt();
+    let mut rng = SplitMix64(seed);
+    let mut positions: Vec<[f64; 3]> = (0..count)
+        .map(|_| [0; 3].map(|_| (rng.next_f64() - 0.5) * side))
+        .collect();
+
+    let index: HashMap<&str, usize> = graph
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (node.id.as_str(), i))
+        .collect();
+    let links: Vec<(usize, usize)> = graph
+        .edges
+        .iter()
+        .filter_map(|edge| {
+            Some((
+                *index.get(edge.source.as_str())?,
+                *index.get(edge.target.as_str())?,
+            ))
+        })
+        .filter(|(source, target)| source != target)
+        .collect();
+
+    let cell_size = 2.0 * SPACING;
+    let initial_temperature = side / 10.0;
+
+    for iteration in 0..iterations {
+        let mut displacements = vec![[0.0; 3]; count];
+
+        let cell = |p: &[f64; 3]| p.map(|c| (c / cell_size).floor() as i64);
+        let mut grid: HashMap<[i64; 3], Vec<usize>> = HashMap::new();
+        for (i, position) in positions.iter().enumerate() {
+            grid.entry(cell(position)).or_default().push(i);
+        }
+
+        // Repulsion: SPACING² / d between nodes of neighboring cells
+        for (i, position) in positions.iter().enumerate() {
+            let [cx, cy, cz] = cell(position);
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    for dz in -1..=1 {
+                        let neighbors = match grid.get(&[cx + dx, cy + dy, cz + dz]) {
+                            Some(neighbors) => neighbors,
+                            None => continue,
+                        };
+                        for &j in neighbors.iter().filter(|&&j| j != i) {
+                            let (delta, distance) = offset(position, &positions[j]);
+                            if distance < cell_size {
+                                let force = SPACING * SPACING / distance;
+                                for axis in 0..3 {
+                                    displacements[i][axis] += delta[axis] / distance * force;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Attraction: d² / SPACING along edges
+        for &(source, target) in &links {
+            let (delta, distance) = offset(&positions[source], &positions[target]);
+            let force = distance * distance / SPACING;
+            for axis in 0..3 {
+                let pull = delta[axis] / distance * force;
+                displacements[source][axis] -= pull;
+                displacements[target][axis] += pull;
+            }
+        }
+
+        let temperature = initial_temperature * (1.0 - iteration as f64 / iterations as f64);
+        for (position, displacement) in positions.iter_mut().zip(&displacements) {
+            let length = displacement.iter().map(|d| d * d).sum::<f64>().sqrt();
+            if length > 0.0 {
+                let step = length.min(temperature);
+                for axis in 0..3 {
+                    position[axis] += displacement[axis] / length * step;
+                }
+            }
+        }
+    }
+
+    for (node, [x, y, z]) in graph.nodes.iter_mut().zip(positions) {
+        node.position = Some(Position3D { x, y, z });
+    }
+}
+
+/// Vector from `b` to `a` and its length, never zero so it can divide
+fn offset(a: &[f64; 3], b: &[f64; 3]) -> ([f64; 3], f64) {
+    let delta = [a[0] - b[0], a[1] - b[1], a[2] - b[2]];
+    let distance = delta.iter().map(|d| d * d).sum::<f64>().sqrt().max(0.01);
+    (delta, distance)
+}
+
+/// Small seeded RNG (SplitMix64), enough for initial positions
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{UnifiedEdge, UnifiedEdgeType, UnifiedNode, UnifiedNodeType};
+
+    /// A ring of `count` nodes
+    fn ring(count: usize) -> UnifiedGraph {
+        let mut graph = UnifiedGraph::new();
+        for i in 0..count {
+            graph.add_node(UnifiedNode::new(
+                i.to_string(),
+                UnifiedNodeType::Class,
+                i.to_string(),
+            ));
+            graph.add_edge(UnifiedEdge::new(
+                i.to_string(),
+                ((i + 1) % count).to_string(),
+                UnifiedEdgeType::Uses,
+            ));
+        }
+        graph
+    }
+
+    fn positions(graph: &UnifiedGraph) -> Vec<(f64, f64, f64)> {
+        graph
+            .nodes
+            .iter()
+            .map(|n| {
+                let p = n.position.as_ref().expect("position");
+                (p.x, p.y, p.z)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_node_gets_a_finite_position() {
+        let mut graph = ring(12);
+
+        force_directed(&mut graph, DEFAULT_LAYOUT_ITERATIONS, DEFAULT_LAYOUT_SEED);
+
+        assert!(positions(&graph)
+            .iter()
+            .all(|(x, y, z)| x.is_finite() && y.is_finite() && z.is_finite()));
+    }
+
+    #[test]
+    fn same_seed_same_positions() {
+        let (mut first, mut second, mut other) = (ring(12), ring(12), ring(12));
+
+        force_directed(&mut first, 50, 7);
+        force_directed(&mut second, 50, 7);
+        force_directed(&mut other, 50, 8);
+
+        assert_eq!(positions(&first), positions(&second));
+        assert_ne!(positions(&first), positions(&other));
+    }
+}
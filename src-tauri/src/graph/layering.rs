@@ -0,0 +1,296 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::models::{UnifiedEdge, UnifiedGraph, UnifiedNode, UnifiedNodeType};
+
+use super::is_dependency_edge;
+
+/// Architectural layer, ordered from the entry point down to persistence
+///
+/// Dependencies are expected to point downwards (routing -> http -> ... -> data).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Layer {
+    Routing,
+    Http,
+    Presentation,
+    Domain,
+    Data,
+}
+
+impl Layer {
+    /// Every layer, from the entry point down
+    pub const ALL: [Layer; 5] = [
+        Layer::Routing,
+        Layer::Http,
+        Layer::Presentation,
+        Layer::Domain,
+        Layer::Data,
+    ];
+
+    /// Name of the layer, as serialized
+    pub fn name(&self) -> &'static str {
+        match self {
+            Layer::Routing => "routing",
+            Layer::Http => "http",
+            Layer::Presentation => "presentation",
+            Layer::Domain => "domain",
+            Layer::Data => "data",
+        }
+    }
+
+    /// Layer by its name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|layer| layer.name() == name)
+    }
+
+    /// Layer of a node type, if it belongs to one
+    pub fn of(node_type: &UnifiedNodeType) -> Option<Self> {
+        match node_type {
+            UnifiedNodeType::Route => Some(Layer::Routing),
+            UnifiedNodeType::Controller | UnifiedNodeType::Middleware => Some(Layer::Http),
+            UnifiedNodeType::View
+            | UnifiedNodeType::Page
+            | UnifiedNodeType::Component
+            | UnifiedNodeType::Form
+            | UnifiedNodeType::FormFile => Some(Layer::Presentation),
+            UnifiedNodeType::Model | UnifiedNodeType::Migration | UnifiedNodeType::Table => {
+                Some(Layer::Data)
+            }
+            UnifiedNodeType::Custom(kind) => match kind.as_str() {
+                "request" => Some(Layer::Http),
+                "service" | "action" | "repository" | "job" | "listener" => Some(Layer::Domain),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Layer of a node: the one pinned in its `layer` metadata, else its type's
+    pub fn of_node(node: &UnifiedNode) -> Option<Self> {
+        node.metadata
+            .extra
+            .get("layer")
+            .and_then(|layer| serde_json::from_value(layer.clone()).ok())
+            .or_else(|| Self::of(&node.node_type))
+    }
+}
+
+/// A forbidden dependency between two node types or layers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayerRule {
+    /// Node type key (`model`, `custom:job`) or layer (`layer:data`) of the
+    /// depending node
+    pub from: String,
+
+    /// Node type key or layer of the node it must not depend on
+    pub to: String,
+
+    /// Edge type keys the rule applies to, empty = every dependency edge
+    #[serde(default)]
+    pub edge_types: Vec<String>,
+
+    /// Why the dependency is forbidden
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+impl LayerRule {
+    pub fn new(from: &str, to: &str, reason: &str) -> Self {
+        Self {
+            from: from.to_string(),
+            to: to.to_string(),
+            edge_types: Vec::new(),
+            reason: Some(reason.to_string()),
+        }
+    }
+
+    /// Whether `edge`, going from `source` to `target`, breaks the rule
+    fn forbids(&self, edge: &UnifiedEdge, source: &UnifiedNode, target: &UnifiedNode) -> bool {
+        let edge_matches = if self.edge_types.is_empty() {
+            is_dependency_edge(&edge.edge_type)
+        } else {
+            self.edge_types.contains(&edge.edge_type.as_key())
+        };
+
+        edge_matches && matches_node(&self.from, source) && matches_node(&self.to, target)
+    }
+}
+
+/// Whether `node` has the type key or the layer (`layer:<name>`) of a rule
+fn matches_node(pattern: &str, node: &UnifiedNode) -> bool {
+    match pattern.strip_prefix("layer:") {
+        Some(layer) => Layer::of_node(node).is_some_and(|l| Some(l) == Layer::from_name(layer)),
+        None => node.node_type.as_key() == pattern,
+    }
+}
+
+/// Rules every Laravel project should follow
+///
+/// Dependencies point down the layers (no layer depends on one above it,
+/// see [`Layer`]) and views never reach the schema directly.
+pub fn default_laravel_rules() -> Vec<LayerRule> {
+    let mut rules = Vec::new();
+    for (i, lower) in Layer::ALL.iter().enumerate() {
+        for higher in &Layer::ALL[..i] {
+            rules.push(LayerRule::new(
+                &format!("layer:{}", lower.name()),
+                &format!("layer:{}", higher.name()),
+                &format!(
+                    "The {} layer must not depend on the {} layer above it",
+                    lower.name(),
+                    higher.name()
+                ),
+            ));
+        }
+    }
+    rules.push(LayerRule::new("view", "migration", "Migrations must not be referenced by views"));
+    rules
+}
+
+/// An edge breaking a layering rule, with its endpoints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleViolation {
+    /// The rule broken
+    pub rule: LayerRule,
+
+    /// Offending edge
+    pub edge: UnifiedEdge,
+
+    /// Depending node
+    pub source: UnifiedNode,
+
+    /// Node depended on
+    pub target: UnifiedNode,
+}
+
+/// Find the edges breaking one of `rules`
+///
+/// An edge breaking several rules is reported once per rule, in the order
+/// of the rules.
+pub fn check_layering(graph: &UnifiedGraph, rules: &[LayerRule]) -> Vec<RuleViolation> {
+    let nodes: HashMap<&str, &UnifiedNode> =
+        graph.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+
+    let mut violations = Vec::new();
+    for rule in rules {
+        for edge in &graph.edges {
+            let source = nodes.get(edge.source.as_str());
+            let target = nodes.get(edge.target.as_str());
+            let (source, target) = match (source, target) {
+                (Some(source), Some(target)) => (*source, *target),
+                _ => continue,
+            };
+
+            if rule.forbids(edge, source, target) {
+                violations.push(RuleViolation {
+                    rule: rule.clone(),
+                    edge: edge.clone(),
+                    source: source.clone(),
+                    target: target.clone(),
+                });
+            }
+        }
+    }
+
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::UnifiedEdgeType;
+
+    fn node(id: &str, node_type: UnifiedNodeType) -> UnifiedNode {
+        UnifiedNode::new(id.to_string(), node_type, id.to_string())
+    }
+
+    fn graph(nodes: Vec<UnifiedNode>, edges: &[(&str, &str)]) -> UnifiedGraph {
+        let mut graph = UnifiedGraph::new();
+        for node in nodes {
+            graph.add_node(node);
+        }
+        for (source, target) in edges {
+            graph.add_edge(UnifiedEdge::new(
+                source.to_string(),
+                target.to_string(),
+                UnifiedEdgeType::Uses,
+            ));
+        }
+        graph
+    }
+
+    /// (source, target) of the edges breaking the default rules
+    fn violations(graph: &UnifiedGraph) -> Vec<(String, String)> {
+        check_layering(graph, &default_laravel_rules())
+            .into_iter()
+            .map(|v| (v.source.id, v.target.id))
+            .collect()
+    }
+
+    #[test]
+    fn default_rules_forbid_dependencies_up_the_layers() {
+        let graph = graph(
+            vec![
+                node("UserController", UnifiedNodeType::Controller),
+                node("User", UnifiedNodeType::Model),
+                node("users.index", UnifiedNodeType::View),
+                node("create_users", UnifiedNodeType::Migration),
+            ],
+            &[
+                ("UserController", "User"),
+                ("UserController", "users.index"),
+                ("User", "UserController"),
+                ("users.index", "create_users"),
+            ],
+        );
+
+        assert_eq!(
+            violations(&graph),
+            vec![
+                ("User".to_string(), "UserController".to_string()),
+                ("users.index".to_string(), "create_users".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn pinned_layers_override_the_node_type() {
+        let mut policy = node("PostPolicy", UnifiedNodeType::Custom("policy".to_string()));
+        let post = node("Post", UnifiedNodeType::Model);
+        let edges = [("Post", "PostPolicy")];
+        assert!(violations(&graph(vec![policy.clone(), post.clone()], &edges)).is_empty());
+
+        policy
+            .metadata
+            .extra
+            .insert("layer".to_string(), serde_json::json!("domain"));
+        let graph = graph(vec![policy, post], &edges);
+
+        let found = check_layering(&graph, &default_laravel_rules());
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].rule.from, "layer:data");
+        assert_eq!(found[0].rule.to, "layer:domain");
+    }
+
+    #[test]
+    fn rules_can_name_node_types() {
+        let graph = graph(
+            vec![
+                node("SendInvoice", UnifiedNodeType::Custom("job".to_string())),
+                node("InvoiceController", UnifiedNodeType::Controller),
+            ],
+            &[("SendInvoice", "InvoiceController")],
+        );
+        let rules = [LayerRule::new(
+            "custom:job",
+            "controller",
+            "Jobs must not call controllers",
+        )];
+
+        let found = check_layering(&graph, &rules);
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].edge.source, "SendInvoice");
+    }
+}
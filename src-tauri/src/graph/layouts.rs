@@ -0,0 +1,276 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::models::{UnifiedEdgeType, UnifiedGraph, UnifiedNode, UnifiedNodeType};
+
+/// A Blade view resolved against its whole `@extends` chain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewLayout {
+    /// View node ID
+    pub node_id: String,
+
+    /// Layout node IDs the view inherits from, nearest first
+    pub layouts: Vec<String>,
+
+    /// Layout name the chain stops at because no view declares it
+    pub unresolved_layout: Option<String>,
+
+    /// Sections no layout of the chain yields
+    pub unmatched_sections: Vec<String>,
+
+    /// Pushes no layout of the chain stacks
+    pub unmatched_pushes: Vec<String>,
+}
+
+/// Layouts a view inherits from, nearest first, following `@extends` edges
+///
+/// Stops at the first layout seen twice, so cyclic chains terminate.
+pub fn layout_chain<'a>(graph: &'a UnifiedGraph, view_id: &str) -> Vec<&'a UnifiedNode> {
+    chain_from(&layout_parents(graph), view_id)
+}
+
+fn chain_from<'a>(
+    parents: &HashMap<&str, &'a UnifiedNode>,
+    view_id: &str,
+) -> Vec<&'a UnifiedNode> {
+    let mut chain = Vec::new();
+    let mut seen: HashSet<&str> = HashSet::new();
+    seen.insert(view_id);
+
+    let mut current = view_id;
+    while let Some(&parent) = parents.get(current) {
+        if !seen.insert(parent.id.as_str()) {
+            break;
+        }
+        chain.push(parent);
+        current = parent.id.as_str();
+    }
+
+    chain
+}
+
+/// Resolve every extending view against its layout chain
+///
+/// A section matches a `@yield` and a push a `@stack` anywhere up the chain,
+/// not only in the direct layout. When the chain ends on an unknown layout
+/// nothing is reported as unmatched, the missing layout may declare it.
+pub fn view_layouts(graph: &UnifiedGraph) -> Vec<ViewLayout> {
+    let parents = layout_parents(graph);
+
+    let mut layouts: Vec<ViewLayout> = graph
+        .nodes
+        .iter()
+        .filter(|n| n.node_type == UnifiedNodeType::View)
+        .filter(|n| n.metadata.extra.contains_key("extends"))
+        .map(|view| {
+            let chain = chain_from(&parents, &view.id);
+            let top = chain.last().copied().unwrap_or(view);
+            let unresolved_layout = if parents.contains_key(top.id.as_str()) {
+                None
+            } else {
+                extra_string(top, "extends")
+            };
+
+            let (unmatched_sections, unmatched_pushes) = if unresolved_layout.is_some() {
+                (Vec::new(), Vec::new())
+            } else {
+                (
+                    unmatched(view, &chain, "sections", "yields"),
+                    unmatched(view, &chain, "pushes", "stacks"),
+                )
+            };
+
+            ViewLayout {
+                node_id: view.id.clone(),
+                layouts: chain.iter().map(|n| n.id.clone()).collect(),
+                unresolved_layout,
+                unmatched_sections,
+                unmatched_pushes,
+            }
+        })
+        .collect();
+
+    layouts.sort_by(|a, b| a.node_id.cmp(&b.node_id));
+    layouts
+}
+
+/// Warnings for the views extending a missing layout or filling no yield
+///
+/// A missing layout is only reported on the view naming it, not on the
+/// views inheriting from it. A section is reported when no layout of the
+/// chain yields it.
+pub fn layout_warnings(graph: &UnifiedGraph) -> Vec<String> {
+    let paths: HashMap<&str, &str> = graph
+        .nodes
+        .iter()
+        .filter_map(|n| Some((n.id.as_str(), n.file_path.as_deref()?)))
+        .collect();
+
+    let mut warnings = Vec::new();
+    for layout in view_layouts(graph) {
+        let view = paths.get(layout.node_id.as_str()).copied().unwrap_or(&layout.node_id);
+        match layout.unresolved_layout {
+            Some(missing) if layout.layouts.is_empty() => {
+                warnings.push(format!("{} extends {}, which matches no view", view, missing));
+            }
+            _ => {
+                for section in layout.unmatched_sections {
+                    warnings.push(format!(
+                        "{} fills section {}, which no layout it extends yields",
+                        view, section
+                    ));
+                }
+            }
+        }
+    }
+
+    warnings
+}
+
+/// The layout each view extends (view node ID -> layout node)
+fn layout_parents(graph: &UnifiedGraph) -> HashMap<&str, &UnifiedNode> {
+    let views: HashMap<&str, &UnifiedNode> = graph
+        .nodes
+        .iter()
+        .filter(|n| n.node_type == UnifiedNodeType::View)
+        .map(|n| (n.id.as_str(), n))
+        .collect();
+
+    graph
+        .edges
+        .iter()
+        .filter(|e| e.edge_type == UnifiedEdgeType::Extends)
+        .filter(|e| views.contains_key(e.source.as_str()))
+        .filter_map(|e| {
+            views
+                .get(e.target.as_str())
+                .map(|&layout| (e.source.as_str(), layout))
+        })
+        .collect()
+}
+
+/// Names in the view's `used` list that no layout of the chain `provided`
+fn unmatched(
+    view: &UnifiedNode,
+    chain: &[&UnifiedNode],
+    used: &str,
+    provided: &str,
+) -> Vec<String> {
+    let available: HashSet<String> = chain
+        .iter()
+        .flat_map(|layout| extra_strings(layout, provided))
+        .collect();
+
+    extra_strings(view, used)
+        .into_iter()
+        .filter(|name| !available.contains(name))
+        .collect()
+}
+
+fn extra_string(node: &UnifiedNode, key: &str) -> Option<String> {
+    node.metadata
+        .extra
+        .get(key)
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+}
+
+fn extra_strings(node: &UnifiedNode, key: &str) -> Vec<String> {
+    node.metadata
+        .extra
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.as_str())
+                .map(|s| s.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::laravel::fixtures::graph_of;
+
+    #[tokio::test]
+    async fn unresolved_layouts_and_sections_are_warnings() {
+        let graph = graph_of(&[
+            (
+                "resources/views/layouts/app.blade.php",
+                "<main>@yield('content')</main>",
+            ),
+            (
+                "resources/views/home.blade.php",
+                "@extends('layouts.app')\n@section('sidebar') Links @endsection",
+            ),
+            (
+                "resources/views/about.blade.php",
+                "@extends('layouts.missing')",
+            ),
+        ])
+        .await;
+
+        let mut warnings = layout_warnings(&graph);
+        warnings.sort();
+
+        assert_eq!(
+            warnings,
+            vec![
+                "resources/views/about.blade.php extends layouts.missing, which matches no view",
+                "resources/views/home.blade.php fills section sidebar, which no layout it extends \
+yields",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn sections_match_yields_anywhere_up_the_chain() {
+        let graph = graph_of(&[
+            (
+                "resources/views/layouts/base.blade.php",
+                "<title>@yield('title')</title>\n<body>@yield('body')</body>",
+            ),
+            (
+                "resources/views/layouts/app.blade.php",
+                "@extends('layouts.base')\n@section('body')\n<nav>@yield('sidebar')</nav>\n\
+@endsection",
+            ),
+            (
+                "resources/views/home.blade.php",
+                "@extends('layouts.app')\n@section('title', 'Home')\n\
+@section('sidebar') Links @endsection",
+            ),
+        ])
+        .await;
+        let id_of = |path: &str| {
+            let node = graph.nodes.iter().find(|n| {
+                n.node_type == UnifiedNodeType::View && n.file_path.as_deref() == Some(path)
+            });
+            node.unwrap().id.clone()
+        };
+        let home = id_of("resources/views/home.blade.php");
+
+        let chain: Vec<&str> = layout_chain(&graph, &home)
+            .iter()
+            .map(|n| n.id.as_str())
+            .collect();
+        assert_eq!(
+            chain,
+            [
+                id_of("resources/views/layouts/app.blade.php"),
+                id_of("resources/views/layouts/base.blade.php"),
+            ]
+        );
+
+        let layout = view_layouts(&graph)
+            .into_iter()
+            .find(|l| l.node_id == home)
+            .unwrap();
+        assert_eq!(layout.unresolved_layout, None);
+        assert!(layout.unmatched_sections.is_empty());
+        assert!(layout_warnings(&graph).is_empty());
+    }
+}
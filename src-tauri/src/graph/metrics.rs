@@ -0,0 +1,245 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::models::{UnifiedEdgeType, UnifiedGraph, UnifiedNodeType};
+
+/// File-level dependency map: file path -> file paths it depends on
+///
+/// Symbol nodes are collapsed into the file that declares them, so a
+/// controller class using a model class counts as one file-to-file edge.
+pub type FileDependencies = BTreeMap<String, BTreeSet<String>>;
+
+/// Check if an edge type expresses a dependency (as opposed to structure)
+///
+/// Back-references like `rendered_by` mirror an existing dependency and
+/// would otherwise turn every rendered page into a cycle. Container `binds`
+/// edges are wiring: the contract doesn't depend on its implementation.
+/// Links from a view to a named route (`references_route`) are URLs, not
+/// code dependencies, and would loop back through the route's controller.
+/// Job chains and batches are ordering decided by the dispatcher.
+pub fn is_dependency_edge(edge_type: &UnifiedEdgeType) -> bool {
+    match edge_type {
+        UnifiedEdgeType::Contains | UnifiedEdgeType::Defines | UnifiedEdgeType::FilePair => false,
+        UnifiedEdgeType::Custom(kind) => !matches!(
+            kind.as_str(),
+            "rendered_by" | "binds" | "references_route" | "chained_with" | "batched_with"
+        ),
+        _ => true,
+    }
+}
+
+/// Build the file-level dependency map of a graph
+pub fn file_dependencies(graph: &UnifiedGraph) -> FileDependencies {
+    let mut deps = FileDependencies::new();
+    let mut file_of: HashMap<&str, &str> = HashMap::new();
+
+    for node in &graph.nodes {
+        if let Some(ref path) = node.file_path {
+            file_of.insert(node.id.as_str(), path.as_str());
+            deps.entry(path.clone()).or_default();
+        }
+    }
+
+    for edge in &graph.edges {
+        if !is_dependency_edge(&edge.edge_type) {
+            continue;
+        }
+        if let (Some(source), Some(target)) = (
+            file_of.get(edge.source.as_str()),
+            file_of.get(edge.target.as_str()),
+        ) {
+            if source != target {
+                deps.entry(source.to_string())
+                    .or_default()
+                    .insert(target.to_string());
+            }
+        }
+    }
+
+    deps
+}
+
+/// Find dependency cycles between files (strongly connected components of size > 1)
+pub fn find_cycles(deps: &FileDependencies) -> Vec<Vec<String>> {
+    // Iterative Tarjan's algorithm
+    let files: Vec<&String> = deps.keys().collect();
+    let index_of: HashMap<&str, usize> = files
+        .iter()
+        .enumerate()
+        .map(|(i, f)| (f.as_str(), i))
+        .collect();
+    let successors: Vec<Vec<usize>> = files
+        .iter()
+        .map(|f| {
+            deps[*f]
+                .iter()
+                .filter_map(|t| index_of.get(t.as_str()).copied())
+                .collect()
+        })
+        .collect();
+
+    let count = files.len();
+    let mut index = vec![usize::MAX; count];
+    let mut lowlink = vec![0; count];
+    let mut on_stack = vec![false; count];
+    let mut stack = Vec::new();
+    let mut next_index = 0;
+    let mut cycles = Vec::new();
+
+    for start in 0..count {
+        if index[start] != usize::MAX {
+            continue;
+        }
+
+        let mut work: Vec<(usize, usize)> = vec![(start, 0)];
+        index[start] = next_index;
+        lowlink[start] = next_index;
+        next_index += 1;
+        stack.push(start);
+        on_stack[start] = true;
+
+        while let Some(&mut (v, ref mut child)) = work.last_mut() {
+            if *child < successors[v].len() {
+                let w = successors[v][*child];
+                *child += 1;
+
+                if index[w] == usize::MAX {
+                    index[w] = next_index;
+                    lowlink[w] = next_index;
+                    next_index += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    work.push((w, 0));
+                } else if on_stack[w] {
+                    lowlink[v] = lowlink[v].min(index[w]);
+                }
+                continue;
+            }
+
+            work.pop();
+            if let Some(&(parent, _)) = work.last() {
+                lowlink[parent] = lowlink[parent].min(lowlink[v]);
+            }
+
+            if lowlink[v] == index[v] {
+                let mut component = Vec::new();
+                while let Some(w) = stack.pop() {
+                    on_stack[w] = false;
+                    component.push(files[w].clone());
+                    if w == v {
+                        break;
+                    }
+                }
+                if component.len() > 1 {
+                    component.sort();
+                    cycles.push(component);
+                }
+            }
+        }
+    }
+
+    cycles.sort();
+    cycles
+}
+
+/// Average number of distinct files each file depends on or is depended on by
+pub fn average_coupling(deps: &FileDependencies) -> f64 {
+    if deps.is_empty() {
+        return 0.0;
+    }
+
+    let mut neighbors: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+    for (source, targets) in deps {
+        for target in targets {
+            neighbors.entry(source).or_default().insert(target);
+            neighbors.entry(target).or_default().insert(source);
+        }
+    }
+
+    let total: usize = neighbors.values().map(|n| n.len()).sum();
+    total as f64 / deps.len() as f64
+}
+
+/// Files with no incoming or outgoing dependency
+pub fn orphan_files(deps: &FileDependencies) -> Vec<String> {
+    let referenced: BTreeSet<&String> = deps.values().flatten().collect();
+
+    deps.iter()
+        .filter(|(file, targets)| targets.is_empty() && !referenced.contains(file))
+        .map(|(file, _)| file.clone())
+        .collect()
+}
+
+/// Files declaring a class that no test file depends on or is named after
+pub fn untested_files(graph: &UnifiedGraph, deps: &FileDependencies) -> Vec<String> {
+    let test_files: Vec<&String> = deps.keys().filter(|f| is_test_path(f)).collect();
+
+    let tested_stems: BTreeSet<String> = test_files
+        .iter()
+        .filter_map(|f| tested_stem(f))
+        .collect();
+    let tested_by_dependency: BTreeSet<&String> = test_files
+        .iter()
+        .flat_map(|f| deps[*f].iter())
+        .collect();
+
+    testable_files(graph)
+        .into_iter()
+        .filter(|f| !tested_by_dependency.contains(f))
+        .filter(|f| {
+            file_stem(f)
+                .map(|stem| !tested_stems.contains(&stem.to_lowercase()))
+                .unwrap_or(true)
+        })
+        .collect()
+}
+
+/// Non-test files declaring at least one class
+pub fn testable_files(graph: &UnifiedGraph) -> BTreeSet<String> {
+    graph
+        .nodes
+        .iter()
+        .filter(|n| {
+            matches!(
+                n.node_type,
+                UnifiedNodeType::Class | UnifiedNodeType::Controller | UnifiedNodeType::Model
+            )
+        })
+        .filter_map(|n| n.file_path.as_ref())
+        .filter(|f| !is_test_path(f))
+        .cloned()
+        .collect()
+}
+
+/// Check if a path looks like a test file (tests/ directory or *Test suffix)
+pub fn is_test_path(path: &str) -> bool {
+    let normalized = path.replace('\\', "/").to_lowercase();
+    let in_test_dir = normalized
+        .split('/')
+        .any(|segment| segment == "test" || segment == "tests");
+
+    in_test_dir
+        || file_stem(path)
+            .map(|stem| stem.ends_with("Test") || stem.ends_with("Tests"))
+            .unwrap_or(false)
+}
+
+/// Name of the unit a test file covers (UserControllerTest.php -> usercontroller)
+fn tested_stem(path: &str) -> Option<String> {
+    let stem = file_stem(path)?.to_lowercase();
+    let stem = stem
+        .strip_suffix("tests")
+        .or_else(|| stem.strip_suffix("test"))
+        .or_else(|| stem.strip_prefix("test"))?;
+
+    if stem.is_empty() {
+        None
+    } else {
+        Some(stem.to_string())
+    }
+}
+
+/// File name without directories and extensions (also strips `.blade.php`)
+fn file_stem(path: &str) -> Option<&str> {
+    let name = path.rsplit(['/', '\\']).next()?;
+    name.split('.').next().filter(|s| !s.is_empty())
+}
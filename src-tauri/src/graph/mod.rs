@@ -1,2 +1,29 @@
 // Graph module - Graph building and metrics
-// TODO: Implement graph builder and layout algorithms
+
+mod metrics;
+mod layering;
+mod architecture;
+mod smells;
+mod layouts;
+mod file_metrics;
+mod slice;
+mod monorepo;
+mod search;
+mod filter;
+mod details;
+mod model_cycles;
+mod force_layout;
+
+pub use metrics::*;
+pub use layering::*;
+pub use architecture::*;
+pub use smells::*;
+pub use layouts::*;
+pub use file_metrics::*;
+pub use slice::*;
+pub use monorepo::*;
+pub use search::*;
+pub use filter::*;
+pub use details::*;
+pub use model_cycles::*;
+pub use force_layout::*;
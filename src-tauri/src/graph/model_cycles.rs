@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+use crate::models::{UnifiedEdge, UnifiedEdgeType, UnifiedGraph, UnifiedNodeType};
+
+use super::{find_cycles, is_file_node, FileDependencies};
+
+/// How worrying a cycle of model relationships is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModelCycleKind {
+    /// Models owning each other in a loop (A belongsTo B belongsTo A)
+    Deep,
+
+    /// Relationships declared from both sides (User hasMany Post, Post belongsTo User)
+    ParentChild,
+
+    /// A model related to itself (Category belongsTo its parent Category)
+    SelfReference,
+}
+
+/// A group of models whose relationships loop back
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelCycle {
+    pub kind: ModelCycleKind,
+
+    /// Model class node IDs (file node when the class wasn't found)
+    pub model_ids: Vec<String>,
+
+    /// Relationship edges between the models of the group
+    pub edge_ids: Vec<String>,
+}
+
+/// Find the cycles of Eloquent relationships between models
+///
+/// Models are grouped when their relationships loop back, whatever the side
+/// declaring them. A group is `Deep` when following ownership (child to
+/// parent: `belongsTo`, `morphTo`, and `hasOne`/`hasMany` read backwards)
+/// loops, `ParentChild` otherwise: the loop only comes from relationships
+/// declared on both sides, or many-to-many ones. Deep cycles come first.
+pub fn model_cycles(graph: &UnifiedGraph) -> Vec<ModelCycle> {
+    // Models are their file, the graph links file nodes to class nodes
+    let mut file_of: HashMap<&str, &str> = HashMap::new();
+    let mut model_id: HashMap<&str, &str> = HashMap::new();
+    for node in graph.nodes.iter().filter(|n| n.node_type == UnifiedNodeType::Model) {
+        if let Some(path) = node.file_path.as_deref() {
+            file_of.insert(node.id.as_str(), path);
+            if !is_file_node(node) || !model_id.contains_key(path) {
+                model_id.insert(path, node.id.as_str());
+            }
+        }
+    }
+
+    let relations: Vec<(&str, &str, &UnifiedEdge)> = graph
+        .edges
+        .iter()
+        .filter_map(|edge| {
+            let source = file_of.get(edge.source.as_str())?;
+            let target = file_of.get(edge.target.as_str())?;
+            relation_kind(edge).map(|_| (*source, *target, edge))
+        })
+        .collect();
+
+    let mut ownership = FileDependencies::new();
+    let mut linked = FileDependencies::new();
+    for &(source, target, edge) in &relations {
+        linked.entry(source.to_string()).or_default().insert(target.to_string());
+        linked.entry(target.to_string()).or_default();
+        if let Some((child, parent)) = owner(source, target, edge) {
+            ownership.entry(child.to_string()).or_default().insert(parent.to_string());
+        }
+    }
+
+    let mut cycles = Vec::new();
+    for group in find_cycles(&linked) {
+        let members: BTreeSet<&str> = group.iter().map(|p| p.as_str()).collect();
+        let owned_within: FileDependencies = ownership
+            .iter()
+            .filter(|(child, _)| members.contains(child.as_str()))
+            .map(|(child, parents)| {
+                let parents = parents
+                    .iter()
+                    .filter(|p| members.contains(p.as_str()))
+                    .cloned()
+                    .collect();
+                (child.clone(), parents)
+            })
+            .collect();
+        let kind = if find_cycles(&owned_within).is_empty() {
+            ModelCycleKind::ParentChild
+        } else {
+            ModelCycleKind::Deep
+        };
+
+        cycles.push(ModelCycle {
+            kind,
+            model_ids: members.iter().map(|p| model_id[p].to_string()).collect(),
+            edge_ids: relations
+                .iter()
+                .filter(|(s, t, _)| s != t && members.contains(s) && members.contains(t))
+                .map(|(_, _, e)| e.id.clone())
+                .collect(),
+        });
+    }
+
+    let mut self_references: Vec<(&str, Vec<String>)> = Vec::new();
+    for &(source, _, edge) in relations.iter().filter(|(s, t, _)| s == t) {
+        match self_references.iter_mut().find(|(path, _)| *path == source) {
+            Some((_, edge_ids)) => edge_ids.push(edge.id.clone()),
+            None => self_references.push((source, vec![edge.id.clone()])),
+        }
+    }
+    cycles.extend(self_references.into_iter().map(|(path, edge_ids)| ModelCycle {
+        kind: ModelCycleKind::SelfReference,
+        model_ids: vec![model_id[path].to_string()],
+        edge_ids,
+    }));
+
+    cycles.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.model_ids.cmp(&b.model_ids)));
+    cycles
+}
+
+/// Relationship type of an Eloquent relationship edge (`belongs_to`, `morphTo`, ...)
+///
+/// Inferred inverses are left out, they would make every relationship a loop.
+fn relation_kind(edge: &UnifiedEdge) -> Option<&str> {
+    if edge.metadata.inferred == Some(true) {
+        return None;
+    }
+
+    match &edge.edge_type {
+        UnifiedEdgeType::HasRelation => edge.label.as_deref(),
+        UnifiedEdgeType::Custom(kind) => match kind.as_str() {
+            "belongs_to" | "has_one" | "has_many" | "belongs_to_many" => Some(kind.as_str()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// `(child, parent)` of a relationship, None for many-to-many ones
+fn owner<'a>(source: &'a str, target: &'a str, edge: &UnifiedEdge) -> Option<(&'a str, &'a str)> {
+    match relation_kind(edge)? {
+        "belongs_to" | "morphTo" => Some((source, target)),
+        "has_one" | "has_many" | "morphOne" | "morphMany" => Some((target, source)),
+        _ => None,
+    }
+}
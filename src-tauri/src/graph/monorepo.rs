@@ -0,0 +1,98 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use crate::models::{GraphMetadata, UnifiedGraph};
+use crate::parsers::common::generate_id;
+
+use super::is_file_node;
+
+/// Directory of the sub-project at the monorepo root
+pub const ROOT_PROJECT: &str = ".";
+
+/// Merge the graphs of a monorepo's sub-projects into one graph
+///
+/// `projects` pairs the directory of each sub-project, relative to `root_path`
+/// (`ROOT_PROJECT` for the root itself), with its graph. File paths are made
+/// relative to `root_path` and IDs rebuilt from them, so two sub-projects with
+/// the same file layout don't collide. Every node is tagged with its
+/// sub-project directory in `extra["project"]`.
+pub fn merge_subprojects(root_path: &Path, projects: Vec<(String, UnifiedGraph)>) -> UnifiedGraph {
+    let mut languages = BTreeSet::new();
+    let mut versions = BTreeSet::new();
+    let mut framework_versions = BTreeSet::new();
+    let mut total_files = 0;
+    let mut total_lines = None;
+    let mut warnings = Vec::new();
+    let mut merged = UnifiedGraph::new();
+
+    for (project, graph) in projects {
+        languages.insert(graph.metadata.language.clone());
+        versions.insert(graph.metadata.parser_version.clone());
+        framework_versions.insert(graph.metadata.framework_version);
+        total_files += graph.metadata.total_files;
+        if let Some(lines) = graph.metadata.total_lines {
+            total_lines = Some(total_lines.unwrap_or(0) + lines);
+        }
+
+        let at_root = project == ROOT_PROJECT;
+        for warning in &graph.metadata.warnings {
+            if at_root {
+                warnings.push(warning.clone());
+            } else {
+                warnings.push(format!("{}: {}", project, warning));
+            }
+        }
+        let foreign_id = |id: &str| generate_id(&format!("{}:{}", project, id));
+
+        // File nodes keep id == generate_id(file_path), symbols get a per-project id
+        let mut ids: HashMap<String, String> = HashMap::new();
+        for mut node in graph.nodes {
+            if !at_root {
+                let is_file = is_file_node(&node);
+                node.file_path = node.file_path.map(|path| format!("{}/{}", project, path));
+                let new_id = match node.file_path.as_deref() {
+                    Some(path) if is_file => generate_id(path),
+                    _ => foreign_id(&node.id),
+                };
+                let old_id = std::mem::replace(&mut node.id, new_id);
+                ids.insert(old_id, node.id.clone());
+            }
+            node.metadata
+                .extra
+                .insert("project".to_string(), serde_json::json!(project));
+            merged.add_node(node);
+        }
+
+        for mut edge in graph.edges {
+            if !at_root {
+                let remap = |id: &str| ids.get(id).cloned().unwrap_or_else(|| foreign_id(id));
+                edge.source = remap(&edge.source);
+                edge.target = remap(&edge.target);
+                edge.id = format!("{}->{}:{}", edge.source, edge.target, edge.edge_type.as_key());
+            }
+            merged.add_edge(edge);
+        }
+    }
+
+    let join = |values: BTreeSet<String>| values.into_iter().collect::<Vec<_>>().join(", ");
+    // Only kept when every sub-project is on the same version
+    let framework_version = match framework_versions.len() {
+        1 => framework_versions.into_iter().next().flatten(),
+        _ => None,
+    };
+    merged.with_metadata(GraphMetadata {
+        project_name: root_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default()
+            .to_string(),
+        root_path: root_path.to_string_lossy().to_string(),
+        language: join(languages),
+        total_files,
+        total_lines,
+        scanned_at: Some(chrono::Utc::now().to_rfc3339()),
+        parser_version: join(versions),
+        framework_version,
+        warnings,
+    })
+}
@@ -0,0 +1,135 @@
+use crate::models::{UnifiedGraph, UnifiedNode, UnifiedNodeType};
+
+/// Default number of nodes `search_nodes` returns
+pub const DEFAULT_SEARCH_LIMIT: usize = 50;
+
+// Match quality, best first. Later fields rank a little lower than `name`.
+const EXACT_SCORE: i64 = 10_000;
+const PREFIX_SCORE: i64 = 8_000;
+const SUBSTRING_SCORE: i64 = 6_000;
+const SUBSEQUENCE_SCORE: i64 = 3_000;
+const FIELD_PENALTY: i64 = 500;
+
+/// Find the nodes matching `query`, best matches first
+///
+/// The query is matched case-insensitively against the name, qualified name
+/// and file path of each node: exact matches rank highest, then prefixes,
+/// substrings and finally fuzzy matches (the query's characters in order).
+/// Only nodes of `node_type` are considered when given. An empty query matches
+/// nothing.
+pub fn search_nodes(
+    graph: &UnifiedGraph,
+    query: &str,
+    node_type: Option<&UnifiedNodeType>,
+    limit: usize,
+) -> Vec<UnifiedNode> {
+    let query = query.trim().to_lowercase();
+    let query = query.as_bytes();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches: Vec<(i64, &UnifiedNode)> = graph
+        .nodes
+        .iter()
+        .filter(|node| node_type.map(|t| &node.node_type == t).unwrap_or(true))
+        .filter_map(|node| {
+            let fields = [
+                Some(node.name.as_str()),
+                Some(node.qualified_name.as_str()),
+                node.file_path.as_deref(),
+            ];
+            fields
+                .iter()
+                .enumerate()
+                .filter_map(|(rank, field)| {
+                    let score = match_score(query, field.as_ref()?)?;
+                    Some(score - rank as i64 * FIELD_PENALTY)
+                })
+                .max()
+                .map(|score| (score, node))
+        })
+        .collect();
+
+    // Shorter names first among equal scores, they are closer to the query
+    matches.sort_by(|(a_score, a), (b_score, b)| {
+        b_score
+            .cmp(a_score)
+            .then(a.name.len().cmp(&b.name.len()))
+            .then(a.name.cmp(&b.name))
+    });
+
+    matches
+        .into_iter()
+        .take(limit)
+        .map(|(_, node)| node.clone())
+        .collect()
+}
+
+/// Score of `candidate` for a lowercase query, `None` when it doesn't match
+///
+/// Compares bytes, lowercasing ASCII on the fly: only non-ASCII candidates
+/// are lowercased up front, keeping searches allocation free on identifiers.
+fn match_score(query: &[u8], candidate: &str) -> Option<i64> {
+    let lowered;
+    let candidate = if candidate.is_ascii() {
+        candidate.as_bytes()
+    } else {
+        lowered = candidate.to_lowercase();
+        lowered.as_bytes()
+    };
+    let matches_at = |start: usize| {
+        candidate[start..start + query.len()]
+            .iter()
+            .zip(query)
+            .all(|(c, q)| c.to_ascii_lowercase() == *q)
+    };
+
+    // Every match is a subsequence too: a cheap single pass rejects most nodes
+    let fuzzy_score = subsequence_score(query, candidate)?;
+    // Closer lengths rank higher within a match kind
+    let extra_length = (candidate.len() - query.len()) as i64;
+
+    if matches_at(0) {
+        return Some(if extra_length == 0 {
+            EXACT_SCORE
+        } else {
+            PREFIX_SCORE - extra_length.min(999)
+        });
+    }
+    if let Some(position) = (1..=candidate.len() - query.len()).find(|&start| matches_at(start)) {
+        return Some(SUBSTRING_SCORE - (position as i64 + extra_length).min(999));
+    }
+
+    Some(fuzzy_score)
+}
+
+/// Fuzzy score: every query character found in order, consecutive ones and
+/// ones starting a word (after `\`, `/`, `_`, `.`, ...) earning a bonus
+fn subsequence_score(query: &[u8], candidate: &[u8]) -> Option<i64> {
+    let mut score = SUBSEQUENCE_SCORE;
+    let mut position = 0;
+    let mut previous: Option<usize> = None;
+
+    for &wanted in query {
+        let found = position
+            + candidate[position..]
+                .iter()
+                .position(|c| c.to_ascii_lowercase() == wanted)?;
+
+        let starts_word = found == 0 || !candidate[found - 1].is_ascii_alphanumeric();
+        if starts_word {
+            score += 20;
+        }
+        match previous {
+            Some(previous) if found == previous + 1 => score += 30,
+            Some(previous) => score -= (found - previous - 1).min(50) as i64,
+            None => score -= found.min(50) as i64,
+        }
+
+        previous = Some(found);
+        position = found + 1;
+    }
+
+    Some(score.min(SUBSTRING_SCORE - FIELD_PENALTY * 3 - 1))
+}
@@ -0,0 +1,186 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::models::{UnifiedEdge, UnifiedEdgeType, UnifiedGraph, UnifiedNode};
+
+use super::is_dependency_edge;
+
+/// Subgraph of one named route: its route file, controller and everything
+/// the controller depends on (models, views, resources, ...)
+///
+/// Files are followed as a whole: reaching a class brings in its file node
+/// and the other nodes of its file, as most edges start from the file node.
+/// Only dependency edges are followed, and the route file contributes only
+/// the `routes_to` edges of this route. Returns `None` when no route file
+/// declares `route_name` with a controller action the graph resolved.
+pub fn route_slice(graph: &UnifiedGraph, route_name: &str) -> Option<UnifiedGraph> {
+    let (route_file, method, uri) = graph.nodes.iter().find_map(|node| {
+        let routes = node.metadata.extra.get("routes")?.as_array()?;
+        let route = routes
+            .iter()
+            .find(|r| r.get("name").and_then(|n| n.as_str()) == Some(route_name))?;
+        Some((
+            node,
+            route.get("method").and_then(|m| m.as_str()).unwrap_or("ANY"),
+            route.get("uri").and_then(|u| u.as_str()).unwrap_or(""),
+        ))
+    })?;
+
+    // The edges generate_edges created for this route
+    let route_edges: Vec<&UnifiedEdge> = graph
+        .edges
+        .iter()
+        .filter(|e| {
+            e.source == route_file.id
+                && e.edge_type == UnifiedEdgeType::Custom("routes_to".to_string())
+                && e.label.as_deref() == Some(method)
+                && e.detail.as_deref() == Some(uri)
+        })
+        .collect();
+    if route_edges.is_empty() {
+        return None;
+    }
+
+    let nodes: HashMap<&str, &UnifiedNode> =
+        graph.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+    let mut nodes_of_file: HashMap<&str, Vec<&str>> = HashMap::new();
+    for node in &graph.nodes {
+        if let Some(path) = node.file_path.as_deref() {
+            nodes_of_file.entry(path).or_default().push(node.id.as_str());
+        }
+    }
+    let mut successors: HashMap<&str, Vec<&str>> = HashMap::new();
+    for edge in graph.edges.iter().filter(|e| is_dependency_edge(&e.edge_type)) {
+        successors.entry(edge.source.as_str()).or_default().push(edge.target.as_str());
+    }
+
+    let mut included: HashSet<&str> = HashSet::new();
+    let mut queue: VecDeque<&str> = route_edges.iter().map(|e| e.target.as_str()).collect();
+    while let Some(id) = queue.pop_front() {
+        let node = match nodes.get(id) {
+            Some(node) => node,
+            None => continue,
+        };
+        // Never expand the route file, its other routes aren't part of the slice
+        if node.id == route_file.id || !included.insert(id) {
+            continue;
+        }
+
+        // The file node and the other symbols of the file
+        let same_file = node
+            .file_path
+            .as_deref()
+            .and_then(|path| nodes_of_file.get(path))
+            .map(|ids| ids.as_slice())
+            .unwrap_or(&[]);
+        queue.extend(same_file.iter().copied());
+        queue.extend(successors.get(id).map(|s| s.as_slice()).unwrap_or(&[]));
+    }
+    included.insert(route_file.id.as_str());
+
+    let mut slice = UnifiedGraph::new().with_metadata(graph.metadata.clone());
+    for node in graph.nodes.iter().filter(|n| included.contains(n.id.as_str())) {
+        slice.add_node(node.clone());
+    }
+    for edge in &graph.edges {
+        let keep = if edge.source == route_file.id {
+            // Edge IDs don't tell two routes to the same controller apart
+            route_edges.iter().any(|e| std::ptr::eq(*e, edge))
+        } else {
+            included.contains(edge.source.as_str()) && included.contains(edge.target.as_str())
+        };
+        if keep {
+            slice.add_edge(edge.clone());
+        }
+    }
+
+    Some(slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::common::generate_id;
+    use crate::parsers::laravel::fixtures::graph_of;
+
+    const ROUTES: &str = "<?php
+
+use App\\Http\\Controllers\\MemberController;
+use App\\Http\\Controllers\\OrderController;
+
+Route::get('/members/{member}', [MemberController::class, 'show'])->name('members.show');
+Route::get('/orders', [OrderController::class, 'index'])->name('orders.index');
+";
+
+    const MEMBER_CONTROLLER: &str = "<?php
+namespace App\\Http\\Controllers;
+
+use App\\Models\\Member;
+
+class MemberController extends Controller
+{
+    public function show($id)
+    {
+        return view('members.show', ['member' => Member::findOrFail($id)]);
+    }
+}
+";
+
+    const ORDER_CONTROLLER: &str = "<?php
+namespace App\\Http\\Controllers;
+
+use App\\Models\\Order;
+
+class OrderController extends Controller
+{
+    public function index()
+    {
+        return Order::all();
+    }
+}
+";
+
+    fn model(name: &str) -> String {
+        format!(
+            "<?php\nnamespace App\\Models;\n\nclass {} extends Model\n{{\n}}\n",
+            name
+        )
+    }
+
+    #[tokio::test]
+    async fn slice_holds_the_route_controller_and_its_models_only() {
+        let (member, order) = (model("Member"), model("Order"));
+        let graph = graph_of(&[
+            ("routes/web.php", ROUTES),
+            (
+                "app/Http/Controllers/MemberController.php",
+                MEMBER_CONTROLLER,
+            ),
+            ("app/Http/Controllers/OrderController.php", ORDER_CONTROLLER),
+            ("app/Models/Member.php", &member),
+            ("app/Models/Order.php", &order),
+        ])
+        .await;
+
+        let slice = route_slice(&graph, "members.show").unwrap();
+        let has = |qualified_name: &str| {
+            slice
+                .nodes
+                .iter()
+                .any(|n| n.qualified_name == qualified_name)
+        };
+
+        assert!(has("App\\Http\\Controllers\\MemberController"));
+        assert!(has("App\\Models\\Member"));
+        assert!(!has("App\\Http\\Controllers\\OrderController"));
+        assert!(!has("App\\Models\\Order"));
+        assert_eq!(
+            slice
+                .edges
+                .iter()
+                .filter(|e| e.source == generate_id("routes/web.php"))
+                .count(),
+            1
+        );
+        assert!(route_slice(&graph, "members.index").is_none());
+    }
+}
@@ -0,0 +1,191 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::models::{UnifiedGraph, UnifiedNode, UnifiedNodeType};
+
+use super::is_file_node;
+
+/// Default number of actions above which a controller is considered fat
+pub const DEFAULT_MAX_CONTROLLER_ACTIONS: usize = 15;
+
+/// A controller exposing too many actions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FatController {
+    /// Controller class node ID
+    pub node_id: String,
+
+    /// Number of public methods (constructor and magic methods excluded)
+    pub action_count: usize,
+}
+
+/// Find controllers with more than `max_actions` public methods
+///
+/// Actions are the public method nodes declared in the controller's file,
+/// sorted by descending action count.
+pub fn fat_controllers(graph: &UnifiedGraph, max_actions: usize) -> Vec<FatController> {
+    let mut actions_per_file: HashMap<&str, usize> = HashMap::new();
+    for node in &graph.nodes {
+        if node.node_type != UnifiedNodeType::Method || node.name.starts_with("__") {
+            continue;
+        }
+        let is_public = node
+            .metadata
+            .visibility
+            .as_deref()
+            .map(|v| v == "public")
+            .unwrap_or(true);
+        if let (true, Some(path)) = (is_public, node.file_path.as_deref()) {
+            *actions_per_file.entry(path).or_insert(0) += 1;
+        }
+    }
+
+    // One controller per file, preferring the class node over the file node
+    let mut controllers: HashMap<&str, &UnifiedNode> = HashMap::new();
+    for node in &graph.nodes {
+        if node.node_type != UnifiedNodeType::Controller {
+            continue;
+        }
+        if let Some(path) = node.file_path.as_deref() {
+            if !is_file_node(node) || !controllers.contains_key(path) {
+                controllers.insert(path, node);
+            }
+        }
+    }
+
+    let mut fat: Vec<FatController> = controllers
+        .into_iter()
+        .filter_map(|(path, node)| {
+            let action_count = actions_per_file.get(path).copied().unwrap_or(0);
+            if action_count > max_actions {
+                Some(FatController {
+                    node_id: node.id.clone(),
+                    action_count,
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    fat.sort_by(|a, b| {
+        b.action_count
+            .cmp(&a.action_count)
+            .then_with(|| a.node_id.cmp(&b.node_id))
+    });
+    fat
+}
+
+/// A fully qualified class name declared in more than one file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateClass {
+    /// Fully qualified name (`App\Models\User`)
+    pub qualified_name: String,
+
+    /// Files declaring it, sorted
+    pub files: Vec<String>,
+}
+
+/// Find PHP classes, interfaces, traits and enums declared in several files
+///
+/// PSR-4 autoloading maps each fully qualified name to one file, so the other
+/// declarations are never loaded (typically leftovers of a bad merge or a
+/// copied file). Delphi types are scoped by their unit and aren't checked.
+pub fn duplicate_classes(graph: &UnifiedGraph) -> Vec<DuplicateClass> {
+    let mut files_of: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+
+    for node in &graph.nodes {
+        let is_class_like = matches!(
+            node.node_type,
+            UnifiedNodeType::Class
+                | UnifiedNodeType::Controller
+                | UnifiedNodeType::Model
+                | UnifiedNodeType::Interface
+                | UnifiedNodeType::Trait
+                | UnifiedNodeType::Enum
+        );
+        if !is_class_like || node.language != "php" {
+            continue;
+        }
+
+        // File nodes share their namespace as qualified name, skip them
+        if let (Some(path), false) = (node.file_path.as_deref(), is_file_node(node)) {
+            files_of.entry(&node.qualified_name).or_default().insert(path);
+        }
+    }
+
+    files_of
+        .into_iter()
+        .filter(|(_, files)| files.len() > 1)
+        .map(|(qualified_name, files)| DuplicateClass {
+            qualified_name: qualified_name.to_string(),
+            files: files.into_iter().map(|f| f.to_string()).collect(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::laravel::fixtures::graph_of;
+
+    const USER: &str = "<?php\nnamespace App\\Models;\n\nclass User extends Model\n{\n}\n";
+
+    #[tokio::test]
+    async fn classes_declared_in_two_files_are_duplicates() {
+        let graph = graph_of(&[
+            ("app/Models/User.php", USER),
+            ("app/Models/User.backup.php", USER),
+            (
+                "app/Models/Post.php",
+                "<?php\nnamespace App\\Models;\n\nclass Post extends Model\n{\n}\n",
+            ),
+        ])
+        .await;
+
+        let duplicates = duplicate_classes(&graph);
+
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].qualified_name, "App\\Models\\User");
+        assert_eq!(
+            duplicates[0].files,
+            vec!["app/Models/User.backup.php", "app/Models/User.php"]
+        );
+    }
+
+    fn controller(name: &str, actions: &[&str]) -> (String, String) {
+        let methods: String = actions
+            .iter()
+            .map(|action| format!("    public function {}()\n    {{\n    }}\n\n", action))
+            .collect();
+        (
+            format!("app/Http/Controllers/{}.php", name),
+            format!(
+                "<?php\nnamespace App\\Http\\Controllers;\n\nclass {} extends Controller\n{{\n\
+    public function __construct()\n    {{\n    }}\n\n{}\
+    private function helper()\n    {{\n    }}\n}}\n",
+                name, methods
+            ),
+        )
+    }
+
+    #[tokio::test]
+    async fn controllers_with_too_many_actions_are_fat() {
+        let fat = controller(
+            "OrderController",
+            &["index", "show", "store", "update", "destroy"],
+        );
+        let lean = controller("HomeController", &["index", "about"]);
+        let graph = graph_of(&[(&fat.0, &fat.1), (&lean.0, &lean.1)]).await;
+
+        let fat_controllers = fat_controllers(&graph, 3);
+
+        assert_eq!(fat_controllers.len(), 1);
+        let node = graph.find_node(&fat_controllers[0].node_id).unwrap();
+        assert_eq!(
+            node.qualified_name,
+            "App\\Http\\Controllers\\OrderController"
+        );
+        assert!(!is_file_node(node));
+        assert_eq!(fat_controllers[0].action_count, 5);
+    }
+}
@@ -3,6 +3,8 @@
 
 pub mod commands;
 pub mod core;
+pub mod export;
+pub mod graph;
 pub mod models;
 pub mod parsers;
 
@@ -17,8 +19,27 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             greet,
             detect_project_type,
+            detect_project_types,
             list_parsers,
             scan_directory,
+            analyze,
+            rebuild_graph,
+            scan_monorepo,
+            diff_graphs,
+            architecture_report,
+            find_fat_controllers,
+            find_duplicate_classes,
+            find_model_cycles,
+            check_layering,
+            check_blade_layouts,
+            export_graph,
+            export_route_slice,
+            search_nodes,
+            filter_graph,
+            get_node_details,
+            find_path,
+            watch_directory,
+            stop_watch,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
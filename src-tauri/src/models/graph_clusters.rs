@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+use super::UnifiedGraph;
+
+impl UnifiedGraph {
+    /// Store the cluster of every node in its `cluster` metadata
+    ///
+    /// The cluster is the namespace declared by the node's file (PHP), else
+    /// the file's parent directory (`.` at the root). Symbols share the
+    /// cluster of their file, nodes without a file (tables, packages) get none.
+    pub fn annotate_clusters(&mut self) {
+        let namespaces: HashMap<String, String> = self
+            .nodes
+            .iter()
+            .filter_map(|node| {
+                let namespace = node.metadata.extra.get("namespace")?.as_str()?;
+                Some((node.file_path.clone()?, namespace.to_string()))
+            })
+            .collect();
+
+        for node in &mut self.nodes {
+            let cluster = match node.file_path.as_deref() {
+                Some(path) => namespaces
+                    .get(path)
+                    .cloned()
+                    .unwrap_or_else(|| parent_directory(path)),
+                None => continue,
+            };
+            node.metadata
+                .extra
+                .insert("cluster".to_string(), serde_json::Value::String(cluster));
+        }
+    }
+
+    /// Node IDs grouped by cluster (see [`UnifiedGraph::annotate_clusters`])
+    ///
+    /// Nodes without a `cluster` are left out.
+    pub fn clusters(&self) -> HashMap<String, Vec<String>> {
+        let mut clusters: HashMap<String, Vec<String>> = HashMap::new();
+        for node in &self.nodes {
+            if let Some(cluster) = node.metadata.extra.get("cluster").and_then(|c| c.as_str()) {
+                clusters.entry(cluster.to_string()).or_default().push(node.id.clone());
+            }
+        }
+        clusters
+    }
+}
+
+fn parent_directory(path: &str) -> String {
+    match path.rsplit_once(['/', '\\']) {
+        Some((parent, _)) if !parent.is_empty() => parent.replace('\\', "/"),
+        _ => ".".to_string(),
+    }
+}
@@ -0,0 +1,169 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use super::{UnifiedEdge, UnifiedGraph, UnifiedNode};
+
+/// A node present in both graphs whose content differs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeChange {
+    /// Node ID (or the new ID for moved nodes)
+    pub id: String,
+
+    /// Node as it was in the old graph
+    pub before: UnifiedNode,
+
+    /// Node as it is in the new graph
+    pub after: UnifiedNode,
+}
+
+/// Differences between two analyses of the same project
+///
+/// Nodes and edges are matched by ID. Since node IDs are derived from the
+/// file path, a moved or renamed file shows up as a removal plus an addition.
+/// When such a pair shares a unique `(node_type, qualified_name)` it is
+/// reported in `moved_nodes` instead.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct GraphDiff {
+    /// Nodes only present in the new graph
+    pub added_nodes: Vec<UnifiedNode>,
+
+    /// Nodes only present in the old graph
+    pub removed_nodes: Vec<UnifiedNode>,
+
+    /// Nodes matched by qualified name after their ID changed
+    pub moved_nodes: Vec<NodeChange>,
+
+    /// Edges only present in the new graph
+    pub added_edges: Vec<UnifiedEdge>,
+
+    /// Edges only present in the old graph
+    pub removed_edges: Vec<UnifiedEdge>,
+
+    /// Nodes present in both graphs with different content
+    pub changed_nodes: Vec<NodeChange>,
+}
+
+impl GraphDiff {
+    /// Check if both graphs are equivalent
+    pub fn is_empty(&self) -> bool {
+        self.added_nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.moved_nodes.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+            && self.changed_nodes.is_empty()
+    }
+
+    /// Pair removed/added nodes sharing a unique (node_type, qualified_name)
+    fn match_moved_nodes(&mut self) {
+        let removed_keys = unique_keys(&self.removed_nodes);
+        let added_keys = unique_keys(&self.added_nodes);
+
+        let mut moved_old_ids = HashSet::new();
+        let mut moved_new_ids = HashSet::new();
+
+        for (old_index, before) in self.removed_nodes.iter().enumerate() {
+            let key = move_key(before);
+            if removed_keys.get(&key) != Some(&old_index) {
+                continue;
+            }
+            if let Some(new_index) = added_keys.get(&key) {
+                let after = &self.added_nodes[*new_index];
+                moved_old_ids.insert(before.id.clone());
+                moved_new_ids.insert(after.id.clone());
+                self.moved_nodes.push(NodeChange {
+                    id: after.id.clone(),
+                    before: before.clone(),
+                    after: after.clone(),
+                });
+            }
+        }
+
+        self.removed_nodes.retain(|n| !moved_old_ids.contains(&n.id));
+        self.added_nodes.retain(|n| !moved_new_ids.contains(&n.id));
+    }
+}
+
+impl UnifiedGraph {
+    /// Compare this graph (old) against another one (new)
+    pub fn diff(&self, other: &UnifiedGraph) -> GraphDiff {
+        let mut diff = GraphDiff::default();
+
+        let old_nodes: HashMap<&str, &UnifiedNode> =
+            self.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+        let new_nodes: HashMap<&str, &UnifiedNode> =
+            other.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+
+        for node in &other.nodes {
+            match old_nodes.get(node.id.as_str()) {
+                Some(old) => {
+                    if node_fingerprint(old) != node_fingerprint(node) {
+                        diff.changed_nodes.push(NodeChange {
+                            id: node.id.clone(),
+                            before: (*old).clone(),
+                            after: node.clone(),
+                        });
+                    }
+                }
+                None => diff.added_nodes.push(node.clone()),
+            }
+        }
+
+        for node in &self.nodes {
+            if !new_nodes.contains_key(node.id.as_str()) {
+                diff.removed_nodes.push(node.clone());
+            }
+        }
+
+        diff.match_moved_nodes();
+
+        let old_edges: HashSet<&str> = self.edges.iter().map(|e| e.id.as_str()).collect();
+        let new_edges: HashSet<&str> = other.edges.iter().map(|e| e.id.as_str()).collect();
+
+        diff.added_edges = other
+            .edges
+            .iter()
+            .filter(|e| !old_edges.contains(e.id.as_str()))
+            .cloned()
+            .collect();
+        diff.removed_edges = self
+            .edges
+            .iter()
+            .filter(|e| !new_edges.contains(e.id.as_str()))
+            .cloned()
+            .collect();
+
+        diff
+    }
+}
+
+/// Map (node_type, qualified_name) to node index, keeping only unambiguous keys
+fn unique_keys(nodes: &[UnifiedNode]) -> HashMap<String, usize> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    let mut indexes: HashMap<String, usize> = HashMap::new();
+
+    for (index, node) in nodes.iter().enumerate() {
+        if node.qualified_name.is_empty() {
+            continue;
+        }
+        let key = move_key(node);
+        *counts.entry(key.clone()).or_insert(0) += 1;
+        indexes.insert(key, index);
+    }
+
+    indexes.retain(|key, _| counts.get(key) == Some(&1));
+    indexes
+}
+
+/// Key used to recognize a node across a file move
+fn move_key(node: &UnifiedNode) -> String {
+    format!("{:?}|{}", node.node_type, node.qualified_name)
+}
+
+/// Comparable representation of a node, ignoring its layout position
+fn node_fingerprint(node: &UnifiedNode) -> serde_json::Value {
+    let mut value = serde_json::to_value(node).unwrap_or(serde_json::Value::Null);
+    if let Some(obj) = value.as_object_mut() {
+        obj.remove("position");
+    }
+    value
+}
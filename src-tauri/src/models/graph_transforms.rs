@@ -0,0 +1,253 @@
+use std::collections::{HashMap, HashSet};
+use super::{UnifiedEdge, UnifiedEdgeType, UnifiedGraph, UnifiedNode, UnifiedNodeType};
+
+impl UnifiedGraph {
+    /// Merge each Inertia page with the controller that renders it into one "screen" node
+    ///
+    /// Only 1:1 pairs are merged: the controller renders exactly one page and
+    /// that page is rendered by no other controller. The screen keeps the
+    /// controller ID (so routes still point at it), takes the page name as
+    /// label and becomes a `Page`. Edges of the page are redirected to the
+    /// screen and the render edges between the pair disappear.
+    pub fn merge_inertia_screens(&self) -> UnifiedGraph {
+        let nodes: HashMap<&str, &UnifiedNode> =
+            self.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+
+        // Distinct controller -> page render pairs
+        let mut pages_of: HashMap<&str, HashSet<&str>> = HashMap::new();
+        let mut controllers_of: HashMap<&str, HashSet<&str>> = HashMap::new();
+        for edge in &self.edges {
+            if edge.edge_type != UnifiedEdgeType::Custom("renders".to_string()) {
+                continue;
+            }
+            let is_pair = matches!(
+                (nodes.get(edge.source.as_str()), nodes.get(edge.target.as_str())),
+                (Some(source), Some(target))
+                    if source.node_type == UnifiedNodeType::Controller && is_inertia_page(target)
+            );
+            if is_pair {
+                pages_of.entry(&edge.source).or_default().insert(&edge.target);
+                controllers_of.entry(&edge.target).or_default().insert(&edge.source);
+            }
+        }
+
+        // page ID -> controller (screen) ID
+        let mut merged: HashMap<&str, &str> = HashMap::new();
+        for (controller, pages) in &pages_of {
+            if pages.len() != 1 {
+                continue;
+            }
+            let page = *pages.iter().next().unwrap();
+            if controllers_of.get(page).map(|c| c.len()) == Some(1) {
+                merged.insert(page, controller);
+            }
+        }
+
+        let mut graph = UnifiedGraph::new().with_metadata(self.metadata.clone());
+        let page_of_screen: HashMap<&str, &UnifiedNode> = merged
+            .iter()
+            .map(|(page, controller)| (*controller, nodes[page]))
+            .collect();
+
+        for node in &self.nodes {
+            if merged.contains_key(node.id.as_str()) {
+                continue;
+            }
+
+            match page_of_screen.get(node.id.as_str()) {
+                None => graph.add_node(node.clone()),
+                Some(page) => {
+                    let mut screen = node.clone();
+                    screen.node_type = UnifiedNodeType::Page;
+                    screen.label = page.label.clone();
+                    screen.metadata.extra.insert(
+                        "merged_page".to_string(),
+                        serde_json::Value::String(page.id.clone()),
+                    );
+                    screen.metadata.extra.insert(
+                        "merged_controller".to_string(),
+                        serde_json::Value::String(node.label.clone()),
+                    );
+                    graph.add_node(screen);
+                }
+            }
+        }
+
+        // Redirected page edges may duplicate an edge the controller already has
+        let mut edge_ids: HashSet<String> = self
+            .edges
+            .iter()
+            .filter(|e| {
+                !merged.contains_key(e.source.as_str()) && !merged.contains_key(e.target.as_str())
+            })
+            .map(|e| e.id.clone())
+            .collect();
+
+        for edge in &self.edges {
+            let source = merged.get(edge.source.as_str()).copied().unwrap_or(&edge.source);
+            let target = merged.get(edge.target.as_str()).copied().unwrap_or(&edge.target);
+            if source == target {
+                continue;
+            }
+
+            if source == edge.source && target == edge.target {
+                graph.add_edge(edge.clone());
+                continue;
+            }
+
+            let mut rewired = edge.clone();
+            rewired.id =
+                UnifiedEdge::new(source.to_string(), target.to_string(), edge.edge_type.clone()).id;
+            rewired.source = source.to_string();
+            rewired.target = target.to_string();
+            if edge_ids.insert(rewired.id.clone()) {
+                graph.add_edge(rewired);
+            }
+        }
+
+        graph
+    }
+
+    /// Copy of the graph without the nodes of `language` and their edges
+    ///
+    /// Lets a merged multi-language graph be narrowed without parsing again.
+    /// Metadata is kept as is.
+    pub fn without_language(&self, language: &str) -> UnifiedGraph {
+        let mut graph = UnifiedGraph::new().with_metadata(self.metadata.clone());
+        let mut removed: HashSet<&str> = HashSet::new();
+
+        for node in &self.nodes {
+            if node.language == language {
+                removed.insert(node.id.as_str());
+            } else {
+                graph.add_node(node.clone());
+            }
+        }
+
+        for edge in &self.edges {
+            if !removed.contains(edge.source.as_str()) && !removed.contains(edge.target.as_str()) {
+                graph.add_edge(edge.clone());
+            }
+        }
+
+        graph
+    }
+}
+
+/// Check if a node is an Inertia page component
+fn is_inertia_page(node: &UnifiedNode) -> bool {
+    node.qualified_name.starts_with("inertia:")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: &str, language: &str) -> UnifiedNode {
+        UnifiedNode::new(id.to_string(), UnifiedNodeType::Class, id.to_string())
+            .with_language(language)
+    }
+
+    fn edge(source: &str, target: &str) -> UnifiedEdge {
+        UnifiedEdge::new(
+            source.to_string(),
+            target.to_string(),
+            UnifiedEdgeType::Uses,
+        )
+    }
+
+    #[test]
+    fn without_language_drops_its_nodes_and_their_edges() {
+        let mut graph = UnifiedGraph::new();
+        graph.metadata.project_name = "mixed".to_string();
+        graph.add_node(node("controller", "php"));
+        graph.add_node(node("model", "php"));
+        graph.add_node(node("page", "vue"));
+        graph.add_edge(edge("controller", "model"));
+        graph.add_edge(edge("controller", "page"));
+        graph.add_edge(edge("page", "model"));
+
+        let php = graph.without_language("vue");
+
+        let ids: Vec<&str> = php.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, ["controller", "model"]);
+        assert_eq!(php.edges.len(), 1);
+        assert_eq!(
+            (php.edges[0].source.as_str(), php.edges[0].target.as_str()),
+            ("controller", "model")
+        );
+        assert_eq!(php.metadata.project_name, "mixed");
+        assert_eq!(graph.node_count(), 3);
+    }
+
+    fn controller(id: &str) -> UnifiedNode {
+        UnifiedNode::new(id.to_string(), UnifiedNodeType::Controller, id.to_string())
+    }
+
+    fn page(name: &str) -> UnifiedNode {
+        let mut page = UnifiedNode::new(name.to_string(), UnifiedNodeType::Page, name.to_string());
+        page.qualified_name = format!("inertia:{}", name);
+        page
+    }
+
+    fn renders(controller: &str, page: &str) -> UnifiedEdge {
+        UnifiedEdge::new(
+            controller.to_string(),
+            page.to_string(),
+            UnifiedEdgeType::Custom("renders".to_string()),
+        )
+    }
+
+    #[test]
+    fn only_one_to_one_controller_pages_are_merged() {
+        let mut graph = UnifiedGraph::new();
+        graph.add_node(controller("ProfileController"));
+        graph.add_node(controller("UserController"));
+        graph.add_node(page("Profile/Edit"));
+        graph.add_node(page("Users/Index"));
+        graph.add_node(page("Users/Show"));
+        graph.add_node(node("User", "php"));
+        graph.add_edge(renders("ProfileController", "Profile/Edit"));
+        graph.add_edge(renders("UserController", "Users/Index"));
+        graph.add_edge(renders("UserController", "Users/Show"));
+        graph.add_edge(edge("Profile/Edit", "User"));
+
+        let merged = graph.merge_inertia_screens();
+
+        let ids: Vec<&str> = merged.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            [
+                "ProfileController",
+                "UserController",
+                "Users/Index",
+                "Users/Show",
+                "User"
+            ]
+        );
+
+        let screen = &merged.nodes[0];
+        assert_eq!(screen.node_type, UnifiedNodeType::Page);
+        assert_eq!(screen.label, "Profile/Edit");
+        assert_eq!(screen.metadata.extra["merged_page"], "Profile/Edit");
+        assert_eq!(
+            screen.metadata.extra["merged_controller"],
+            "ProfileController"
+        );
+        assert_eq!(merged.nodes[1].node_type, UnifiedNodeType::Controller);
+
+        let edges: Vec<(&str, &str)> = merged
+            .edges
+            .iter()
+            .map(|e| (e.source.as_str(), e.target.as_str()))
+            .collect();
+        assert_eq!(
+            edges,
+            [
+                ("UserController", "Users/Index"),
+                ("UserController", "Users/Show"),
+                ("ProfileController", "User"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use super::{UnifiedEdgeType, UnifiedGraph};
+
+impl UnifiedGraph {
+    /// IDs of the nodes reachable from `id` along directed edges
+    ///
+    /// Only edges of `edge_types` are followed, all edges when it's empty.
+    /// `id` itself is only included when a cycle leads back to it.
+    pub fn reachable_from(&self, id: &str, edge_types: &[UnifiedEdgeType]) -> HashSet<String> {
+        self.breadth_first(id, edge_types, true)
+            .into_keys()
+            .collect()
+    }
+
+    /// IDs of the nodes `id` depends on, directly or not (see [`UnifiedGraph::reachable_from`])
+    pub fn descendants(&self, id: &str) -> HashSet<String> {
+        self.reachable_from(id, &[])
+    }
+
+    /// IDs of the nodes depending on `id`, directly or not
+    pub fn ancestors(&self, id: &str) -> HashSet<String> {
+        self.breadth_first(id, &[], false).into_keys().collect()
+    }
+
+    /// Node IDs along a shortest directed path from `from` to `to`, both included
+    ///
+    /// None when `to` can't be reached. Among paths of equal length the one
+    /// found first, following edges in graph order, is returned.
+    pub fn shortest_path(&self, from: &str, to: &str) -> Option<Vec<String>> {
+        if from == to {
+            return self.find_node(from).map(|_| vec![from.to_string()]);
+        }
+
+        let parents = self.breadth_first(from, &[], true);
+        if !parents.contains_key(to) {
+            return None;
+        }
+
+        let mut path = vec![to.to_string()];
+        let mut current = to;
+        while current != from {
+            current = parents.get(current)?;
+            path.push(current.to_string());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Breadth-first search from `start`, following edges forward (to their
+    /// target) or backward (to their source)
+    ///
+    /// Maps every reached node to the node it was first reached from.
+    fn breadth_first(
+        &self,
+        start: &str,
+        edge_types: &[UnifiedEdgeType],
+        forward: bool,
+    ) -> HashMap<String, String> {
+        let mut parents: HashMap<String, String> = HashMap::new();
+        let mut queue: VecDeque<String> = VecDeque::from([start.to_string()]);
+
+        while let Some(id) = queue.pop_front() {
+            let edges = if forward {
+                self.edges_from(&id)
+            } else {
+                self.edges_to(&id)
+            };
+
+            for edge in edges {
+                if !edge_types.is_empty() && !edge_types.contains(&edge.edge_type) {
+                    continue;
+                }
+
+                let next = if forward { &edge.target } else { &edge.source };
+                if !parents.contains_key(next) {
+                    parents.insert(next.clone(), id.clone());
+                    queue.push_back(next.clone());
+                }
+            }
+        }
+
+        parents
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{UnifiedEdge, UnifiedNode, UnifiedNodeType};
+
+    /// a -> b -> d, a -> c -> d, then d -calls-> e
+    fn diamond() -> UnifiedGraph {
+        let mut graph = UnifiedGraph::new();
+        for id in ["a", "b", "c", "d", "e"] {
+            graph.add_node(UnifiedNode::new(
+                id.to_string(),
+                UnifiedNodeType::Class,
+                id.to_string(),
+            ));
+        }
+        for (source, target) in [("a", "b"), ("a", "c"), ("b", "d"), ("c", "d")] {
+            graph.add_edge(UnifiedEdge::new(
+                source.to_string(),
+                target.to_string(),
+                UnifiedEdgeType::Uses,
+            ));
+        }
+        graph.add_edge(UnifiedEdge::new(
+            "d".to_string(),
+            "e".to_string(),
+            UnifiedEdgeType::Calls,
+        ));
+        graph
+    }
+
+    fn ids(ids: &[&str]) -> HashSet<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn diamond_reachability() {
+        let graph = diamond();
+
+        assert_eq!(graph.descendants("a"), ids(&["b", "c", "d", "e"]));
+        assert_eq!(graph.ancestors("d"), ids(&["a", "b", "c"]));
+        assert_eq!(graph.ancestors("a"), ids(&[]));
+        assert_eq!(
+            graph.reachable_from("a", &[UnifiedEdgeType::Uses]),
+            ids(&["b", "c", "d"])
+        );
+    }
+
+    #[test]
+    fn start_is_reachable_only_through_a_cycle() {
+        let mut graph = diamond();
+        assert!(!graph.descendants("a").contains("a"));
+
+        graph.add_edge(UnifiedEdge::new(
+            "e".to_string(),
+            "a".to_string(),
+            UnifiedEdgeType::Uses,
+        ));
+        assert!(graph.descendants("a").contains("a"));
+    }
+
+    #[test]
+    fn shortest_path_takes_the_first_branch() {
+        let graph = diamond();
+
+        assert_eq!(
+            graph.shortest_path("a", "e"),
+            Some(vec![
+                "a".to_string(),
+                "b".to_string(),
+                "d".to_string(),
+                "e".to_string()
+            ])
+        );
+        assert_eq!(graph.shortest_path("c", "c"), Some(vec!["c".to_string()]));
+        assert_eq!(graph.shortest_path("d", "a"), None);
+        assert_eq!(graph.shortest_path("a", "missing"), None);
+    }
+}
@@ -5,9 +5,14 @@ mod unified_edge;
 mod unified_graph;
 mod source_file;
 mod parse_result;
+mod graph_diff;
+mod graph_transforms;
+mod graph_clusters;
+mod graph_traversal;
 
 pub use unified_node::*;
 pub use unified_edge::*;
 pub use unified_graph::*;
 pub use source_file::*;
 pub use parse_result::*;
+pub use graph_diff::*;
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use super::SourceFile;
+use super::{AttributeInfo, EnumCase, ParameterInfo, SourceFile, UnifiedGraph};
 
 /// Represents a symbol found in code (class, function, etc.)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,11 +29,40 @@ pub struct Symbol {
     /// Implemented interfaces
     pub implements: Option<Vec<String>>,
 
+    /// Parameters (for functions and methods)
+    pub parameters: Option<Vec<ParameterInfo>>,
+
+    /// Declared return type (for functions and methods)
+    pub return_type: Option<String>,
+
+    /// Attributes on the declaration (PHP 8 `#[...]`)
+    pub attributes: Option<Vec<AttributeInfo>>,
+
     /// Start line
     pub line_start: Option<u32>,
 
     /// End line
     pub line_end: Option<u32>,
+
+    /// Unit section the symbol is declared in (Delphi), None elsewhere
+    #[serde(default)]
+    pub section: Option<UnitSection>,
+
+    /// Backing type of a backed enum (`string` for `enum Status: string`)
+    #[serde(default)]
+    pub backing_type: Option<String>,
+
+    /// Cases of an enum
+    #[serde(default)]
+    pub enum_cases: Option<Vec<EnumCase>>,
+}
+
+/// Section of a Pascal unit: the public `interface` or the private `implementation`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UnitSection {
+    Interface,
+    Implementation,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -128,6 +157,14 @@ pub struct ParseResult {
 
     /// Total files with errors
     pub total_errors: usize,
+
+    /// Non-fatal project-level warnings (skipped files, ...)
+    #[serde(default)]
+    pub warnings: Vec<String>,
+
+    /// Directory the file paths are relative to
+    #[serde(default)]
+    pub root_path: String,
 }
 
 impl ParseResult {
@@ -145,4 +182,29 @@ impl ParseResult {
         self.total_errors += 1;
         self.errors.insert(path, error);
     }
+
+    pub fn add_warning(&mut self, warning: String) {
+        self.warnings.push(warning);
+    }
+
+    /// Project-level warnings followed by the warnings of every file
+    pub fn all_warnings(&self) -> Vec<String> {
+        self.warnings
+            .iter()
+            .chain(self.files.iter().flat_map(|f| f.warnings.iter()))
+            .cloned()
+            .collect()
+    }
+}
+
+/// A project's parse result with the graph built from it
+///
+/// The parse result can be sent back to rebuild the graph without scanning
+/// the disk again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectAnalysis {
+    /// ID of the parser that produced the parse result
+    pub parser_id: String,
+    pub parse_result: ParseResult,
+    pub graph: UnifiedGraph,
 }
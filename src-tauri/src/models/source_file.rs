@@ -23,6 +23,9 @@ pub struct SourceFile {
 
     /// Last modified timestamp
     pub modified_at: Option<String>,
+
+    /// Why the file is not parsed (too large, ...), `None` when it is
+    pub skip_reason: Option<String>,
 }
 
 impl SourceFile {
@@ -41,6 +44,7 @@ impl SourceFile {
             size_bytes: 0,
             hash: None,
             modified_at: None,
+            skip_reason: None,
         }
     }
 
@@ -49,6 +53,16 @@ impl SourceFile {
         self
     }
 
+    pub fn with_skip_reason(mut self, reason: String) -> Self {
+        self.skip_reason = Some(reason);
+        self
+    }
+
+    pub fn with_modified_at(mut self, modified_at: String) -> Self {
+        self.modified_at = Some(modified_at);
+        self
+    }
+
     pub fn with_hash(mut self, hash: String) -> Self {
         self.hash = Some(hash);
         self
@@ -41,6 +41,28 @@ impl Default for UnifiedEdgeType {
     }
 }
 
+impl UnifiedEdgeType {
+    /// Stable string key: the serialized name (`uses`, `file_pair`) or
+    /// `custom:{name}` for custom types
+    pub fn as_key(&self) -> String {
+        match self {
+            UnifiedEdgeType::Custom(name) => format!("custom:{}", name),
+            other => serde_json::to_value(other)
+                .ok()
+                .and_then(|v| v.as_str().map(|s| s.to_string()))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Parse a key produced by [`UnifiedEdgeType::as_key`]
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key.strip_prefix("custom:") {
+            Some(name) => Some(UnifiedEdgeType::Custom(name.to_string())),
+            None => serde_json::from_value(serde_json::Value::String(key.to_string())).ok(),
+        }
+    }
+}
+
 /// Edge metadata
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct EdgeMetadata {
@@ -55,6 +77,12 @@ pub struct EdgeMetadata {
 
     /// Required version (for packages)
     pub version_constraint: Option<String>,
+
+    /// Is polymorphic relation (Eloquent morphTo, morphMany, ...)
+    pub polymorphic: Option<bool>,
+
+    /// Is inferred rather than declared (the inverse of a one-sided Eloquent relationship)
+    pub inferred: Option<bool>,
 }
 
 /// Unified graph edge - language independent
@@ -90,8 +118,9 @@ pub struct UnifiedEdge {
 }
 
 impl UnifiedEdge {
+    /// Create an edge, its ID is `{source}->{target}:{edge type key}`
     pub fn new(source: String, target: String, edge_type: UnifiedEdgeType) -> Self {
-        let id = format!("{}->{}:{:?}", source, target, edge_type);
+        let id = format!("{}->{}:{}", source, target, edge_type.as_key());
         Self {
             id,
             source,
@@ -115,3 +144,25 @@ impl UnifiedEdge {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edge_type_keys_round_trip() {
+        for (edge_type, key) in [
+            (UnifiedEdgeType::Uses, "uses"),
+            (UnifiedEdgeType::FilePair, "file_pair"),
+            (
+                UnifiedEdgeType::Custom("routes_to".to_string()),
+                "custom:routes_to",
+            ),
+        ] {
+            assert_eq!(edge_type.as_key(), key);
+            assert_eq!(UnifiedEdgeType::from_key(key), Some(edge_type));
+        }
+
+        assert_eq!(UnifiedEdgeType::from_key("no_such_type"), None);
+    }
+}
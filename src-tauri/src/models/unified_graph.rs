@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use super::{UnifiedNode, UnifiedEdge};
 
 /// Graph metadata
@@ -24,6 +25,27 @@ pub struct GraphMetadata {
 
     /// Parser version used
     pub parser_version: String,
+
+    /// Major version of the project's framework (Laravel 10 is 10), when known
+    #[serde(default)]
+    pub framework_version: Option<u32>,
+
+    /// Non-fatal warnings of the scan (skipped files, suspicious parses, ...)
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+/// Node, edge and line counts of one language in a graph
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct LanguageStats {
+    /// Nodes of this language
+    pub node_count: usize,
+
+    /// Edges whose source node is of this language
+    pub edge_count: usize,
+
+    /// Lines of code, from the furthest line reached by a node in each file
+    pub loc: u64,
 }
 
 /// Complete unified graph
@@ -74,6 +96,11 @@ impl UnifiedGraph {
         self.nodes.iter().find(|n| n.id == id)
     }
 
+    /// Find edge by ID
+    pub fn find_edge(&self, id: &str) -> Option<&UnifiedEdge> {
+        self.edges.iter().find(|e| e.id == id)
+    }
+
     /// Get all edges from a node
     pub fn edges_from(&self, node_id: &str) -> Vec<&UnifiedEdge> {
         self.edges.iter().filter(|e| e.source == node_id).collect()
@@ -93,6 +120,43 @@ impl UnifiedGraph {
     pub fn out_degree(&self, node_id: &str) -> usize {
         self.edges_from(node_id).len()
     }
+
+    /// Node, edge and line counts per node language
+    ///
+    /// Nodes without a language are grouped under "unknown". Edges count
+    /// towards the language of their source node.
+    pub fn stats_by_language(&self) -> HashMap<String, LanguageStats> {
+        let mut stats: HashMap<String, LanguageStats> = HashMap::new();
+        let mut language_of: HashMap<&str, &str> = HashMap::new();
+        let mut file_lines: HashMap<(&str, &str), u32> = HashMap::new();
+
+        for node in &self.nodes {
+            let language = if node.language.is_empty() {
+                "unknown"
+            } else {
+                node.language.as_str()
+            };
+            language_of.insert(node.id.as_str(), language);
+            stats.entry(language.to_string()).or_default().node_count += 1;
+
+            if let (Some(path), Some(last_line)) = (node.file_path.as_deref(), node.line_end) {
+                let lines = file_lines.entry((language, path)).or_insert(0);
+                *lines = (*lines).max(last_line);
+            }
+        }
+
+        for edge in &self.edges {
+            if let Some(language) = language_of.get(edge.source.as_str()) {
+                stats.entry(language.to_string()).or_default().edge_count += 1;
+            }
+        }
+
+        for ((language, _), lines) in file_lines {
+            stats.entry(language.to_string()).or_default().loc += u64::from(lines);
+        }
+
+        stats
+    }
 }
 
 impl Default for UnifiedGraph {
@@ -100,3 +164,96 @@ impl Default for UnifiedGraph {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{UnifiedEdgeType, UnifiedNodeType};
+
+    fn node(id: &str, language: &str, path: &str, line_end: Option<u32>) -> UnifiedNode {
+        let mut node = UnifiedNode::new(id.to_string(), UnifiedNodeType::Class, id.to_string())
+            .with_file(path.to_string())
+            .with_language(language);
+        node.line_end = line_end;
+        node
+    }
+
+    #[test]
+    fn stats_by_language_counts_nodes_edges_and_lines() {
+        let mut graph = UnifiedGraph::new();
+        graph.add_node(node("a", "php", "app/A.php", Some(40)));
+        graph.add_node(node("a_method", "php", "app/A.php", Some(25)));
+        graph.add_node(node("b", "php", "app/B.php", Some(10)));
+        graph.add_node(node("view", "", "resources/views/a.blade.php", None));
+        graph.add_node(node("unit", "delphi", "Main.pas", Some(100)));
+        graph.add_edge(UnifiedEdge::new(
+            "a".into(),
+            "b".into(),
+            UnifiedEdgeType::Uses,
+        ));
+        graph.add_edge(UnifiedEdge::new(
+            "a".into(),
+            "view".into(),
+            UnifiedEdgeType::Renders,
+        ));
+        graph.add_edge(UnifiedEdge::new(
+            "unit".into(),
+            "a".into(),
+            UnifiedEdgeType::Uses,
+        ));
+
+        let stats = graph.stats_by_language();
+
+        assert_eq!(
+            stats["php"],
+            LanguageStats {
+                node_count: 3,
+                edge_count: 2,
+                loc: 50,
+            }
+        );
+        assert_eq!(
+            stats["unknown"],
+            LanguageStats {
+                node_count: 1,
+                edge_count: 0,
+                loc: 0,
+            }
+        );
+        assert_eq!(stats["delphi"].edge_count, 1);
+        assert_eq!(stats["delphi"].loc, 100);
+    }
+
+    #[tokio::test]
+    async fn edges_are_found_by_their_readable_id() {
+        let graph = crate::parsers::laravel::fixtures::graph_of(&[
+            (
+                "app/Models/User.php",
+                "<?php\nnamespace App\\Models;\n\nclass User extends Model\n{\n}\n",
+            ),
+            (
+                "app/Http/Controllers/UserController.php",
+                "<?php\nnamespace App\\Http\\Controllers;\n\nuse App\\Models\\User;\n\n\
+                 class UserController extends Controller\n{\n}\n",
+            ),
+        ])
+        .await;
+
+        let controller_file =
+            crate::parsers::common::generate_id("app/Http/Controllers/UserController.php");
+        let user = graph
+            .nodes
+            .iter()
+            .find(|n| {
+                n.qualified_name == "App\\Models\\User" && n.node_type == UnifiedNodeType::Model
+            })
+            .unwrap();
+        let id = format!("{}->{}:uses", controller_file, user.id);
+
+        let edge = graph.find_edge(&id).unwrap();
+        assert_eq!(edge.source, controller_file);
+        assert_eq!(edge.target, user.id);
+        assert_eq!(edge.edge_type, UnifiedEdgeType::Uses);
+        assert!(graph.find_edge("missing->edge:uses").is_none());
+    }
+}
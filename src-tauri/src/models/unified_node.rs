@@ -54,6 +54,28 @@ impl Default for UnifiedNodeType {
     }
 }
 
+impl UnifiedNodeType {
+    /// Stable string key: the serialized name (`controller`, `source_file`) or
+    /// `custom:{name}` for custom types
+    pub fn as_key(&self) -> String {
+        match self {
+            UnifiedNodeType::Custom(name) => format!("custom:{}", name),
+            other => serde_json::to_value(other)
+                .ok()
+                .and_then(|v| v.as_str().map(|s| s.to_string()))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Parse a key produced by [`UnifiedNodeType::as_key`]
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key.strip_prefix("custom:") {
+            Some(name) => Some(UnifiedNodeType::Custom(name.to_string())),
+            None => serde_json::from_value(serde_json::Value::String(key.to_string())).ok(),
+        }
+    }
+}
+
 /// 3D Position for graph visualization
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Position3D {
@@ -69,6 +91,24 @@ pub struct ParameterInfo {
     pub param_type: Option<String>,
     pub default_value: Option<String>,
     pub is_optional: bool,
+
+    /// Passing mode (Delphi `var`, `const`, `out`)
+    #[serde(default)]
+    pub modifier: Option<String>,
+}
+
+/// PHP attribute / annotation attached to a declaration (`#[Route('/users')]`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AttributeInfo {
+    pub name: String,
+    pub arguments: Vec<String>,
+}
+
+/// Case of a PHP enum, with its value when the enum is backed (`case Active = 'active';`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EnumCase {
+    pub name: String,
+    pub value: Option<String>,
 }
 
 /// Node status for annotations
@@ -112,6 +152,15 @@ pub struct NodeMetadata {
     /// Used traits (PHP)
     pub uses_traits: Option<Vec<String>>,
 
+    /// Attributes on the declaration (PHP 8 `#[...]`)
+    pub attributes: Option<Vec<AttributeInfo>>,
+
+    /// Backing type of a backed enum (`string` for `enum Status: string`)
+    pub backing_type: Option<String>,
+
+    /// Cases of an enum
+    pub enum_cases: Option<Vec<EnumCase>>,
+
     /// Documentation/comments
     pub documentation: Option<String>,
 
@@ -203,3 +252,25 @@ impl UnifiedNode {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn node_type_keys_round_trip() {
+        for (node_type, key) in [
+            (UnifiedNodeType::SourceFile, "source_file"),
+            (UnifiedNodeType::Controller, "controller"),
+            (
+                UnifiedNodeType::Custom("policy".to_string()),
+                "custom:policy",
+            ),
+        ] {
+            assert_eq!(node_type.as_key(), key);
+            assert_eq!(UnifiedNodeType::from_key(key), Some(node_type));
+        }
+
+        assert_eq!(UnifiedNodeType::from_key("no_such_type"), None);
+    }
+}
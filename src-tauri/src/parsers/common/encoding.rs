@@ -0,0 +1,99 @@
+use encoding_rs::{Encoding, UTF_8};
+
+use crate::models::SourceFile;
+use crate::parsers::{ParserConfig, ParserResult};
+
+/// Read a source file decoded with `config.encoding`
+///
+/// Returns the content and the decoding warnings to record on the parsed file.
+pub fn read_source(file: &SourceFile, config: &ParserConfig) -> ParserResult<(String, Vec<String>)> {
+    let bytes = std::fs::read(&file.absolute_path)?;
+
+    Ok(decode_source(&bytes, &config.encoding, &file.path))
+}
+
+/// Decode source bytes with an encoding label (`utf-8`, `windows-1252`, `latin1`, ...)
+///
+/// A UTF-8 or UTF-16 BOM wins over the label and is stripped. Bytes invalid
+/// in the encoding are replaced with U+FFFD and an unknown label falls back to
+/// UTF-8, both reported as warnings rather than failing the file.
+pub fn decode_source(bytes: &[u8], encoding: &str, path: &str) -> (String, Vec<String>) {
+    let mut warnings = Vec::new();
+
+    let (encoding, bom_length) = match Encoding::for_bom(bytes) {
+        Some(detected) => detected,
+        None => match Encoding::for_label(encoding.trim().as_bytes()) {
+            Some(encoding) => (encoding, 0),
+            None => {
+                warnings.push(format!("Unknown encoding {}, {} read as UTF-8", encoding, path));
+                (UTF_8, 0)
+            }
+        },
+    };
+
+    let (content, had_errors) = encoding.decode_without_bom_handling(&bytes[bom_length..]);
+    if had_errors {
+        warnings.push(format!(
+            "{} is not valid {}, undecodable bytes were replaced",
+            path,
+            encoding.name()
+        ));
+    }
+
+    (content.into_owned(), warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `unit Caf\u{e9};` saved in Windows-1252
+    const WINDOWS_1252_UNIT: &[u8] = b"unit Caf\xe9;\r\n// Gr\xfc\xdfe \x80\r\n";
+
+    #[test]
+    fn windows_1252_sources_decode_with_their_label() {
+        let (content, warnings) = decode_source(WINDOWS_1252_UNIT, "windows-1252", "Cafe.pas");
+
+        assert_eq!(content, "unit Café;\r\n// Grüße €\r\n");
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn undecodable_bytes_are_a_warning() {
+        let (content, warnings) = decode_source(WINDOWS_1252_UNIT, "utf-8", "Cafe.pas");
+
+        assert!(content.starts_with("unit Caf\u{fffd};"));
+        assert_eq!(
+            warnings,
+            vec!["Cafe.pas is not valid UTF-8, undecodable bytes were replaced"]
+        );
+    }
+
+    #[test]
+    fn a_bom_wins_over_the_label_and_is_stripped() {
+        let (content, _) = decode_source(b"\xef\xbb\xbfunit A;", "windows-1252", "A.pas");
+        assert_eq!(content, "unit A;");
+
+        let (content, _) = decode_source(b"\xff\xfeu\0n\0i\0t\0", "utf-8", "A.pas");
+        assert_eq!(content, "unit");
+    }
+
+    #[test]
+    fn read_source_honours_the_configured_encoding() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("Cafe.pas");
+        std::fs::write(&path, WINDOWS_1252_UNIT).unwrap();
+        let file = SourceFile::new(
+            "Cafe.pas".to_string(),
+            "Cafe.pas".to_string(),
+            path.to_string_lossy().to_string(),
+        );
+        let mut config = ParserConfig::new();
+        config.encoding = "windows-1252".to_string();
+
+        let (content, warnings) = read_source(&file, &config).unwrap();
+
+        assert!(content.starts_with("unit Café;"));
+        assert!(warnings.is_empty());
+    }
+}
@@ -1,16 +1,103 @@
-use std::path::Path;
+use chrono::{DateTime, Utc};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use ignore::WalkBuilder;
+use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 use crate::models::SourceFile;
+use crate::parsers::common::file_content_hash;
+use crate::parsers::{ParseError, ParserConfig, ParserResult};
 
 /// Scan directory for files with specific extensions
+///
+/// Directories named in `config.exclude_dirs` are skipped. `include_globs`
+/// and `exclude_globs` match the path relative to `root_path` (`*` within one
+/// segment, `**` across segments): a file must match an include glob when
+/// there are any, and no exclude glob. With `respect_gitignore`, paths ignored
+/// by `.gitignore` files (nested ones, the ones above `root_path` and
+/// `.git/info/exclude` included) are skipped too, whether or not the directory
+/// is a git repository. Files over `max_file_size_bytes` are returned with a
+/// `skip_reason`, for `parse_project` to report instead of parsing them. The
+/// other files get their content `hash` when `compute_hashes` is set.
 pub fn scan_directory(
     root_path: &Path,
     extensions: &[&str],
-    exclude_dirs: &[&str],
-) -> Vec<SourceFile> {
+    config: &ParserConfig,
+) -> ParserResult<Vec<SourceFile>> {
+    let include_globs = build_glob_set(&config.include_globs)?;
+    let exclude_globs = build_glob_set(&config.exclude_globs)?;
+    let exclude_dirs: Vec<&str> = config.exclude_dirs.iter().map(|s| s.as_str()).collect();
+
+    let paths = if config.respect_gitignore {
+        walk_respecting_gitignore(root_path, &exclude_dirs)
+    } else {
+        walk(root_path, &exclude_dirs)
+    };
+
     let mut files = Vec::new();
 
-    for entry in WalkDir::new(root_path)
+    for path in paths {
+        let relative = relative_path(&path, root_path);
+        if exclude_globs.is_match(&relative)
+            || (!config.include_globs.is_empty() && !include_globs.is_match(&relative))
+        {
+            continue;
+        }
+
+        if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+            let ext_lower = ext.to_lowercase();
+            if extensions.iter().any(|e| e.eq_ignore_ascii_case(&ext_lower)) {
+                if let Some(file) = create_source_file(&path, root_path) {
+                    let mut file = skip_if_too_large(file, config.max_file_size_bytes);
+                    if config.compute_hashes && file.skip_reason.is_none() {
+                        file.hash = file_content_hash(&path).ok();
+                    }
+                    files.push(file);
+                }
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// Mark a file over the size limit as skipped
+fn skip_if_too_large(file: SourceFile, max_file_size_bytes: Option<u64>) -> SourceFile {
+    match max_file_size_bytes {
+        Some(max) if file.size_bytes > max => {
+            let reason = format!("{} bytes, over the {} bytes limit", file.size_bytes, max);
+            file.with_skip_reason(reason)
+        }
+        _ => file,
+    }
+}
+
+/// Compile glob patterns, `*` not crossing `/`
+fn build_glob_set(patterns: &[String]) -> ParserResult<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()
+            .map_err(|e| ParseError::Config(format!("Invalid glob {}: {}", pattern, e)))?;
+        builder.add(glob);
+    }
+
+    builder
+        .build()
+        .map_err(|e| ParseError::Config(e.to_string()))
+}
+
+/// Path relative to `root_path` with `/` separators, as globs are written
+fn relative_path(path: &Path, root_path: &Path) -> String {
+    path.strip_prefix(root_path)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Files below `root_path`, excluded directories skipped
+fn walk(root_path: &Path, exclude_dirs: &[&str]) -> Vec<PathBuf> {
+    WalkDir::new(root_path)
         .follow_links(false)
         .into_iter()
         .filter_entry(|e| {
@@ -22,22 +109,35 @@ pub fn scan_directory(
             true
         })
         .filter_map(|e| e.ok())
-    {
-        let path = entry.path();
-
-        if path.is_file() {
-            if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
-                let ext_lower = ext.to_lowercase();
-                if extensions.iter().any(|e| e.eq_ignore_ascii_case(&ext_lower)) {
-                    if let Some(file) = create_source_file(path, root_path) {
-                        files.push(file);
-                    }
-                }
-            }
-        }
-    }
+        .map(|e| e.into_path())
+        .filter(|path| path.is_file())
+        .collect()
+}
+
+/// Files below `root_path`, excluded directories and gitignored paths skipped
+fn walk_respecting_gitignore(root_path: &Path, exclude_dirs: &[&str]) -> Vec<PathBuf> {
+    let exclude_dirs: Vec<String> = exclude_dirs.iter().map(|d| d.to_string()).collect();
 
-    files
+    WalkBuilder::new(root_path)
+        .follow_links(false)
+        // Only .gitignore rules, hidden files are scanned like without the flag
+        .hidden(false)
+        .ignore(false)
+        .git_global(false)
+        .require_git(false)
+        .filter_entry(move |e| {
+            // Skip excluded directories
+            if e.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+                let name = e.file_name().to_str().unwrap_or("");
+                return !exclude_dirs.iter().any(|d| d == name);
+            }
+            true
+        })
+        .build()
+        .filter_map(|e| e.ok())
+        .map(|e| e.into_path())
+        .filter(|path| path.is_file())
+        .collect()
 }
 
 /// Create a SourceFile from a path
@@ -53,10 +153,12 @@ pub fn create_source_file(path: &Path, root_path: &Path) -> Option<SourceFile> {
     let metadata = std::fs::metadata(path).ok()?;
     let size = metadata.len();
 
-    Some(
-        SourceFile::new(name, relative_path, absolute_path)
-            .with_size(size)
-    )
+    let file = SourceFile::new(name, relative_path, absolute_path).with_size(size);
+
+    Some(match metadata.modified() {
+        Ok(modified) => file.with_modified_at(DateTime::<Utc>::from(modified).to_rfc3339()),
+        Err(_) => file,
+    })
 }
 
 /// Check if file has specific extension
@@ -67,9 +169,163 @@ pub fn has_extension(path: &Path, ext: &str) -> bool {
         .unwrap_or(false)
 }
 
+/// Directory levels below the root `has_files_with_extension` looks into
+pub const DEFAULT_PROBE_DEPTH: usize = 2;
+
+/// Directories never probed for files (dependencies, version control data)
+const PROBE_SKIPPED_DIRS: [&str; 3] = [".git", "node_modules", "vendor"];
+
+/// Check if a directory or its subdirectories, up to `DEFAULT_PROBE_DEPTH`
+/// levels down, hold a file with this extension
+pub fn has_files_with_extension(root_path: &Path, ext: &str) -> bool {
+    has_files_with_extension_within(root_path, ext, DEFAULT_PROBE_DEPTH)
+}
+
+/// Check if a directory or its subdirectories, up to `max_depth` levels down,
+/// hold a file with this extension
+///
+/// Directories are probed level by level and the search stops at the first
+/// match. `.git`, `node_modules` and `vendor` directories are skipped.
+pub fn has_files_with_extension_within(root_path: &Path, ext: &str, max_depth: usize) -> bool {
+    let mut level = vec![root_path.to_path_buf()];
+
+    for depth in 0..=max_depth {
+        let mut subdirectories = Vec::new();
+        for dir in &level {
+            let entries = match std::fs::read_dir(dir) {
+                Ok(entries) => entries,
+                Err(_) => continue,
+            };
+
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.is_file() {
+                    if has_extension(&path, ext) {
+                        return true;
+                    }
+                } else if depth < max_depth
+                    && path.is_dir()
+                    && !PROBE_SKIPPED_DIRS.contains(&entry.file_name().to_string_lossy().as_ref())
+                {
+                    subdirectories.push(path);
+                }
+            }
+        }
+        level = subdirectories;
+    }
+
+    false
+}
+
+/// Project-relative path of `relative`, written relative to the file at `file_path`
+///
+/// Backslashes are path separators (Delphi and MSBuild write them), `.` and
+/// `..` components are resolved without touching the filesystem.
+pub fn resolve_relative_path(file_path: &str, relative: &str) -> String {
+    let mut components: Vec<&str> = file_path.split(['/', '\\']).collect();
+    components.pop();
+
+    for component in relative.split(['/', '\\']) {
+        match component {
+            "" | "." => {}
+            ".." => {
+                components.pop();
+            }
+            component => components.push(component),
+        }
+    }
+
+    components.join("/")
+}
+
 /// Get file name without extension
 pub fn file_stem(path: &Path) -> Option<String> {
     path.file_stem()
         .and_then(|s| s.to_str())
         .map(|s| s.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::common::fixtures::project_dir;
+    use crate::parsers::common::md5_hash;
+
+    const FILES: &[(&str, &str)] = &[
+        ("app/User.php", "<?php"),
+        ("app/Models/Post.php", "<?php"),
+        ("app/Legacy/Old.php", "<?php"),
+        ("app/Legacy/Deep/Older.php", "<?php"),
+    ];
+
+    /// Paths of the PHP files scanned with the given globs, sorted
+    fn scanned(include_globs: &[&str], exclude_globs: &[&str]) -> Vec<String> {
+        let dir = project_dir(FILES);
+        let mut config = ParserConfig::new();
+        config.include_globs = include_globs.iter().map(|g| g.to_string()).collect();
+        config.exclude_globs = exclude_globs.iter().map(|g| g.to_string()).collect();
+
+        let mut paths: Vec<String> = scan_directory(dir.path(), &["php"], &config)
+            .unwrap()
+            .into_iter()
+            .map(|f| f.path.replace('\\', "/"))
+            .collect();
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn double_star_globs_match_whole_subtrees() {
+        assert_eq!(
+            scanned(&[], &["app/Legacy/**"]),
+            vec!["app/Models/Post.php", "app/User.php"]
+        );
+        assert_eq!(
+            scanned(&["app/Legacy/**"], &[]),
+            vec!["app/Legacy/Deep/Older.php", "app/Legacy/Old.php"]
+        );
+    }
+
+    #[test]
+    fn single_star_globs_stay_within_one_segment() {
+        assert_eq!(scanned(&["app/*.php"], &[]), vec!["app/User.php"]);
+        assert_eq!(
+            scanned(&["app/*/*.php"], &["app/Legacy/*"]),
+            vec!["app/Models/Post.php"]
+        );
+    }
+
+    #[test]
+    fn scanned_files_carry_their_modification_time_and_optional_hash() {
+        let dir = project_dir(&[("app/User.php", "<?php")]);
+        let mut config = ParserConfig::new();
+
+        let file = scan_directory(dir.path(), &["php"], &config)
+            .unwrap()
+            .remove(0);
+        let modified_at = file.modified_at.expect("modified_at");
+        assert!(DateTime::parse_from_rfc3339(&modified_at).is_ok());
+        assert_eq!(file.hash, None);
+
+        config.compute_hashes = true;
+        let file = scan_directory(dir.path(), &["php"], &config)
+            .unwrap()
+            .remove(0);
+        assert_eq!(file.hash, Some(md5_hash("<?php")));
+    }
+
+    #[test]
+    fn probing_stops_at_the_depth_limit_and_skips_dependencies() {
+        let dir = project_dir(&[
+            ("src/Forms/Main.pas", "unit Main;"),
+            ("lib/a/b/Deep.php", "<?php"),
+            ("vendor/Dep.rb", "class Dep; end"),
+        ]);
+
+        assert!(has_files_with_extension(dir.path(), "pas"));
+        assert!(!has_files_with_extension_within(dir.path(), "pas", 1));
+        assert!(!has_files_with_extension(dir.path(), "php"));
+        assert!(has_files_with_extension_within(dir.path(), "php", 3));
+        assert!(!has_files_with_extension(dir.path(), "rb"));
+    }
+}
@@ -0,0 +1,14 @@
+// Test fixtures - project files written to a temporary directory
+
+use tempfile::TempDir;
+
+/// Temporary project directory holding the given files
+pub(crate) fn project_dir(files: &[(&str, &str)]) -> TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    for (path, content) in files {
+        let path = dir.path().join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+    dir
+}
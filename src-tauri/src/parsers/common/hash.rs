@@ -1,4 +1,5 @@
 use md5::{Digest, Md5};
+use std::path::Path;
 
 /// Generate MD5 hash of content
 pub fn md5_hash(content: &str) -> String {
@@ -7,6 +8,15 @@ pub fn md5_hash(content: &str) -> String {
     format!("{:x}", hasher.finalize())
 }
 
+/// Hash of a file's content, compared to detect changed files
+///
+/// The raw bytes are hashed: files in legacy encodings (Windows-1251,
+/// Latin-1) that differ only in non-ASCII bytes get different hashes.
+pub fn file_content_hash(path: &Path) -> std::io::Result<String> {
+    let bytes = std::fs::read(path)?;
+    Ok(format!("{:x}", Md5::digest(&bytes)))
+}
+
 /// Generate ID from path
 pub fn generate_id(path: &str) -> String {
     md5_hash(path)
@@ -16,3 +26,32 @@ pub fn generate_id(path: &str) -> String {
 pub fn generate_edge_id(source: &str, target: &str, edge_type: &str) -> String {
     md5_hash(&format!("{}->{}:{}", source, target, edge_type))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn files_differing_in_one_high_byte_hash_differently() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = dir.path().join("First.pas");
+        let second = dir.path().join("Second.pas");
+        // 'Привет' in Windows-1251 (invalid UTF-8), then with its last byte changed
+        std::fs::write(&first, b"const S = '\xcf\xf0\xe8\xe2\xe5\xf2';").unwrap();
+        std::fs::write(&second, b"const S = '\xcf\xf0\xe8\xe2\xe5\xf3';").unwrap();
+
+        assert_ne!(
+            file_content_hash(&first).unwrap(),
+            file_content_hash(&second).unwrap()
+        );
+    }
+
+    #[test]
+    fn utf8_files_hash_like_their_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("User.php");
+        std::fs::write(&path, "<?php // Café").unwrap();
+
+        assert_eq!(file_content_hash(&path).unwrap(), md5_hash("<?php // Café"));
+    }
+}
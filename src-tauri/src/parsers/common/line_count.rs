@@ -0,0 +1,183 @@
+use regex::Regex;
+use std::path::Path;
+
+use super::{blank_comments, blank_comments_and_strings, blank_regions, PhpRegion, PhpRegionKind};
+
+lazy_static::lazy_static! {
+    // Match: {{-- Blade comment --}} or <!-- HTML comment -->
+    static ref MARKUP_COMMENT_REGEX: Regex = Regex::new(r"(?s)\{\{--.*?--\}\}|<!--.*?-->").unwrap();
+}
+
+/// Comment syntax of a source language
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentSyntax {
+    /// `//`, `#` and `/* */`
+    Php,
+    /// `{{-- --}}` and `<!-- -->`
+    Blade,
+    /// `//`, `{ }` and `(* *)`, `{$...}` directives being code
+    Pascal,
+    /// No comments (forms, config, unknown files)
+    None,
+}
+
+impl CommentSyntax {
+    /// Syntax of a file, from its extension
+    pub fn from_path(path: &str) -> Self {
+        if path.to_lowercase().ends_with(".blade.php") {
+            return CommentSyntax::Blade;
+        }
+
+        let extension = Path::new(path)
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        match extension.as_str() {
+            "php" => CommentSyntax::Php,
+            "pas" | "dpr" | "dpk" | "inc" | "lpr" => CommentSyntax::Pascal,
+            _ => CommentSyntax::None,
+        }
+    }
+
+    /// `content` with its comments replaced by spaces, newlines kept
+    fn blank_comments(&self, content: &str) -> String {
+        match self {
+            CommentSyntax::Php => blank_comments(content),
+            CommentSyntax::Blade => MARKUP_COMMENT_REGEX
+                .replace_all(content, |caps: &regex::Captures| -> String {
+                    caps[0]
+                        .chars()
+                        .map(|c| if c == '\n' { '\n' } else { ' ' })
+                        .collect()
+                })
+                .into_owned(),
+            CommentSyntax::Pascal => {
+                let comments: Vec<_> = pascal_regions(content)
+                    .into_iter()
+                    .filter(|r| r.kind == PhpRegionKind::Comment)
+                    .collect();
+                blank_regions(content, &comments)
+            }
+            CommentSyntax::None => content.to_string(),
+        }
+    }
+
+    /// `content` with its comments and string literals replaced by spaces,
+    /// leaving only code
+    pub fn blank_comments_and_strings(&self, content: &str) -> String {
+        match self {
+            CommentSyntax::Php => blank_comments_and_strings(content),
+            CommentSyntax::Pascal => blank_regions(content, &pascal_regions(content)),
+            CommentSyntax::Blade | CommentSyntax::None => self.blank_comments(content),
+        }
+    }
+}
+
+/// Line counts of one source file
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LineCounts {
+    pub total: u64,
+    /// Lines with code on them, even if they also hold a comment
+    pub code: u64,
+    /// Lines holding only comments
+    pub comment: u64,
+    pub blank: u64,
+}
+
+/// Count the total, code, comment and blank lines of `content`
+pub fn count_lines(content: &str, syntax: CommentSyntax) -> LineCounts {
+    let code = syntax.blank_comments(content);
+    let mut counts = LineCounts::default();
+
+    for (line, code_line) in content.lines().zip(code.lines()) {
+        counts.total += 1;
+        if line.trim().is_empty() {
+            counts.blank += 1;
+        } else if code_line.trim().is_empty() {
+            counts.comment += 1;
+        } else {
+            counts.code += 1;
+        }
+    }
+
+    counts
+}
+
+/// Comment and string literal regions of Pascal source
+///
+/// A `{` or `//` in a `'...'` string doesn't open a comment. An unclosed
+/// comment or string runs to the end of the source.
+fn pascal_regions(content: &str) -> Vec<PhpRegion> {
+    let bytes = content.as_bytes();
+    let mut regions = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let start = i;
+        let (kind, close) = match bytes[i] {
+            b'\'' => (PhpRegionKind::String, "'"),
+            b'/' if bytes.get(i + 1) == Some(&b'/') => (PhpRegionKind::Comment, "\n"),
+            b'{' if bytes.get(i + 1) != Some(&b'$') => (PhpRegionKind::Comment, "}"),
+            b'(' if bytes.get(i + 1) == Some(&b'*') => (PhpRegionKind::Comment, "*)"),
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+
+        // The newline ending a // comment isn't part of it
+        i = match content[start + 1..].find(close) {
+            Some(p) if close == "\n" => start + 1 + p,
+            Some(p) => start + 1 + p + close.len(),
+            None => bytes.len(),
+        };
+        regions.push(PhpRegion { kind, start, end: i });
+    }
+
+    regions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(path: &str, content: &str) -> (u64, u64, u64, u64) {
+        let counts = count_lines(content, CommentSyntax::from_path(path));
+        (counts.total, counts.code, counts.comment, counts.blank)
+    }
+
+    #[test]
+    fn php_comments() {
+        let content =
+            "<?php\n// line\n# hash\n/*\n * block\n */\n\n$url = 'http://x'; // trailing\n";
+
+        assert_eq!(counts("app/User.php", content), (8, 2, 5, 1));
+    }
+
+    #[test]
+    fn blade_comments() {
+        let content = "{{-- note\n  spanning --}}\n<!-- html -->\n\n<p>{{ $name }}</p>\n";
+
+        assert_eq!(
+            counts("resources/views/home.blade.php", content),
+            (5, 1, 3, 1)
+        );
+    }
+
+    #[test]
+    fn pascal_comments_and_directives() {
+        let content = "unit A;\n{ brace }\n(* paren\n   star *)\n// slash\n{$IFDEF DEBUG}\n\n\
+s := '{ not a comment }';\n";
+
+        assert_eq!(counts("A.pas", content), (8, 3, 4, 1));
+    }
+
+    #[test]
+    fn other_files_have_no_comments() {
+        assert_eq!(
+            counts("Form1.dfm", "object Form1\n  // kept\nend\n"),
+            (3, 3, 0, 0)
+        );
+    }
+}
@@ -1,7 +1,21 @@
 // Common utilities for parsers
 
+mod encoding;
 mod file_utils;
+#[cfg(test)]
+pub(crate) mod fixtures;
 mod hash;
+mod line_count;
+mod naming;
+mod panic_guard;
+mod php_scan;
+mod validation;
 
+pub use encoding::*;
 pub use file_utils::*;
 pub use hash::*;
+pub use line_count::*;
+pub use naming::*;
+pub use panic_guard::*;
+pub use php_scan::*;
+pub use validation::*;
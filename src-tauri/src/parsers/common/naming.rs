@@ -0,0 +1,52 @@
+/// Convert a name to StudlyCase like Laravel's `Str::studly`
+/// (create_users_table -> CreateUsersTable, user-card -> UserCard)
+pub fn studly_case(name: &str) -> String {
+    name.split(['_', '-', ' '])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Convert a camelCase or StudlyCase name to snake_case like Laravel's `Str::snake`
+/// (BlogPost -> blog_post, parentPost -> parent_post)
+pub fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn studly_case_joins_words() {
+        assert_eq!(studly_case("create_users_table"), "CreateUsersTable");
+        assert_eq!(studly_case("user-card"), "UserCard");
+        assert_eq!(studly_case("createUser"), "CreateUser");
+        assert_eq!(studly_case("__"), "");
+    }
+
+    #[test]
+    fn snake_case_splits_words() {
+        assert_eq!(snake_case("BlogPost"), "blog_post");
+        assert_eq!(snake_case("parentPost"), "parent_post");
+        assert_eq!(snake_case("FirstName"), "first_name");
+        assert_eq!(snake_case("author"), "author");
+    }
+}
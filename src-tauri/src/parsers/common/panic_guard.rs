@@ -0,0 +1,41 @@
+use std::any::Any;
+use std::future::Future;
+use std::panic::{catch_unwind as catch_unwind_sync, AssertUnwindSafe};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Future catching a panic raised while polling the future it wraps
+struct CatchUnwind<F: Future> {
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for CatchUnwind<F> {
+    type Output = Result<F::Output, Box<dyn Any + Send>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let future = self.future.as_mut();
+        match catch_unwind_sync(AssertUnwindSafe(|| future.poll(cx))) {
+            Ok(Poll::Pending) => Poll::Pending,
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Err(payload) => Poll::Ready(Err(payload)),
+        }
+    }
+}
+
+/// Await a future, turning a panic into an error carrying the panic message
+///
+/// Lets one file hitting a parser bug be reported instead of aborting a scan.
+/// The future must not be polled again after panicking, it isn't.
+pub async fn catch_unwind<F: Future>(future: F) -> Result<F::Output, String> {
+    CatchUnwind {
+        future: Box::pin(future),
+    }
+    .await
+    .map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string())
+    })
+}
@@ -0,0 +1,273 @@
+/// Find the `}` matching the `{` at byte offset `open`
+///
+/// Braces inside PHP strings ('...', "...") and comments (//, #, /* */)
+/// are ignored. Returns None if `open` is not a `{` or the block is unclosed.
+pub fn matching_brace(content: &str, open: usize) -> Option<usize> {
+    matching_delimiter(content, open, b'{', b'}')
+}
+
+/// Find the `)` matching the `(` at byte offset `open`, like [`matching_brace`]
+pub fn matching_paren(content: &str, open: usize) -> Option<usize> {
+    matching_delimiter(content, open, b'(', b')')
+}
+
+/// Find the `]` matching the `[` at byte offset `open`, like [`matching_brace`]
+pub fn matching_bracket(content: &str, open: usize) -> Option<usize> {
+    matching_delimiter(content, open, b'[', b']')
+}
+
+fn matching_delimiter(content: &str, open: usize, open_char: u8, close_char: u8) -> Option<usize> {
+    let bytes = content.as_bytes();
+    if bytes.get(open) != Some(&open_char) {
+        return None;
+    }
+
+    let mut depth = 0usize;
+    let mut i = open;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            c if c == open_char => depth += 1,
+            c if c == close_char => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            quote @ (b'\'' | b'"') => {
+                // Skip to the closing quote, honoring escapes
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    if bytes[i] == b'\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = content[i + 2..].find("*/").map(|p| i + 2 + p + 1)?;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                i = skip_line(content, i);
+            }
+            b'#' if bytes.get(i + 1) != Some(&b'[') => {
+                // `#` starts a comment, `#[` an attribute
+                i = skip_line(content, i);
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    None
+}
+
+/// Body (between the braces) of the block opened at byte offset `open`
+pub fn block_body(content: &str, open: usize) -> Option<&str> {
+    matching_brace(content, open).map(|close| &content[open + 1..close])
+}
+
+/// Split `list` on `separator` where it isn't nested in brackets or strings
+///
+/// Used for argument and parameter lists: `array $a = [1, 2], $b` has two items.
+pub fn split_top_level(list: &str, separator: u8) -> Vec<&str> {
+    let bytes = list.as_bytes();
+    let mut items = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth = depth.saturating_sub(1),
+            quote @ (b'\'' | b'"') => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    if bytes[i] == b'\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+            }
+            c if c == separator && depth == 0 => {
+                items.push(&list[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    items.push(&list[start..]);
+    items
+}
+
+fn skip_line(content: &str, from: usize) -> usize {
+    content[from..]
+        .find('\n')
+        .map(|p| from + p)
+        .unwrap_or(content.len())
+}
+
+/// Kind of non-code region in PHP source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhpRegionKind {
+    Comment,
+    String,
+}
+
+/// A comment or string literal, as a byte range of the source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhpRegion {
+    pub kind: PhpRegionKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Lex PHP source into its comment and string literal regions
+///
+/// Handles '...' and "..." with escapes, heredoc/nowdoc, and `//`, `#` and
+/// `/* */` comments (`#[` opens an attribute, not a comment). An unclosed
+/// region runs to the end of the source.
+pub fn php_regions(content: &str) -> Vec<PhpRegion> {
+    let bytes = content.as_bytes();
+    let mut regions = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let start = i;
+        let region = match bytes[i] {
+            quote @ (b'\'' | b'"') => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    if bytes[i] == b'\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                i = (i + 1).min(bytes.len());
+                Some(PhpRegionKind::String)
+            }
+            b'<' if content[i..].starts_with("<<<") => match heredoc_end(content, i) {
+                Some(end) => {
+                    i = end;
+                    Some(PhpRegionKind::String)
+                }
+                None => {
+                    i += 3;
+                    None
+                }
+            },
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = content[i + 2..]
+                    .find("*/")
+                    .map(|p| i + 2 + p + 2)
+                    .unwrap_or(bytes.len());
+                Some(PhpRegionKind::Comment)
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                i = skip_line(content, i);
+                Some(PhpRegionKind::Comment)
+            }
+            b'#' if bytes.get(i + 1) != Some(&b'[') => {
+                i = skip_line(content, i);
+                Some(PhpRegionKind::Comment)
+            }
+            _ => {
+                i += 1;
+                None
+            }
+        };
+
+        if let Some(kind) = region {
+            regions.push(PhpRegion { kind, start, end: i });
+        }
+    }
+
+    regions
+}
+
+/// `content` with its comments blanked (see [`blank_regions`])
+pub fn blank_comments(content: &str) -> String {
+    let comments: Vec<_> = php_regions(content)
+        .into_iter()
+        .filter(|r| r.kind == PhpRegionKind::Comment)
+        .collect();
+    blank_regions(content, &comments)
+}
+
+/// `content` with its comments and string literals blanked
+///
+/// Declarations matched in the result are real code, not commented-out or
+/// quoted samples. Offsets are kept, so what a match covers (attributes,
+/// default values) can still be read from `content`.
+pub fn blank_comments_and_strings(content: &str) -> String {
+    blank_regions(content, &php_regions(content))
+}
+
+/// Warning for PHP source whose `{` and `}` don't pair up
+///
+/// Braces in strings and comments don't count. Files mixing PHP and HTML
+/// (a `?>` before the end) aren't checked: their text isn't code.
+pub fn unbalanced_braces_warning(content: &str, path: &str) -> Option<String> {
+    let trimmed = content.trim_end();
+    if trimmed.strip_suffix("?>").unwrap_or(trimmed).contains("?>") {
+        return None;
+    }
+
+    let code = blank_regions(content, &php_regions(content));
+    let open = code.matches('{').count();
+    let close = code.matches('}').count();
+    (open != close).then(|| {
+        format!("Unbalanced braces in {}: {} opened, {} closed", path, open, close)
+    })
+}
+
+/// Replace `regions` with spaces, keeping newlines so offsets and line numbers stay valid
+pub fn blank_regions(content: &str, regions: &[PhpRegion]) -> String {
+    let mut blanked = String::with_capacity(content.len());
+    let mut last = 0;
+
+    for region in regions {
+        blanked.push_str(&content[last..region.start]);
+        for c in content[region.start..region.end].chars() {
+            if c == '\n' {
+                blanked.push('\n');
+            } else {
+                // One space per byte, multi-byte characters keep their width
+                blanked.extend(std::iter::repeat_n(' ', c.len_utf8()));
+            }
+        }
+        last = region.end;
+    }
+
+    blanked.push_str(&content[last..]);
+    blanked
+}
+
+/// End of the heredoc/nowdoc starting at `start` (`<<<EOT` ... `EOT`)
+fn heredoc_end(content: &str, start: usize) -> Option<usize> {
+    let header_end = skip_line(content, start);
+    let label = content[start + 3..header_end]
+        .trim()
+        .trim_matches(|c| c == '\'' || c == '"');
+    if label.is_empty() || !label.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return None;
+    }
+
+    // The closing label starts a line (PHP 7.3+ allows indenting it)
+    let mut line_start = header_end + 1;
+    while line_start < content.len() {
+        let line_end = skip_line(content, line_start);
+        let line = content[line_start..line_end].trim_start();
+        if let Some(after) = line.strip_prefix(label) {
+            if !after.starts_with(|c: char| c.is_alphanumeric() || c == '_') {
+                return Some(line_end - after.len());
+            }
+        }
+        line_start = line_end + 1;
+    }
+
+    Some(content.len())
+}
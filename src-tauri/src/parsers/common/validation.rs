@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+
+use crate::models::UnifiedNode;
+
+use super::generate_id;
+
+/// Find symbol nodes whose containing file node is missing
+///
+/// File nodes use `generate_id(path)` as ID, every other node carrying a
+/// `file_path` is a symbol and must have its file node in the same list.
+/// A non-empty result means `generate_nodes` and the ID scheme disagree.
+pub fn find_orphan_symbols(nodes: &[UnifiedNode]) -> Vec<&UnifiedNode> {
+    let ids: HashSet<&str> = nodes.iter().map(|n| n.id.as_str()).collect();
+
+    nodes
+        .iter()
+        .filter(|node| {
+            node.file_path
+                .as_ref()
+                .map(|path| {
+                    let file_id = generate_id(path);
+                    node.id != file_id && !ids.contains(file_id.as_str())
+                })
+                .unwrap_or(false)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::UnifiedNodeType;
+    use crate::parsers::laravel::fixtures::graph_of;
+
+    #[tokio::test]
+    async fn every_symbol_of_a_built_graph_has_its_file_node() {
+        let graph = graph_of(&[(
+            "app/Models/User.php",
+            "<?php\nnamespace App\\Models;\n\nclass User extends Model\n{\n\
+             public function posts()\n    {\n        return $this->hasMany(Post::class);\n\
+             }\n}\n",
+        )])
+        .await;
+
+        assert!(graph.nodes.len() > 1);
+        assert!(find_orphan_symbols(&graph.nodes).is_empty());
+    }
+
+    #[test]
+    fn symbols_without_their_file_node_are_orphans() {
+        let file = UnifiedNode::new(
+            generate_id("app/User.php"),
+            UnifiedNodeType::SourceFile,
+            "User.php".to_string(),
+        )
+        .with_file("app/User.php".to_string());
+        let user = UnifiedNode::new(
+            "user".to_string(),
+            UnifiedNodeType::Class,
+            "User".to_string(),
+        )
+        .with_file("app/User.php".to_string());
+        let post = UnifiedNode::new(
+            "post".to_string(),
+            UnifiedNodeType::Class,
+            "Post".to_string(),
+        )
+        .with_file("app/Post.php".to_string());
+        let nodes = vec![file, user, post];
+
+        let orphans: Vec<&str> = find_orphan_symbols(&nodes)
+            .into_iter()
+            .map(|n| n.id.as_str())
+            .collect();
+        assert_eq!(orphans, ["post"]);
+    }
+}
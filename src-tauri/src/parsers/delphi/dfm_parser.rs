@@ -1,8 +1,8 @@
 use regex::Regex;
-use std::fs;
 
 use crate::models::{ParsedFile, SourceFile, Symbol, SymbolType};
-use crate::parsers::{ParserConfig, ParserResult, ParseError};
+use crate::parsers::common::read_source;
+use crate::parsers::{ParserConfig, ParserResult};
 
 /// Parser for Delphi .dfm/.fmx form files
 pub struct DfmParser {
@@ -14,8 +14,9 @@ impl DfmParser {
     pub fn new() -> Self {
         Self {
             // Match: object ComponentName: TComponentClass
+            // (inline is used for embedded frames)
             object_regex: Regex::new(
-                r"(?i)^\s*(?:object|inherited)\s+(\w+)\s*:\s*(\w+)"
+                r"(?i)^\s*(object|inherited|inline)\s+(\w+)\s*:\s*(\w+)"
             ).unwrap(),
 
             // Match: PropertyName = Value
@@ -28,16 +29,25 @@ impl DfmParser {
     pub async fn parse(
         &self,
         file: &SourceFile,
-        _config: &ParserConfig,
+        config: &ParserConfig,
     ) -> ParserResult<ParsedFile> {
-        let content = fs::read_to_string(&file.absolute_path)
-            .map_err(|e| ParseError::Io(e))?;
+        let (content, decode_warnings) = read_source(file, config)?;
 
         let mut parsed = ParsedFile::new(file.clone());
+        parsed.warnings.extend(decode_warnings);
 
         // Extract all components
         self.extract_components(&content, &mut parsed);
 
+        // Extract embedded frames (declared with `inline`)
+        let frames = self.extract_embedded_frames(&content);
+        if !frames.is_empty() {
+            parsed.metadata.insert(
+                "embedded_frames".to_string(),
+                serde_json::json!(frames),
+            );
+        }
+
         Ok(parsed)
     }
 
@@ -50,8 +60,8 @@ impl DfmParser {
 
             // Check for object declaration
             if let Some(caps) = self.object_regex.captures(trimmed) {
-                let component_name = caps.get(1).map(|m| m.as_str().to_string()).unwrap_or_default();
-                let component_type = caps.get(2).map(|m| m.as_str().to_string()).unwrap_or_default();
+                let component_name = caps.get(2).map(|m| m.as_str().to_string()).unwrap_or_default();
+                let component_type = caps.get(3).map(|m| m.as_str().to_string()).unwrap_or_default();
 
                 parsed.add_symbol(Symbol {
                     name: component_name.clone(),
@@ -62,8 +72,14 @@ impl DfmParser {
                     is_static: None,
                     extends: Some(component_type),
                     implements: None,
+                    parameters: None,
+                    return_type: None,
+                    attributes: None,
                     line_start: None,
                     line_end: None,
+                    section: None,
+                    backing_type: None,
+                    enum_cases: None,
                 });
 
                 current_component = Some(component_name);
@@ -87,6 +103,24 @@ impl DfmParser {
             }
         }
     }
+
+    fn extract_embedded_frames(&self, content: &str) -> Vec<serde_json::Value> {
+        content
+            .lines()
+            .filter_map(|line| self.object_regex.captures(line.trim()))
+            .filter(|caps| {
+                caps.get(1)
+                    .map(|m| m.as_str().eq_ignore_ascii_case("inline"))
+                    .unwrap_or(false)
+            })
+            .map(|caps| {
+                serde_json::json!({
+                    "name": caps.get(2).map(|m| m.as_str()).unwrap_or(""),
+                    "type": caps.get(3).map(|m| m.as_str()).unwrap_or("")
+                })
+            })
+            .collect()
+    }
 }
 
 impl Default for DfmParser {
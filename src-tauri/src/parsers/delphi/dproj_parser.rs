@@ -0,0 +1,84 @@
+use regex::Regex;
+
+use crate::models::{ParsedFile, SourceFile};
+use crate::parsers::common::{read_source, resolve_relative_path};
+use crate::parsers::{ParserConfig, ParserResult};
+
+/// Parser for Delphi .dproj project files and .groupproj project groups
+/// (MSBuild XML)
+pub struct DprojParser {
+    member_regex: Regex,
+    main_source_regex: Regex,
+    reference_regex: Regex,
+}
+
+impl DprojParser {
+    pub fn new() -> Self {
+        Self {
+            // Match: <Projects Include="ProjA\ProjA.dproj">  (a member of a .groupproj)
+            member_regex: Regex::new(r#"(?i)<Projects\s+Include\s*=\s*"([^"]+)""#).unwrap(),
+
+            // Match: <MainSource>ProjA.dpr</MainSource>
+            // (not the <MainSource>MainSource</MainSource> marker of DelphiCompile items)
+            main_source_regex: Regex::new(
+                r"(?i)<MainSource>\s*([^<]+?\.dp[rk])\s*</MainSource>"
+            ).unwrap(),
+
+            // Match: <DCCReference Include="..\Shared\Utils.pas"/>
+            reference_regex: Regex::new(
+                r#"(?i)<DCCReference\s+Include\s*=\s*"([^"]+)""#
+            ).unwrap(),
+        }
+    }
+
+    pub async fn parse(
+        &self,
+        file: &SourceFile,
+        config: &ParserConfig,
+    ) -> ParserResult<ParsedFile> {
+        let (content, decode_warnings) = read_source(file, config)?;
+
+        let mut parsed = ParsedFile::new(file.clone());
+        parsed.warnings.extend(decode_warnings);
+
+        // Paths in both files are relative to the file itself
+        let resolve = |regex: &Regex| -> Vec<String> {
+            let mut paths: Vec<String> = Vec::new();
+            for caps in regex.captures_iter(&content) {
+                let path = resolve_relative_path(&file.path, caps[1].trim());
+                if !paths.contains(&path) {
+                    paths.push(path);
+                }
+            }
+            paths
+        };
+
+        if file.extension.eq_ignore_ascii_case("groupproj") {
+            // Member projects of the group
+            parsed.metadata.insert(
+                "members".to_string(),
+                serde_json::json!(resolve(&self.member_regex)),
+            );
+        } else {
+            // Program/package source first, then the units it references
+            let mut sources = resolve(&self.main_source_regex);
+            for path in resolve(&self.reference_regex) {
+                if !sources.contains(&path) {
+                    sources.push(path);
+                }
+            }
+            parsed.metadata.insert(
+                "sources".to_string(),
+                serde_json::json!(sources),
+            );
+        }
+
+        Ok(parsed)
+    }
+}
+
+impl Default for DprojParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,24 @@
+// Test fixtures - Delphi projects written to a temporary directory
+
+use crate::models::{ParseResult, UnifiedGraph};
+use crate::parsers::common::fixtures::project_dir;
+use crate::parsers::ProjectParser;
+
+use super::DelphiParser;
+
+/// Parse result of a Delphi project made of the given files
+pub(crate) async fn parse_of(files: &[(&str, &str)]) -> ParseResult {
+    let dir = project_dir(files);
+    let parser = DelphiParser::new();
+    let config = parser.default_config();
+    let files = parser.scan_files(dir.path(), &config, None).await.unwrap();
+    parser
+        .parse_project(dir.path(), &files, &config, None)
+        .await
+        .unwrap()
+}
+
+/// Graph of a Delphi project made of the given files
+pub(crate) async fn graph_of(files: &[(&str, &str)]) -> UnifiedGraph {
+    DelphiParser::new().build_graph(&parse_of(files).await)
+}
@@ -3,5 +3,8 @@
 mod parser;
 mod pas_parser;
 mod dfm_parser;
+mod dproj_parser;
+#[cfg(test)]
+pub(crate) mod fixtures;
 
 pub use parser::DelphiParser;
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
 use crate::core::{ParserInfo, ProjectType};
@@ -6,18 +7,25 @@ use crate::models::{
     ParseResult, ParsedFile, SourceFile, UnifiedEdge, UnifiedEdgeType, UnifiedNode,
     UnifiedNodeType,
 };
-use crate::parsers::common::{generate_id, scan_directory};
+use crate::parsers::common::{generate_id, has_files_with_extension, scan_directory};
 use crate::parsers::{
     ParserCapabilities, ParserConfig, ParserResult, ParseProgress, ProgressCallback, ProjectParser,
 };
 
 use super::pas_parser::PasParser;
 use super::dfm_parser::DfmParser;
+use super::dproj_parser::DprojParser;
+
+/// Extensions scanned by default: units, forms, projects, packages and includes
+const SCANNED_EXTENSIONS: &[&str] = &[
+    "pas", "dfm", "fmx", "dpr", "dpk", "inc", "dproj", "groupproj",
+];
 
 /// Delphi/Object Pascal project parser
 pub struct DelphiParser {
     pas_parser: PasParser,
     dfm_parser: DfmParser,
+    dproj_parser: DprojParser,
 }
 
 impl DelphiParser {
@@ -25,6 +33,7 @@ impl DelphiParser {
         Self {
             pas_parser: PasParser::new(),
             dfm_parser: DfmParser::new(),
+            dproj_parser: DprojParser::new(),
         }
     }
 
@@ -34,9 +43,115 @@ impl DelphiParser {
             "dfm" | "fmx" => UnifiedNodeType::Form,
             "dpr" => UnifiedNodeType::SourceFile,
             "dpk" => UnifiedNodeType::Package,
+            "dproj" | "groupproj" => UnifiedNodeType::ConfigFile,
             _ => UnifiedNodeType::SourceFile,
         }
     }
+
+    /// Map frame classes (lowercase) to the unit declaring them
+    /// A frame is any class inheriting TFrame, directly or through another frame
+    fn frame_units(&self, parse_result: &ParseResult) -> HashMap<String, String> {
+        let classes: Vec<(String, String, String)> = parse_result
+            .files
+            .iter()
+            .filter(|f| f.source.is_delphi_unit())
+            .flat_map(|f| {
+                f.symbols
+                    .iter()
+                    .filter(|s| s.symbol_type == crate::models::SymbolType::Class)
+                    .filter_map(move |s| {
+                        s.extends.as_ref().map(|parent| {
+                            (s.name.to_lowercase(), parent.to_lowercase(), f.source.path.clone())
+                        })
+                    })
+            })
+            .collect();
+
+        let mut frames: HashMap<String, String> = HashMap::new();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (name, parent, path) in &classes {
+                if !frames.contains_key(name)
+                    && (parent == "tframe" || frames.contains_key(parent))
+                {
+                    frames.insert(name.clone(), path.clone());
+                    changed = true;
+                }
+            }
+        }
+
+        frames
+    }
+
+    /// Map file paths to the projects (.dproj file stems) they belong to
+    ///
+    /// A project holds its .dproj, main source and referenced units, the forms
+    /// paired with those units and the files they include. A unit shared by
+    /// several projects of a group belongs to each of them.
+    fn project_files(&self, parse_result: &ParseResult) -> HashMap<String, Vec<String>> {
+        let files_by_path: HashMap<String, &ParsedFile> = parse_result
+            .files
+            .iter()
+            .map(|f| (f.source.path.to_lowercase(), f))
+            .collect();
+        let pairs = self.detect_file_pairs(
+            &parse_result.files.iter().map(|f| f.source.clone()).collect::<Vec<_>>(),
+        );
+
+        let mut projects: HashMap<String, Vec<String>> = HashMap::new();
+        for project_file in parse_result
+            .files
+            .iter()
+            .filter(|f| f.source.extension.eq_ignore_ascii_case("dproj"))
+        {
+            let project = Path::new(&project_file.source.name)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or_default()
+                .to_string();
+
+            let mut members: Vec<&str> = vec![&project_file.source.path];
+            for source in metadata_paths(project_file, "sources") {
+                let parsed_file = match files_by_path.get(&source.to_lowercase()) {
+                    Some(parsed_file) => parsed_file,
+                    None => continue,
+                };
+                members.push(&parsed_file.source.path);
+                members.extend(
+                    pairs
+                        .iter()
+                        .filter(|(pas, _)| *pas == parsed_file.source.path)
+                        .map(|(_, form)| form.as_str()),
+                );
+                members.extend(
+                    metadata_paths(parsed_file, "includes")
+                        .filter_map(|include| files_by_path.get(&include.to_lowercase()))
+                        .map(|f| f.source.path.as_str()),
+                );
+            }
+
+            for member in members {
+                let member_projects = projects.entry(member.to_string()).or_default();
+                if !member_projects.contains(&project) {
+                    member_projects.push(project.clone());
+                }
+            }
+        }
+
+        projects
+    }
+}
+
+/// Paths listed in a parsed file's `key` metadata (`includes`, `sources`, `members`)
+fn metadata_paths<'a>(parsed_file: &'a ParsedFile, key: &str) -> impl Iterator<Item = &'a str> {
+    parsed_file
+        .metadata
+        .get(key)
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|p| p.as_str())
 }
 
 impl Default for DelphiParser {
@@ -59,6 +174,9 @@ impl ProjectParser for DelphiParser {
                 "fmx".to_string(),
                 "dpr".to_string(),
                 "dpk".to_string(),
+                "inc".to_string(),
+                "dproj".to_string(),
+                "groupproj".to_string(),
             ],
             marker_files: vec!["*.dpr".to_string(), "*.dproj".to_string()],
             marker_dirs: vec![],
@@ -70,12 +188,7 @@ impl ProjectParser for DelphiParser {
 
     fn default_config(&self) -> ParserConfig {
         ParserConfig {
-            include_extensions: vec![
-                "pas".to_string(),
-                "dfm".to_string(),
-                "fmx".to_string(),
-                "dpr".to_string(),
-            ],
+            include_extensions: SCANNED_EXTENSIONS.iter().map(|e| e.to_string()).collect(),
             exclude_dirs: vec![
                 "__history".to_string(),
                 "__recovery".to_string(),
@@ -85,10 +198,15 @@ impl ProjectParser for DelphiParser {
                 "Release".to_string(),
                 ".git".to_string(),
             ],
+            include_globs: Vec::new(),
+            exclude_globs: Vec::new(),
             encoding: "utf-8".to_string(),
             parse_external_deps: false,
             max_depth: None,
             language_options: Default::default(),
+            respect_gitignore: false,
+            max_file_size_bytes: None,
+            compute_hashes: false,
         }
     }
 
@@ -100,36 +218,44 @@ impl ProjectParser for DelphiParser {
                 "class".to_string(),
                 "interface".to_string(),
                 "function".to_string(),
-                "procedure".to_string(),
+                "method".to_string(),
             ],
             edge_types: vec![
                 "uses".to_string(),
                 "extends".to_string(),
                 "implements".to_string(),
                 "file_pair".to_string(),
+                "embeds_frame".to_string(),
+                "includes".to_string(),
+                "contains".to_string(),
             ],
-            supports_incremental: false,
+            supports_incremental: true,
             supports_cancellation: true,
             available_metrics: vec![
                 "lines_of_code".to_string(),
+                "comment_lines".to_string(),
+                "blank_lines".to_string(),
                 "cyclomatic_complexity".to_string(),
             ],
         }
     }
 
-    fn detect_confidence(&self, root_path: &Path) -> f32 {
+    fn detect_markers(&self, root_path: &Path) -> (f32, Vec<String>) {
         let mut score = 0.0f32;
+        let mut markers = Vec::new();
 
         // Check for project files
         for ext in ["dpr", "dproj", "groupproj"] {
             if has_files_with_extension(root_path, ext) {
                 score += 0.4;
+                markers.push(format!("*.{}", ext));
             }
         }
 
         // Check for .pas files
         if has_files_with_extension(root_path, "pas") {
             score += 0.3;
+            markers.push("*.pas".to_string());
         }
 
         // Check for form files
@@ -137,16 +263,17 @@ impl ProjectParser for DelphiParser {
             || has_files_with_extension(root_path, "fmx")
         {
             score += 0.2;
+            markers.push("*.dfm/*.fmx".to_string());
         }
 
-        score.min(1.0)
+        (score.min(1.0), markers)
     }
 
     fn can_handle_file(&self, file_path: &Path) -> bool {
         if let Some(ext) = file_path.extension().and_then(|e| e.to_str()) {
             matches!(
                 ext.to_lowercase().as_str(),
-                "pas" | "dfm" | "fmx" | "dpr" | "dpk"
+                "pas" | "dfm" | "fmx" | "dpr" | "dpk" | "inc" | "dproj" | "groupproj"
             )
         } else {
             false
@@ -159,10 +286,13 @@ impl ProjectParser for DelphiParser {
         config: &ParserConfig,
         _progress: Option<ProgressCallback>,
     ) -> ParserResult<Vec<SourceFile>> {
-        let extensions: Vec<&str> = vec!["pas", "dfm", "fmx", "dpr", "dpk"];
-        let exclude_dirs: Vec<&str> = config.exclude_dirs.iter().map(|s| s.as_str()).collect();
+        let extensions: Vec<&str> = SCANNED_EXTENSIONS
+            .iter()
+            .copied()
+            .filter(|ext| config.includes_extension(ext))
+            .collect();
 
-        Ok(scan_directory(root_path, &extensions, &exclude_dirs))
+        scan_directory(root_path, &extensions, config)
     }
 
     async fn parse_file(
@@ -171,8 +301,9 @@ impl ProjectParser for DelphiParser {
         config: &ParserConfig,
     ) -> ParserResult<ParsedFile> {
         match file.extension.to_lowercase().as_str() {
-            "pas" | "dpr" | "dpk" => self.pas_parser.parse(file, config).await,
+            "pas" | "dpr" | "dpk" | "inc" => self.pas_parser.parse(file, config).await,
             "dfm" | "fmx" => self.dfm_parser.parse(file, config).await,
+            "dproj" | "groupproj" => self.dproj_parser.parse(file, config).await,
             _ => Ok(ParsedFile::new(file.clone())),
         }
     }
@@ -190,6 +321,13 @@ impl ProjectParser for DelphiParser {
                     .with_file(parsed_file.source.path.clone())
                     .with_language("delphi");
 
+            // Keep parsed metadata (message handlers, class registrations, ...)
+            node.metadata.extra = parsed_file
+                .metadata
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect();
+
             // Set size based on file size
             let size = match parsed_file.source.size_bytes {
                 0..=1000 => 2,
@@ -212,7 +350,7 @@ impl ProjectParser for DelphiParser {
                         "{}::{}",
                         parsed_file.source.path, symbol.name
                     ));
-                    let class_node = UnifiedNode::new(
+                    let mut class_node = UnifiedNode::new(
                         class_id,
                         UnifiedNodeType::Class,
                         symbol.name.clone(),
@@ -220,10 +358,69 @@ impl ProjectParser for DelphiParser {
                     .with_file(parsed_file.source.path.clone())
                     .with_language("delphi")
                     .with_size(4);
+                    if let Some(section) = symbol.section {
+                        class_node
+                            .metadata
+                            .extra
+                            .insert("section".to_string(), serde_json::json!(section));
+                    }
 
                     nodes.push(class_node);
                 }
             }
+
+            // Create nodes for routines, overloads get a numbered ID
+            let mut declared: HashMap<String, usize> = HashMap::new();
+            for symbol in &parsed_file.symbols {
+                let node_type = match symbol.symbol_type {
+                    crate::models::SymbolType::Method => UnifiedNodeType::Method,
+                    crate::models::SymbolType::Function => UnifiedNodeType::Function,
+                    _ => continue,
+                };
+
+                let occurrence = declared
+                    .entry(symbol.qualified_name.to_lowercase())
+                    .or_insert(0);
+                *occurrence += 1;
+                let routine_id = match *occurrence {
+                    1 => generate_id(&format!(
+                        "{}::{}",
+                        parsed_file.source.path, symbol.qualified_name
+                    )),
+                    n => generate_id(&format!(
+                        "{}::{}#{}",
+                        parsed_file.source.path, symbol.qualified_name, n
+                    )),
+                };
+
+                let mut routine_node = UnifiedNode::new(routine_id, node_type, symbol.name.clone())
+                    .with_file(parsed_file.source.path.clone())
+                    .with_language("delphi")
+                    .with_size(2);
+                routine_node.qualified_name = symbol.qualified_name.clone();
+                routine_node.metadata.is_static = symbol.is_static;
+                routine_node.metadata.parameters = symbol.parameters.clone();
+                routine_node.metadata.return_type = symbol.return_type.clone();
+                if let Some(section) = symbol.section {
+                    routine_node
+                        .metadata
+                        .extra
+                        .insert("section".to_string(), serde_json::json!(section));
+                }
+
+                nodes.push(routine_node);
+            }
+        }
+
+        // Tag nodes with their projects, so a group can be filtered per project
+        let project_files = self.project_files(parse_result);
+        for node in &mut nodes {
+            let projects = node.file_path.as_ref().and_then(|path| project_files.get(path));
+            if let Some(projects) = projects {
+                node.metadata
+                    .extra
+                    .insert("projects".to_string(), serde_json::json!(projects));
+            }
         }
 
         nodes
@@ -236,11 +433,71 @@ impl ProjectParser for DelphiParser {
     ) -> Vec<UnifiedEdge> {
         let mut edges = Vec::new();
 
+        // Detect file pairs (.pas <-> .dfm)
+        let pairs = self.detect_file_pairs(
+            &parse_result.files.iter().map(|f| f.source.clone()).collect::<Vec<_>>(),
+        );
+
+        // Files by lowercase path, and by lowercase file name for includes
+        // found through the compiler's search path rather than relative
+        let files_by_path: HashMap<String, &str> = parse_result
+            .files
+            .iter()
+            .map(|f| (f.source.path.to_lowercase(), f.source.path.as_str()))
+            .collect();
+        let files_by_name: HashMap<String, &str> = parse_result
+            .files
+            .iter()
+            .map(|f| (f.source.name.to_lowercase(), f.source.path.as_str()))
+            .collect();
+
         for parsed_file in &parse_result.files {
             let source_id = generate_id(&parsed_file.source.path);
+            let includes: Vec<&str> = metadata_paths(parsed_file, "includes").collect();
+
+            // Group -> member projects, project -> its sources
+            for member in metadata_paths(parsed_file, "members")
+                .chain(metadata_paths(parsed_file, "sources"))
+            {
+                if let Some(target) = files_by_path.get(&member.to_lowercase()) {
+                    edges.push(UnifiedEdge::new(
+                        source_id.clone(),
+                        generate_id(target),
+                        UnifiedEdgeType::Contains,
+                    ));
+                }
+            }
+
+            // {$I} files and {$R} forms, unless the form is already the unit's pair
+            for include in &includes {
+                let name = include.rsplit('/').next().unwrap_or(include);
+                let target = files_by_path
+                    .get(&include.to_lowercase())
+                    .or_else(|| files_by_name.get(&name.to_lowercase()));
+
+                if let Some(target) = target {
+                    let paired = pairs
+                        .iter()
+                        .any(|(pas, form)| *pas == parsed_file.source.path && form == target);
+                    if !paired {
+                        edges.push(
+                            UnifiedEdge::new(
+                                source_id.clone(),
+                                generate_id(target),
+                                UnifiedEdgeType::Includes,
+                            )
+                            .with_label(name),
+                        );
+                    }
+                }
+            }
 
             // Create edges for dependencies (uses clauses)
             for dep in &parsed_file.dependencies {
+                if includes.contains(&dep.target.as_str()) {
+                    continue;
+                }
+
                 // Try to find the target file
                 let target_id = generate_id(&dep.target);
 
@@ -251,10 +508,43 @@ impl ProjectParser for DelphiParser {
             }
         }
 
-        // Detect file pairs (.pas <-> .dfm)
-        let pairs = self.detect_file_pairs(
-            &parse_result.files.iter().map(|f| f.source.clone()).collect::<Vec<_>>(),
-        );
+        // Frame inheritance (unit -> unit of the parent frame)
+        // and frame embedding (form -> unit of the embedded frame)
+        let frame_units = self.frame_units(parse_result);
+        let mut seen_frame_edges = HashSet::new();
+        for parsed_file in &parse_result.files {
+            let source_id = generate_id(&parsed_file.source.path);
+
+            // In forms the first object is the form/frame itself, not an embedded component
+            let (edge_type, skip) = if parsed_file.source.is_delphi_form() {
+                (UnifiedEdgeType::Custom("embeds_frame".to_string()), 1)
+            } else if parsed_file.source.is_delphi_unit() {
+                (UnifiedEdgeType::Extends, 0)
+            } else {
+                continue;
+            };
+
+            for symbol in parsed_file.symbols.iter().skip(skip) {
+                let frame_path = symbol
+                    .extends
+                    .as_ref()
+                    .and_then(|t| frame_units.get(&t.to_lowercase()));
+
+                if let Some(frame_path) = frame_path {
+                    if *frame_path == parsed_file.source.path
+                        || !seen_frame_edges.insert((source_id.clone(), frame_path.clone()))
+                    {
+                        continue;
+                    }
+
+                    edges.push(
+                        UnifiedEdge::new(source_id.clone(), generate_id(frame_path), edge_type.clone())
+                            .with_label(&symbol.name),
+                    );
+                }
+            }
+        }
+
         for (pas_path, dfm_path) in pairs {
             let pas_id = generate_id(&pas_path);
             let dfm_id = generate_id(&dfm_path);
@@ -302,18 +592,239 @@ impl ProjectParser for DelphiParser {
     }
 }
 
-fn has_files_with_extension(root_path: &Path, ext: &str) -> bool {
-    if let Ok(entries) = std::fs::read_dir(root_path) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.is_file() {
-                if let Some(file_ext) = path.extension().and_then(|e| e.to_str()) {
-                    if file_ext.eq_ignore_ascii_case(ext) {
-                        return true;
-                    }
-                }
-            }
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::delphi::fixtures::graph_of;
+
+    #[tokio::test]
+    async fn default_config_scans_packages() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("Components.dpk"),
+            "package Components;\nend.",
+        )
+        .unwrap();
+
+        let parser = DelphiParser::new();
+        let config = parser.default_config();
+        let files = parser.scan_files(dir.path(), &config, None).await.unwrap();
+
+        assert!(files.iter().any(|f| f.path.ends_with("Components.dpk")));
+    }
+
+    #[tokio::test]
+    async fn include_directives_link_units_to_inc_files() {
+        let main = "unit Main;
+
+{$I ..\\shared\\common.inc}
+{$I+}
+
+interface
+
+type
+  TMainForm = class(TForm)
+  end;
+
+implementation
+
+{$R *.dfm}
+{$INCLUDE 'defs.inc'}
+{$I %DATE%}
+
+end.
+";
+        let graph = graph_of(&[
+            ("app/Main.pas", main),
+            ("app/Main.dfm", "object MainForm: TMainForm\nend\n"),
+            (
+                "shared/common.inc",
+                "{$DEFINE SHARED}\nprocedure SharedHelper;\n",
+            ),
+            ("defs/defs.inc", "const Version = 1;\n"),
+        ])
+        .await;
+
+        let mut includes: Vec<_> = graph
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == UnifiedEdgeType::Includes)
+            .map(|e| (e.source.clone(), e.target.clone()))
+            .collect();
+        includes.sort();
+        let mut expected = vec![
+            (
+                generate_id("app/Main.pas"),
+                generate_id("shared/common.inc"),
+            ),
+            (generate_id("app/Main.pas"), generate_id("defs/defs.inc")),
+        ];
+        expected.sort();
+        assert_eq!(includes, expected);
+
+        assert!(graph
+            .nodes
+            .iter()
+            .any(|n| n.qualified_name == "SharedHelper"));
+        let uses: Vec<_> = graph
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == UnifiedEdgeType::Uses)
+            .filter_map(|e| e.label.as_deref())
+            .collect();
+        assert!(
+            uses.iter().all(|label| !label.ends_with(".inc")),
+            "{:?}",
+            uses
+        );
+    }
+
+    fn dproj(main_source: &str, units: &[&str]) -> String {
+        let references: String = units
+            .iter()
+            .map(|unit| format!("        <DCCReference Include=\"{}\"/>\n", unit))
+            .collect();
+        format!(
+            "<Project>\n    <PropertyGroup>\n        <MainSource>{}</MainSource>\n    \
+</PropertyGroup>\n    <ItemGroup>\n        <DelphiCompile Include=\"$(MainSource)\"/>\n{}    \
+</ItemGroup>\n</Project>\n",
+            main_source, references
+        )
+    }
+
+    #[tokio::test]
+    async fn group_members_tag_the_files_of_their_projects() {
+        let group = "<Project>\n    <ItemGroup>\n        \
+<Projects Include=\"ProjA\\ProjA.dproj\"/>\n        \
+<Projects Include=\"ProjB\\ProjB.dproj\"/>\n    </ItemGroup>\n</Project>\n";
+        let proj_a = dproj("ProjA.dpr", &["MainA.pas", "..\\Shared\\Utils.pas"]);
+        let proj_b = dproj("ProjB.dpr", &["MainB.pas", "..\\Shared\\Utils.pas"]);
+        let graph = graph_of(&[
+            ("All.groupproj", group),
+            ("ProjA/ProjA.dproj", &proj_a),
+            (
+                "ProjA/ProjA.dpr",
+                "program ProjA;\nuses MainA, Utils;\nbegin\nend.\n",
+            ),
+            (
+                "ProjA/MainA.pas",
+                "unit MainA;\ninterface\nuses Utils;\nimplementation\nend.\n",
+            ),
+            ("ProjB/ProjB.dproj", &proj_b),
+            (
+                "ProjB/ProjB.dpr",
+                "program ProjB;\nuses MainB, Utils;\nbegin\nend.\n",
+            ),
+            (
+                "ProjB/MainB.pas",
+                "unit MainB;\ninterface\nuses Utils;\nimplementation\nend.\n",
+            ),
+            (
+                "Shared/Utils.pas",
+                "unit Utils;\ninterface\nimplementation\nend.\n",
+            ),
+        ])
+        .await;
+
+        let projects = |path: &str| {
+            let node = graph.find_node(&generate_id(path)).unwrap();
+            node.metadata.extra.get("projects").cloned()
+        };
+        assert_eq!(
+            projects("ProjA/MainA.pas"),
+            Some(serde_json::json!(["ProjA"]))
+        );
+        assert_eq!(
+            projects("ProjB/ProjB.dpr"),
+            Some(serde_json::json!(["ProjB"]))
+        );
+        assert_eq!(
+            projects("Shared/Utils.pas"),
+            Some(serde_json::json!(["ProjA", "ProjB"]))
+        );
+        assert_eq!(projects("All.groupproj"), None);
+
+        let contains = |source: &str, target: &str| {
+            graph.edges.iter().any(|e| {
+                e.edge_type == UnifiedEdgeType::Contains
+                    && e.source == generate_id(source)
+                    && e.target == generate_id(target)
+            })
+        };
+        assert!(contains("All.groupproj", "ProjA/ProjA.dproj"));
+        assert!(contains("All.groupproj", "ProjB/ProjB.dproj"));
+        assert!(contains("ProjA/ProjA.dproj", "Shared/Utils.pas"));
+        assert!(contains("ProjA/ProjA.dproj", "ProjA/ProjA.dpr"));
+    }
+
+    #[tokio::test]
+    async fn forms_link_to_the_units_of_their_embedded_frames() {
+        let frame = "unit AddressFrame;
+
+interface
+
+type
+  TAddressFrame = class(TFrame)
+    StreetEdit: TEdit;
+  end;
+
+implementation
+
+{$R *.dfm}
+
+end.
+";
+        let main = "unit Main;
+
+interface
+
+uses
+  AddressFrame;
+
+type
+  TMainForm = class(TForm)
+    BillingAddress: TAddressFrame;
+  end;
+
+implementation
+
+{$R *.dfm}
+
+end.
+";
+        let form = "object MainForm: TMainForm
+  Caption = 'Order'
+  inline BillingAddress: TAddressFrame
+    Left = 8
+  end
+  object OkButton: TButton
+  end
+end
+";
+        let graph = graph_of(&[
+            ("AddressFrame.pas", frame),
+            (
+                "AddressFrame.dfm",
+                "object AddressFrame: TAddressFrame\n  object StreetEdit: TEdit\n  end\nend\n",
+            ),
+            ("Main.pas", main),
+            ("Main.dfm", form),
+        ])
+        .await;
+
+        let embeds: Vec<_> = graph
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == UnifiedEdgeType::Custom("embeds_frame".to_string()))
+            .map(|e| (e.source.clone(), e.target.clone(), e.label.as_deref()))
+            .collect();
+        assert_eq!(
+            embeds,
+            [(
+                generate_id("Main.dfm"),
+                generate_id("AddressFrame.pas"),
+                Some("BillingAddress")
+            )]
+        );
     }
-    false
 }
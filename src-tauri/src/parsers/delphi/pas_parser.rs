@@ -1,8 +1,11 @@
 use regex::Regex;
-use std::fs;
+use std::path::Path;
 
-use crate::models::{Dependency, ParsedFile, SourceFile, Symbol, SymbolType};
-use crate::parsers::{ParserConfig, ParserResult, ParseError};
+use crate::models::{
+    Dependency, ParameterInfo, ParsedFile, SourceFile, Symbol, SymbolType, UnitSection,
+};
+use crate::parsers::common::{read_source, resolve_relative_path, split_top_level};
+use crate::parsers::{ParserConfig, ParserResult};
 
 /// Parser for Delphi .pas files
 pub struct PasParser {
@@ -12,6 +15,16 @@ pub struct PasParser {
     interface_regex: Regex,
     procedure_regex: Regex,
     function_regex: Regex,
+    end_regex: Regex,
+    interface_section_regex: Regex,
+    implementation_section_regex: Regex,
+    message_handler_regex: Regex,
+    include_regex: Regex,
+    resource_regex: Regex,
+    registration_regex: Regex,
+    class_lookup_regex: Regex,
+    string_literal_regex: Regex,
+    type_name_regex: Regex,
 }
 
 impl PasParser {
@@ -33,27 +46,62 @@ impl PasParser {
                 r"(?i)(\w+)\s*=\s*interface\s*(?:\[|(?:\((\w+)\)))?"
             ).unwrap(),
 
-            // Match: procedure Name
+            // Match: procedure Name  or  class procedure TClass.Name
             procedure_regex: Regex::new(
-                r"(?i)^\s*(?:(class)\s+)?procedure\s+(\w+)(?:\.(\w+))?\s*(?:\(|;)"
+                r"(?im)^\s*(?:(class)\s+)?procedure\s+(\w+)(?:\.(\w+))?\s*[(;]"
             ).unwrap(),
 
-            // Match: function Name
+            // Match: function Name  or  class function TClass.Name
             function_regex: Regex::new(
-                r"(?i)^\s*(?:(class)\s+)?function\s+(\w+)(?:\.(\w+))?\s*(?:\(|:)"
+                r"(?im)^\s*(?:(class)\s+)?function\s+(\w+)(?:\.(\w+))?\s*[(:;]"
             ).unwrap(),
+
+            // Match: end;  (closing a class or interface body)
+            end_regex: Regex::new(r"(?im)^\s*end\s*;").unwrap(),
+
+            // Match: interface / implementation  (section keywords, on their own line)
+            interface_section_regex: Regex::new(r"(?im)^\s*interface\b").unwrap(),
+            implementation_section_regex: Regex::new(r"(?im)^\s*implementation\b").unwrap(),
+
+            // Match: procedure WMPaint(var Msg: TWMPaint); message WM_PAINT;
+            message_handler_regex: Regex::new(
+                r"(?im)^\s*procedure\s+(\w+)\s*\([^)]*\)\s*;\s*message\s+(\w+)\s*;"
+            ).unwrap(),
+
+            // Match: {$I common.inc}  or  {$INCLUDE '..\shared\common.inc'}  (not the {$I+} switch)
+            include_regex: Regex::new(
+                r"(?i)\{\$(?:I|INCLUDE)\s+('[^']*'|[^}\s]+)\s*\}"
+            ).unwrap(),
+
+            // Match: {$R *.dfm}  or  {$R 'Main.fmx'}
+            resource_regex: Regex::new(r"(?i)\{\$R\s+('[^']*'|[^}\s]+)\s*\}").unwrap(),
+
+            // Match: RegisterClass(TFoo);  RegisterClasses([TFoo, TBar]);
+            //    or  RegisterComponents('Samples', [TFoo]);  RegisterClassAlias(TFoo, 'Foo');
+            registration_regex: Regex::new(
+                r"(?is)\b(RegisterClass|RegisterClasses|RegisterClassAlias|RegisterComponents)\s*\((.*?)\)\s*;"
+            ).unwrap(),
+
+            // Match: FindClass('TFoo')  or  GetClass('TFoo')
+            class_lookup_regex: Regex::new(r"(?i)\b(FindClass|GetClass)\s*\(\s*'(\w+)'").unwrap(),
+
+            // Match: 'Samples'
+            string_literal_regex: Regex::new(r"'[^']*'").unwrap(),
+
+            // Match: TFoo  (a type name in a registration call)
+            type_name_regex: Regex::new(r"\bT\w+").unwrap(),
         }
     }
 
     pub async fn parse(
         &self,
         file: &SourceFile,
-        _config: &ParserConfig,
+        config: &ParserConfig,
     ) -> ParserResult<ParsedFile> {
-        let content = fs::read_to_string(&file.absolute_path)
-            .map_err(|e| ParseError::Io(e))?;
+        let (content, decode_warnings) = read_source(file, config)?;
 
         let mut parsed = ParsedFile::new(file.clone());
+        parsed.warnings.extend(decode_warnings);
 
         // Extract unit name
         if let Some(caps) = self.unit_regex.captures(&content) {
@@ -67,48 +115,80 @@ impl PasParser {
                 is_static: None,
                 extends: None,
                 implements: None,
+                parameters: None,
+                return_type: None,
+                attributes: None,
                 line_start: Some(1),
                 line_end: None,
+                section: None,
+                backing_type: None,
+                enum_cases: None,
             });
         }
 
+        // Declarations before `implementation` are the unit's public surface
+        let sections = UnitSections {
+            interface: self
+                .interface_section_regex
+                .find(&content)
+                .map(|m| m.start()),
+            implementation: self
+                .implementation_section_regex
+                .find(&content)
+                .map(|m| m.start()),
+        };
+
         // Extract uses clauses
-        self.extract_uses(&content, &mut parsed);
+        self.extract_uses(&content, sections, &mut parsed);
+
+        // Extract {$I} include files and {$R} form resources
+        let includes = self.extract_includes(&content, sections, &mut parsed);
+        if !includes.is_empty() {
+            parsed.metadata.insert(
+                "includes".to_string(),
+                serde_json::json!(includes),
+            );
+        }
 
         // Extract classes
-        self.extract_classes(&content, &mut parsed);
+        self.extract_classes(&content, sections, &mut parsed);
 
         // Extract interfaces
-        self.extract_interfaces(&content, &mut parsed);
+        self.extract_interfaces(&content, sections, &mut parsed);
 
         // Extract procedures and functions
-        self.extract_procedures(&content, &mut parsed);
-        self.extract_functions(&content, &mut parsed);
+        self.extract_routines(&content, sections, &mut parsed);
+
+        // Extract Windows message handlers (procedure ...; message WM_XXX;)
+        let handlers = self.extract_message_handlers(&content);
+        if !handlers.is_empty() {
+            parsed.metadata.insert(
+                "message_handlers".to_string(),
+                serde_json::json!(handlers),
+            );
+        }
+
+        // Extract RTTI class registrations and lookups by name
+        let registrations = self.extract_class_registrations(&content);
+        if !registrations.is_empty() {
+            parsed.metadata.insert(
+                "class_registrations".to_string(),
+                serde_json::json!(registrations),
+            );
+        }
 
         Ok(parsed)
     }
 
-    fn extract_uses(&self, content: &str, parsed: &mut ParsedFile) {
-        // Find interface section
-        let interface_pos = content.to_lowercase().find("interface");
-        let implementation_pos = content.to_lowercase().find("implementation");
-
+    fn extract_uses(&self, content: &str, sections: UnitSections, parsed: &mut ParsedFile) {
         for caps in self.uses_regex.captures_iter(content) {
             if let Some(uses_match) = caps.get(1) {
                 let uses_str = uses_match.as_str();
-                let match_pos = uses_match.start();
 
                 // Determine if this is interface or implementation uses
-                let is_interface = match (interface_pos, implementation_pos) {
-                    (Some(iface), Some(impl_)) => match_pos > iface && match_pos < impl_,
-                    (Some(iface), None) => match_pos > iface,
-                    _ => false,
-                };
-
-                let is_implementation = match implementation_pos {
-                    Some(impl_) => match_pos > impl_,
-                    None => false,
-                };
+                let section = sections.at(uses_match.start());
+                let is_interface = section == Some(UnitSection::Interface);
+                let is_implementation = section == Some(UnitSection::Implementation);
 
                 // Parse individual unit names
                 for unit in uses_str.split(',') {
@@ -133,7 +213,63 @@ impl PasParser {
         }
     }
 
-    fn extract_classes(&self, content: &str, parsed: &mut ParsedFile) {
+    /// Files pulled in by `{$I}` / `{$INCLUDE}` and form files linked by `{$R}`,
+    /// as project-relative paths
+    ///
+    /// Paths are relative to the including file; `*` in a resource name
+    /// stands for the unit's own name. Other resources (`.res`) and `{$I %DATE%}`
+    /// style compiler inserts aren't files of the project and are skipped.
+    fn extract_includes(
+        &self,
+        content: &str,
+        sections: UnitSections,
+        parsed: &mut ParsedFile,
+    ) -> Vec<String> {
+        let stem = Path::new(&parsed.source.name)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("")
+            .to_string();
+
+        let includes = self
+            .include_regex
+            .captures_iter(content)
+            .filter(|caps| !caps[1].starts_with('%'))
+            .map(|caps| (caps.get(0).map(|m| m.start()).unwrap_or(0), caps[1].to_string()));
+        let forms = self
+            .resource_regex
+            .captures_iter(content)
+            .map(|caps| {
+                let name = caps[1].trim_matches('\'').replace('*', &stem);
+                (caps.get(0).map(|m| m.start()).unwrap_or(0), name)
+            })
+            .filter(|(_, name)| {
+                let name = name.to_lowercase();
+                name.ends_with(".dfm") || name.ends_with(".fmx")
+            });
+
+        let mut paths: Vec<String> = Vec::new();
+        for (position, include) in includes.chain(forms) {
+            let path = resolve_relative_path(&parsed.source.path, include.trim_matches('\''));
+            if paths.contains(&path) {
+                continue;
+            }
+
+            let section = sections.at(position);
+            parsed.add_dependency(Dependency {
+                target: path.clone(),
+                alias: None,
+                line_number: None,
+                is_interface: section == Some(UnitSection::Interface),
+                is_implementation: section == Some(UnitSection::Implementation),
+            });
+            paths.push(path);
+        }
+
+        paths
+    }
+
+    fn extract_classes(&self, content: &str, sections: UnitSections, parsed: &mut ParsedFile) {
         for caps in self.class_regex.captures_iter(content) {
             let class_name = caps.get(1).map(|m| m.as_str().to_string()).unwrap_or_default();
             let parent = caps.get(2).map(|m| m.as_str().to_string());
@@ -148,14 +284,20 @@ impl PasParser {
                     is_static: None,
                     extends: parent,
                     implements: None,
+                    parameters: None,
+                    return_type: None,
+                    attributes: None,
                     line_start: None,
                     line_end: None,
+                    section: sections.at(caps.get(0).map(|m| m.start()).unwrap_or(0)),
+                    backing_type: None,
+                    enum_cases: None,
                 });
             }
         }
     }
 
-    fn extract_interfaces(&self, content: &str, parsed: &mut ParsedFile) {
+    fn extract_interfaces(&self, content: &str, sections: UnitSections, parsed: &mut ParsedFile) {
         for caps in self.interface_regex.captures_iter(content) {
             let iface_name = caps.get(1).map(|m| m.as_str().to_string()).unwrap_or_default();
             let parent = caps.get(2).map(|m| m.as_str().to_string());
@@ -170,70 +312,317 @@ impl PasParser {
                     is_static: None,
                     extends: parent,
                     implements: None,
+                    parameters: None,
+                    return_type: None,
+                    attributes: None,
                     line_start: None,
                     line_end: None,
+                    section: sections.at(caps.get(0).map(|m| m.start()).unwrap_or(0)),
+                    backing_type: None,
+                    enum_cases: None,
                 });
             }
         }
     }
 
-    fn extract_procedures(&self, content: &str, parsed: &mut ParsedFile) {
-        for caps in self.procedure_regex.captures_iter(content) {
+    /// Procedures and functions with their parameters and return type
+    ///
+    /// Routines declared in a class or interface body are qualified with the
+    /// type (`TForm1.ButtonClick`), like method bodies of the implementation
+    /// section, and are methods. A body whose routine was already declared
+    /// (same qualified name, and the same parameters or none written) isn't
+    /// recorded twice, overloads are.
+    fn extract_routines(&self, content: &str, sections: UnitSections, parsed: &mut ParsedFile) {
+        let bodies = self.type_bodies(content);
+
+        let mut matches: Vec<regex::Captures> = self
+            .procedure_regex
+            .captures_iter(content)
+            .chain(self.function_regex.captures_iter(content))
+            .collect();
+        matches.sort_by_key(|caps| caps.get(0).map(|m| m.start()).unwrap_or(0));
+
+        let mut routines: Vec<Symbol> = Vec::new();
+        for caps in matches {
+            let position = caps.get(0).map(|m| m.start()).unwrap_or(0);
             let is_class = caps.get(1).is_some();
-            let name = caps.get(2).map(|m| m.as_str().to_string()).unwrap_or_default();
-            let method_name = caps.get(3).map(|m| m.as_str().to_string());
+            let (owner, name) = match (caps.get(2), caps.get(3)) {
+                (Some(owner), Some(name)) => (Some(owner.as_str()), name),
+                (Some(name), None) => {
+                    let owner = bodies
+                        .iter()
+                        .find(|(_, start, end)| position > *start && position < *end)
+                        .map(|(owner, _, _)| owner.as_str());
+                    (owner, name)
+                }
+                _ => continue,
+            };
 
-            let full_name = if let Some(method) = method_name {
-                format!("{}.{}", name, method)
-            } else {
-                name.clone()
+            let qualified_name = match owner {
+                Some(owner) => format!("{}.{}", owner, name.as_str()),
+                None => name.as_str().to_string(),
             };
+            let (parameters, return_type) = routine_signature(content, name.end());
+
+            let declared = routines.iter().any(|routine| {
+                routine.qualified_name.eq_ignore_ascii_case(&qualified_name)
+                    && (parameters.is_empty()
+                        || same_parameters(
+                            routine.parameters.as_deref().unwrap_or(&[]),
+                            &parameters,
+                        ))
+            });
+            if declared {
+                continue;
+            }
 
-            if !full_name.is_empty() {
-                parsed.add_symbol(Symbol {
-                    name: full_name.clone(),
-                    qualified_name: full_name,
-                    symbol_type: SymbolType::Method,
-                    visibility: None,
-                    is_abstract: None,
-                    is_static: Some(is_class),
-                    extends: None,
-                    implements: None,
-                    line_start: None,
-                    line_end: None,
-                });
+            routines.push(Symbol {
+                name: name.as_str().to_string(),
+                qualified_name,
+                symbol_type: if owner.is_some() {
+                    SymbolType::Method
+                } else {
+                    SymbolType::Function
+                },
+                visibility: None,
+                is_abstract: None,
+                is_static: Some(is_class),
+                extends: None,
+                implements: None,
+                parameters: Some(parameters),
+                return_type,
+                attributes: None,
+                line_start: None,
+                line_end: None,
+                section: sections.at(position),
+                backing_type: None,
+                enum_cases: None,
+            });
+        }
+
+        for routine in routines {
+            parsed.add_symbol(routine);
+        }
+    }
+
+    /// Class and interface bodies: type name, byte offsets of the header and of its `end;`
+    ///
+    /// Forward declarations (`TFoo = class;`) and class references
+    /// (`TFooClass = class of TFoo;`) have no body.
+    fn type_bodies(&self, content: &str) -> Vec<(String, usize, usize)> {
+        let headers = self
+            .class_regex
+            .captures_iter(content)
+            .filter(|caps| caps[1].starts_with('T'))
+            .chain(
+                self.interface_regex
+                    .captures_iter(content)
+                    .filter(|caps| caps[1].starts_with('I')),
+            );
+
+        let mut bodies = Vec::new();
+        for caps in headers {
+            let header = caps
+                .get(0)
+                .map(|m| (m.start(), m.end()))
+                .unwrap_or_default();
+            let rest = content[header.1..].trim_start().to_lowercase();
+            if rest.starts_with(';') || rest.starts_with("of ") {
+                continue;
             }
+
+            let end = self
+                .end_regex
+                .find_at(content, header.1)
+                .map(|m| m.start())
+                .unwrap_or(content.len());
+            bodies.push((caps[1].to_string(), header.0, end));
         }
+
+        bodies
     }
 
-    fn extract_functions(&self, content: &str, parsed: &mut ParsedFile) {
-        for caps in self.function_regex.captures_iter(content) {
-            let is_class = caps.get(1).is_some();
-            let name = caps.get(2).map(|m| m.as_str().to_string()).unwrap_or_default();
-            let method_name = caps.get(3).map(|m| m.as_str().to_string());
+    /// Message handler methods with the message they handle and their class
+    fn extract_message_handlers(&self, content: &str) -> Vec<serde_json::Value> {
+        self.message_handler_regex
+            .captures_iter(content)
+            .map(|caps| {
+                let position = caps.get(0).map(|m| m.start()).unwrap_or(0);
+                // Handlers are declared in the class body, after the class header
+                let class_name = self
+                    .class_regex
+                    .captures_iter(&content[..position])
+                    .filter_map(|c| c.get(1))
+                    .map(|m| m.as_str())
+                    .filter(|name| name.starts_with('T'))
+                    .last();
+
+                serde_json::json!({
+                    "class": class_name,
+                    "method": &caps[1],
+                    "message": &caps[2],
+                })
+            })
+            .collect()
+    }
 
-            let full_name = if let Some(method) = method_name {
-                format!("{}.{}", name, method)
-            } else {
-                name.clone()
-            };
+    /// Classes made available by name through RTTI
+    ///
+    /// `RegisterClass`-style calls record one entry per registered class,
+    /// `FindClass`/`GetClass` record the class name they look up.
+    fn extract_class_registrations(&self, content: &str) -> Vec<serde_json::Value> {
+        let mut registrations = Vec::new();
+
+        for caps in self.registration_regex.captures_iter(content) {
+            // Palette pages and aliases are strings, not classes
+            let arguments = self.string_literal_regex.replace_all(&caps[2], "");
+            for class_name in self.type_name_regex.find_iter(&arguments) {
+                registrations.push(serde_json::json!({
+                    "function": &caps[1],
+                    "class": class_name.as_str(),
+                }));
+            }
+        }
 
-            if !full_name.is_empty() {
-                parsed.add_symbol(Symbol {
-                    name: full_name.clone(),
-                    qualified_name: full_name,
-                    symbol_type: SymbolType::Function,
-                    visibility: None,
-                    is_abstract: None,
-                    is_static: Some(is_class),
-                    extends: None,
-                    implements: None,
-                    line_start: None,
-                    line_end: None,
-                });
+        for caps in self.class_lookup_regex.captures_iter(content) {
+            registrations.push(serde_json::json!({
+                "function": &caps[1],
+                "class": &caps[2],
+            }));
+        }
+
+        registrations
+    }
+}
+
+/// Start offsets of a unit's `interface` and `implementation` sections
+#[derive(Debug, Clone, Copy)]
+struct UnitSections {
+    interface: Option<usize>,
+    implementation: Option<usize>,
+}
+
+impl UnitSections {
+    /// Section holding byte offset `position`, None before `interface` and
+    /// in programs and packages, which have no sections
+    fn at(&self, position: usize) -> Option<UnitSection> {
+        match (self.interface, self.implementation) {
+            (_, Some(implementation)) if position > implementation => {
+                Some(UnitSection::Implementation)
+            }
+            (Some(interface), _) if position > interface => Some(UnitSection::Interface),
+            _ => None,
+        }
+    }
+}
+
+/// Parameters and return type of the routine whose name ends at byte offset `name_end`
+///
+/// The parameter list and the `: ReturnType` may both be left out
+/// (implementation bodies can omit them). An unclosed list gives no parameters.
+fn routine_signature(content: &str, name_end: usize) -> (Vec<ParameterInfo>, Option<String>) {
+    let mut rest = content[name_end..].trim_start();
+    let mut parameters = Vec::new();
+
+    if rest.starts_with('(') {
+        let close = match closing_paren(rest) {
+            Some(close) => close,
+            None => return (parameters, None),
+        };
+        parameters = parse_parameters(&rest[1..close]);
+        rest = rest[close + 1..].trim_start();
+    }
+
+    let return_type = rest
+        .strip_prefix(':')
+        .map(|ty| ty[..ty.find(';').unwrap_or(ty.len())].trim().to_string())
+        .filter(|ty| !ty.is_empty());
+
+    (parameters, return_type)
+}
+
+/// Offset of the `)` closing the `(` that starts `text`, skipping 'strings'
+fn closing_paren(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut in_string = false;
+
+    for (i, c) in text.char_indices() {
+        match c {
+            '\'' => in_string = !in_string,
+            '(' if !in_string => depth += 1,
+            ')' if !in_string => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
             }
+            _ => {}
         }
     }
+
+    None
+}
+
+/// Parse a parameter list: `const A, B: Integer; var List: TStrings; Count: Integer = 0`
+///
+/// Every name of a group gets the group's type, default value and passing
+/// mode. Untyped parameters (`var Buffer`) have no type.
+fn parse_parameters(list: &str) -> Vec<ParameterInfo> {
+    let mut parameters = Vec::new();
+
+    for group in split_top_level(list, b';') {
+        let (declaration, default_value) = match group.split_once('=') {
+            Some((declaration, default)) => (declaration, Some(default.trim().to_string())),
+            None => (group, None),
+        };
+        let (names, param_type) = match declaration.split_once(':') {
+            Some((names, ty)) => (
+                names,
+                Some(ty.trim().to_string()).filter(|ty| !ty.is_empty()),
+            ),
+            None => (declaration, None),
+        };
+
+        let mut names = names.trim();
+        let modifier = ["var", "const", "out", "constref"]
+            .into_iter()
+            .find(|modifier| {
+                names
+                    .get(..modifier.len())
+                    .map(|word| word.eq_ignore_ascii_case(modifier))
+                    .unwrap_or(false)
+                    && names[modifier.len()..].starts_with(char::is_whitespace)
+            });
+        if let Some(modifier) = modifier {
+            names = names[modifier.len()..].trim_start();
+        }
+
+        for name in names
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+        {
+            parameters.push(ParameterInfo {
+                name: name.to_string(),
+                param_type: param_type.clone(),
+                default_value: default_value.clone(),
+                is_optional: default_value.is_some(),
+                modifier: modifier.map(|modifier| modifier.to_string()),
+            });
+        }
+    }
+
+    parameters
+}
+
+/// Check if two parameter lists have the same names and types, ignoring case
+fn same_parameters(a: &[ParameterInfo], b: &[ParameterInfo]) -> bool {
+    let lowercase = |ty: &Option<String>| ty.as_ref().map(|ty| ty.to_lowercase());
+    a.len() == b.len()
+        && a.iter().zip(b).all(|(a, b)| {
+            a.name.eq_ignore_ascii_case(&b.name)
+                && lowercase(&a.param_type) == lowercase(&b.param_type)
+        })
 }
 
 impl Default for PasParser {
@@ -241,3 +630,236 @@ impl Default for PasParser {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::models::{UnifiedGraph, UnifiedNodeType};
+    use crate::parsers::common::generate_id;
+    use crate::parsers::delphi::fixtures::{graph_of, parse_of};
+
+    const UNIT: &str = "unit Unit1;
+
+interface
+
+type
+  TForm1 = class(TForm)
+    function Sum(const A, B: Integer; Scale: Double = 1.0): Double; overload;
+    function Sum(const Values: array of Integer): Integer; overload;
+    class function Create2(var List: TStrings; out Count: Integer; Name: string = 'x;y'): TForm1;
+  end;
+
+implementation
+
+function TForm1.Sum(const A, B: Integer; Scale: Double = 1.0): Double;
+begin
+  Result := (A + B) * Scale;
+end;
+
+function TForm1.Sum(const Values: array of Integer): Integer;
+begin
+end;
+
+class function TForm1.Create2;
+begin
+end;
+
+procedure LocalOnly(var Buffer; Size: Integer);
+begin
+end;
+
+end.
+";
+
+    /// `Name (mode Param: Type = Default; ...): Return` of every routine node
+    fn signatures(graph: &UnifiedGraph) -> Vec<String> {
+        graph
+            .nodes
+            .iter()
+            .filter(|n| {
+                matches!(
+                    n.node_type,
+                    UnifiedNodeType::Method | UnifiedNodeType::Function
+                )
+            })
+            .map(|n| {
+                let parameters: Vec<String> = n
+                    .metadata
+                    .parameters
+                    .iter()
+                    .flatten()
+                    .map(|p| {
+                        let modifier = p.modifier.as_ref().map(|m| format!("{} ", m));
+                        let default = p.default_value.as_ref().map(|d| format!(" = {}", d));
+                        format!(
+                            "{}{}: {}{}",
+                            modifier.unwrap_or_default(),
+                            p.name,
+                            p.param_type.as_deref().unwrap_or("-"),
+                            default.unwrap_or_default()
+                        )
+                    })
+                    .collect();
+                format!(
+                    "{} ({}): {}",
+                    n.qualified_name,
+                    parameters.join("; "),
+                    n.metadata.return_type.as_deref().unwrap_or("-")
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn overloads_keep_their_own_parameters_and_defaults() {
+        let graph = graph_of(&[("Unit1.pas", UNIT)]).await;
+
+        assert_eq!(
+            signatures(&graph),
+            [
+                "TForm1.Sum (const A: Integer; const B: Integer; Scale: Double = 1.0): Double",
+                "TForm1.Sum (const Values: array of Integer): Integer",
+                "TForm1.Create2 (var List: TStrings; out Count: Integer; Name: string = 'x;y'): \
+TForm1",
+                "LocalOnly (var Buffer: -; Size: Integer): -",
+            ]
+        );
+
+        let create = graph.nodes.iter().find(|n| n.name == "Create2").unwrap();
+        assert_eq!(create.metadata.is_static, Some(true));
+
+        let ids: HashSet<_> = graph.nodes.iter().map(|n| &n.id).collect();
+        assert_eq!(ids.len(), graph.nodes.len());
+    }
+
+    const SECTIONED_UNIT: &str = "unit Unit2;
+
+interface
+
+uses
+  Classes;
+
+type
+  TPublicThing = class(TObject)
+    procedure Run;
+  end;
+
+  IGreeter = interface
+    function Greet(const Name: string): string;
+  end;
+
+function MakeThing: TPublicThing;
+
+implementation
+
+uses
+  SysUtils;
+
+type
+  THelper = class
+    procedure Assist;
+  end;
+
+procedure THelper.Assist;
+begin
+end;
+
+procedure TPublicThing.Run;
+begin
+end;
+
+function MakeThing: TPublicThing;
+begin
+end;
+
+procedure Internal;
+begin
+end;
+
+end.
+";
+
+    #[tokio::test]
+    async fn declarations_record_their_unit_section() {
+        let files = [
+            ("Unit2.pas", SECTIONED_UNIT),
+            (
+                "Prog.dpr",
+                "program Prog;\nuses Unit2;\nprocedure Main;\nbegin\nend;\nbegin\nend.\n",
+            ),
+        ];
+        let graph = graph_of(&files).await;
+
+        let mut sections: Vec<String> = graph
+            .nodes
+            .iter()
+            .filter(|n| n.file_path.as_deref().map(generate_id).as_ref() != Some(&n.id))
+            .map(|n| {
+                let section = n.metadata.extra.get("section").and_then(|s| s.as_str());
+                format!("{} {}", n.qualified_name, section.unwrap_or("-"))
+            })
+            .collect();
+        sections.sort();
+        assert_eq!(
+            sections,
+            [
+                "IGreeter interface",
+                "IGreeter.Greet interface",
+                "Internal implementation",
+                "Main -",
+                "MakeThing interface",
+                "THelper implementation",
+                "THelper.Assist implementation",
+                "TPublicThing interface",
+                "TPublicThing.Run interface",
+            ]
+        );
+
+        let result = parse_of(&files).await;
+        let unit = result
+            .files
+            .iter()
+            .find(|f| f.source.name == "Unit2.pas")
+            .unwrap();
+        let uses: Vec<_> = unit
+            .dependencies
+            .iter()
+            .map(|d| (d.target.as_str(), d.is_interface, d.is_implementation))
+            .collect();
+        assert_eq!(uses, [("Classes", true, false), ("SysUtils", false, true)]);
+    }
+
+    #[tokio::test]
+    async fn message_handlers_record_their_message() {
+        let unit = "unit Canvas;
+
+interface
+
+type
+  TPaintBox = class(TCustomControl)
+  private
+    procedure WMPaint(var Msg: TWMPaint); message WM_PAINT;
+    procedure WMSize(var Msg: TWMSize); message WM_SIZE;
+    procedure Refresh;
+  end;
+
+implementation
+
+procedure TPaintBox.WMPaint(var Msg: TWMPaint);
+begin
+end;
+
+end.
+";
+        let result = parse_of(&[("Canvas.pas", unit)]).await;
+
+        assert_eq!(
+            result.files[0].metadata["message_handlers"],
+            serde_json::json!([
+                { "class": "TPaintBox", "method": "WMPaint", "message": "WM_PAINT" },
+                { "class": "TPaintBox", "method": "WMSize", "message": "WM_SIZE" },
+            ])
+        );
+    }
+}
@@ -1,8 +1,12 @@
 use regex::Regex;
-use std::fs;
 
 use crate::models::{Dependency, ParsedFile, SourceFile, Symbol, SymbolType};
-use crate::parsers::{ParseError, ParserConfig, ParserResult};
+use crate::parsers::common::read_source;
+use crate::parsers::{ParserConfig, ParserResult};
+
+/// `ParserConfig::language_options` key: resolve includes relative to the
+/// including view's directory when no view matches the absolute name (default: true)
+pub const BLADE_RELATIVE_INCLUDES_OPTION: &str = "blade_relative_includes";
 
 /// Parser for Laravel Blade template files
 pub struct BladeParser {
@@ -50,6 +54,7 @@ pub struct BladeParser {
 
     // Props
     props_regex: Regex,
+    prop_name_regex: Regex,
 
     // Livewire
     livewire_regex: Regex,
@@ -58,6 +63,16 @@ pub struct BladeParser {
     csrf_regex: Regex,
     method_regex: Regex,
     error_regex: Regex,
+
+    // Route and asset helpers
+    route_helper_regex: Regex,
+    asset_helper_regex: Regex,
+    vite_regex: Regex,
+    quoted_string_regex: Regex,
+
+    // Regions that aren't compiled
+    comment_regex: Regex,
+    verbatim_regex: Regex,
 }
 
 impl BladeParser {
@@ -149,6 +164,7 @@ impl BladeParser {
 
             // Props
             props_regex: Regex::new(r"@props\s*\(\s*\[([^\]]+)\]").unwrap(),
+            prop_name_regex: Regex::new(r#"['"](\w+)['"]"#).unwrap(),
 
             // Livewire
             livewire_regex: Regex::new(r#"(?:@livewire\s*\(\s*['"]([^'"]+)['"]|<livewire:([a-z][a-z0-9\-\.]*))"#).unwrap(),
@@ -157,18 +173,40 @@ impl BladeParser {
             csrf_regex: Regex::new(r"@csrf\b").unwrap(),
             method_regex: Regex::new(r#"@method\s*\(\s*['"]([^'"]+)['"]"#).unwrap(),
             error_regex: Regex::new(r#"@error\s*\(\s*['"]([^'"]+)['"]"#).unwrap(),
+
+            // Match: route('users.index') or to_route('home'), not $request->route('id')
+            route_helper_regex: Regex::new(
+                r#"(?:^|[^\w>$])(?:to_)?route\s*\(\s*['"]([^'"]+)['"]"#
+            ).unwrap(),
+            // Match: asset('css/app.css'), secure_asset('...') or mix('js/app.js')
+            asset_helper_regex: Regex::new(
+                r#"(?:^|[^\w>$])(asset|secure_asset|mix)\s*\(\s*['"]([^'"]+)['"]"#
+            ).unwrap(),
+            // Match: @vite(['resources/css/app.css', 'resources/js/app.js']) or @vite('...')
+            vite_regex: Regex::new(
+                r#"@vite\s*\(\s*(\[[^\]]*\]|['"][^'"]+['"])"#
+            ).unwrap(),
+            quoted_string_regex: Regex::new(r#"['"]([^'"]+)['"]"#).unwrap(),
+
+            // Match: {{-- comment --}}
+            comment_regex: Regex::new(r"(?s)\{\{--.*?--\}\}").unwrap(),
+            // Match: @verbatim ... @endverbatim
+            verbatim_regex: Regex::new(r"(?s)@verbatim\b.*?@endverbatim").unwrap(),
         }
     }
 
     pub async fn parse(
         &self,
         file: &SourceFile,
-        _config: &ParserConfig,
+        config: &ParserConfig,
     ) -> ParserResult<ParsedFile> {
-        let content = fs::read_to_string(&file.absolute_path)
-            .map_err(ParseError::Io)?;
+        let (content, decode_warnings) = read_source(file, config)?;
+
+        // Comments and @verbatim blocks hold no live directives
+        let content = self.blank_inert_regions(&content);
 
         let mut parsed = ParsedFile::new(file.clone());
+        parsed.warnings.extend(decode_warnings);
 
         // Determine view name from path
         let view_name = self.extract_view_name(&file.path);
@@ -233,6 +271,21 @@ impl BladeParser {
                 serde_json::json!(includes),
             );
 
+            // Directory the includes may be relative to (admin/users/index -> admin.users)
+            let relative_includes = config
+                .language_options
+                .get(BLADE_RELATIVE_INCLUDES_OPTION)
+                .and_then(|v| v.as_bool())
+                .unwrap_or(true);
+            if relative_includes {
+                if let Some((directory, _)) = view_name.rsplit_once('.') {
+                    parsed.metadata.insert(
+                        "include_base".to_string(),
+                        serde_json::Value::String(directory.to_string()),
+                    );
+                }
+            }
+
             for include in &includes {
                 parsed.add_dependency(Dependency {
                     target: format!("view:{}", include),
@@ -316,6 +369,24 @@ impl BladeParser {
             );
         }
 
+        // Extract named routes referenced through route()
+        let routes_referenced = self.extract_routes_referenced(&content);
+        if !routes_referenced.is_empty() {
+            parsed.metadata.insert(
+                "routes_referenced".to_string(),
+                serde_json::json!(routes_referenced),
+            );
+        }
+
+        // Extract assets loaded through asset(), mix() and @vite
+        let assets = self.extract_assets(&content);
+        if !assets.is_empty() {
+            parsed.metadata.insert(
+                "assets".to_string(),
+                serde_json::json!(assets),
+            );
+        }
+
         // Count directives usage
         let directive_counts = self.count_directives(&content);
         parsed.metadata.insert(
@@ -333,13 +404,41 @@ impl BladeParser {
             is_static: None,
             extends: None,
             implements: None,
+            parameters: None,
+            return_type: None,
+            attributes: None,
             line_start: None,
             line_end: None,
+            section: None,
+            backing_type: None,
+            enum_cases: None,
         });
 
         Ok(parsed)
     }
 
+    /// Replace Blade comments and @verbatim blocks with spaces
+    ///
+    /// Newlines are kept and other characters become one space per byte, so
+    /// offsets and line numbers stay valid.
+    fn blank_inert_regions(&self, content: &str) -> String {
+        let blank = |caps: &regex::Captures| -> String {
+            let mut blanked = String::with_capacity(caps[0].len());
+            for c in caps[0].chars() {
+                if c == '\n' {
+                    blanked.push('\n');
+                } else {
+                    blanked.extend(std::iter::repeat_n(' ', c.len_utf8()));
+                }
+            }
+            blanked
+        };
+
+        // Verbatim first, a literal {{-- inside it doesn't open a comment
+        let content = self.verbatim_regex.replace_all(content, blank);
+        self.comment_regex.replace_all(&content, blank).into_owned()
+    }
+
     fn extract_view_name(&self, path: &str) -> String {
         path.replace("resources/views/", "")
             .replace(".blade.php", "")
@@ -437,12 +536,17 @@ impl BladeParser {
             }
         }
 
-        // X-components
+        // X-components (<x-forms.input> lives in components/forms/input.blade.php,
+        // hyphens are part of the name: <x-user-card> -> components/user-card)
         for caps in self.component_x_regex.captures_iter(content) {
             if let Some(name) = caps.get(1) {
-                let normalized = name.as_str().replace('-', ".");
+                let name = name.as_str();
+                // <x-slot> and <x-dynamic-component> are Blade built-ins
+                if name == "slot" || name.starts_with("slot.") || name == "dynamic-component" {
+                    continue;
+                }
                 components.push(serde_json::json!({
-                    "name": normalized,
+                    "name": name,
                     "type": "anonymous"
                 }));
             }
@@ -513,8 +617,7 @@ impl BladeParser {
 
         for caps in self.props_regex.captures_iter(content) {
             if let Some(prop_list) = caps.get(1) {
-                let prop_regex = Regex::new(r#"['"](\w+)['"]"#).unwrap();
-                for prop_caps in prop_regex.captures_iter(prop_list.as_str()) {
+                for prop_caps in self.prop_name_regex.captures_iter(prop_list.as_str()) {
                     if let Some(prop) = prop_caps.get(1) {
                         let name = prop.as_str().to_string();
                         if !props.contains(&name) {
@@ -575,6 +678,49 @@ impl BladeParser {
             .collect()
     }
 
+    fn extract_routes_referenced(&self, content: &str) -> Vec<String> {
+        let mut routes = Vec::new();
+
+        for caps in self.route_helper_regex.captures_iter(content) {
+            if let Some(name) = caps.get(1) {
+                let n = name.as_str().to_string();
+                if !routes.contains(&n) {
+                    routes.push(n);
+                }
+            }
+        }
+
+        routes
+    }
+
+    fn extract_assets(&self, content: &str) -> Vec<serde_json::Value> {
+        let mut assets = Vec::new();
+
+        for caps in self.asset_helper_regex.captures_iter(content) {
+            let asset = serde_json::json!({
+                "path": &caps[2],
+                "type": &caps[1],
+            });
+            if !assets.contains(&asset) {
+                assets.push(asset);
+            }
+        }
+
+        for caps in self.vite_regex.captures_iter(content) {
+            for path_caps in self.quoted_string_regex.captures_iter(&caps[1]) {
+                let asset = serde_json::json!({
+                    "path": &path_caps[1],
+                    "type": "vite",
+                });
+                if !assets.contains(&asset) {
+                    assets.push(asset);
+                }
+            }
+        }
+
+        assets
+    }
+
     fn count_directives(&self, content: &str) -> serde_json::Value {
         let mut counts = serde_json::Map::new();
 
@@ -619,3 +765,19 @@ impl Default for BladeParser {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blanking_keeps_byte_offsets() {
+        let content = "{{-- café résumé --}}\n@include('partials.nav')";
+        let blanked = BladeParser::new().blank_inert_regions(content);
+
+        assert_eq!(blanked.len(), content.len());
+        let include = content.find("@include").unwrap();
+        assert_eq!(&blanked[include..], &content[include..]);
+        assert!(blanked[..include].trim().is_empty());
+    }
+}
@@ -0,0 +1,219 @@
+use regex::Regex;
+
+use crate::models::{ParsedFile, SourceFile, SymbolType};
+use crate::parsers::common::read_source;
+use crate::parsers::{ParserConfig, ParserResult};
+
+use super::php_parser::{unquote, PhpParser};
+
+/// Parser for Artisan console commands
+pub struct CommandParser {
+    php_parser: PhpParser,
+    signature_regex: Regex,
+    name_regex: Regex,
+    description_regex: Regex,
+    token_regex: Regex,
+}
+
+impl CommandParser {
+    pub fn new() -> Self {
+        Self {
+            php_parser: PhpParser::new(),
+
+            // Match: protected $signature = 'reports:generate {user} {--daily}';  (may span lines)
+            signature_regex: Regex::new(
+                r#"(?:protected|public)\s+(?:\??string\s+)?\$signature\s*=\s*(?:'([^']*)'|"([^"]*)")"#
+            ).unwrap(),
+
+            // Match: protected $name = 'reports:generate';
+            name_regex: Regex::new(
+                r#"(?:protected|public)\s+(?:\??string\s+)?\$name\s*=\s*(?:'([^']*)'|"([^"]*)")"#
+            ).unwrap(),
+
+            // Match: protected $description = 'Generate the reports';
+            description_regex: Regex::new(
+                r#"(?:protected|public)\s+(?:\??string\s+)?\$description\s*=\s*(?:'([^']*)'|"([^"]*)")"#
+            ).unwrap(),
+
+            // Match: {user?}  or  {--Q|queue=default : The queue}
+            token_regex: Regex::new(r"\{\s*([^}]*?)\s*\}").unwrap(),
+        }
+    }
+
+    /// Parse a console command file
+    pub async fn parse(
+        &self,
+        file: &SourceFile,
+        config: &ParserConfig,
+    ) -> ParserResult<ParsedFile> {
+        let (content, decode_warnings) = read_source(file, config)?;
+
+        let mut parsed = self.php_parser.parse_content(file, &content);
+        parsed.warnings.extend(decode_warnings);
+
+        // The signature declares the name, arguments and options
+        let signature = string_property(&self.signature_regex, &content)
+            .map(|signature| signature.split_whitespace().collect::<Vec<_>>().join(" "));
+        let name = signature
+            .as_deref()
+            .and_then(|signature| signature.split(|c: char| c.is_whitespace() || c == '{').next())
+            .filter(|name| !name.is_empty())
+            .map(|name| name.to_string())
+            .or_else(|| string_property(&self.name_regex, &content))
+            .or_else(|| as_command_argument(&parsed, "name", 0));
+
+        if let Some(ref signature) = signature {
+            let (arguments, options) = self.extract_parameters(signature);
+            if !arguments.is_empty() {
+                parsed.metadata.insert(
+                    "command_arguments".to_string(),
+                    serde_json::json!(arguments),
+                );
+            }
+            if !options.is_empty() {
+                parsed.metadata.insert(
+                    "command_options".to_string(),
+                    serde_json::json!(options),
+                );
+            }
+            parsed.metadata.insert(
+                "command_signature".to_string(),
+                serde_json::Value::String(signature.clone()),
+            );
+        }
+        if let Some(name) = name {
+            parsed.metadata.insert(
+                "command_name".to_string(),
+                serde_json::Value::String(name),
+            );
+        }
+
+        let description = string_property(&self.description_regex, &content)
+            .or_else(|| as_command_argument(&parsed, "description", 1));
+        if let Some(description) = description {
+            parsed.metadata.insert(
+                "command_description".to_string(),
+                serde_json::Value::String(description),
+            );
+        }
+
+        Ok(parsed)
+    }
+
+    /// Argument and option names of a signature
+    ///
+    /// `{user?}`, `{user*}` and `{user=1}` are the argument `user`;
+    /// `{--Q|queue=}` is the option `queue`. Descriptions (`: ...`) are dropped.
+    fn extract_parameters(&self, signature: &str) -> (Vec<String>, Vec<String>) {
+        let mut arguments = Vec::new();
+        let mut options = Vec::new();
+
+        for caps in self.token_regex.captures_iter(signature) {
+            let token = caps[1].split(" : ").next().unwrap_or("").trim();
+            let (token, is_option) = match token.strip_prefix("--") {
+                Some(option) => (option.rsplit('|').next().unwrap_or(option), true),
+                None => (token, false),
+            };
+            let name = token
+                .split('=')
+                .next()
+                .unwrap_or("")
+                .trim_end_matches(['?', '*'])
+                .trim();
+
+            if name.is_empty() {
+                continue;
+            }
+            if is_option {
+                options.push(name.to_string());
+            } else {
+                arguments.push(name.to_string());
+            }
+        }
+
+        (arguments, options)
+    }
+}
+
+impl Default for CommandParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Value of a string property matched by `regex` (single or double quoted)
+fn string_property(regex: &Regex, content: &str) -> Option<String> {
+    regex
+        .captures(content)
+        .and_then(|caps| caps.get(1).or_else(|| caps.get(2)))
+        .map(|m| m.as_str().trim().to_string())
+}
+
+/// Argument of the `#[AsCommand(name: ..., description: ...)]` class attribute
+///
+/// Named (`name: 'x'`) or at `position` when positional.
+fn as_command_argument(parsed: &ParsedFile, name: &str, position: usize) -> Option<String> {
+    let attribute = parsed
+        .symbols
+        .iter()
+        .filter(|s| s.symbol_type == SymbolType::Class)
+        .flat_map(|s| s.attributes.iter().flatten())
+        .find(|a| a.name.rsplit('\\').next() == Some("AsCommand"))?;
+
+    let named = attribute.arguments.iter().find_map(|argument| {
+        let (key, value) = argument.split_once(':')?;
+        (key.trim() == name).then_some(value)
+    });
+    let value = named.or_else(|| {
+        attribute
+            .arguments
+            .get(position)
+            .filter(|argument| argument.trim_start().starts_with(['\'', '"']))
+            .map(|argument| argument.as_str())
+    })?;
+
+    Some(unquote(value.trim()).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::laravel::fixtures::graph_of;
+
+    const COMMAND: &str = r#"<?php
+namespace App\Console\Commands;
+
+use Illuminate\Console\Command;
+
+class GenerateReports extends Command
+{
+    protected $signature = 'reports:generate
+        {user : The user to report on}
+        {period?}
+        {--daily}
+        {--Q|queue=default : The queue}';
+
+    protected $description = 'Generate the reports';
+}
+"#;
+
+    #[tokio::test]
+    async fn signatures_give_the_command_name_arguments_and_options() {
+        let graph = graph_of(&[("app/Console/Commands/GenerateReports.php", COMMAND)]).await;
+
+        let node = graph
+            .nodes
+            .iter()
+            .find(|n| n.qualified_name == "artisan:reports:generate")
+            .expect("command file node");
+        let metadata = &node.metadata.extra;
+        assert_eq!(
+            metadata["command_arguments"],
+            serde_json::json!(["user", "period"])
+        );
+        assert_eq!(
+            metadata["command_options"],
+            serde_json::json!(["daily", "queue"])
+        );
+        assert_eq!(metadata["command_description"], "Generate the reports");
+    }
+}
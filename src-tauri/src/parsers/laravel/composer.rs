@@ -0,0 +1,124 @@
+use std::path::Path;
+
+/// Composer packages of the framework, full Laravel first
+const FRAMEWORK_PACKAGES: [&str; 2] = ["laravel/framework", "laravel/lumen-framework"];
+
+/// Major version of the Laravel (or Lumen) framework a project uses
+///
+/// Read from the version `composer.lock` pins, else from the constraint
+/// `composer.json` requires: the highest major it allows (`^9.0|^10.0` is 10).
+pub fn framework_major_version(root_path: &Path) -> Option<u32> {
+    locked_version(root_path).or_else(|| required_version(root_path))
+}
+
+fn read_json(path: &Path) -> Option<serde_json::Value> {
+    let content = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Major version of the framework package in `composer.lock`
+fn locked_version(root_path: &Path) -> Option<u32> {
+    let lock = read_json(&root_path.join("composer.lock"))?;
+    let packages = lock.get("packages")?.as_array()?;
+
+    FRAMEWORK_PACKAGES.iter().find_map(|name| {
+        packages
+            .iter()
+            .find(|p| p.get("name").and_then(|n| n.as_str()) == Some(name))
+            .and_then(|p| p.get("version")?.as_str())
+            .and_then(major_version)
+    })
+}
+
+/// Highest major version the `composer.json` constraint on the framework allows
+fn required_version(root_path: &Path) -> Option<u32> {
+    let composer = read_json(&root_path.join("composer.json"))?;
+    let require = composer.get("require")?;
+
+    FRAMEWORK_PACKAGES.iter().find_map(|name| {
+        let constraint = require.get(*name)?.as_str()?;
+        constraint.split('|').filter_map(highest_major).max()
+    })
+}
+
+/// Highest major version one alternative of a constraint allows
+///
+/// The major of its bounds (`^10.0`, `>=9.0`, `9.0 - 10.5`), capped by an
+/// upper bound: `>=9.0 <11` and `>=9.0 <11.0` are 10, `>=9.0 <11.2` and
+/// `>=9.0 <=11` are 11.
+fn highest_major(constraint: &str) -> Option<u32> {
+    let mut highest = None;
+    let mut upper = None;
+
+    for term in constraint.split([' ', ',']).filter(|t| !t.is_empty()) {
+        if let Some(bound) = term.strip_prefix("<=") {
+            upper = major_version(bound);
+        } else if let Some(bound) = term.strip_prefix('<') {
+            // `<11` and `<11.0` stop before 11.0, `<11.2` lets 11.0 through
+            let excludes_major = bound
+                .split('.')
+                .skip(1)
+                .all(|part| part.trim_matches('0').is_empty());
+            upper = major_version(bound)
+                .map(|major| if excludes_major { major.saturating_sub(1) } else { major });
+        } else {
+            highest = highest.max(major_version(term));
+        }
+    }
+
+    upper.or(highest)
+}
+
+/// Leading major number of a version or bound (`v10.48.4`, `^11.0`,
+/// `~8.75`, `>=9.0`, `10.*`), None for branches like `dev-master`
+fn major_version(version: &str) -> Option<u32> {
+    let digits: String = version
+        .trim_start_matches(|c: char| !c.is_ascii_digit())
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::common::fixtures::project_dir;
+
+    fn required(constraint: &str) -> Option<u32> {
+        let composer = format!(
+            r#"{{"require": {{"laravel/framework": "{}"}}}}"#,
+            constraint
+        );
+        let dir = project_dir(&[("composer.json", composer.as_str())]);
+        framework_major_version(dir.path())
+    }
+
+    #[test]
+    fn constraints_give_the_highest_major_they_allow() {
+        assert_eq!(required("^9.0|^10.0"), Some(10));
+        assert_eq!(required("^10.0 || ^11.0"), Some(11));
+        assert_eq!(required(">=9.0 <11"), Some(10));
+        assert_eq!(required(">=9.0,<11.0"), Some(10));
+        assert_eq!(required(">=9.0 <11.2"), Some(11));
+        assert_eq!(required(">=9.0 <=11"), Some(11));
+        assert_eq!(required("9.0 - 10.5"), Some(10));
+        assert_eq!(required("dev-master"), None);
+    }
+
+    #[test]
+    fn locked_version_wins_over_the_constraint() {
+        let dir = project_dir(&[
+            (
+                "composer.json",
+                r#"{"require": {"laravel/lumen-framework": "^10.0"}}"#,
+            ),
+            (
+                "composer.lock",
+                r#"{"packages": [{"name": "laravel/lumen-framework", "version": "v9.1.6"}]}"#,
+            ),
+        ]);
+
+        assert_eq!(framework_major_version(dir.path()), Some(9));
+    }
+}
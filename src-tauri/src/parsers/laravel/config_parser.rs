@@ -0,0 +1,239 @@
+use regex::Regex;
+
+use crate::models::{ParsedFile, SourceFile};
+use crate::parsers::common::{
+    blank_comments, matching_bracket, matching_paren, read_source, split_top_level,
+};
+use crate::parsers::{ParserConfig, ParserResult};
+
+use super::php_parser::{unquote, PhpParser, RETURN_ARRAY_REGEX};
+
+/// Parser for Laravel config files (config/*.php)
+pub struct ConfigParser {
+    php_parser: PhpParser,
+    array_key_regex: Regex,
+    env_call_regex: Regex,
+}
+
+/// A value of the config array, by its dotted key path
+struct ConfigEntry {
+    path: String,
+    start: usize,
+    end: usize,
+}
+
+impl ConfigParser {
+    pub fn new() -> Self {
+        Self {
+            php_parser: PhpParser::new(),
+
+            // Match: 'mailgun' =>  (the key of one array entry)
+            array_key_regex: Regex::new(r#"^\s*['"]([^'"]+)['"]\s*=>\s*"#).unwrap(),
+
+            // Match: env(  (not ->env( or ::env()
+            env_call_regex: Regex::new(r"(?:^|[^\w>:$])env\s*\(").unwrap(),
+        }
+    }
+
+    /// Parse a config file
+    pub async fn parse(
+        &self,
+        file: &SourceFile,
+        config: &ParserConfig,
+    ) -> ParserResult<ParsedFile> {
+        let (content, decode_warnings) = read_source(file, config)?;
+
+        let mut parsed = self.php_parser.parse_content(file, &content);
+        parsed.warnings.extend(decode_warnings);
+
+        // config('services.mailgun.domain') reads key mailgun.domain of config/services.php
+        let config_name = file.name.strip_suffix(".php").unwrap_or(&file.name);
+        parsed.metadata.insert(
+            "config_name".to_string(),
+            serde_json::Value::String(config_name.to_string()),
+        );
+
+        // Comments are full of quotes and sample code, blank them first
+        let code = blank_comments(&content);
+
+        let entries = self.extract_entries(&code);
+        if !entries.is_empty() {
+            let keys: Vec<&str> = entries.iter().map(|e| e.path.as_str()).collect();
+            parsed.metadata.insert(
+                "config_keys".to_string(),
+                serde_json::json!(keys),
+            );
+        }
+
+        let env_references = self.extract_env_references(&code, &entries);
+        if !env_references.is_empty() {
+            parsed.metadata.insert(
+                "env_references".to_string(),
+                serde_json::json!(env_references),
+            );
+        }
+
+        Ok(parsed)
+    }
+
+    /// Key paths of the returned array, nested arrays included (`mailgun.domain`)
+    ///
+    /// Parents come before their children. List items without a key are skipped.
+    fn extract_entries(&self, code: &str) -> Vec<ConfigEntry> {
+        let mut entries = Vec::new();
+
+        let array = RETURN_ARRAY_REGEX.find(code).and_then(|found| {
+            let open = found.end() - 1;
+            matching_bracket(code, open).map(|close| (open + 1, close))
+        });
+        if let Some((start, end)) = array {
+            self.collect_entries(code, start, end, "", &mut entries);
+        }
+
+        entries
+    }
+
+    fn collect_entries(
+        &self,
+        code: &str,
+        start: usize,
+        end: usize,
+        prefix: &str,
+        entries: &mut Vec<ConfigEntry>,
+    ) {
+        // Items are contiguous, each followed by a one-byte separator
+        let mut item_start = start;
+        for item in split_top_level(&code[start..end], b',') {
+            let item_end = item_start + item.len();
+
+            if let Some(caps) = self.array_key_regex.captures(item) {
+                let path = if prefix.is_empty() {
+                    caps[1].to_string()
+                } else {
+                    format!("{}.{}", prefix, &caps[1])
+                };
+                let value_start = item_start + caps.get(0).map(|m| m.end()).unwrap_or(0);
+                let value = item[value_start - item_start..].trim_end();
+
+                entries.push(ConfigEntry {
+                    path: path.clone(),
+                    start: value_start,
+                    end: item_end,
+                });
+
+                if value.starts_with('[') && value.ends_with(']') {
+                    let close = value_start + value.len() - 1;
+                    self.collect_entries(code, value_start + 1, close, &path, entries);
+                }
+            }
+
+            item_start = item_end + 1;
+        }
+    }
+
+    /// Every `env('KEY', default)` call, with the deepest config key it sets
+    ///
+    /// Defaults are unquoted when they are string literals, other expressions
+    /// are kept as written. `path` is null for calls outside the returned array.
+    fn extract_env_references(
+        &self,
+        code: &str,
+        entries: &[ConfigEntry],
+    ) -> Vec<serde_json::Value> {
+        let mut references = Vec::new();
+
+        for found in self.env_call_regex.find_iter(code) {
+            let open = found.end() - 1;
+            let arguments = match matching_paren(code, open) {
+                Some(close) => split_top_level(&code[open + 1..close], b','),
+                None => continue,
+            };
+
+            let key = arguments.first().map(|k| unquote(k.trim())).unwrap_or("");
+            if key.is_empty() {
+                continue;
+            }
+            let default = arguments
+                .get(1)
+                .map(|d| unquote(d.trim()))
+                .filter(|d| !d.is_empty());
+
+            // Children are pushed after their parents, the last match is the deepest
+            let path = entries
+                .iter()
+                .rev()
+                .find(|e| e.start <= open && open < e.end)
+                .map(|e| e.path.as_str());
+
+            references.push(serde_json::json!({
+                "key": key,
+                "default": default,
+                "path": path,
+            }));
+        }
+
+        references
+    }
+}
+
+impl Default for ConfigParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::laravel::fixtures::parse_with;
+
+    #[tokio::test]
+    async fn nested_keys_and_env_calls_are_extracted() {
+        let services = r#"<?php
+
+return [
+    // 'legacy' => env('LEGACY_KEY'),
+    'mailgun' => [
+        'domain' => env('MAILGUN_DOMAIN'),
+        'secret' => env('MAILGUN_SECRET', 'local-secret'),
+        'options' => [
+            'endpoint' => env('MAILGUN_ENDPOINT', 'api.mailgun.net'),
+            'timeout' => 30,
+        ],
+    ],
+    'slack' => [
+        'webhook' => env('SLACK_WEBHOOK', null),
+    ],
+];
+"#;
+        let result = parse_with(&[("config/services.php", services)], |_| {}).await;
+        let metadata = &result.files[0].metadata;
+
+        assert_eq!(metadata["config_name"], "services");
+        assert_eq!(
+            metadata["config_keys"],
+            serde_json::json!([
+                "mailgun",
+                "mailgun.domain",
+                "mailgun.secret",
+                "mailgun.options",
+                "mailgun.options.endpoint",
+                "mailgun.options.timeout",
+                "slack",
+                "slack.webhook",
+            ])
+        );
+        assert_eq!(
+            metadata["env_references"],
+            serde_json::json!([
+                { "key": "MAILGUN_DOMAIN", "default": null, "path": "mailgun.domain" },
+                { "key": "MAILGUN_SECRET", "default": "local-secret", "path": "mailgun.secret" },
+                {
+                    "key": "MAILGUN_ENDPOINT",
+                    "default": "api.mailgun.net",
+                    "path": "mailgun.options.endpoint"
+                },
+                { "key": "SLACK_WEBHOOK", "default": "null", "path": "slack.webhook" },
+            ])
+        );
+    }
+}
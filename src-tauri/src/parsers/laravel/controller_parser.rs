@@ -1,33 +1,55 @@
 use regex::Regex;
-use std::fs;
 
 use crate::models::{Dependency, ParsedFile, SourceFile, Symbol, SymbolType};
-use crate::parsers::{ParseError, ParserConfig, ParserResult};
+use crate::parsers::common::{
+    blank_comments_and_strings, matching_bracket, read_source, split_top_level,
+    unbalanced_braces_warning,
+};
+use crate::parsers::{ParserConfig, ParserResult};
+
+use super::php_parser::{
+    attributes_before, expand_imported_name, function_signature, qualify_name, PhpParser,
+    ARRAY_ENTRY_REGEX, QUOTED_NAME_REGEX,
+};
 
 /// Parser for Laravel Controllers
 pub struct ControllerParser {
+    php_parser: PhpParser,
     namespace_regex: Regex,
     use_regex: Regex,
     class_regex: Regex,
     method_regex: Regex,
     route_method_regex: Regex,
     middleware_regex: Regex,
-    resource_methods: Vec<&'static str>,
+    middleware_array_regex: Regex,
+    resource_method_regexes: Vec<Regex>,
+    view_regex: Regex,
+    model_static_regex: Regex,
+    type_hint_regex: Regex,
     // Inertia support
     inertia_render_regex: Regex,
     inertia_function_regex: Regex,
+    prop_resource_regex: Regex,
+    prop_model_regex: Regex,
+    prop_query_regex: Regex,
+    compact_regex: Regex,
+    // Response style detection
+    return_regex: Regex,
+    api_response_regex: Regex,
+    web_response_regex: Regex,
 }
 
 impl ControllerParser {
     pub fn new() -> Self {
         Self {
+            php_parser: PhpParser::new(),
             namespace_regex: Regex::new(r"(?m)^\s*namespace\s+([\w\\]+)\s*;").unwrap(),
             use_regex: Regex::new(r"(?m)^\s*use\s+([\w\\]+)(?:\s+as\s+(\w+))?\s*;").unwrap(),
             class_regex: Regex::new(
                 r"(?m)^\s*class\s+(\w+)(?:\s+extends\s+([\w\\]+))?"
             ).unwrap(),
             method_regex: Regex::new(
-                r"(?m)^\s*(public|protected|private)\s+function\s+(\w+)\s*\(([^)]*)\)"
+                r"(?m)^\s*(?:#\[[^\n]*\]\s*)?(public|protected|private)\s+function\s+(\w+)\s*\("
             ).unwrap(),
             // Detect route-related annotations or method calls
             route_method_regex: Regex::new(
@@ -37,9 +59,29 @@ impl ControllerParser {
             middleware_regex: Regex::new(
                 r#"\$this\s*->\s*middleware\s*\(\s*['"]([^'"]+)['"]"#
             ).unwrap(),
-            resource_methods: vec![
+            // Match: $this->middleware(['auth', 'verified'])
+            middleware_array_regex: Regex::new(
+                r"\$this\s*->\s*middleware\s*\(\s*\[([^\]]+)\]"
+            ).unwrap(),
+            // Match: function index( ... one per resource action
+            resource_method_regexes: [
                 "index", "create", "store", "show", "edit", "update", "destroy"
-            ],
+            ]
+            .iter()
+            .map(|method| Regex::new(&format!(r"function\s+{}\s*\(", method)).unwrap())
+            .collect(),
+            // Match: view('users.index') or View::make('users.show')
+            view_regex: Regex::new(
+                r#"(?:view|View::make)\s*\(\s*['"]([^'"]+)['"]"#
+            ).unwrap(),
+            // Match: Model::query() or Model::find() or Model::where() etc.
+            model_static_regex: Regex::new(
+                r"([A-Z][a-zA-Z]+)::(find|findOrFail|where|all|create|firstOrCreate|updateOrCreate|query|with)\s*\("
+            ).unwrap(),
+            // Match type-hinted model parameters: function show(User $user)
+            type_hint_regex: Regex::new(
+                r"function\s+\w+\s*\([^)]*?([A-Z][a-zA-Z]+)\s+\$\w+"
+            ).unwrap(),
             // Match: Inertia::render('Pages/Dashboard') or Inertia::render('Dashboard')
             inertia_render_regex: Regex::new(
                 r#"Inertia::render\s*\(\s*['"]([^'"]+)['"]"#
@@ -48,21 +90,48 @@ impl ControllerParser {
             inertia_function_regex: Regex::new(
                 r#"(?:return\s+)?inertia\s*\(\s*['"]([^'"]+)['"]"#
             ).unwrap(),
+            // Match: UserResource::collection(...), UserResource::make(...) or new UserResource(...)
+            prop_resource_regex: Regex::new(
+                r"(?:new\s+(\\?[\w\\]*(?:Resource|Collection))\s*\(|(\\?[\w\\]*(?:Resource|Collection))::(?:collection|make)\s*\()"
+            ).unwrap(),
+            // Match: User::find(...), User::findOrFail(...), User::first()
+            prop_model_regex: Regex::new(
+                r"(\\?[A-Z][\w\\]*)::(?:find|findOrFail|first|firstOrFail|firstWhere|create)\s*\("
+            ).unwrap(),
+            // Match: User::query(), User::where(...), User::latest(), User::all()
+            prop_query_regex: Regex::new(
+                r"(\\?[A-Z][\w\\]*)::(?:query|where\w*|with|latest|oldest|orderBy\w*|select|all|get|paginate|simplePaginate|cursorPaginate)\s*\("
+            ).unwrap(),
+            // Match: compact('users', 'filters')
+            compact_regex: Regex::new(r"^\s*compact\s*\(([^)]*)\)").unwrap(),
+            // Match: return <expression>;
+            return_regex: Regex::new(r"\breturn\s+([^;]+);").unwrap(),
+            // Match: response()->json(...), new UserResource(...), UserResource::collection(...), [...]
+            api_response_regex: Regex::new(
+                r"^(?:response\s*\(\s*\)\s*->\s*(?:json|noContent)\b|Response::json\b|new\s+(?:JsonResponse|\w+(?:Resource|Collection))\s*\(|\w+(?:Resource|Collection)::(?:collection|make)\s*\(|\[|array\s*\()"
+            ).unwrap(),
+            // Match: view(...), View::make(...), Inertia::render(...), inertia(...), redirect(...)
+            web_response_regex: Regex::new(
+                r"^(?:view\s*\(|View::make\s*\(|Inertia::render\s*\(|inertia\s*\(|redirect\s*\(|Redirect::\w+\s*\(|back\s*\(|to_route\s*\()"
+            ).unwrap(),
         }
     }
 
     pub async fn parse(
         &self,
         file: &SourceFile,
-        _config: &ParserConfig,
+        config: &ParserConfig,
     ) -> ParserResult<ParsedFile> {
-        let content = fs::read_to_string(&file.absolute_path)
-            .map_err(ParseError::Io)?;
+        let (content, decode_warnings) = read_source(file, config)?;
 
         let mut parsed = ParsedFile::new(file.clone());
+        parsed.warnings.extend(decode_warnings);
+
+        // Declarations are matched on the code alone (see PhpParser::parse_content)
+        let code = blank_comments_and_strings(&content);
 
         // Extract namespace
-        let namespace = self.extract_namespace(&content);
+        let namespace = self.extract_namespace(&code);
         if let Some(ref ns) = namespace {
             parsed.metadata.insert(
                 "namespace".to_string(),
@@ -71,13 +140,26 @@ impl ControllerParser {
         }
 
         // Extract use statements
-        self.extract_use_statements(&content, &mut parsed);
+        self.extract_use_statements(&code, &mut parsed);
 
         // Extract controller class
-        self.extract_controller_class(&content, &namespace, &mut parsed);
+        self.extract_controller_class(&content, &code, &namespace, &mut parsed);
+        if !parsed.symbols.iter().any(|s| s.symbol_type == SymbolType::Class) {
+            parsed.add_warning(format!("No controller class found in {}", file.path));
+        }
+        parsed.warnings.extend(unbalanced_braces_warning(&content, &file.path));
 
         // Extract controller methods (actions)
-        self.extract_controller_methods(&content, &mut parsed);
+        self.extract_controller_methods(&content, &code, &mut parsed);
+
+        // Extract job chains and batches dispatched by the actions
+        self.php_parser.extract_job_workflows(&content, &mut parsed);
+
+        // Extract jobs dispatched and events fired by the actions
+        self.php_parser.extract_dispatches(&content, &mut parsed);
+
+        // Extract inline validation rules
+        self.php_parser.extract_validation_rules(&content, &mut parsed);
 
         // Extract middleware usage
         let middlewares = self.extract_middlewares(&content);
@@ -88,6 +170,16 @@ impl ControllerParser {
             );
         }
 
+        // Single action controllers are routed to their __invoke method
+        let is_invokable = parsed
+            .symbols
+            .iter()
+            .any(|s| s.symbol_type == SymbolType::Method && s.name == "__invoke");
+        parsed.metadata.insert(
+            "is_invokable".to_string(),
+            serde_json::Value::Bool(is_invokable),
+        );
+
         // Detect if it's a resource controller
         let is_resource = self.is_resource_controller(&content);
         parsed.metadata.insert(
@@ -126,6 +218,23 @@ impl ControllerParser {
             );
         }
 
+        // Extract the props each Inertia page is rendered with
+        let inertia_props = self.extract_inertia_props(&content, &parsed);
+        if !inertia_props.is_empty() {
+            parsed.metadata.insert(
+                "inertia_props".to_string(),
+                serde_json::json!(inertia_props),
+            );
+        }
+
+        // Classify the controller as API, web or mixed from its responses
+        if let Some(style) = self.detect_interface_style(&content) {
+            parsed.metadata.insert(
+                "interface_style".to_string(),
+                serde_json::Value::String(style.to_string()),
+            );
+        }
+
         Ok(parsed)
     }
 
@@ -156,18 +265,19 @@ impl ControllerParser {
     fn extract_controller_class(
         &self,
         content: &str,
+        code: &str,
         namespace: &Option<String>,
         parsed: &mut ParsedFile,
     ) {
-        if let Some(caps) = self.class_regex.captures(content) {
+        for caps in self.class_regex.captures_iter(code) {
             let class_name = caps.get(1).map(|m| m.as_str().to_string()).unwrap_or_default();
             let extends = caps.get(2).map(|m| m.as_str().to_string());
 
             if !class_name.is_empty() {
-                let qualified_name = match namespace {
-                    Some(ns) => format!("{}\\{}", ns, class_name),
-                    None => class_name.clone(),
-                };
+                let start = caps.get(0).map_or(0, |m| m.start());
+                let qualified_name = qualify_name(code, start, namespace, &class_name);
+
+                let attributes = attributes_before(content, start);
 
                 parsed.add_symbol(Symbol {
                     name: class_name,
@@ -178,25 +288,35 @@ impl ControllerParser {
                     is_static: None,
                     extends,
                     implements: None,
+                    parameters: None,
+                    return_type: None,
+                    attributes,
                     line_start: None,
                     line_end: None,
+                    section: None,
+                    backing_type: None,
+                    enum_cases: None,
                 });
             }
         }
     }
 
-    fn extract_controller_methods(&self, content: &str, parsed: &mut ParsedFile) {
-        for caps in self.method_regex.captures_iter(content) {
+    fn extract_controller_methods(&self, content: &str, code: &str, parsed: &mut ParsedFile) {
+        for caps in self.method_regex.captures_iter(code) {
             let visibility = caps.get(1).map(|m| m.as_str().to_string());
             let method_name = caps.get(2).map(|m| m.as_str().to_string()).unwrap_or_default();
-            let _params = caps.get(3).map(|m| m.as_str().to_string()).unwrap_or_default();
 
-            // Skip constructor and other magic methods for action listing
-            if method_name.starts_with("__") {
+            // Skip constructor and other magic methods for action listing,
+            // __invoke is the action of a single action controller
+            if method_name.starts_with("__") && method_name != "__invoke" {
                 continue;
             }
 
             if !method_name.is_empty() {
+                let open = caps.get(0).map(|m| m.end() - 1).unwrap_or(0);
+                let (parameters, return_type) = function_signature(content, open);
+                let attributes = attributes_before(content, caps.get(1).map_or(0, |m| m.start()));
+
                 parsed.add_symbol(Symbol {
                     name: method_name.clone(),
                     qualified_name: method_name,
@@ -206,8 +326,14 @@ impl ControllerParser {
                     is_static: None,
                     extends: None,
                     implements: None,
+                    parameters,
+                    return_type,
+                    attributes,
                     line_start: None,
                     line_end: None,
+                    section: None,
+                    backing_type: None,
+                    enum_cases: None,
                 });
             }
         }
@@ -226,11 +352,7 @@ impl ControllerParser {
         }
 
         // Also check for middleware in constructor patterns
-        let group_middleware = Regex::new(
-            r"\$this\s*->\s*middleware\s*\(\s*\[([^\]]+)\]"
-        ).unwrap();
-
-        for caps in group_middleware.captures_iter(content) {
+        for caps in self.middleware_array_regex.captures_iter(content) {
             if let Some(list) = caps.get(1) {
                 for item in list.as_str().split(',') {
                     let cleaned = item.trim().trim_matches(|c| c == '\'' || c == '"');
@@ -245,14 +367,11 @@ impl ControllerParser {
     }
 
     fn is_resource_controller(&self, content: &str) -> bool {
-        let mut found_methods = 0;
-
-        for method in &self.resource_methods {
-            let pattern = format!(r"function\s+{}\s*\(", method);
-            if Regex::new(&pattern).unwrap().is_match(content) {
-                found_methods += 1;
-            }
-        }
+        let found_methods = self
+            .resource_method_regexes
+            .iter()
+            .filter(|regex| regex.is_match(content))
+            .count();
 
         // Consider it a resource controller if it has at least 4 resource methods
         found_methods >= 4
@@ -261,10 +380,7 @@ impl ControllerParser {
     fn extract_view_references(&self, content: &str) -> Vec<String> {
         let mut views = Vec::new();
 
-        // Match: view('users.index') or View::make('users.show')
-        let view_regex = Regex::new(r#"(?:view|View::make)\s*\(\s*['"]([^'"]+)['"]"#).unwrap();
-
-        for caps in view_regex.captures_iter(content) {
+        for caps in self.view_regex.captures_iter(content) {
             if let Some(v) = caps.get(1) {
                 let view = v.as_str().to_string();
                 if !views.contains(&view) {
@@ -280,11 +396,7 @@ impl ControllerParser {
         let mut models = Vec::new();
 
         // Match Model::query() or Model::find() or Model::where() etc.
-        let model_static_regex = Regex::new(
-            r"([A-Z][a-zA-Z]+)::(find|findOrFail|where|all|create|firstOrCreate|updateOrCreate|query|with)\s*\("
-        ).unwrap();
-
-        for caps in model_static_regex.captures_iter(content) {
+        for caps in self.model_static_regex.captures_iter(content) {
             if let Some(m) = caps.get(1) {
                 let model = m.as_str().to_string();
                 // Exclude common non-model classes
@@ -298,9 +410,7 @@ impl ControllerParser {
         }
 
         // Match type-hinted model parameters: function show(User $user)
-        let type_hint_regex = Regex::new(r"function\s+\w+\s*\([^)]*?([A-Z][a-zA-Z]+)\s+\$\w+").unwrap();
-
-        for caps in type_hint_regex.captures_iter(content) {
+        for caps in self.type_hint_regex.captures_iter(content) {
             if let Some(m) = caps.get(1) {
                 let model = m.as_str().to_string();
                 if !["Request", "Response", "Collection", "Builder", "Carbon", "Closure", "Exception"]
@@ -340,6 +450,134 @@ impl ControllerParser {
 
         pages
     }
+
+    /// Extract the props passed to each Inertia render call
+    ///
+    /// Returns `[{page, props: [{name, source, type}]}]` where source is
+    /// `resource` (UserResource::collection), `model` (User::find), `query`
+    /// (User::where...), `variable` ($user, compact) or `value`, and type the
+    /// resource or model class expanded through the use statements.
+    fn extract_inertia_props(
+        &self,
+        content: &str,
+        parsed: &ParsedFile,
+    ) -> Vec<serde_json::Value> {
+        let mut renders = Vec::new();
+
+        let render_calls = self
+            .inertia_render_regex
+            .captures_iter(content)
+            .chain(self.inertia_function_regex.captures_iter(content));
+
+        for caps in render_calls {
+            let (page, call_end) = match (caps.get(1), caps.get(0)) {
+                (Some(page), Some(call)) => (page.as_str(), call.end()),
+                _ => continue,
+            };
+
+            // The data argument follows the page name: , [ ... ]  or  , compact(...)
+            let rest = content[call_end..].trim_start();
+            let data = match rest.strip_prefix(',') {
+                Some(data) => data.trim_start(),
+                None => continue,
+            };
+            let data_start = content.len() - data.len();
+
+            let props: Vec<serde_json::Value> = if data.starts_with('[') {
+                let close = match matching_bracket(content, data_start) {
+                    Some(close) => close,
+                    None => continue,
+                };
+                split_top_level(&content[data_start + 1..close], b',')
+                    .into_iter()
+                    .filter_map(|entry| ARRAY_ENTRY_REGEX.captures(entry))
+                    .map(|entry| {
+                        let (source, prop_type) = self.classify_prop_source(&entry[2], parsed);
+                        serde_json::json!({
+                            "name": &entry[1],
+                            "source": source,
+                            "type": prop_type,
+                        })
+                    })
+                    .collect()
+            } else if let Some(compact) = self.compact_regex.captures(data) {
+                QUOTED_NAME_REGEX
+                    .captures_iter(&compact[1])
+                    .map(|name| {
+                        serde_json::json!({
+                            "name": &name[1],
+                            "source": "variable",
+                            "type": null,
+                        })
+                    })
+                    .collect()
+            } else {
+                continue;
+            };
+
+            if !props.is_empty() {
+                renders.push(serde_json::json!({
+                    "page": page,
+                    "props": props,
+                }));
+            }
+        }
+
+        renders
+    }
+
+    /// Where a prop value comes from, and the class it is built from
+    fn classify_prop_source(
+        &self,
+        value: &str,
+        parsed: &ParsedFile,
+    ) -> (&'static str, Option<String>) {
+        let expand = |name: &str| Some(expand_imported_name(&parsed.dependencies, name));
+
+        if let Some(caps) = self.prop_resource_regex.captures(value) {
+            let resource = caps
+                .get(1)
+                .or_else(|| caps.get(2))
+                .map(|m| m.as_str())
+                .unwrap_or("");
+            return ("resource", expand(resource));
+        }
+        if let Some(caps) = self.prop_model_regex.captures(value) {
+            return ("model", expand(&caps[1]));
+        }
+        if let Some(caps) = self.prop_query_regex.captures(value) {
+            return ("query", expand(&caps[1]));
+        }
+        if value.starts_with('$') {
+            return ("variable", None);
+        }
+
+        ("value", None)
+    }
+
+    /// Classify return statements as API (JSON, resources, arrays) or web (views, redirects)
+    fn detect_interface_style(&self, content: &str) -> Option<&'static str> {
+        let mut returns_api = false;
+        let mut returns_web = false;
+
+        for caps in self.return_regex.captures_iter(content) {
+            if let Some(expr) = caps.get(1) {
+                let expr = expr.as_str().trim();
+                if self.api_response_regex.is_match(expr) {
+                    returns_api = true;
+                } else if self.web_response_regex.is_match(expr) {
+                    returns_web = true;
+                }
+            }
+        }
+
+        match (returns_api, returns_web) {
+            (true, true) => Some("mixed"),
+            (true, false) => Some("api"),
+            (false, true) => Some("web"),
+            (false, false) => None,
+        }
+    }
 }
 
 impl Default for ControllerParser {
@@ -347,3 +585,120 @@ impl Default for ControllerParser {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::models::ParseResult;
+    use crate::parsers::laravel::fixtures::parse_with;
+
+    /// Metadata value `key` of the controller at `path`
+    fn metadata(result: &ParseResult, path: &str, key: &str) -> serde_json::Value {
+        let file = result.files.iter().find(|f| f.source.path == path).unwrap();
+        file.metadata.get(key).cloned().unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn api_and_web_controllers_are_tagged_by_their_responses() {
+        let api = r#"<?php
+namespace App\Http\Controllers\Api;
+
+class UserController extends Controller
+{
+    public function index()
+    {
+        return UserResource::collection(User::all());
+    }
+
+    public function destroy(User $user)
+    {
+        $user->delete();
+        return response()->json(['deleted' => true]);
+    }
+}
+"#;
+        let web = r#"<?php
+namespace App\Http\Controllers;
+
+class PageController extends Controller
+{
+    public function about()
+    {
+        return view('pages.about');
+    }
+
+    public function store()
+    {
+        return redirect()->route('home');
+    }
+}
+"#;
+        let result = parse_with(
+            &[
+                ("app/Http/Controllers/Api/UserController.php", api),
+                ("app/Http/Controllers/PageController.php", web),
+            ],
+            |_| {},
+        )
+        .await;
+
+        assert_eq!(
+            metadata(
+                &result,
+                "app/Http/Controllers/Api/UserController.php",
+                "interface_style"
+            ),
+            "api"
+        );
+        assert_eq!(
+            metadata(
+                &result,
+                "app/Http/Controllers/PageController.php",
+                "interface_style"
+            ),
+            "web"
+        );
+    }
+
+    #[tokio::test]
+    async fn inertia_props_record_their_resource_types() {
+        let controller = r#"<?php
+namespace App\Http\Controllers;
+
+use App\Http\Resources\TeamResource;
+use App\Http\Resources\UserResource;
+use Inertia\Inertia;
+
+class UserController extends Controller
+{
+    public function show(User $user)
+    {
+        return Inertia::render('Users/Show', [
+            'user' => new UserResource($user),
+            'teams' => TeamResource::collection($user->teams),
+        ]);
+    }
+}
+"#;
+        let path = "app/Http/Controllers/UserController.php";
+        let result = parse_with(&[(path, controller)], |_| {}).await;
+
+        assert_eq!(
+            metadata(&result, path, "inertia_props"),
+            serde_json::json!([{
+                "page": "Users/Show",
+                "props": [
+                    {
+                        "name": "user",
+                        "source": "resource",
+                        "type": "App\\Http\\Resources\\UserResource"
+                    },
+                    {
+                        "name": "teams",
+                        "source": "resource",
+                        "type": "App\\Http\\Resources\\TeamResource"
+                    },
+                ],
+            }])
+        );
+    }
+}
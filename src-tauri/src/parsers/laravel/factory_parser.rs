@@ -0,0 +1,122 @@
+use regex::Regex;
+
+use crate::models::{ParsedFile, SourceFile, SymbolType};
+use crate::parsers::common::{block_body, matching_bracket, read_source, split_top_level};
+use crate::parsers::{ParserConfig, ParserResult};
+
+use super::php_parser::{expand_imported_name, PhpParser, RETURN_ARRAY_REGEX};
+
+/// Parser for Laravel model factories
+pub struct FactoryParser {
+    php_parser: PhpParser,
+    model_property_regex: Regex,
+    definition_method_regex: Regex,
+    attribute_key_regex: Regex,
+}
+
+impl FactoryParser {
+    pub fn new() -> Self {
+        Self {
+            php_parser: PhpParser::new(),
+
+            // Match: protected $model = User::class;
+            model_property_regex: Regex::new(
+                r"(?:protected|public)\s+(?:\??[\w\\]+\s+)?\$model\s*=\s*(\\?[\w\\]+)::class"
+            ).unwrap(),
+
+            // Match: public function definition(): array {  (up to the opening brace)
+            definition_method_regex: Regex::new(
+                r"function\s+definition\s*\(\s*\)(?:\s*:\s*\??\w+)?\s*\{"
+            ).unwrap(),
+
+            // Match: 'email' =>  (the key of one definition entry)
+            attribute_key_regex: Regex::new(r#"^\s*['"]([^'"]+)['"]\s*=>"#).unwrap(),
+        }
+    }
+
+    /// Parse a factory file
+    pub async fn parse(
+        &self,
+        file: &SourceFile,
+        config: &ParserConfig,
+    ) -> ParserResult<ParsedFile> {
+        let (content, decode_warnings) = read_source(file, config)?;
+
+        let mut parsed = self.php_parser.parse_content(file, &content);
+        parsed.warnings.extend(decode_warnings);
+
+        // Resolve the model this factory creates
+        if let Some((model, source)) = self.resolve_model(&content, &parsed) {
+            parsed.metadata.insert(
+                "factory_model".to_string(),
+                serde_json::Value::String(model),
+            );
+            parsed.metadata.insert(
+                "factory_model_source".to_string(),
+                serde_json::Value::String(source.to_string()),
+            );
+        }
+
+        // Extract the attributes set by definition()
+        let attributes = self.extract_definition_keys(&content);
+        if !attributes.is_empty() {
+            parsed.metadata.insert(
+                "factory_attributes".to_string(),
+                serde_json::json!(attributes),
+            );
+        }
+
+        Ok(parsed)
+    }
+
+    /// Model created by the factory, and how it was found
+    ///
+    /// The `$model` property wins. Without it Laravel's convention applies:
+    /// `UserFactory` creates `App\Models\User`.
+    fn resolve_model(&self, content: &str, parsed: &ParsedFile) -> Option<(String, &'static str)> {
+        if let Some(caps) = self.model_property_regex.captures(content) {
+            let model = expand_imported_name(&parsed.dependencies, &caps[1]);
+            return Some((model, "property"));
+        }
+
+        parsed
+            .symbols
+            .iter()
+            .find(|s| s.symbol_type == SymbolType::Class)
+            .and_then(|class| class.name.strip_suffix("Factory"))
+            .filter(|name| !name.is_empty())
+            .map(|name| (format!("App\\Models\\{}", name), "convention"))
+    }
+
+    /// Keys of the array returned by `definition()`
+    fn extract_definition_keys(&self, content: &str) -> Vec<String> {
+        let body = match self
+            .definition_method_regex
+            .find(content)
+            .and_then(|header| block_body(content, header.end() - 1))
+        {
+            Some(body) => body,
+            None => return Vec::new(),
+        };
+
+        let attributes = match RETURN_ARRAY_REGEX.find(body).and_then(|found| {
+            let open = found.end() - 1;
+            matching_bracket(body, open).map(|close| &body[open + 1..close])
+        }) {
+            Some(attributes) => attributes,
+            None => return Vec::new(),
+        };
+
+        split_top_level(attributes, b',')
+            .into_iter()
+            .filter_map(|entry| self.attribute_key_regex.captures(entry))
+            .map(|caps| caps[1].to_string())
+            .collect()
+    }
+}
+
+impl Default for FactoryParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,36 @@
+// Test fixtures - Laravel projects written to a temporary directory
+
+use crate::models::{ParseResult, UnifiedGraph};
+use crate::parsers::common::fixtures::project_dir;
+use crate::parsers::{ParserConfig, ProjectParser};
+
+use super::LaravelParser;
+
+/// Parse result of a Laravel project, parsed with an adjusted default config
+pub(crate) async fn parse_with(
+    files: &[(&str, &str)],
+    configure: impl FnOnce(&mut ParserConfig),
+) -> ParseResult {
+    let dir = project_dir(files);
+    let parser = LaravelParser::new();
+    let mut config = parser.default_config();
+    configure(&mut config);
+    let files = parser.scan_files(dir.path(), &config, None).await.unwrap();
+    parser
+        .parse_project(dir.path(), &files, &config, None)
+        .await
+        .unwrap()
+}
+
+/// Graph of a Laravel project made of the given files
+pub(crate) async fn graph_of(files: &[(&str, &str)]) -> UnifiedGraph {
+    graph_with(files, |_| {}).await
+}
+
+/// Graph of a Laravel project, parsed with an adjusted default config
+pub(crate) async fn graph_with(
+    files: &[(&str, &str)],
+    configure: impl FnOnce(&mut ParserConfig),
+) -> UnifiedGraph {
+    LaravelParser::new().build_graph(&parse_with(files, configure).await)
+}
@@ -0,0 +1,240 @@
+use regex::Regex;
+
+use crate::models::{ParsedFile, SourceFile};
+use crate::parsers::common::{block_body, read_source, studly_case};
+use crate::parsers::{ParserConfig, ParserResult};
+
+/// Root operation types and the namespace Lighthouse looks up their resolvers in
+const ROOT_TYPES: [(&str, &str); 3] = [
+    ("Query", "App\\GraphQL\\Queries"),
+    ("Mutation", "App\\GraphQL\\Mutations"),
+    ("Subscription", "App\\GraphQL\\Subscriptions"),
+];
+
+/// Parser for Lighthouse GraphQL schema files (.graphql)
+pub struct GraphqlParser {
+    type_regex: Regex,
+    field_regex: Regex,
+    field_directive_regex: Regex,
+    import_regex: Regex,
+}
+
+impl GraphqlParser {
+    pub fn new() -> Self {
+        Self {
+            // Match: type User {  or  extend type Query {  or  enum Role
+            type_regex: Regex::new(
+                r"(?m)^\s*(extend\s+)?(type|input|interface|enum|union|scalar)\s+(\w+)"
+            ).unwrap(),
+
+            // Match: users(first: Int): [User!]! @paginate
+            field_regex: Regex::new(
+                r"(\w+)\s*(?:\([^)]*\))?\s*:\s*([\[\]\w!]+)((?:\s*@\w+(?:\s*\([^)]*\))?)*)"
+            ).unwrap(),
+
+            // Match: @field(resolver: "App\\GraphQL\\Queries\\Users@resolve")
+            field_directive_regex: Regex::new(
+                r#"@field\s*\(\s*resolver\s*:\s*"([^"]+)""#
+            ).unwrap(),
+
+            // Match: #import user.graphql
+            import_regex: Regex::new(r"(?m)^\s*#import\s+(\S+)").unwrap(),
+        }
+    }
+
+    pub async fn parse(
+        &self,
+        file: &SourceFile,
+        config: &ParserConfig,
+    ) -> ParserResult<ParsedFile> {
+        let (content, decode_warnings) = read_source(file, config)?;
+
+        let mut parsed = ParsedFile::new(file.clone());
+        parsed.warnings.extend(decode_warnings);
+
+        // Extract type definitions and root operations
+        let (types, operations) = self.extract_types(&content);
+        if !types.is_empty() {
+            parsed.metadata.insert(
+                "graphql_types".to_string(),
+                serde_json::json!(types),
+            );
+        }
+        if !operations.is_empty() {
+            parsed.metadata.insert(
+                "graphql_operations".to_string(),
+                serde_json::json!(operations),
+            );
+        }
+
+        // Extract schema imports
+        let imports: Vec<String> = self
+            .import_regex
+            .captures_iter(&content)
+            .map(|caps| caps[1].to_string())
+            .collect();
+        if !imports.is_empty() {
+            parsed.metadata.insert(
+                "graphql_imports".to_string(),
+                serde_json::json!(imports),
+            );
+        }
+
+        Ok(parsed)
+    }
+
+    /// Extract type definitions, plus the fields that map to a resolver class
+    ///
+    /// Fields of root types (Query, Mutation, Subscription) are always
+    /// reported. Without an explicit `@field(resolver: ...)` and without any
+    /// other directive, Lighthouse resolves them by convention to
+    /// `App\GraphQL\Queries\{FieldName}` (or Mutations/Subscriptions).
+    fn extract_types(&self, content: &str) -> (Vec<serde_json::Value>, Vec<serde_json::Value>) {
+        let mut types = Vec::new();
+        let mut operations = Vec::new();
+
+        for caps in self.type_regex.captures_iter(content) {
+            let is_extension = caps.get(1).is_some();
+            let kind = &caps[2];
+            let type_name = &caps[3];
+
+            if !is_extension {
+                types.push(serde_json::json!({
+                    "name": type_name,
+                    "kind": kind,
+                }));
+            }
+
+            if !matches!(kind, "type" | "interface") {
+                continue;
+            }
+
+            // Fields live between the braces following the header, unless the
+            // definition has no body (extend type Query @guard)
+            let header_end = caps.get(0).map(|m| m.end()).unwrap_or(0);
+            let rest = &content[header_end..];
+            let body = rest
+                .find('{')
+                .filter(|&open| !self.type_regex.is_match(&rest[..open]))
+                .and_then(|open| block_body(content, header_end + open));
+            let body = match body {
+                Some(body) => body,
+                None => continue,
+            };
+
+            let root_namespace = ROOT_TYPES
+                .iter()
+                .find(|(root, _)| *root == type_name)
+                .map(|(_, namespace)| *namespace);
+
+            for field_caps in self.field_regex.captures_iter(body) {
+                let field_name = &field_caps[1];
+                let return_type = &field_caps[2];
+                let directives = field_caps.get(3).map(|m| m.as_str().trim()).unwrap_or("");
+
+                let (resolver_class, resolver_method, resolver_source) =
+                    match self.field_directive_regex.captures(directives) {
+                        Some(directive) => {
+                            let (class, method) = self.parse_resolver(&directive[1], root_namespace);
+                            (Some(class), Some(method), Some("directive"))
+                        }
+                        None => match root_namespace {
+                            Some(namespace) if directives.is_empty() => (
+                                Some(format!("{}\\{}", namespace, studly_case(field_name))),
+                                Some("__invoke".to_string()),
+                                Some("convention"),
+                            ),
+                            Some(_) => (None, None, None),
+                            // Fields of regular types only matter with an explicit resolver
+                            None => continue,
+                        },
+                    };
+
+                operations.push(serde_json::json!({
+                    "type": type_name,
+                    "field": field_name,
+                    "return_type": return_type,
+                    "directives": directives,
+                    "resolver": resolver_class,
+                    "resolver_method": resolver_method,
+                    "resolver_source": resolver_source,
+                }));
+            }
+        }
+
+        (types, operations)
+    }
+
+    /// Split `Class@method` and expand short class names with the root namespace
+    fn parse_resolver(&self, raw: &str, root_namespace: Option<&str>) -> (String, String) {
+        // Schema strings escape namespace separators (App\\GraphQL\\...)
+        let raw = raw.replace("\\\\", "\\");
+        let (class, method) = match raw.split_once('@') {
+            Some((class, method)) => (class.to_string(), method.to_string()),
+            None => (raw.clone(), "__invoke".to_string()),
+        };
+        let class = class.trim_start_matches('\\').to_string();
+
+        let class = match root_namespace {
+            Some(namespace) if !class.contains('\\') => format!("{}\\{}", namespace, class),
+            _ => class,
+        };
+
+        (class, method)
+    }
+}
+
+impl Default for GraphqlParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::is_file_node;
+    use crate::models::{UnifiedEdgeType, UnifiedGraph, UnifiedNode};
+    use crate::parsers::laravel::fixtures::graph_of;
+
+    fn node<'a>(graph: &'a UnifiedGraph, qualified_name: &str) -> &'a UnifiedNode {
+        graph
+            .nodes
+            .iter()
+            .find(|n| n.qualified_name == qualified_name && !is_file_node(n))
+            .unwrap_or_else(|| panic!("no node {}", qualified_name))
+    }
+
+    #[tokio::test]
+    async fn conventional_query_fields_resolve_to_their_class() {
+        let graph = graph_of(&[
+            (
+                "graphql/schema.graphql",
+                "type Query {\n    latestPosts: [Post!]!\n    users: [User!]! @paginate\n}\n",
+            ),
+            (
+                "app/GraphQL/Queries/LatestPosts.php",
+                "<?php\n\nnamespace App\\GraphQL\\Queries;\n\n\
+                 final class LatestPosts\n{\n    public function __invoke() {}\n}\n",
+            ),
+        ])
+        .await;
+
+        let resolves = UnifiedEdgeType::Custom("resolves".to_string());
+        let latest_posts = node(&graph, "graphql:Query.latestPosts");
+        let resolver = node(&graph, "App\\GraphQL\\Queries\\LatestPosts");
+        let edge = graph
+            .edges
+            .iter()
+            .find(|e| e.source == latest_posts.id && e.edge_type == resolves)
+            .expect("no resolves edge");
+        assert_eq!(edge.target, resolver.id);
+        assert_eq!(edge.detail.as_deref(), Some("__invoke"));
+
+        // Directive-driven fields have no resolver class
+        let users = node(&graph, "graphql:Query.users");
+        assert!(!graph
+            .edges
+            .iter()
+            .any(|e| e.source == users.id && e.edge_type == resolves));
+    }
+}
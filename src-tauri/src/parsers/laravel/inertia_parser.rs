@@ -1,8 +1,11 @@
 use regex::Regex;
-use std::fs;
+use std::collections::HashSet;
 
 use crate::models::{Dependency, ParsedFile, SourceFile, Symbol, SymbolType};
-use crate::parsers::{ParseError, ParserConfig, ParserResult};
+use crate::parsers::common::{
+    block_body, matching_paren, read_source, resolve_relative_path, split_top_level,
+};
+use crate::parsers::{ParserConfig, ParserResult};
 
 /// Parser for Inertia.js page components (Vue, React, Svelte)
 pub struct InertiaParser {
@@ -11,22 +14,34 @@ pub struct InertiaParser {
     vue_component_regex: Regex,
     vue_props_regex: Regex,
     vue_emit_regex: Regex,
+    vue_props_object_regex: Regex,
+    object_key_regex: Regex,
 
     // React imports
     react_import_regex: Regex,
     react_component_regex: Regex,
+    export_default_regex: Regex,
+
+    // Svelte props
+    svelte_export_let_regex: Regex,
+    svelte_props_rune_regex: Regex,
 
     // Inertia specific
     inertia_link_regex: Regex,
     inertia_form_regex: Regex,
     inertia_router_regex: Regex,
+    ziggy_route_regex: Regex,
     use_page_regex: Regex,
     use_form_regex: Regex,
+    form_fields_regex: Regex,
+    page_prop_regex: Regex,
+    page_props_destructure_regex: Regex,
 
     // TypeScript props
     define_props_regex: Regex,
     interface_regex: Regex,
     type_regex: Regex,
+    type_member_regex: Regex,
 }
 
 impl InertiaParser {
@@ -52,14 +67,37 @@ impl InertiaParser {
                 r#"defineEmits\s*\(\s*\[([^\]]+)\]"#
             ).unwrap(),
 
+            // Match: defineProps({ user: Object, ... })
+            vue_props_object_regex: Regex::new(
+                r#"defineProps\s*\(\s*\{([^}]+)\}"#
+            ).unwrap(),
+
+            // Match: key: (object literal keys)
+            object_key_regex: Regex::new(r"(\w+)\s*:").unwrap(),
+
             // Match: import { Component } from 'react' or import Component from './Component'
             react_import_regex: Regex::new(
                 r#"import\s+(?:\{([^}]+)\}|(\w+))\s+from\s+['"]([^'"]+)['"]"#
             ).unwrap(),
 
-            // Match: function ComponentName() or const ComponentName = () =>
+            // Match: function ComponentName(  or  const ComponentName: React.FC<Props> = (
             react_component_regex: Regex::new(
-                r"(?:function|const)\s+([A-Z][a-zA-Z0-9]+)\s*(?:=\s*\([^)]*\)\s*=>|\()"
+                r"(?:function\s+([A-Z][a-zA-Z0-9]*)\s*|const\s+([A-Z][a-zA-Z0-9]*)\s*(?::\s*([^=]+?)\s*)?=\s*(?:async\s*)?)\("
+            ).unwrap(),
+
+            // Match: export default function Dashboard  or  export default Dashboard
+            export_default_regex: Regex::new(
+                r"export\s+default\s+(?:function\s+)?([A-Z][a-zA-Z0-9]*)"
+            ).unwrap(),
+
+            // Match: export let user  or  export let title: string = ''  (Svelte 3/4 props)
+            svelte_export_let_regex: Regex::new(
+                r"(?m)^\s*export\s+let\s+(\w+)"
+            ).unwrap(),
+
+            // Match: let { user, title = '' }: Props = $props()  (Svelte 5 props)
+            svelte_props_rune_regex: Regex::new(
+                r"let\s*(\{[^}]*\})\s*(?::\s*(\w+)\s*)?=\s*\$props\s*\("
             ).unwrap(),
 
             // Match: <Link href="/path"> or <InertiaLink>
@@ -77,6 +115,12 @@ impl InertiaParser {
                 r#"router\.(visit|get|post|put|patch|delete)\s*\(\s*['"]([^'"]+)['"]"#
             ).unwrap(),
 
+            // Match: route('users.show', user.id)  or  this.route('home')  (Ziggy),
+            // not router.route(...) or route().current('users.*')
+            ziggy_route_regex: Regex::new(
+                r#"(?:^|[^\w.$])(?:this\.)?route\s*\(\s*['"`]([^'"`$]+)['"`]"#
+            ).unwrap(),
+
             // Match: usePage()
             use_page_regex: Regex::new(
                 r"usePage\s*\(\s*\)"
@@ -87,6 +131,21 @@ impl InertiaParser {
                 r"useForm\s*\("
             ).unwrap(),
 
+            // Match: useForm({  or  useForm('CreateUser', {  (remembered form)
+            form_fields_regex: Regex::new(
+                r#"useForm\s*\(\s*(?:['"][^'"]*['"]\s*,\s*)?\{"#
+            ).unwrap(),
+
+            // Match: usePage().props.auth  or  usePage().props.value.auth
+            page_prop_regex: Regex::new(
+                r"usePage\s*\(\s*\)\s*\.props(?:\.value)?\.(\w+)"
+            ).unwrap(),
+
+            // Match: const { auth, flash } = usePage().props
+            page_props_destructure_regex: Regex::new(
+                r"\{([^{}]*)\}\s*=\s*usePage\s*\(\s*\)\s*\.props"
+            ).unwrap(),
+
             // Match: defineProps<Props>() with type extraction
             define_props_regex: Regex::new(
                 r"defineProps\s*<\s*(\w+)\s*>"
@@ -101,25 +160,35 @@ impl InertiaParser {
             type_regex: Regex::new(
                 r"type\s+(\w+)\s*="
             ).unwrap(),
+
+            // Match: user: User  or  readonly title?: string  (a member of a TS object type)
+            type_member_regex: Regex::new(
+                r"^\s*(?:readonly\s+)?(\w+)\??\s*:"
+            ).unwrap(),
         }
     }
 
     pub async fn parse(
         &self,
         file: &SourceFile,
-        _config: &ParserConfig,
+        config: &ParserConfig,
     ) -> ParserResult<ParsedFile> {
-        let content = fs::read_to_string(&file.absolute_path)
-            .map_err(ParseError::Io)?;
+        let (content, decode_warnings) = read_source(file, config)?;
 
         let mut parsed = ParsedFile::new(file.clone());
+        parsed.warnings.extend(decode_warnings);
+
+        // Pages live in resources/js/Pages, the other files are components they import
+        let is_page = file.path.to_lowercase().contains("resources/js/pages/");
 
         // Determine page name from path
         let page_name = self.extract_page_name(&file.path);
-        parsed.metadata.insert(
-            "page_name".to_string(),
-            serde_json::Value::String(page_name.clone()),
-        );
+        if is_page {
+            parsed.metadata.insert(
+                "page_name".to_string(),
+                serde_json::Value::String(page_name.clone()),
+            );
+        }
 
         // Detect framework (Vue, React, Svelte)
         let framework = self.detect_framework(&content, &file.name);
@@ -130,7 +199,7 @@ impl InertiaParser {
 
         parsed.metadata.insert(
             "is_inertia_page".to_string(),
-            serde_json::json!(true),
+            serde_json::json!(is_page),
         );
 
         // Extract imports
@@ -141,8 +210,17 @@ impl InertiaParser {
                 serde_json::json!(imports),
             );
 
+            // npm packages are recorded, local files become dependencies
+            let mut external: Vec<&str> = Vec::new();
             for import in &imports {
                 if let Some(path) = import.get("path").and_then(|p| p.as_str()) {
+                    if !is_local_import(path) {
+                        if !external.contains(&path) {
+                            external.push(path);
+                        }
+                        continue;
+                    }
+
                     parsed.add_dependency(Dependency {
                         target: path.to_string(),
                         alias: import.get("name").and_then(|n| n.as_str()).map(|s| s.to_string()),
@@ -152,6 +230,13 @@ impl InertiaParser {
                     });
                 }
             }
+
+            if !external.is_empty() {
+                parsed.metadata.insert(
+                    "external_imports".to_string(),
+                    serde_json::json!(external),
+                );
+            }
         }
 
         // Extract child components used
@@ -181,6 +266,15 @@ impl InertiaParser {
             );
         }
 
+        // Extract named Laravel routes called through Ziggy
+        let routes_referenced = self.extract_routes_referenced(&content);
+        if !routes_referenced.is_empty() {
+            parsed.metadata.insert(
+                "routes_referenced".to_string(),
+                serde_json::json!(routes_referenced),
+            );
+        }
+
         // Check for Inertia hooks usage
         let uses_page = self.use_page_regex.is_match(&content);
         let uses_form = self.use_form_regex.is_match(&content);
@@ -197,18 +291,52 @@ impl InertiaParser {
                 "uses_inertia_form".to_string(),
                 serde_json::json!(true),
             );
-        }
 
-        // Extract props (Vue 3 style)
-        if framework == "vue" {
-            let props = self.extract_vue_props(&content);
-            if !props.is_empty() {
+            let fields = self.extract_form_fields(&content);
+            if !fields.is_empty() {
                 parsed.metadata.insert(
-                    "props".to_string(),
-                    serde_json::json!(props),
+                    "form_fields".to_string(),
+                    serde_json::json!(fields),
                 );
             }
+        }
+
+        // Shared props read through usePage() (auth, flash, ...)
+        let page_props = self.extract_page_props(&content);
+        if !page_props.is_empty() {
+            parsed.metadata.insert(
+                "page_props".to_string(),
+                serde_json::json!(page_props),
+            );
+        }
+
+        // Inertia adapter the hooks come from (@inertiajs/react, @inertiajs/vue3, ...)
+        let adapter = imports
+            .iter()
+            .filter_map(|i| i.get("path").and_then(|p| p.as_str()))
+            .find(|path| path.starts_with("@inertiajs/"));
+        if let Some(adapter) = adapter {
+            parsed.metadata.insert(
+                "inertia_adapter".to_string(),
+                serde_json::json!(adapter),
+            );
+        }
 
+        // Extract props
+        let props = match framework.as_str() {
+            "vue" => self.extract_vue_props(&content),
+            "react" => self.extract_react_props(&content),
+            "svelte" => self.extract_svelte_props(&content),
+            _ => Vec::new(),
+        };
+        if !props.is_empty() {
+            parsed.metadata.insert(
+                "props".to_string(),
+                serde_json::json!(props),
+            );
+        }
+
+        if framework == "vue" {
             let emits = self.extract_vue_emits(&content);
             if !emits.is_empty() {
                 parsed.metadata.insert(
@@ -228,18 +356,26 @@ impl InertiaParser {
         }
 
         // Add the page as a symbol
-        parsed.add_symbol(Symbol {
-            name: page_name.clone(),
-            qualified_name: format!("inertia:{}", page_name),
-            symbol_type: SymbolType::Unit,
-            visibility: Some("public".to_string()),
-            is_abstract: None,
-            is_static: None,
-            extends: None,
-            implements: None,
-            line_start: None,
-            line_end: None,
-        });
+        if is_page {
+            parsed.add_symbol(Symbol {
+                name: page_name.clone(),
+                qualified_name: format!("inertia:{}", page_name),
+                symbol_type: SymbolType::Unit,
+                visibility: Some("public".to_string()),
+                is_abstract: None,
+                is_static: None,
+                extends: None,
+                implements: None,
+                parameters: None,
+                return_type: None,
+                attributes: None,
+                line_start: None,
+                line_end: None,
+                section: None,
+                backing_type: None,
+                enum_cases: None,
+            });
+        }
 
         Ok(parsed)
     }
@@ -294,11 +430,7 @@ impl InertiaParser {
         }
 
         // General ES6 imports
-        let general_import = Regex::new(
-            r#"import\s+(?:\{([^}]+)\}|(\w+))\s+from\s+['"]([^'"]+)['"]"#
-        ).unwrap();
-
-        for caps in general_import.captures_iter(content) {
+        for caps in self.react_import_regex.captures_iter(content) {
             let named = caps.get(1).map(|m| m.as_str());
             let default = caps.get(2).map(|m| m.as_str());
             let path = caps.get(3).map(|m| m.as_str()).unwrap_or("");
@@ -380,33 +512,215 @@ impl InertiaParser {
         calls
     }
 
+    fn extract_routes_referenced(&self, content: &str) -> Vec<String> {
+        let mut routes = Vec::new();
+
+        for caps in self.ziggy_route_regex.captures_iter(content) {
+            let name = caps[1].to_string();
+            if !routes.contains(&name) {
+                routes.push(name);
+            }
+        }
+
+        routes
+    }
+
     fn extract_vue_props(&self, content: &str) -> Vec<String> {
         let mut props = Vec::new();
 
         // Try to extract from defineProps
-        let props_object_regex = Regex::new(
-            r#"defineProps\s*\(\s*\{([^}]+)\}"#
-        ).ok();
-
-        if let Some(regex) = props_object_regex {
-            for caps in regex.captures_iter(content) {
-                if let Some(props_str) = caps.get(1) {
-                    let prop_name_regex = Regex::new(r"(\w+)\s*:").unwrap();
-                    for prop_caps in prop_name_regex.captures_iter(props_str.as_str()) {
-                        if let Some(prop) = prop_caps.get(1) {
-                            let name = prop.as_str().to_string();
-                            if !props.contains(&name) {
-                                props.push(name);
-                            }
+        for caps in self.vue_props_object_regex.captures_iter(content) {
+            if let Some(props_str) = caps.get(1) {
+                for prop_caps in self.object_key_regex.captures_iter(props_str.as_str()) {
+                    if let Some(prop) = prop_caps.get(1) {
+                        let name = prop.as_str().to_string();
+                        if !props.contains(&name) {
+                            props.push(name);
                         }
                     }
                 }
             }
         }
 
+        // defineProps<Props>()
+        for caps in self.define_props_regex.captures_iter(content) {
+            for name in self.type_members(content, &caps[1]) {
+                if !props.contains(&name) {
+                    props.push(name);
+                }
+            }
+        }
+
         props
     }
 
+    /// Props of the page component (the default export, else the first component)
+    ///
+    /// Taken from the destructured first parameter (`function Edit({ user, roles })`),
+    /// then from its TypeScript type (`props: Props`, `React.FC<Props>`).
+    fn extract_react_props(&self, content: &str) -> Vec<String> {
+        let page = self
+            .export_default_regex
+            .captures(content)
+            .map(|caps| caps[1].to_string());
+        let component = self.react_component_regex.captures_iter(content).find(|caps| {
+            let name = caps.get(1).or_else(|| caps.get(2)).map(|m| m.as_str());
+            page.is_none() || name == page.as_deref()
+        });
+
+        let caps = match component {
+            Some(caps) => caps,
+            None => return Vec::new(),
+        };
+        let open = caps.get(0).map(|m| m.end() - 1).unwrap_or(0);
+        let parameters = match matching_paren(content, open) {
+            Some(close) => &content[open + 1..close],
+            None => return Vec::new(),
+        };
+
+        // First parameter: `{ a, b }: Props` or `props: Props`
+        let first = split_top_level(parameters, b',')[0].trim();
+        let parts = split_top_level(first, b':');
+        let mut props = if first.starts_with('{') {
+            destructured_keys(parts[0])
+        } else {
+            Vec::new()
+        };
+
+        // `Props`, `React.FC<Props>` or an inline `{ a: string }`
+        let annotation = parts
+            .get(1)
+            .map(|t| t.trim())
+            .or_else(|| caps.get(3).map(|m| m.as_str()))
+            .unwrap_or("");
+        let typed = if annotation.starts_with('{') {
+            block_body(annotation, 0)
+                .map(|body| self.object_type_members(body))
+                .unwrap_or_default()
+        } else {
+            let type_name = annotation.rsplit('<').next().unwrap_or(annotation);
+            self.type_members(content, type_name.trim_end_matches('>').trim())
+        };
+        for name in typed {
+            if !props.contains(&name) {
+                props.push(name);
+            }
+        }
+
+        props
+    }
+
+    /// Props of a Svelte component: `export let` declarations (Svelte 3/4)
+    /// or the `$props()` destructuring and its type (Svelte 5)
+    fn extract_svelte_props(&self, content: &str) -> Vec<String> {
+        let mut props: Vec<String> = Vec::new();
+
+        for caps in self.svelte_export_let_regex.captures_iter(content) {
+            let name = caps[1].to_string();
+            if !props.contains(&name) {
+                props.push(name);
+            }
+        }
+
+        for caps in self.svelte_props_rune_regex.captures_iter(content) {
+            let mut names = destructured_keys(&caps[1]);
+            if let Some(type_name) = caps.get(2) {
+                names.extend(self.type_members(content, type_name.as_str()));
+            }
+            for name in names {
+                if !props.contains(&name) {
+                    props.push(name);
+                }
+            }
+        }
+
+        props
+    }
+
+    /// Fields of the `useForm({ ... })` initial data
+    fn extract_form_fields(&self, content: &str) -> Vec<String> {
+        let mut fields: Vec<String> = Vec::new();
+
+        for m in self.form_fields_regex.find_iter(content) {
+            if let Some(body) = block_body(content, m.end() - 1) {
+                for name in object_keys(body) {
+                    if !fields.contains(&name) {
+                        fields.push(name);
+                    }
+                }
+            }
+        }
+
+        fields
+    }
+
+    /// Shared props read from `usePage().props`
+    fn extract_page_props(&self, content: &str) -> Vec<String> {
+        let mut props: Vec<String> = Vec::new();
+
+        let read = self
+            .page_prop_regex
+            .captures_iter(content)
+            .map(|caps| vec![caps[1].to_string()]);
+        let destructured = self
+            .page_props_destructure_regex
+            .captures_iter(content)
+            .map(|caps| destructured_keys(&format!("{{{}}}", &caps[1])));
+        for name in read.chain(destructured).flatten() {
+            if !props.contains(&name) {
+                props.push(name);
+            }
+        }
+
+        props
+    }
+
+    /// Members of the TS `interface Name { ... }` or `type Name = { ... }`
+    /// declared in `content`, members of nested object types not included
+    fn type_members(&self, content: &str, name: &str) -> Vec<String> {
+        let declaration = self
+            .interface_regex
+            .captures_iter(content)
+            .find(|caps| &caps[1] == name)
+            .and_then(|caps| caps.get(0).map(|m| m.end() - 1))
+            .or_else(|| {
+                self.type_regex
+                    .captures_iter(content)
+                    .find(|caps| &caps[1] == name)
+                    .and_then(|caps| caps.get(0))
+                    .and_then(|m| {
+                        let rest = &content[m.end()..];
+                        let offset = rest.len() - rest.trim_start().len();
+                        Some(m.end() + offset).filter(|&open| content[open..].starts_with('{'))
+                    })
+            });
+
+        declaration
+            .and_then(|open| block_body(content, open))
+            .map(|body| self.object_type_members(body))
+            .unwrap_or_default()
+    }
+
+    /// Members of an object type body (`a: string; b?: number`)
+    fn object_type_members(&self, body: &str) -> Vec<String> {
+        let mut members: Vec<String> = Vec::new();
+        for line in split_top_level(body, b'\n') {
+            for member in split_top_level(line, b';')
+                .into_iter()
+                .flat_map(|m| split_top_level(m, b','))
+            {
+                if let Some(caps) = self.type_member_regex.captures(member) {
+                    let name = caps[1].to_string();
+                    if !members.contains(&name) {
+                        members.push(name);
+                    }
+                }
+            }
+        }
+
+        members
+    }
+
     fn extract_vue_emits(&self, content: &str) -> Vec<String> {
         let mut emits = Vec::new();
 
@@ -449,8 +763,170 @@ impl InertiaParser {
     }
 }
 
+/// Names bound by an object destructuring pattern `{ a, b = 1, c: alias, ...rest }`
+fn destructured_keys(pattern: &str) -> Vec<String> {
+    let inner = pattern
+        .trim()
+        .trim_start_matches('{')
+        .trim_end_matches('}');
+    object_keys(inner)
+}
+
+/// Keys of the entries of an object literal or pattern body, `...spread` skipped
+fn object_keys(body: &str) -> Vec<String> {
+    split_top_level(body, b',')
+        .into_iter()
+        .map(|entry| entry.trim())
+        .filter(|entry| !entry.starts_with("..."))
+        .map(|entry| {
+            entry
+                .trim_matches(|c| c == '\'' || c == '"')
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+                .collect::<String>()
+        })
+        .filter(|key| !key.is_empty())
+        .collect()
+}
+
+/// File extensions tried, in order, for an import written without one
+///
+/// Only scanned extensions: plain `.ts`/`.js` modules are not parsed, imports
+/// of them end up in the file's `unresolved_imports`.
+const IMPORT_EXTENSIONS: [&str; 4] = ["vue", "tsx", "jsx", "svelte"];
+
+/// Whether an import points into the project (`./Widget`, `../Layouts/App`,
+/// `@/Components/Button.vue`) rather than at an npm package
+pub fn is_local_import(path: &str) -> bool {
+    path.starts_with("./") || path.starts_with("../") || path.starts_with("@/")
+}
+
+/// Scanned file an import of the file at `importer` refers to
+///
+/// Relative imports are resolved from the importing file and `@/` is the
+/// usual Vite alias of `resources/js/`. Without an extension, `.vue`, `.tsx`,
+/// `.jsx` and `.svelte` files are tried, then an `index` file in a directory
+/// of that name. `files` holds the project-relative paths of
+/// the scanned files.
+pub fn resolve_import(importer: &str, import: &str, files: &HashSet<&str>) -> Option<String> {
+    let path = match import.strip_prefix("@/") {
+        Some(rest) => format!("resources/js/{}", rest),
+        None if is_local_import(import) => resolve_relative_path(importer, import),
+        None => return None,
+    };
+
+    if files.contains(path.as_str()) {
+        return Some(path);
+    }
+    IMPORT_EXTENSIONS
+        .iter()
+        .map(|ext| format!("{}.{}", path, ext))
+        .chain(IMPORT_EXTENSIONS.iter().map(|ext| format!("{}/index.{}", path, ext)))
+        .find(|candidate| files.contains(candidate.as_str()))
+}
+
+/// Comparable key for an Inertia page name
+///
+/// Controllers and page files don't always spell the same page identically
+/// (`Pages/Users/Index`, `users/index`, `Users\Index.vue`), so both sides are
+/// reduced to lowercase, `/`-separated, without the `Pages/` prefix or extension.
+pub fn normalize_page_name(name: &str) -> String {
+    let mut key = name.replace('\\', "/").to_lowercase();
+
+    for ext in [".vue", ".jsx", ".tsx", ".svelte", ".js", ".ts"] {
+        if let Some(stripped) = key.strip_suffix(ext) {
+            key = stripped.to_string();
+            break;
+        }
+    }
+
+    if let Some(index) = key.rfind("/pages/") {
+        key = key[index + "/pages/".len()..].to_string();
+    } else if let Some(stripped) = key.strip_prefix("pages/") {
+        key = stripped.to_string();
+    }
+
+    key.trim_matches('/').to_string()
+}
+
 impl Default for InertiaParser {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::models::ParseResult;
+    use crate::parsers::laravel::fixtures::parse_with;
+
+    /// Metadata value `key` of the file at `path`
+    fn metadata(result: &ParseResult, path: &str, key: &str) -> serde_json::Value {
+        let file = result.files.iter().find(|f| f.source.path == path).unwrap();
+        file.metadata.get(key).cloned().unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn react_and_svelte_pages_list_their_props() {
+        let edit = "import { useForm, usePage } from '@inertiajs/react';
+
+interface Props {
+    user: User;
+    roles: string[];
+}
+
+function Field({ label }: { label: string }) {
+    return <label>{label}</label>;
+}
+
+export default function Edit({ user, ...rest }: Props) {
+    const { auth, flash } = usePage().props;
+    const form = useForm({ name: user.name, email: '', role_id: null });
+    return <Field label={auth.user.name} />;
+}
+";
+        let show = "<script lang=\"ts\">
+    interface Props { post: Post; comments: Comment[] }
+    let { post, canEdit = false }: Props = $props();
+</script>
+";
+        let legacy = "<script>\n    export let title;\n    export let items = [];\n</script>\n";
+        let result = parse_with(
+            &[
+                ("resources/js/Pages/Users/Edit.tsx", edit),
+                ("resources/js/Pages/Posts/Show.svelte", show),
+                ("resources/js/Components/List.svelte", legacy),
+            ],
+            |_| {},
+        )
+        .await;
+
+        let edit = "resources/js/Pages/Users/Edit.tsx";
+        assert_eq!(metadata(&result, edit, "framework"), "react");
+        assert_eq!(
+            metadata(&result, edit, "props"),
+            serde_json::json!(["user", "roles"])
+        );
+        assert_eq!(
+            metadata(&result, edit, "form_fields"),
+            serde_json::json!(["name", "email", "role_id"])
+        );
+        assert_eq!(
+            metadata(&result, edit, "page_props"),
+            serde_json::json!(["auth", "flash"])
+        );
+        assert_eq!(
+            metadata(&result, edit, "inertia_adapter"),
+            "@inertiajs/react"
+        );
+
+        assert_eq!(
+            metadata(&result, "resources/js/Pages/Posts/Show.svelte", "props"),
+            serde_json::json!(["post", "canEdit", "comments"])
+        );
+        assert_eq!(
+            metadata(&result, "resources/js/Components/List.svelte", "props"),
+            serde_json::json!(["title", "items"])
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use regex::Regex;
+
+use crate::models::{ParsedFile, SourceFile, SymbolType};
+use crate::parsers::common::{block_body, read_source};
+use crate::parsers::{ParserConfig, ParserResult};
+
+use super::php_parser::PhpParser;
+
+/// Namespaces Livewire discovers components in (v3, then v2)
+const LIVEWIRE_NAMESPACES: [&str; 2] = ["App\\Livewire\\", "App\\Http\\Livewire\\"];
+
+/// Lifecycle hooks Livewire calls itself, never actions of the component
+const LIFECYCLE_HOOKS: [&str; 7] = [
+    "mount", "boot", "booted", "render", "rendering", "rendered", "exception",
+];
+
+/// Parser for Livewire components (classes extending `Livewire\Component`)
+pub struct LivewireParser {
+    php_parser: PhpParser,
+    render_method_regex: Regex,
+    view_regex: Regex,
+    computed_getter_regex: Regex,
+}
+
+impl LivewireParser {
+    pub fn new() -> Self {
+        Self {
+            php_parser: PhpParser::new(),
+
+            // Match: public function render(): View {  (up to the opening brace)
+            render_method_regex: Regex::new(
+                r"function\s+render\s*\(\s*\)(?:\s*:\s*\??[\w\\]+)?\s*\{"
+            ).unwrap(),
+
+            // Match: view('livewire.counter')  or  View::make('livewire.counter')
+            view_regex: Regex::new(
+                r#"(?:view|View::make)\s*\(\s*['"]([^'"]+)['"]"#
+            ).unwrap(),
+
+            // Match: getTotalProperty  (Livewire 2 computed property getter)
+            computed_getter_regex: Regex::new(r"^get(\w+)Property$").unwrap(),
+        }
+    }
+
+    /// Parse a Livewire component file
+    pub async fn parse(
+        &self,
+        file: &SourceFile,
+        config: &ParserConfig,
+    ) -> ParserResult<ParsedFile> {
+        let (content, decode_warnings) = read_source(file, config)?;
+
+        let mut parsed = self.php_parser.parse_content(file, &content);
+        parsed.warnings.extend(decode_warnings);
+
+        // Name the component is used by in Blade (<livewire:forms.user-card>)
+        let name = parsed
+            .symbols
+            .iter()
+            .find(|s| s.symbol_type == SymbolType::Class)
+            .map(|class| component_name(&class.qualified_name));
+        if let Some(ref name) = name {
+            parsed.metadata.insert(
+                "livewire_name".to_string(),
+                serde_json::Value::String(name.clone()),
+            );
+        }
+
+        // Public properties: the component state synced with the browser
+        let properties: Vec<&str> = parsed
+            .symbols
+            .iter()
+            .filter(|s| s.symbol_type == SymbolType::Property)
+            .filter(|s| s.visibility.as_deref() == Some("public") && s.is_static != Some(true))
+            .map(|s| s.name.as_str())
+            .collect();
+        if !properties.is_empty() {
+            parsed.metadata.insert(
+                "livewire_properties".to_string(),
+                serde_json::json!(properties),
+            );
+        }
+
+        // Computed properties and actions callable from the view
+        let (computed, actions) = self.extract_methods(&parsed);
+        if !computed.is_empty() {
+            parsed.metadata.insert(
+                "livewire_computed".to_string(),
+                serde_json::json!(computed),
+            );
+        }
+        if !actions.is_empty() {
+            parsed.metadata.insert(
+                "livewire_actions".to_string(),
+                serde_json::json!(actions),
+            );
+        }
+
+        // The view rendered, linked like the views of a controller
+        let view = self.extract_view(&content, name.as_deref());
+        if let Some(view) = view {
+            parsed.metadata.insert(
+                "views_referenced".to_string(),
+                serde_json::json!([view]),
+            );
+        }
+
+        Ok(parsed)
+    }
+
+    /// Computed properties and actions among the public methods
+    ///
+    /// Computed properties are `#[Computed]` methods or Livewire 2
+    /// `getFooProperty()` getters (named `foo`). Every other public method
+    /// except lifecycle hooks (`mount`, `updatedFoo`, ...) is an action.
+    fn extract_methods(&self, parsed: &ParsedFile) -> (Vec<String>, Vec<String>) {
+        let mut computed = Vec::new();
+        let mut actions = Vec::new();
+
+        for method in parsed.symbols.iter().filter(|s| s.symbol_type == SymbolType::Method) {
+            if method.visibility.as_deref() != Some("public") || method.is_static == Some(true) {
+                continue;
+            }
+
+            let has_computed_attribute = method.attributes.iter().flatten().any(|attribute| {
+                attribute.name.rsplit('\\').next() == Some("Computed")
+            });
+            if has_computed_attribute {
+                computed.push(method.name.clone());
+            } else if let Some(caps) = self.computed_getter_regex.captures(&method.name) {
+                computed.push(lcfirst(&caps[1]));
+            } else if !is_lifecycle_hook(&method.name) && !method.name.starts_with("__") {
+                actions.push(method.name.clone());
+            }
+        }
+
+        (computed, actions)
+    }
+
+    /// View returned by `render()`
+    ///
+    /// Without a `render()` method Livewire renders `livewire.{name}`.
+    fn extract_view(&self, content: &str, name: Option<&str>) -> Option<String> {
+        match self.render_method_regex.find(content) {
+            Some(header) => block_body(content, header.end() - 1)
+                .and_then(|body| self.view_regex.captures(body))
+                .map(|caps| caps[1].to_string()),
+            None => name.map(|name| format!("livewire.{}", name)),
+        }
+    }
+}
+
+impl Default for LivewireParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Blade name of a component class (App\Livewire\Forms\UserCard -> forms.user-card)
+///
+/// Classes outside the Livewire namespaces are named after the class alone.
+pub fn component_name(qualified_name: &str) -> String {
+    let relative = LIVEWIRE_NAMESPACES
+        .iter()
+        .find_map(|namespace| qualified_name.strip_prefix(namespace))
+        .unwrap_or_else(|| qualified_name.rsplit('\\').next().unwrap_or(qualified_name));
+
+    relative.split('\\').map(kebab_case).collect::<Vec<_>>().join(".")
+}
+
+/// `Str::kebab()`: a dash before every uppercase letter but the first
+fn kebab_case(segment: &str) -> String {
+    let mut kebab = String::with_capacity(segment.len() + 4);
+    for (i, c) in segment.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            kebab.push('-');
+        }
+        kebab.extend(c.to_lowercase());
+    }
+    kebab
+}
+
+fn lcfirst(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// `mount`, `render`, ... and the property hooks (`updatingFoo`, `hydrateFoo`, ...)
+fn is_lifecycle_hook(method: &str) -> bool {
+    LIFECYCLE_HOOKS.contains(&method)
+        || ["updating", "updated", "hydrate", "dehydrate"]
+            .iter()
+            .any(|prefix| method.starts_with(prefix))
+}
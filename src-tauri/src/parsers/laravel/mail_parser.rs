@@ -0,0 +1,292 @@
+use regex::Regex;
+
+use crate::models::{ParsedFile, SourceFile};
+use crate::parsers::common::{
+    block_body, matching_bracket, matching_paren, read_source, split_top_level,
+};
+use crate::parsers::{ParserConfig, ParserResult};
+
+use super::php_parser::{expand_imported_name, unquote, PhpParser};
+
+/// Parser for Mailables and Notifications
+pub struct MailParser {
+    php_parser: PhpParser,
+    view_call_regex: Regex,
+    content_regex: Regex,
+    named_view_regex: Regex,
+    via_method_regex: Regex,
+    channel_regex: Regex,
+}
+
+impl MailParser {
+    pub fn new() -> Self {
+        Self {
+            php_parser: PhpParser::new(),
+
+            // Match: ->view('emails.invoice')  or  ->markdown(  or  ->text(  (Mailable::build, MailMessage)
+            view_call_regex: Regex::new(
+                r#"->\s*(?:view|markdown|text)\s*\(\s*['"]([^'"]+)['"]"#
+            ).unwrap(),
+
+            // Match: new Content(  (up to the parenthesis)
+            content_regex: Regex::new(r"new\s+Content\s*\(").unwrap(),
+
+            // Match: markdown: 'emails.invoice'  (a named argument of Content)
+            named_view_regex: Regex::new(
+                r#"^\s*(?:view|markdown|text|html)\s*:\s*['"]([^'"]+)['"]"#
+            ).unwrap(),
+
+            // Match: public function via($notifiable): array {  (up to the opening brace)
+            via_method_regex: Regex::new(
+                r"function\s+via\s*\([^)]*\)(?:\s*:\s*\??\w+)?\s*\{"
+            ).unwrap(),
+
+            // Match: 'mail'  or  SlackChannel::class
+            channel_regex: Regex::new(r#"'([\w\-]+)'|"([\w\-]+)"|(\\?[\w\\]+)::class"#).unwrap(),
+        }
+    }
+
+    /// Parse a Mailable or Notification file
+    pub async fn parse(
+        &self,
+        file: &SourceFile,
+        config: &ParserConfig,
+    ) -> ParserResult<ParsedFile> {
+        let (content, decode_warnings) = read_source(file, config)?;
+
+        let mut parsed = self.php_parser.parse_content(file, &content);
+        parsed.warnings.extend(decode_warnings);
+
+        // Views of the message, linked like the views of a controller
+        let views = self.extract_views(&content);
+        if !views.is_empty() {
+            parsed.metadata.insert(
+                "views_referenced".to_string(),
+                serde_json::json!(views),
+            );
+        }
+
+        // Delivery channels of a notification
+        let channels = self.extract_channels(&content, &parsed);
+        if !channels.is_empty() {
+            parsed.metadata.insert(
+                "notification_channels".to_string(),
+                serde_json::json!(channels),
+            );
+        }
+
+        Ok(parsed)
+    }
+
+    /// Views named by `->view()`/`->markdown()`/`->text()` calls and `Content` envelopes
+    ///
+    /// `new Content('emails.invoice')` takes the view first, the other formats
+    /// are named arguments (`markdown: 'emails.invoice'`).
+    fn extract_views(&self, content: &str) -> Vec<String> {
+        let mut views: Vec<String> = self
+            .view_call_regex
+            .captures_iter(content)
+            .map(|caps| caps[1].to_string())
+            .collect();
+
+        for found in self.content_regex.find_iter(content) {
+            let open = found.end() - 1;
+            let arguments = match matching_paren(content, open) {
+                Some(close) => split_top_level(&content[open + 1..close], b','),
+                None => continue,
+            };
+            for (i, argument) in arguments.iter().enumerate() {
+                if let Some(caps) = self.named_view_regex.captures(argument) {
+                    views.push(caps[1].to_string());
+                } else if i == 0 && argument.trim_start().starts_with(['\'', '"']) {
+                    views.push(unquote(argument.trim()).to_string());
+                }
+            }
+        }
+
+        let mut unique = Vec::new();
+        for view in views {
+            if !unique.contains(&view) {
+                unique.push(view);
+            }
+        }
+        unique
+    }
+
+    /// Channels returned by `via()`: names (`mail`, `database`) or channel classes
+    ///
+    /// Every channel of the array literals of `via()` is listed, whatever the
+    /// condition picking it. Channel classes are expanded through the use
+    /// statements.
+    fn extract_channels(&self, content: &str, parsed: &ParsedFile) -> Vec<String> {
+        let via_body = match self
+            .via_method_regex
+            .find(content)
+            .and_then(|header| block_body(content, header.end() - 1))
+        {
+            Some(body) => body,
+            None => return Vec::new(),
+        };
+
+        let mut channels: Vec<String> = Vec::new();
+        for caps in array_literals(via_body)
+            .into_iter()
+            .flat_map(|array| self.channel_regex.captures_iter(array))
+        {
+            let channel = match caps.get(3) {
+                Some(class) => expand_imported_name(&parsed.dependencies, class.as_str()),
+                None => caps
+                    .get(1)
+                    .or_else(|| caps.get(2))
+                    .map(|m| m.as_str().to_string())
+                    .unwrap_or_default(),
+            };
+            if !channel.is_empty() && !channels.contains(&channel) {
+                channels.push(channel);
+            }
+        }
+
+        channels
+    }
+}
+
+impl Default for MailParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Contents of the array literals of `code`, skipping index accesses (`$a['k']`)
+fn array_literals(code: &str) -> Vec<&str> {
+    let mut arrays = Vec::new();
+    let mut from = 0;
+
+    while let Some(offset) = code[from..].find('[') {
+        let open = from + offset;
+        let is_index = is_indexable(code[..open].trim_end());
+        match matching_bracket(code, open) {
+            Some(close) if !is_index => {
+                arrays.push(&code[open + 1..close]);
+                from = close + 1;
+            }
+            _ => from = open + 1,
+        }
+    }
+
+    arrays
+}
+
+/// Whether `code` ends with an expression a `[` would index (`$a`, `$a->b`, `f()`, `$a[0]`)
+///
+/// A keyword (`return [`) or an operator (`? [`) starts an array literal instead.
+fn is_indexable(code: &str) -> bool {
+    if code.ends_with([']', ')', '}']) {
+        return true;
+    }
+
+    let word = code.trim_end_matches(|c: char| c.is_alphanumeric() || c == '_');
+    word.len() != code.len() && (word.ends_with('$') || word.ends_with("->") || word.ends_with("::"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{UnifiedEdgeType, UnifiedGraph, UnifiedNode};
+    use crate::parsers::common::generate_id;
+    use crate::parsers::laravel::fixtures::graph_of;
+
+    const INVOICE_MAIL: &str = r#"<?php
+namespace App\Mail;
+
+use Illuminate\Mail\Mailable;
+use Illuminate\Mail\Mailables\Content;
+
+class InvoiceMail extends Mailable
+{
+    public function content(): Content
+    {
+        return new Content(
+            markdown: 'emails.invoice',
+            text: 'emails.invoice_plain',
+        );
+    }
+}
+"#;
+
+    const INVOICE_PAID: &str = r#"<?php
+namespace App\Notifications;
+
+use App\Channels\SmsChannel;
+use Illuminate\Notifications\Notification;
+
+class InvoicePaid extends Notification
+{
+    public function via($notifiable): array
+    {
+        return $notifiable->prefersSms()
+            ? ['database', SmsChannel::class]
+            : ['mail', 'database', 'broadcast'];
+    }
+
+    public function toMail($notifiable)
+    {
+        return (new MailMessage)->markdown('mail.invoice.paid');
+    }
+}
+"#;
+
+    async fn mail_graph() -> UnifiedGraph {
+        graph_of(&[
+            ("app/Mail/InvoiceMail.php", INVOICE_MAIL),
+            ("app/Notifications/InvoicePaid.php", INVOICE_PAID),
+            ("resources/views/emails/invoice.blade.php", "# Invoice"),
+            ("resources/views/emails/invoice_plain.blade.php", "Invoice"),
+            ("resources/views/mail/invoice/paid.blade.php", "# Paid"),
+        ])
+        .await
+    }
+
+    fn file_node<'a>(graph: &'a UnifiedGraph, path: &str) -> &'a UnifiedNode {
+        graph.find_node(&generate_id(path)).unwrap()
+    }
+
+    /// Views the file at `path` renders, by view name
+    fn rendered_views(graph: &UnifiedGraph, path: &str) -> Vec<String> {
+        let mut views: Vec<String> = graph
+            .edges
+            .iter()
+            .filter(|e| {
+                e.source == generate_id(path)
+                    && e.edge_type == UnifiedEdgeType::Custom("renders".to_string())
+            })
+            .filter_map(|e| graph.find_node(&e.target))
+            .map(|n| n.qualified_name.trim_start_matches("view:").to_string())
+            .collect();
+        views.sort();
+        views
+    }
+
+    #[tokio::test]
+    async fn markdown_mailables_render_their_views() {
+        let graph = mail_graph().await;
+
+        assert_eq!(
+            rendered_views(&graph, "app/Mail/InvoiceMail.php"),
+            vec!["emails.invoice", "emails.invoice_plain"]
+        );
+    }
+
+    #[tokio::test]
+    async fn notifications_record_every_channel_of_via() {
+        let graph = mail_graph().await;
+
+        let notification = file_node(&graph, "app/Notifications/InvoicePaid.php");
+        assert_eq!(
+            notification.metadata.extra["notification_channels"],
+            serde_json::json!(["database", "App\\Channels\\SmsChannel", "mail", "broadcast"])
+        );
+        assert_eq!(
+            rendered_views(&graph, "app/Notifications/InvoicePaid.php"),
+            vec!["mail.invoice.paid"]
+        );
+    }
+}
@@ -1,13 +1,18 @@
 use regex::Regex;
-use std::fs;
 
 use crate::models::{Dependency, ParsedFile, SourceFile, Symbol, SymbolType};
-use crate::parsers::{ParseError, ParserConfig, ParserResult};
+use crate::parsers::common::{
+    block_body, matching_paren, read_source, studly_case, unbalanced_braces_warning,
+};
+use crate::parsers::{ParserConfig, ParserResult};
+
+use super::php_parser::QUOTED_NAME_REGEX;
 
 /// Parser for Laravel database migrations
 pub struct MigrationParser {
     // Class and method patterns
     class_regex: Regex,
+    anonymous_class_regex: Regex,
     up_method_regex: Regex,
     down_method_regex: Regex,
 
@@ -20,6 +25,13 @@ pub struct MigrationParser {
     // Column definitions
     column_regex: Regex,
     column_type_regex: Regex,
+    shorthand_column_regex: Regex,
+
+    // Column alterations
+    drop_column_regex: Regex,
+    drop_morphs_regex: Regex,
+    drop_shorthand_regex: Regex,
+    rename_column_regex: Regex,
 
     // Index and key patterns
     primary_key_regex: Regex,
@@ -31,6 +43,16 @@ pub struct MigrationParser {
     timestamps_regex: Regex,
     soft_deletes_regex: Regex,
     remember_token_regex: Regex,
+
+    // Column modifiers and foreign key actions
+    column_modifier_regexes: Vec<(&'static str, Regex)>,
+    default_value_regex: Regex,
+    constrained_regex: Regex,
+    on_action_regex: Regex,
+
+    // Misc
+    timestamp_regex: Regex,
+    use_regex: Regex,
 }
 
 impl MigrationParser {
@@ -41,14 +63,19 @@ impl MigrationParser {
                 r"class\s+(\w+)\s+extends\s+Migration"
             ).unwrap(),
 
-            // Match: public function up()
+            // Match: return new class extends Migration
+            anonymous_class_regex: Regex::new(
+                r"new\s+class\s+extends\s+Migration"
+            ).unwrap(),
+
+            // Match: public function up(): void {  (up to the opening brace)
             up_method_regex: Regex::new(
-                r"(?s)public\s+function\s+up\s*\(\s*\)(?:\s*:\s*void)?\s*\{(.*?)\n\s{4}\}"
+                r"function\s+up\s*\(\s*\)(?:\s*:\s*\??\w+)?\s*\{"
             ).unwrap(),
 
-            // Match: public function down()
+            // Match: public function down(): void {  (up to the opening brace)
             down_method_regex: Regex::new(
-                r"(?s)public\s+function\s+down\s*\(\s*\)(?:\s*:\s*void)?\s*\{(.*?)\n\s{4}\}"
+                r"function\s+down\s*\(\s*\)(?:\s*:\s*\??\w+)?\s*\{"
             ).unwrap(),
 
             // Match: Schema::create('users', function (Blueprint $table) {
@@ -81,6 +108,31 @@ impl MigrationParser {
                 r"(bigIncrements|bigInteger|binary|boolean|char|date|dateTime|dateTimeTz|decimal|double|enum|float|foreignId|foreignIdFor|foreignUlid|foreignUuid|geometry|id|increments|integer|ipAddress|json|jsonb|longText|macAddress|mediumIncrements|mediumInteger|mediumText|morphs|nullableMorphs|nullableTimestamps|nullableUlidMorphs|nullableUuidMorphs|point|polygon|rememberToken|set|smallIncrements|smallInteger|softDeletes|softDeletesTz|string|text|time|timeTz|timestamp|timestampTz|timestamps|timestampsTz|tinyIncrements|tinyInteger|tinyText|unsignedBigInteger|unsignedDecimal|unsignedInteger|unsignedMediumInteger|unsignedSmallInteger|unsignedTinyInteger|ulidMorphs|uuid|uuidMorphs|year)"
             ).unwrap(),
 
+            // Match: $table->id() or $table->timestamps()  (columns named by Laravel)
+            shorthand_column_regex: Regex::new(
+                r"\$table\s*->\s*(id|timestamps|timestampsTz|nullableTimestamps|softDeletes|softDeletesTz|rememberToken)\s*\(\s*\)"
+            ).unwrap(),
+
+            // Match: $table->dropColumn('votes') or $table->dropColumn(['votes', 'avatar'])  (up to the paren)
+            drop_column_regex: Regex::new(
+                r"\$table\s*->\s*dropColumns?\s*\("
+            ).unwrap(),
+
+            // Match: $table->dropMorphs('commentable')
+            drop_morphs_regex: Regex::new(
+                r#"\$table\s*->\s*dropMorphs\s*\(\s*['"](\w+)['"]"#
+            ).unwrap(),
+
+            // Match: $table->dropTimestamps() or $table->dropSoftDeletes()
+            drop_shorthand_regex: Regex::new(
+                r"\$table\s*->\s*drop(Timestamps|TimestampsTz|SoftDeletes|SoftDeletesTz|RememberToken)\s*\("
+            ).unwrap(),
+
+            // Match: $table->renameColumn('from', 'to')
+            rename_column_regex: Regex::new(
+                r#"\$table\s*->\s*renameColumn\s*\(\s*['"](\w+)['"]\s*,\s*['"](\w+)['"]"#
+            ).unwrap(),
+
             // Match: $table->primary('id') or $table->primary(['id', 'name'])
             primary_key_regex: Regex::new(
                 r#"\$table\s*->\s*primary\s*\(\s*(?:['"](\w+)['"]|\[([^\]]+)\])"#
@@ -115,21 +167,69 @@ impl MigrationParser {
             remember_token_regex: Regex::new(
                 r"\$table\s*->\s*rememberToken\s*\("
             ).unwrap(),
+
+            // Match: ->nullable(), ->unique(), ... (one regex per modifier, in output order)
+            column_modifier_regexes: [
+                "nullable",
+                "unique",
+                "primary",
+                "unsigned",
+                "autoIncrement",
+                "index",
+                "useCurrent",
+                "useCurrentOnUpdate",
+                "comment",
+                "after",
+                "first",
+                "change",
+            ]
+            .into_iter()
+            .map(|name| (name, Regex::new(&format!(r"->\s*{}\s*\(", name)).unwrap()))
+            .collect(),
+
+            // Match: ->default('value') or ->default(0)
+            default_value_regex: Regex::new(
+                r"->\s*default\s*\(\s*([^)]+)\s*\)"
+            ).unwrap(),
+
+            // Match: $table->foreignId('user_id')->constrained() or ->constrained('users'),
+            // after other modifiers too: ->foreignId('user_id')->unique()->constrained()
+            constrained_regex: Regex::new(
+                r#"\$table\s*->\s*foreignId\s*\(\s*['"](\w+)['"]\s*\)(?:\s*->\s*\w+\s*\([^)]*\))*?\s*->\s*constrained\s*\(\s*(?:['"](\w+)['"])?"#
+            ).unwrap(),
+
+            // Match: ->onDelete('cascade') or ->onUpdate('restrict')
+            on_action_regex: Regex::new(
+                r#"->\s*(onDelete|onUpdate|cascadeOnDelete|cascadeOnUpdate)\s*\(\s*['"](\w+)['"]"#
+            ).unwrap(),
+
+            // Match: 2024_01_15_123456_create_users_table.php
+            timestamp_regex: Regex::new(r"^(\d{4}_\d{2}_\d{2}_\d{6})").unwrap(),
+
+            // Match: use Illuminate\Database\Migrations\Migration;
+            use_regex: Regex::new(r"(?m)^\s*use\s+([\w\\]+)(?:\s+as\s+(\w+))?\s*;").unwrap(),
         }
     }
 
     pub async fn parse(
         &self,
         file: &SourceFile,
-        _config: &ParserConfig,
+        config: &ParserConfig,
     ) -> ParserResult<ParsedFile> {
-        let content = fs::read_to_string(&file.absolute_path)
-            .map_err(ParseError::Io)?;
+        let (content, decode_warnings) = read_source(file, config)?;
 
         let mut parsed = ParsedFile::new(file.clone());
+        parsed.warnings.extend(decode_warnings);
 
-        // Extract migration class name
-        let class_name = self.extract_class_name(&content);
+        // Extract migration class name (synthesized for anonymous classes)
+        let mut class_name = self.extract_class_name(&content);
+        if class_name.is_none() && self.anonymous_class_regex.is_match(&content) {
+            class_name = Some(self.class_name_from_filename(&file.name));
+            parsed.metadata.insert(
+                "is_anonymous_class".to_string(),
+                serde_json::Value::Bool(true),
+            );
+        }
         if let Some(ref name) = class_name {
             parsed.add_symbol(Symbol {
                 name: name.clone(),
@@ -140,8 +240,14 @@ impl MigrationParser {
                 is_static: None,
                 extends: Some("Migration".to_string()),
                 implements: None,
+                parameters: None,
+                return_type: None,
+                attributes: None,
                 line_start: None,
                 line_end: None,
+                section: None,
+                backing_type: None,
+                enum_cases: None,
             });
 
             parsed.metadata.insert(
@@ -165,6 +271,15 @@ impl MigrationParser {
                 "up_operations".to_string(),
                 serde_json::json!(up_operations),
             );
+
+            // Table and column changes in order, folded into final schemas later
+            let schema_changes = self.extract_schema_changes(&up_content);
+            if !schema_changes.is_empty() {
+                parsed.metadata.insert(
+                    "schema_changes".to_string(),
+                    serde_json::json!(schema_changes),
+                );
+            }
         }
 
         // Parse down() method
@@ -176,6 +291,13 @@ impl MigrationParser {
             );
         }
 
+        if !parsed.metadata.contains_key("up_operations")
+            && !parsed.metadata.contains_key("down_operations")
+        {
+            parsed.add_warning(format!("Neither up() nor down() found in migration {}", file.path));
+        }
+        parsed.warnings.extend(unbalanced_braces_warning(&content, &file.path));
+
         // Extract tables created
         let tables_created = self.extract_created_tables(&content);
         if !tables_created.is_empty() {
@@ -260,24 +382,36 @@ impl MigrationParser {
             .map(|m| m.as_str().to_string())
     }
 
-    fn extract_migration_timestamp(&self, filename: &str) -> Option<String> {
-        let timestamp_regex = Regex::new(r"^(\d{4}_\d{2}_\d{2}_\d{6})").ok()?;
+    /// Class name Laravel would derive from the filename
+    /// (2024_01_01_000000_create_users_table.php -> CreateUsersTable)
+    fn class_name_from_filename(&self, filename: &str) -> String {
+        let stem = filename.trim_end_matches(".php");
+        let stem = match self.timestamp_regex.find(stem) {
+            Some(m) => stem[m.end()..].trim_start_matches('_'),
+            None => stem,
+        };
 
-        timestamp_regex.captures(filename)
+        studly_case(stem)
+    }
+
+    fn extract_migration_timestamp(&self, filename: &str) -> Option<String> {
+        self.timestamp_regex.captures(filename)
             .and_then(|caps| caps.get(1))
             .map(|m| m.as_str().to_string())
     }
 
     fn extract_up_method(&self, content: &str) -> Option<String> {
-        self.up_method_regex.captures(content)
-            .and_then(|caps| caps.get(1))
-            .map(|m| m.as_str().to_string())
+        self.extract_method_body(&self.up_method_regex, content)
     }
 
     fn extract_down_method(&self, content: &str) -> Option<String> {
-        self.down_method_regex.captures(content)
-            .and_then(|caps| caps.get(1))
-            .map(|m| m.as_str().to_string())
+        self.extract_method_body(&self.down_method_regex, content)
+    }
+
+    /// Body of the method whose header (ending with `{`) matches `header_regex`
+    fn extract_method_body(&self, header_regex: &Regex, content: &str) -> Option<String> {
+        let header = header_regex.find(content)?;
+        block_body(content, header.end() - 1).map(|body| body.to_string())
     }
 
     fn parse_schema_operations(&self, method_content: &str) -> Vec<serde_json::Value> {
@@ -327,6 +461,122 @@ impl MigrationParser {
         operations
     }
 
+    /// Schema changes of a migration method, in execution order
+    ///
+    /// `create` and `modify` changes carry the column operations of their
+    /// blueprint closure (see `extract_column_changes`).
+    fn extract_schema_changes(&self, method_content: &str) -> Vec<serde_json::Value> {
+        let mut changes: Vec<(usize, serde_json::Value)> = Vec::new();
+
+        for (regex, change_type) in [
+            (&self.create_table_regex, "create"),
+            (&self.table_modify_regex, "modify"),
+        ] {
+            for caps in regex.captures_iter(method_content) {
+                let start = caps.get(0).map(|m| m.start()).unwrap_or(0);
+                let blueprint = method_content[start..]
+                    .find('(')
+                    .map(|p| start + p)
+                    .and_then(|open| matching_paren(method_content, open).map(|close| (open, close)))
+                    .map(|(open, close)| &method_content[open + 1..close])
+                    .unwrap_or("");
+
+                changes.push((
+                    start,
+                    serde_json::json!({
+                        "type": change_type,
+                        "table": &caps[1],
+                        "columns": self.extract_column_changes(blueprint),
+                    }),
+                ));
+            }
+        }
+
+        for caps in self.drop_table_regex.captures_iter(method_content) {
+            let start = caps.get(0).map(|m| m.start()).unwrap_or(0);
+            changes.push((start, serde_json::json!({ "type": "drop", "table": &caps[1] })));
+        }
+
+        for caps in self.rename_table_regex.captures_iter(method_content) {
+            let start = caps.get(0).map(|m| m.start()).unwrap_or(0);
+            changes.push((
+                start,
+                serde_json::json!({ "type": "rename", "from": &caps[1], "to": &caps[2] }),
+            ));
+        }
+
+        changes.sort_by_key(|(start, _)| *start);
+        changes.into_iter().map(|(_, change)| change).collect()
+    }
+
+    /// Column operations of a blueprint closure, in order
+    ///
+    /// `{"op": "add", "column": {...}}` (a `->change()`d column too),
+    /// `{"op": "drop", "name"}` and `{"op": "rename", "from", "to"}`.
+    fn extract_column_changes(&self, blueprint: &str) -> Vec<serde_json::Value> {
+        let mut operations: Vec<(usize, serde_json::Value)> = Vec::new();
+
+        for caps in self.column_regex.captures_iter(blueprint) {
+            if !self.is_valid_column_type(&caps[1]) {
+                continue;
+            }
+            let start = caps.get(0).map(|m| m.start()).unwrap_or(0);
+            for column in self.column_definitions(blueprint, start, &caps[1], &caps[2]) {
+                operations.push((start, serde_json::json!({ "op": "add", "column": column })));
+            }
+        }
+
+        for caps in self.shorthand_column_regex.captures_iter(blueprint) {
+            let start = caps.get(0).map(|m| m.start()).unwrap_or(0);
+            for name in shorthand_columns(&caps[1]) {
+                let mut column = self.column_definition(blueprint, start, &caps[1], name);
+                // Laravel makes these timestamps nullable
+                if *name != "id" && *name != "remember_token" {
+                    column["nullable"] = serde_json::Value::Bool(true);
+                }
+                operations.push((start, serde_json::json!({ "op": "add", "column": column })));
+            }
+        }
+
+        for found in self.drop_column_regex.find_iter(blueprint) {
+            let open = found.end() - 1;
+            let arguments = matching_paren(blueprint, open)
+                .map(|close| &blueprint[open + 1..close])
+                .unwrap_or("");
+            for caps in QUOTED_NAME_REGEX.captures_iter(arguments) {
+                operations.push((found.start(), serde_json::json!({ "op": "drop", "name": &caps[1] })));
+            }
+        }
+
+        for caps in self.drop_morphs_regex.captures_iter(blueprint) {
+            let start = caps.get(0).map(|m| m.start()).unwrap_or(0);
+            for suffix in ["id", "type"] {
+                let name = format!("{}_{}", &caps[1], suffix);
+                operations.push((start, serde_json::json!({ "op": "drop", "name": name })));
+            }
+        }
+
+        for caps in self.drop_shorthand_regex.captures_iter(blueprint) {
+            let start = caps.get(0).map(|m| m.start()).unwrap_or(0);
+            // dropSoftDeletes drops what softDeletes added
+            let added_by = format!("{}{}", caps[1][..1].to_lowercase(), &caps[1][1..]);
+            for name in shorthand_columns(&added_by) {
+                operations.push((start, serde_json::json!({ "op": "drop", "name": name })));
+            }
+        }
+
+        for caps in self.rename_column_regex.captures_iter(blueprint) {
+            let start = caps.get(0).map(|m| m.start()).unwrap_or(0);
+            operations.push((
+                start,
+                serde_json::json!({ "op": "rename", "from": &caps[1], "to": &caps[2] }),
+            ));
+        }
+
+        operations.sort_by_key(|(start, _)| *start);
+        operations.into_iter().map(|(_, operation)| operation).collect()
+    }
+
     fn extract_created_tables(&self, content: &str) -> Vec<String> {
         self.create_table_regex.captures_iter(content)
             .filter_map(|caps| caps.get(1))
@@ -360,26 +610,66 @@ impl MigrationParser {
                 continue;
             }
 
-            // Get the full line for modifier extraction
             let match_start = caps.get(0).map(|m| m.start()).unwrap_or(0);
-            let line_context = self.get_line_context(content, match_start);
-
-            let modifiers = self.extract_column_modifiers(&line_context);
-            let nullable = modifiers.contains(&"nullable".to_string());
-            let default = self.extract_default_value(&line_context);
-
-            columns.push(serde_json::json!({
-                "name": column_name,
-                "type": column_type,
-                "nullable": nullable,
-                "default": default,
-                "modifiers": modifiers
-            }));
+            columns.extend(self.column_definitions(content, match_start, column_type, column_name));
         }
 
         columns
     }
 
+    /// Definitions of the columns a column method at `start` adds
+    ///
+    /// `morphs('commentable')` and its variants add `commentable_id` and
+    /// `commentable_type`, both flagged `polymorphic`.
+    fn column_definitions(
+        &self,
+        content: &str,
+        start: usize,
+        column_type: &str,
+        column_name: &str,
+    ) -> Vec<serde_json::Value> {
+        let id_type = match morph_id_type(column_type) {
+            Some(id_type) => id_type,
+            None => return vec![self.column_definition(content, start, column_type, column_name)],
+        };
+
+        [("id", id_type), ("type", "string")]
+            .iter()
+            .map(|(suffix, implied_type)| {
+                let name = format!("{}_{}", column_name, suffix);
+                let mut column = self.column_definition(content, start, implied_type, &name);
+                column["polymorphic"] = serde_json::Value::Bool(true);
+                if column_type.starts_with("nullable") {
+                    column["nullable"] = serde_json::Value::Bool(true);
+                }
+                column
+            })
+            .collect()
+    }
+
+    /// Definition of the column declared at `start`, modifiers read from its line
+    fn column_definition(
+        &self,
+        content: &str,
+        start: usize,
+        column_type: &str,
+        column_name: &str,
+    ) -> serde_json::Value {
+        let line_context = self.get_line_context(content, start);
+
+        let modifiers = self.extract_column_modifiers(&line_context);
+        let nullable = modifiers.contains(&"nullable".to_string());
+        let default = self.extract_default_value(&line_context);
+
+        serde_json::json!({
+            "name": column_name,
+            "type": column_type,
+            "nullable": nullable,
+            "default": default,
+            "modifiers": modifiers
+        })
+    }
+
     fn is_valid_column_type(&self, column_type: &str) -> bool {
         self.column_type_regex.is_match(column_type)
     }
@@ -391,36 +681,15 @@ impl MigrationParser {
     }
 
     fn extract_column_modifiers(&self, line: &str) -> Vec<String> {
-        let mut modifiers = Vec::new();
-
-        let modifier_patterns = [
-            ("nullable", r"->\s*nullable\s*\("),
-            ("unique", r"->\s*unique\s*\("),
-            ("primary", r"->\s*primary\s*\("),
-            ("unsigned", r"->\s*unsigned\s*\("),
-            ("autoIncrement", r"->\s*autoIncrement\s*\("),
-            ("index", r"->\s*index\s*\("),
-            ("useCurrent", r"->\s*useCurrent\s*\("),
-            ("useCurrentOnUpdate", r"->\s*useCurrentOnUpdate\s*\("),
-            ("comment", r"->\s*comment\s*\("),
-            ("after", r"->\s*after\s*\("),
-            ("first", r"->\s*first\s*\("),
-            ("change", r"->\s*change\s*\("),
-        ];
-
-        for (name, pattern) in modifier_patterns {
-            if Regex::new(pattern).map(|r| r.is_match(line)).unwrap_or(false) {
-                modifiers.push(name.to_string());
-            }
-        }
-
-        modifiers
+        self.column_modifier_regexes
+            .iter()
+            .filter(|(_, regex)| regex.is_match(line))
+            .map(|(name, _)| name.to_string())
+            .collect()
     }
 
     fn extract_default_value(&self, line: &str) -> Option<String> {
-        let default_regex = Regex::new(r"->\s*default\s*\(\s*([^)]+)\s*\)").ok()?;
-
-        default_regex.captures(line)
+        self.default_value_regex.captures(line)
             .and_then(|caps| caps.get(1))
             .map(|m| m.as_str().trim().to_string())
     }
@@ -450,11 +719,7 @@ impl MigrationParser {
         }
 
         // Also check for foreignId()->constrained() pattern
-        let constrained_regex = Regex::new(
-            r#"\$table\s*->\s*foreignId\s*\(\s*['"](\w+)['"]\s*\)\s*->\s*constrained\s*\(\s*(?:['"](\w+)['"])?"#
-        ).unwrap();
-
-        for caps in constrained_regex.captures_iter(content) {
+        for caps in self.constrained_regex.captures_iter(content) {
             let column = caps.get(1).map(|m| m.as_str()).unwrap_or("");
             let table = caps.get(2).map(|m| m.as_str().to_string());
 
@@ -487,11 +752,9 @@ impl MigrationParser {
     }
 
     fn extract_on_action(&self, context: &str, action: &str) -> Option<String> {
-        let pattern = format!(r#"->\s*{}\s*\(\s*['"](\w+)['"]"#, action);
-        let regex = Regex::new(&pattern).ok()?;
-
-        regex.captures(context)
-            .and_then(|caps| caps.get(1))
+        self.on_action_regex.captures_iter(context)
+            .find(|caps| caps.get(1).map(|m| m.as_str()) == Some(action))
+            .and_then(|caps| caps.get(2))
             .map(|m| m.as_str().to_string())
     }
 
@@ -547,9 +810,7 @@ impl MigrationParser {
     }
 
     fn extract_use_statements(&self, content: &str, parsed: &mut ParsedFile) {
-        let use_regex = Regex::new(r"(?m)^\s*use\s+([\w\\]+)(?:\s+as\s+(\w+))?\s*;").unwrap();
-
-        for caps in use_regex.captures_iter(content) {
+        for caps in self.use_regex.captures_iter(content) {
             let target = caps.get(1).map(|m| m.as_str().to_string()).unwrap_or_default();
             let alias = caps.get(2).map(|m| m.as_str().to_string());
 
@@ -566,8 +827,106 @@ impl MigrationParser {
     }
 }
 
+/// Columns added by a shorthand column method (`timestamps` -> created_at, updated_at)
+fn shorthand_columns(method: &str) -> &'static [&'static str] {
+    match method {
+        "id" => &["id"],
+        "timestamps" | "timestampsTz" | "nullableTimestamps" => &["created_at", "updated_at"],
+        "softDeletes" | "softDeletesTz" => &["deleted_at"],
+        "rememberToken" => &["remember_token"],
+        _ => &[],
+    }
+}
+
+/// Type of the `{name}_id` column added by a morphs method, `None` for other methods
+fn morph_id_type(method: &str) -> Option<&'static str> {
+    match method {
+        "morphs" | "nullableMorphs" => Some("unsignedBigInteger"),
+        "uuidMorphs" | "nullableUuidMorphs" => Some("uuid"),
+        "ulidMorphs" | "nullableUlidMorphs" => Some("ulid"),
+        _ => None,
+    }
+}
+
 impl Default for MigrationParser {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::laravel::fixtures::parse_with;
+
+    const TABBED: &str = "<?php
+
+return new class extends Migration
+{
+\tpublic function up(): void
+\t{
+\t\tSchema::create('tags', function (Blueprint $table) {
+\t\t\t$table->id();
+\t\t\t$table->string('name');
+\t\t});
+\t}
+
+\tpublic function down(): void
+\t{
+\t\tSchema::dropIfExists('tags');
+\t}
+};
+";
+
+    const COMPACT: &str = "<?php
+return new class extends Migration {
+    public function up(): void { Schema::table('posts', function (Blueprint $t) { \
+$t->boolean('pinned'); }); }
+    public function down(): void { Schema::rename('posts', 'articles'); }
+};
+";
+
+    #[tokio::test]
+    async fn up_and_down_bodies_are_found_with_tabs_and_on_one_line() {
+        let result = parse_with(
+            &[
+                (
+                    "database/migrations/2024_01_01_000000_create_tags_table.php",
+                    TABBED,
+                ),
+                (
+                    "database/migrations/2024_01_02_000000_pin_posts.php",
+                    COMPACT,
+                ),
+            ],
+            |_| {},
+        )
+        .await;
+        let operations = |name: &str| {
+            let file = result
+                .files
+                .iter()
+                .find(|f| f.source.name.contains(name))
+                .unwrap();
+            assert!(file.warnings.is_empty(), "{:?}", file.warnings);
+            (
+                file.metadata["up_operations"].clone(),
+                file.metadata["down_operations"].clone(),
+            )
+        };
+
+        assert_eq!(
+            operations("create_tags_table"),
+            (
+                serde_json::json!([{ "type": "create", "table": "tags" }]),
+                serde_json::json!([{ "type": "drop", "table": "tags" }]),
+            )
+        );
+        assert_eq!(
+            operations("pin_posts"),
+            (
+                serde_json::json!([{ "type": "modify", "table": "posts" }]),
+                serde_json::json!([{ "type": "rename", "from": "posts", "to": "articles" }]),
+            )
+        );
+    }
+}
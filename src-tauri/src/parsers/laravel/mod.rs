@@ -8,7 +8,20 @@ mod model_parser;
 mod route_parser;
 mod migration_parser;
 mod blade_parser;
+mod command_parser;
 mod inertia_parser;
+mod graphql_parser;
+mod livewire_parser;
+mod mail_parser;
+mod provider_parser;
+mod seeder_parser;
+mod factory_parser;
+mod config_parser;
+mod table_schema;
+mod relationship_inverse;
+mod composer;
+#[cfg(test)]
+pub(crate) mod fixtures;
 
 pub use parser::LaravelParser;
 pub use php_parser::PhpParser;
@@ -16,5 +29,15 @@ pub use controller_parser::ControllerParser;
 pub use model_parser::ModelParser;
 pub use route_parser::RouteParser;
 pub use migration_parser::MigrationParser;
-pub use blade_parser::BladeParser;
+pub use blade_parser::{BladeParser, BLADE_RELATIVE_INCLUDES_OPTION};
+pub use command_parser::CommandParser;
 pub use inertia_parser::InertiaParser;
+pub use graphql_parser::GraphqlParser;
+pub use livewire_parser::LivewireParser;
+pub use mail_parser::MailParser;
+pub use provider_parser::ProviderParser;
+pub use seeder_parser::SeederParser;
+pub use factory_parser::FactoryParser;
+pub use config_parser::ConfigParser;
+pub use table_schema::{table_schemas, TableSchema};
+pub use composer::framework_major_version;
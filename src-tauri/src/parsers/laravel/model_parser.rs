@@ -1,8 +1,16 @@
 use regex::Regex;
-use std::fs;
+use std::collections::HashSet;
 
 use crate::models::{Dependency, ParsedFile, SourceFile, Symbol, SymbolType};
-use crate::parsers::{ParseError, ParserConfig, ParserResult};
+use crate::parsers::common::{
+    blank_comments, blank_comments_and_strings, block_body, matching_bracket, matching_paren,
+    read_source, snake_case, split_top_level, unbalanced_braces_warning,
+};
+use crate::parsers::{ParserConfig, ParserResult};
+
+use super::php_parser::{
+    attributes_before, expand_imported_name, function_signature, qualify_name, CLASS_REF_REGEX,
+};
 
 /// Parser for Laravel Eloquent Models
 pub struct ModelParser {
@@ -16,6 +24,22 @@ pub struct ModelParser {
     accessor_regex: Regex,
     mutator_regex: Regex,
     cast_attribute_regex: Regex,
+    array_property_regex: Regex,
+    timestamps_regex: Regex,
+    incrementing_regex: Regex,
+    relation_method_regex: Regex,
+    related_class_regex: Regex,
+    casts_property_regex: Regex,
+    casts_pair_regex: Regex,
+    casts_method_regex: Regex,
+    casts_method_pair_regex: Regex,
+    trait_use_regex: Regex,
+    group_use_regex: Regex,
+    table_regex: Regex,
+    primary_key_regex: Regex,
+    dispatches_events_regex: Regex,
+    dispatches_events_entry_regex: Regex,
+    observe_call_regex: Regex,
 }
 
 impl ModelParser {
@@ -24,18 +48,18 @@ impl ModelParser {
             namespace_regex: Regex::new(r"(?m)^\s*namespace\s+([\w\\]+)\s*;").unwrap(),
             use_regex: Regex::new(r"(?m)^\s*use\s+([\w\\]+)(?:\s+as\s+(\w+))?\s*;").unwrap(),
             class_regex: Regex::new(
-                r"(?m)^\s*class\s+(\w+)(?:\s+extends\s+([\w\\]+))?(?:\s+implements\s+([\w\\,\s]+))?"
+                r"(?m)^\s*(?:(abstract|final)\s+)?class\s+(\w+)(?:\s+extends\s+([\w\\]+))?(?:\s+implements\s+([\w\\,\s]+))?"
             ).unwrap(),
             // Match: protected $fillable = [...]; or public $timestamps = false;
             property_regex: Regex::new(
                 r"(?m)^\s*(public|protected|private)\s+(?:(static)\s+)?(?:\??\w+\s+)?\$(\w+)\s*=?"
             ).unwrap(),
             method_regex: Regex::new(
-                r"(?m)^\s*(public|protected|private)\s+(?:(static)\s+)?function\s+(\w+)\s*\(([^)]*)\)"
+                r"(?m)^\s*(?:#\[[^\n]*\]\s*)?(public|protected|private)\s+(?:(static)\s+)?function\s+(\w+)\s*\("
             ).unwrap(),
             // Match relationship methods: return $this->hasMany(Post::class);
             relation_regex: Regex::new(
-                r"\$this\s*->\s*(hasOne|hasMany|belongsTo|belongsToMany|hasManyThrough|hasOneThrough|morphOne|morphMany|morphTo|morphToMany|morphedByMany)\s*\(\s*([^)]+)\)"
+                r"\$this\s*->\s*(hasOne|hasMany|belongsTo|belongsToMany|hasManyThrough|hasOneThrough|morphOne|morphMany|morphTo|morphToMany|morphedByMany)\s*\(\s*([^)]*)\)"
             ).unwrap(),
             // Match: public function scopeActive($query)
             scope_regex: Regex::new(
@@ -53,21 +77,72 @@ impl ModelParser {
             cast_attribute_regex: Regex::new(
                 r"(?m)protected\s+function\s+(\w+)\s*\(\s*\)\s*:\s*Attribute"
             ).unwrap(),
+            // Match: protected $fillable = ['name', 'email'];
+            array_property_regex: Regex::new(
+                r"\$(fillable|guarded|hidden|visible|appends|with|dates)\s*=\s*\[([^\]]*)\]"
+            ).unwrap(),
+            // Match: public $timestamps = false;
+            timestamps_regex: Regex::new(r"\$timestamps\s*=\s*(true|false)").unwrap(),
+            // Match: public $incrementing = false;
+            incrementing_regex: Regex::new(r"\$incrementing\s*=\s*(true|false)").unwrap(),
+            // Match: public function posts(): HasMany { ... }
+            relation_method_regex: Regex::new(
+                r"public\s+function\s+(\w+)\s*\([^)]*\)\s*(?::\s*\??[\w\\]+)?\s*\{"
+            ).unwrap(),
+            // Match: Model::class or 'App\Models\Model' or "App\Models\Model"
+            related_class_regex: Regex::new(r#"([A-Z]\w*)::class|['"]([^'"]+)['"]"#).unwrap(),
+            // Match: protected $casts = ['field' => 'type', ...];
+            casts_property_regex: Regex::new(r"\$casts\s*=\s*\[([^\]]+)\]").unwrap(),
+            // Match: 'field' => 'type' or 'field' => Status::class
+            casts_pair_regex: Regex::new(
+                r#"['"](\w+)['"]\s*=>\s*(?:['"]([^'"]+)['"]|(\\?[\w\\]+::class))"#
+            ).unwrap(),
+            // Match: protected function casts(): array { return [...]; } (Laravel 9+)
+            casts_method_regex: Regex::new(
+                r"(?s)protected\s+function\s+casts\s*\(\s*\)\s*:\s*array\s*\{[^}]*return\s*\[([^\]]+)\]"
+            ).unwrap(),
+            // Match: 'field' => 'type' or 'field' => Status::class
+            casts_method_pair_regex: Regex::new(r#"['"](\w+)['"]\s*=>\s*([^,\]]+)"#).unwrap(),
+            // Match: use HasFactory, SoftDeletes, Notifiable;
+            trait_use_regex: Regex::new(
+                r"(?m)^\s*use\s+((?:[\w\\]+\s*,\s*)*[\w\\]+)\s*;"
+            ).unwrap(),
+            // Match: use App\Models\Concerns\{HasUuid, Sluggable};
+            group_use_regex: Regex::new(
+                r"(?m)^\s*use\s+([\w\\]+)\\\{([^}]+)\}\s*;"
+            ).unwrap(),
+            // Match: protected $table = 'users';
+            table_regex: Regex::new(r#"\$table\s*=\s*['"]([^'"]+)['"]"#).unwrap(),
+            // Match: protected $primaryKey = 'uuid';
+            primary_key_regex: Regex::new(r#"\$primaryKey\s*=\s*['"]([^'"]+)['"]"#).unwrap(),
+            // Match: protected $dispatchesEvents = [  (up to the opening bracket)
+            dispatches_events_regex: Regex::new(
+                r"(?:protected|public)\s+(?:array\s+)?\$dispatchesEvents\s*=\s*\["
+            ).unwrap(),
+            // Match: 'saved' => UserSaved::class
+            dispatches_events_entry_regex: Regex::new(
+                r#"['"](\w+)['"]\s*=>\s*(\\?[\w\\]+)::class"#
+            ).unwrap(),
+            // Match: static::observe(  (up to the parenthesis, in booted())
+            observe_call_regex: Regex::new(r"\b(?:static|self)::observe\s*\(").unwrap(),
         }
     }
 
     pub async fn parse(
         &self,
         file: &SourceFile,
-        _config: &ParserConfig,
+        config: &ParserConfig,
     ) -> ParserResult<ParsedFile> {
-        let content = fs::read_to_string(&file.absolute_path)
-            .map_err(ParseError::Io)?;
+        let (content, decode_warnings) = read_source(file, config)?;
 
         let mut parsed = ParsedFile::new(file.clone());
+        parsed.warnings.extend(decode_warnings);
+
+        // Declarations are matched on the code alone (see PhpParser::parse_content)
+        let code = blank_comments_and_strings(&content);
 
         // Extract namespace
-        let namespace = self.extract_namespace(&content);
+        let namespace = self.extract_namespace(&code);
         if let Some(ref ns) = namespace {
             parsed.metadata.insert(
                 "namespace".to_string(),
@@ -76,10 +151,14 @@ impl ModelParser {
         }
 
         // Extract use statements
-        self.extract_use_statements(&content, &mut parsed);
+        self.extract_use_statements(&code, &mut parsed);
 
         // Extract model class
-        self.extract_model_class(&content, &namespace, &mut parsed);
+        self.extract_model_class(&content, &code, &namespace, &mut parsed);
+        if !parsed.symbols.iter().any(|s| s.symbol_type == SymbolType::Class) {
+            parsed.add_warning(format!("No model class found in {}", file.path));
+        }
+        parsed.warnings.extend(unbalanced_braces_warning(&content, &file.path));
 
         // Extract model properties (fillable, guarded, etc.)
         let properties = self.extract_model_properties(&content);
@@ -92,8 +171,8 @@ impl ModelParser {
             }
         }
 
-        // Extract relationships
-        let relationships = self.extract_relationships(&content);
+        // Extract relationships (related classes may be quoted, only comments are blanked)
+        let relationships = self.extract_relationships(&blank_comments(&content));
         if !relationships.is_empty() {
             parsed.metadata.insert(
                 "relationships".to_string(),
@@ -131,6 +210,15 @@ impl ModelParser {
         // Extract casts
         let casts = self.extract_casts(&content);
         if let serde_json::Value::Object(ref map) = casts {
+            // Classes the attributes are cast to (enums, value objects, cast classes)
+            let cast_classes = self.extract_cast_classes(map, &parsed.dependencies);
+            if !cast_classes.is_empty() {
+                parsed.metadata.insert(
+                    "cast_classes".to_string(),
+                    serde_json::json!(cast_classes),
+                );
+            }
+
             if !map.is_empty() {
                 parsed.metadata.insert(
                     "casts".to_string(),
@@ -140,7 +228,7 @@ impl ModelParser {
         }
 
         // Check for traits (SoftDeletes, HasFactory, etc.)
-        let traits = self.extract_traits_used(&content);
+        let traits = self.extract_traits_used(&code);
         if !traits.is_empty() {
             parsed.metadata.insert(
                 "traits_used".to_string(),
@@ -148,12 +236,42 @@ impl ModelParser {
             );
         }
 
-        // Extract table name if specified
-        if let Some(table) = self.extract_table_name(&content) {
+        // Extract the events fired on lifecycle events ($dispatchesEvents)
+        let dispatched_events = self.extract_dispatched_events(&blank_comments(&content), &parsed);
+        if !dispatched_events.is_empty() {
+            parsed.metadata.insert(
+                "dispatched_events".to_string(),
+                serde_json::json!(dispatched_events),
+            );
+        }
+
+        // Extract the observers registered by the model itself
+        let observers = self.extract_observers(&code, &parsed);
+        if !observers.is_empty() {
+            parsed.metadata.insert(
+                "observed_by".to_string(),
+                serde_json::json!(observers),
+            );
+        }
+
+        // Extract table name, Laravel's convention applies when none is specified
+        let table = match self.extract_table_name(&content) {
+            Some(table) => Some((table, "property")),
+            None => parsed
+                .symbols
+                .iter()
+                .find(|s| s.symbol_type == SymbolType::Class)
+                .map(|class| (self.table_name_for(&class.name), "convention")),
+        };
+        if let Some((table, source)) = table {
             parsed.metadata.insert(
                 "table".to_string(),
                 serde_json::Value::String(table),
             );
+            parsed.metadata.insert(
+                "table_source".to_string(),
+                serde_json::Value::String(source.to_string()),
+            );
         }
 
         // Extract primary key if specified
@@ -165,7 +283,7 @@ impl ModelParser {
         }
 
         // Extract methods
-        self.extract_methods(&content, &mut parsed);
+        self.extract_methods(&content, &code, &mut parsed);
 
         Ok(parsed)
     }
@@ -197,13 +315,15 @@ impl ModelParser {
     fn extract_model_class(
         &self,
         content: &str,
+        code: &str,
         namespace: &Option<String>,
         parsed: &mut ParsedFile,
     ) {
-        if let Some(caps) = self.class_regex.captures(content) {
-            let class_name = caps.get(1).map(|m| m.as_str().to_string()).unwrap_or_default();
-            let extends = caps.get(2).map(|m| m.as_str().to_string());
-            let implements = caps.get(3).map(|m| {
+        for caps in self.class_regex.captures_iter(code) {
+            let modifier = caps.get(1).map(|m| m.as_str());
+            let class_name = caps.get(2).map(|m| m.as_str().to_string()).unwrap_or_default();
+            let extends = caps.get(3).map(|m| m.as_str().to_string());
+            let implements = caps.get(4).map(|m| {
                 m.as_str()
                     .split(',')
                     .map(|s| s.trim().to_string())
@@ -212,22 +332,28 @@ impl ModelParser {
             });
 
             if !class_name.is_empty() {
-                let qualified_name = match namespace {
-                    Some(ns) => format!("{}\\{}", ns, class_name),
-                    None => class_name.clone(),
-                };
+                let start = caps.get(0).map_or(0, |m| m.start());
+                let qualified_name = qualify_name(code, start, namespace, &class_name);
+
+                let attributes = attributes_before(content, start);
 
                 parsed.add_symbol(Symbol {
                     name: class_name,
                     qualified_name,
                     symbol_type: SymbolType::Class,
                     visibility: Some("public".to_string()),
-                    is_abstract: None,
+                    is_abstract: Some(modifier == Some("abstract")),
                     is_static: None,
                     extends,
                     implements,
+                    parameters: None,
+                    return_type: None,
+                    attributes,
                     line_start: None,
                     line_end: None,
+                    section: None,
+                    backing_type: None,
+                    enum_cases: None,
                 });
             }
         }
@@ -272,15 +398,13 @@ impl ModelParser {
         }
 
         // Check for timestamps
-        let timestamps_regex = Regex::new(r"\$timestamps\s*=\s*(true|false)").unwrap();
-        if let Some(caps) = timestamps_regex.captures(content) {
+        if let Some(caps) = self.timestamps_regex.captures(content) {
             let value = caps.get(1).map(|m| m.as_str() == "true").unwrap_or(true);
             properties.insert("timestamps".to_string(), serde_json::json!(value));
         }
 
         // Check for incrementing
-        let incrementing_regex = Regex::new(r"\$incrementing\s*=\s*(true|false)").unwrap();
-        if let Some(caps) = incrementing_regex.captures(content) {
+        if let Some(caps) = self.incrementing_regex.captures(content) {
             let value = caps.get(1).map(|m| m.as_str() == "true").unwrap_or(true);
             properties.insert("incrementing".to_string(), serde_json::json!(value));
         }
@@ -289,55 +413,60 @@ impl ModelParser {
     }
 
     fn extract_array_property(&self, content: &str, property_name: &str) -> Option<Vec<String>> {
-        let pattern = format!(
-            r"\${}\s*=\s*\[([^\]]*)\]",
-            regex::escape(property_name)
-        );
-        let regex = Regex::new(&pattern).ok()?;
-
-        regex.captures(content).and_then(|caps| {
-            caps.get(1).map(|m| {
-                m.as_str()
-                    .split(',')
-                    .filter_map(|s| {
-                        let trimmed = s.trim().trim_matches(|c| c == '\'' || c == '"');
-                        if trimmed.is_empty() {
-                            None
-                        } else {
-                            Some(trimmed.to_string())
-                        }
-                    })
-                    .collect()
+        self.array_property_regex
+            .captures_iter(content)
+            .find(|caps| caps.get(1).map(|m| m.as_str()) == Some(property_name))
+            .and_then(|caps| {
+                caps.get(2).map(|m| {
+                    m.as_str()
+                        .split(',')
+                        .filter_map(|s| {
+                            let trimmed = s.trim().trim_matches(|c| c == '\'' || c == '"');
+                            if trimmed.is_empty() {
+                                None
+                            } else {
+                                Some(trimmed.to_string())
+                            }
+                        })
+                        .collect()
+                })
             })
-        })
     }
 
     fn extract_relationships(&self, content: &str) -> Vec<serde_json::Value> {
         let mut relationships = Vec::new();
 
         // First, find all relationship method definitions
-        let method_regex = Regex::new(
-            r"(?s)public\s+function\s+(\w+)\s*\([^)]*\)\s*(?::\s*[\w\\]+)?\s*\{([^}]+)\}"
-        ).unwrap();
-
-        for method_caps in method_regex.captures_iter(content) {
+        for method_caps in self.relation_method_regex.captures_iter(content) {
             let method_name = method_caps.get(1).map(|m| m.as_str()).unwrap_or("");
-            let method_body = method_caps.get(2).map(|m| m.as_str()).unwrap_or("");
+            let method_body = method_caps
+                .get(0)
+                .and_then(|header| block_body(content, header.end() - 1))
+                .unwrap_or("");
 
             // Check if this method contains a relationship call
             for rel_caps in self.relation_regex.captures_iter(method_body) {
                 let rel_type = rel_caps.get(1).map(|m| m.as_str()).unwrap_or("");
                 let rel_args = rel_caps.get(2).map(|m| m.as_str()).unwrap_or("");
 
-                // Extract the related model from arguments
-                let related_model = self.extract_related_model(rel_args);
+                // Extract the related model from arguments (morphTo has none, its
+                // arguments name the morph)
+                let related_model = if rel_type == "morphTo" {
+                    None
+                } else {
+                    self.extract_related_model(rel_args)
+                };
 
-                relationships.push(serde_json::json!({
+                let mut relationship = serde_json::json!({
                     "method": method_name,
                     "type": rel_type,
                     "related_model": related_model,
                     "raw_args": rel_args.trim()
-                }));
+                });
+                if let Some(morph_name) = morph_name(rel_type, method_name, rel_args) {
+                    relationship["morph_name"] = serde_json::Value::String(morph_name);
+                }
+                relationships.push(relationship);
             }
         }
 
@@ -346,9 +475,7 @@ impl ModelParser {
 
     fn extract_related_model(&self, args: &str) -> Option<String> {
         // Match Model::class or 'App\Models\Model' or "App\Models\Model"
-        let class_regex = Regex::new(r#"([A-Z]\w*)::class|['"]([^'"]+)['"]"#).unwrap();
-
-        class_regex.captures(args).and_then(|caps| {
+        self.related_class_regex.captures(args).and_then(|caps| {
             caps.get(1)
                 .or_else(|| caps.get(2))
                 .map(|m| m.as_str().to_string())
@@ -373,7 +500,7 @@ impl ModelParser {
         // Laravel < 9 style: getXxxAttribute
         for caps in self.accessor_regex.captures_iter(content) {
             if let Some(attr_name) = caps.get(1) {
-                accessors.push(self.snake_case(attr_name.as_str()));
+                accessors.push(snake_case(attr_name.as_str()));
             }
         }
 
@@ -395,7 +522,7 @@ impl ModelParser {
 
         for caps in self.mutator_regex.captures_iter(content) {
             if let Some(attr_name) = caps.get(1) {
-                mutators.push(self.snake_case(attr_name.as_str()));
+                mutators.push(snake_case(attr_name.as_str()));
             }
         }
 
@@ -404,16 +531,17 @@ impl ModelParser {
 
     fn extract_casts(&self, content: &str) -> serde_json::Value {
         // Match $casts = ['field' => 'type', ...];
-        let casts_regex = Regex::new(r"\$casts\s*=\s*\[([^\]]+)\]").unwrap();
-
-        if let Some(caps) = casts_regex.captures(content) {
+        if let Some(caps) = self.casts_property_regex.captures(content) {
             if let Some(casts_content) = caps.get(1) {
                 let mut casts = serde_json::Map::new();
-                let pair_regex = Regex::new(r#"['"](\w+)['"]\s*=>\s*['"]([^'"]+)['"]"#).unwrap();
 
-                for pair_caps in pair_regex.captures_iter(casts_content.as_str()) {
+                for pair_caps in self.casts_pair_regex.captures_iter(casts_content.as_str()) {
                     let field = pair_caps.get(1).map(|m| m.as_str()).unwrap_or("");
-                    let cast_type = pair_caps.get(2).map(|m| m.as_str()).unwrap_or("");
+                    let cast_type = pair_caps
+                        .get(2)
+                        .or_else(|| pair_caps.get(3))
+                        .map(|m| m.as_str())
+                        .unwrap_or("");
 
                     if !field.is_empty() && !cast_type.is_empty() {
                         casts.insert(field.to_string(), serde_json::json!(cast_type));
@@ -425,16 +553,11 @@ impl ModelParser {
         }
 
         // Also check for casts() method (Laravel 9+)
-        let casts_method_regex = Regex::new(
-            r"(?s)protected\s+function\s+casts\s*\(\s*\)\s*:\s*array\s*\{[^}]*return\s*\[([^\]]+)\]"
-        ).unwrap();
-
-        if let Some(caps) = casts_method_regex.captures(content) {
+        if let Some(caps) = self.casts_method_regex.captures(content) {
             if let Some(casts_content) = caps.get(1) {
                 let mut casts = serde_json::Map::new();
-                let pair_regex = Regex::new(r#"['"](\w+)['"]\s*=>\s*([^,\]]+)"#).unwrap();
 
-                for pair_caps in pair_regex.captures_iter(casts_content.as_str()) {
+                for pair_caps in self.casts_method_pair_regex.captures_iter(casts_content.as_str()) {
                     let field = pair_caps.get(1).map(|m| m.as_str()).unwrap_or("");
                     let cast_type = pair_caps.get(2).map(|m| m.as_str().trim().trim_matches(|c| c == '\'' || c == '"')).unwrap_or("");
 
@@ -450,32 +573,136 @@ impl ModelParser {
         serde_json::Value::Object(serde_json::Map::new())
     }
 
+    /// Class references in cast values, expanded through the use statements
+    fn extract_cast_classes(
+        &self,
+        casts: &serde_json::Map<String, serde_json::Value>,
+        dependencies: &[Dependency],
+    ) -> Vec<String> {
+        let mut classes = Vec::new();
+
+        for cast_type in casts.values().filter_map(|v| v.as_str()) {
+            for caps in CLASS_REF_REGEX.captures_iter(cast_type) {
+                let class_name = expand_imported_name(dependencies, &caps[1]);
+                if !classes.contains(&class_name) {
+                    classes.push(class_name);
+                }
+            }
+        }
+
+        classes
+    }
+
+    /// Lifecycle event -> event class pairs of the `$dispatchesEvents` array
+    fn extract_dispatched_events(
+        &self,
+        content: &str,
+        parsed: &ParsedFile,
+    ) -> Vec<serde_json::Value> {
+        let array = self
+            .dispatches_events_regex
+            .find(content)
+            .and_then(|header| {
+                let open = header.end() - 1;
+                matching_bracket(content, open).map(|close| &content[open + 1..close])
+            });
+
+        let mut events = Vec::new();
+        for caps in array
+            .into_iter()
+            .flat_map(|a| self.dispatches_events_entry_regex.captures_iter(a))
+        {
+            events.push(serde_json::json!({
+                "event": &caps[1],
+                "class": expand_imported_name(&parsed.dependencies, &caps[2]),
+            }));
+        }
+
+        events
+    }
+
+    /// Observers of the `#[ObservedBy]` attribute on the model class (one
+    /// class or a list) and of `static::observe()` calls, as in `booted()`
+    fn extract_observers(&self, code: &str, parsed: &ParsedFile) -> Vec<String> {
+        let mut references: Vec<&str> = Vec::new();
+
+        let attributes = parsed
+            .symbols
+            .iter()
+            .filter(|s| s.symbol_type == SymbolType::Class)
+            .flat_map(|s| s.attributes.iter().flatten());
+        for attribute in attributes {
+            if attribute.name.rsplit('\\').next() == Some("ObservedBy") {
+                for argument in &attribute.arguments {
+                    references.extend(
+                        CLASS_REF_REGEX
+                            .captures_iter(argument)
+                            .map(|caps| caps.get(1).map_or("", |m| m.as_str())),
+                    );
+                }
+            }
+        }
+
+        for call in self.observe_call_regex.find_iter(code) {
+            let open = call.end() - 1;
+            if let Some(close) = matching_paren(code, open) {
+                references.extend(
+                    CLASS_REF_REGEX
+                        .captures_iter(&code[open + 1..close])
+                        .map(|caps| caps.get(1).map_or("", |m| m.as_str())),
+                );
+            }
+        }
+
+        let mut observers = Vec::new();
+        for reference in references {
+            let observer = expand_imported_name(&parsed.dependencies, reference);
+            if !observers.contains(&observer) {
+                observers.push(observer);
+            }
+        }
+
+        observers
+    }
+
+    /// Extract traits used by the model class
+    ///
+    /// Only `use` statements between the class opening brace and the first
+    /// method are considered, and each trait must either be imported at the top
+    /// of the file or written fully qualified.
     fn extract_traits_used(&self, content: &str) -> Vec<String> {
         let mut traits = Vec::new();
 
-        // Match: use HasFactory, SoftDeletes, Notifiable;
-        let trait_use_regex = Regex::new(
-            r"(?m)^\s*use\s+((?:[\w\\]+\s*,\s*)*[\w\\]+)\s*;"
-        ).unwrap();
-
-        // Find class body start
-        if let Some(class_start) = content.find("class ") {
-            if let Some(brace_pos) = content[class_start..].find('{') {
-                let class_body = &content[class_start + brace_pos..];
-
-                for caps in trait_use_regex.captures_iter(class_body) {
-                    if let Some(trait_list) = caps.get(1) {
-                        for trait_name in trait_list.as_str().split(',') {
-                            let name = trait_name.trim();
-                            // Filter out namespace imports (they contain backslashes at start)
-                            if !name.is_empty() && !name.starts_with('\\') {
-                                // Get just the trait name without namespace
-                                let short_name = name.rsplit('\\').next().unwrap_or(name);
-                                if !traits.contains(&short_name.to_string()) {
-                                    traits.push(short_name.to_string());
-                                }
-                            }
-                        }
+        let class_match = match self.class_regex.find(content) {
+            Some(m) => m,
+            None => return traits,
+        };
+        let class_body = match content[class_match.end()..]
+            .find('{')
+            .and_then(|offset| block_body(content, class_match.end() + offset))
+        {
+            Some(body) => body,
+            None => return traits,
+        };
+        let trait_section = match class_body.find("function ") {
+            Some(end) => &class_body[..end],
+            None => class_body,
+        };
+
+        let imported = self.imported_names(&content[..class_match.start()]);
+
+        for caps in self.trait_use_regex.captures_iter(trait_section) {
+            if let Some(trait_list) = caps.get(1) {
+                for trait_name in trait_list.as_str().split(',') {
+                    let name = trait_name.trim();
+                    if name.is_empty() || !(name.contains('\\') || imported.contains(name)) {
+                        continue;
+                    }
+
+                    // Get just the trait name without namespace
+                    let short_name = name.rsplit('\\').next().unwrap_or(name);
+                    if !traits.contains(&short_name.to_string()) {
+                        traits.push(short_name.to_string());
                     }
                 }
             }
@@ -484,24 +711,49 @@ impl ModelParser {
         traits
     }
 
-    fn extract_table_name(&self, content: &str) -> Option<String> {
-        let table_regex = Regex::new(r#"\$table\s*=\s*['"]([^'"]+)['"]"#).unwrap();
+    /// Short names (or aliases) brought in by the file's top-level use statements
+    fn imported_names<'a>(&self, header: &'a str) -> HashSet<&'a str> {
+        let mut names = HashSet::new();
+
+        for caps in self.use_regex.captures_iter(header) {
+            let name = match (caps.get(2), caps.get(1)) {
+                (Some(alias), _) => alias.as_str(),
+                (None, Some(target)) => target.as_str().rsplit('\\').next().unwrap_or(""),
+                _ => continue,
+            };
+            names.insert(name);
+        }
+
+        for caps in self.group_use_regex.captures_iter(header) {
+            if let Some(members) = caps.get(2) {
+                for member in members.as_str().split(',') {
+                    // Member may be aliased: Sluggable as HasSlug
+                    let name = member.split_whitespace().last().unwrap_or("");
+                    let name = name.rsplit('\\').next().unwrap_or(name);
+                    if !name.is_empty() {
+                        names.insert(name);
+                    }
+                }
+            }
+        }
 
-        table_regex.captures(content)
+        names
+    }
+
+    fn extract_table_name(&self, content: &str) -> Option<String> {
+        self.table_regex.captures(content)
             .and_then(|caps| caps.get(1))
             .map(|m| m.as_str().to_string())
     }
 
     fn extract_primary_key(&self, content: &str) -> Option<String> {
-        let pk_regex = Regex::new(r#"\$primaryKey\s*=\s*['"]([^'"]+)['"]"#).unwrap();
-
-        pk_regex.captures(content)
+        self.primary_key_regex.captures(content)
             .and_then(|caps| caps.get(1))
             .map(|m| m.as_str().to_string())
     }
 
-    fn extract_methods(&self, content: &str, parsed: &mut ParsedFile) {
-        for caps in self.method_regex.captures_iter(content) {
+    fn extract_methods(&self, content: &str, code: &str, parsed: &mut ParsedFile) {
+        for caps in self.method_regex.captures_iter(code) {
             let visibility = caps.get(1).map(|m| m.as_str().to_string());
             let is_static = caps.get(2).is_some();
             let method_name = caps.get(3).map(|m| m.as_str().to_string()).unwrap_or_default();
@@ -512,6 +764,10 @@ impl ModelParser {
             }
 
             if !method_name.is_empty() {
+                let open = caps.get(0).map(|m| m.end() - 1).unwrap_or(0);
+                let (parameters, return_type) = function_signature(content, open);
+                let attributes = attributes_before(content, caps.get(1).map_or(0, |m| m.start()));
+
                 parsed.add_symbol(Symbol {
                     name: method_name.clone(),
                     qualified_name: method_name,
@@ -521,27 +777,86 @@ impl ModelParser {
                     is_static: Some(is_static),
                     extends: None,
                     implements: None,
+                    parameters,
+                    return_type,
+                    attributes,
                     line_start: None,
                     line_end: None,
+                    section: None,
+                    backing_type: None,
+                    enum_cases: None,
                 });
             }
         }
     }
 
-    /// Convert PascalCase to snake_case
-    fn snake_case(&self, s: &str) -> String {
-        let mut result = String::new();
-        for (i, c) in s.chars().enumerate() {
-            if c.is_uppercase() {
-                if i > 0 {
-                    result.push('_');
-                }
-                result.push(c.to_lowercase().next().unwrap());
-            } else {
-                result.push(c);
-            }
-        }
-        result
+    /// Table Eloquent infers for a model class (`BlogPost` -> `blog_posts`)
+    fn table_name_for(&self, class_name: &str) -> String {
+        let snake = snake_case(class_name);
+        let (head, last) = match snake.rfind('_') {
+            Some(pos) => snake.split_at(pos + 1),
+            None => ("", snake.as_str()),
+        };
+        format!("{}{}", head, pluralize(last))
+    }
+}
+
+/// Morph name of a polymorphic relationship (`commentable` for the
+/// `commentable_id`/`commentable_type` columns), `None` for other relationships
+///
+/// `morphTo` takes it as first argument and defaults to the method name, the
+/// other morph relationships take it after the related model.
+fn morph_name(rel_type: &str, method_name: &str, args: &str) -> Option<String> {
+    let position = match rel_type {
+        "morphTo" => 0,
+        "morphOne" | "morphMany" | "morphToMany" | "morphedByMany" => 1,
+        _ => return None,
+    };
+    // Only a quoted name counts, not __FUNCTION__ or a variable
+    let argument = split_top_level(args, b',')
+        .into_iter()
+        .nth(position)
+        .map(|a| a.trim())
+        .filter(|a| a.len() > 2 && (a.starts_with('\'') || a.starts_with('"')))
+        .map(|a| &a[1..a.len() - 1]);
+
+    match argument {
+        Some(name) => Some(name.to_string()),
+        // morphTo() or morphTo(__FUNCTION__)
+        None if position == 0 => Some(method_name.to_string()),
+        None => None,
+    }
+}
+
+/// Plural of an English noun, the common rules and irregular nouns Laravel knows
+fn pluralize(word: &str) -> String {
+    let irregular = match word {
+        "person" => Some("people"),
+        "man" => Some("men"),
+        "woman" => Some("women"),
+        "child" => Some("children"),
+        "mouse" => Some("mice"),
+        "foot" => Some("feet"),
+        "tooth" => Some("teeth"),
+        "goose" => Some("geese"),
+        // Uncountable
+        "equipment" | "information" | "rice" | "money" | "species" | "series" | "fish"
+        | "sheep" | "news" | "data" | "metadata" | "feedback" | "staff" => Some(word),
+        _ => None,
+    };
+    if let Some(plural) = irregular {
+        return plural.to_string();
+    }
+
+    let ends_with_consonant_y = word.ends_with('y')
+        && !word[..word.len() - 1].ends_with(['a', 'e', 'i', 'o', 'u']);
+
+    if ends_with_consonant_y {
+        format!("{}ies", &word[..word.len() - 1])
+    } else if ["s", "x", "z", "ch", "sh"].iter().any(|suffix| word.ends_with(suffix)) {
+        format!("{}es", word)
+    } else {
+        format!("{}s", word)
     }
 }
 
@@ -550,3 +865,197 @@ impl Default for ModelParser {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::is_file_node;
+    use crate::models::UnifiedEdgeType;
+    use crate::parsers::common::generate_id;
+    use crate::parsers::laravel::fixtures::{graph_of, parse_with};
+
+    #[test]
+    fn morph_names_are_read_past_nested_commas() {
+        assert_eq!(
+            morph_name("morphMany", "comments", "Comment::class, 'commentable'"),
+            Some("commentable".to_string())
+        );
+        assert_eq!(
+            morph_name(
+                "morphOne",
+                "image",
+                "$this->imageClass(['a', 'b']), 'imageable'"
+            ),
+            Some("imageable".to_string())
+        );
+        assert_eq!(
+            morph_name("morphTo", "owner", "__FUNCTION__"),
+            Some("owner".to_string())
+        );
+        assert_eq!(
+            morph_name("hasMany", "posts", "Post::class, 'author_id'"),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn models_link_to_their_custom_cast_classes() {
+        let user = "<?php
+
+namespace App\\Models;
+
+use App\\Casts\\Address;
+use Illuminate\\Database\\Eloquent\\Model;
+
+class User extends Model
+{
+    protected $casts = [
+        'address' => Address::class,
+        'options' => 'array',
+    ];
+}
+";
+        let address = "<?php
+
+namespace App\\Casts;
+
+use Illuminate\\Contracts\\Database\\Eloquent\\CastsAttributes;
+
+class Address implements CastsAttributes
+{
+}
+";
+        let graph = graph_of(&[
+            ("app/Models/User.php", user),
+            ("app/Casts/Address.php", address),
+        ])
+        .await;
+
+        let user_id = generate_id("app/Models/User.php");
+        let user = graph.nodes.iter().find(|n| n.id == user_id).unwrap();
+        let cast = graph
+            .nodes
+            .iter()
+            .find(|n| n.qualified_name == "App\\Casts\\Address" && !is_file_node(n))
+            .expect("no cast class node");
+        assert_eq!(
+            user.metadata.extra["cast_classes"],
+            serde_json::json!(["App\\Casts\\Address"])
+        );
+
+        let casts_to: Vec<_> = graph
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == UnifiedEdgeType::Custom("casts_to".to_string()))
+            .map(|e| (&e.source, &e.target))
+            .collect();
+        assert_eq!(casts_to, [(&user.id, &cast.id)]);
+    }
+
+    #[tokio::test]
+    async fn models_map_to_their_explicit_or_inferred_table() {
+        let person = "<?php
+
+namespace App\\Models;
+
+class Person extends Model
+{
+    protected $table = 'people_archive';
+}
+";
+        let blog_post = "<?php
+
+namespace App\\Models;
+
+class BlogPost extends Model
+{
+}
+";
+        let graph = graph_of(&[
+            ("app/Models/Person.php", person),
+            ("app/Models/BlogPost.php", blog_post),
+        ])
+        .await;
+
+        let node = |id: &str| graph.find_node(id).unwrap();
+        let mut maps_to: Vec<(&str, &str)> = graph
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == UnifiedEdgeType::Custom("maps_to".to_string()))
+            .map(|e| {
+                let model = node(&e.source).file_path.as_deref().unwrap_or("-");
+                (model, node(&e.target).qualified_name.as_str())
+            })
+            .collect();
+        maps_to.sort();
+        assert_eq!(
+            maps_to,
+            [
+                ("app/Models/BlogPost.php", "table:blog_posts"),
+                ("app/Models/Person.php", "table:people_archive"),
+            ]
+        );
+
+        let table_source = |path: &str| {
+            let node = graph.find_node(&generate_id(path)).unwrap();
+            node.metadata.extra["table_source"].clone()
+        };
+        assert_eq!(table_source("app/Models/Person.php"), "property");
+        assert_eq!(table_source("app/Models/BlogPost.php"), "convention");
+    }
+
+    #[tokio::test]
+    async fn relationships_split_across_lines_are_extracted() {
+        let user = "<?php
+
+namespace App\\Models;
+
+class User extends Model
+{
+    public function posts(): HasMany
+    {
+        return $this->hasMany(
+            Post::class,
+            'author_id'
+        );
+    }
+
+    public function roles(): BelongsToMany
+    {
+        return $this
+            ->belongsToMany(Role::class, 'role_user')
+            ->withTimestamps();
+    }
+
+    public function team()
+    {
+        return $this->belongsTo(
+            Team::class
+        )->withDefault();
+    }
+}
+";
+        let result = parse_with(&[("app/Models/User.php", user)], |_| {}).await;
+
+        let relationships: Vec<(&str, &str, &str)> = result.files[0].metadata["relationships"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|r| {
+                (
+                    r["method"].as_str().unwrap(),
+                    r["type"].as_str().unwrap(),
+                    r["related_model"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            relationships,
+            [
+                ("posts", "hasMany", "Post"),
+                ("roles", "belongsToMany", "Role"),
+                ("team", "belongsTo", "Team"),
+            ]
+        );
+    }
+}
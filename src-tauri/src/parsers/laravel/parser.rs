@@ -1,24 +1,40 @@
 use async_trait::async_trait;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
 use crate::core::{ParserInfo, ProjectType};
+use crate::graph::is_file_node;
 use crate::models::{
     ParseResult, ParsedFile, SourceFile, UnifiedEdge, UnifiedEdgeType, UnifiedNode,
     UnifiedNodeType,
 };
-use crate::parsers::common::{generate_id, scan_directory};
+use crate::parsers::common::{generate_id, scan_directory, studly_case};
 use crate::parsers::{
     ParserCapabilities, ParserConfig, ParserResult, ProgressCallback, ProjectParser,
 };
 
 use super::blade_parser::BladeParser;
+use super::command_parser::CommandParser;
+use super::config_parser::ConfigParser;
 use super::controller_parser::ControllerParser;
-use super::inertia_parser::InertiaParser;
+use super::factory_parser::FactoryParser;
+use super::graphql_parser::GraphqlParser;
+use super::inertia_parser::{normalize_page_name, resolve_import, InertiaParser};
+use super::livewire_parser::LivewireParser;
+use super::mail_parser::MailParser;
 use super::migration_parser::MigrationParser;
 use super::model_parser::ModelParser;
-use super::php_parser::PhpParser;
+use super::php_parser::{expand_imported_name, PhpParser};
+use super::provider_parser::ProviderParser;
 use super::route_parser::RouteParser;
+use super::seeder_parser::SeederParser;
+use super::relationship_inverse::{infer_inverse_relationships, DeclaredRelation};
+use super::table_schema::{table_schemas, TableSchema};
+use super::composer::framework_major_version;
+
+/// Extensions scanned by default: PHP, Inertia pages (Vue, React, Svelte) and
+/// GraphQL schemas
+const SCANNED_EXTENSIONS: &[&str] = &["php", "vue", "jsx", "tsx", "svelte", "graphql"];
 
 /// Laravel PHP framework parser
 pub struct LaravelParser {
@@ -29,6 +45,14 @@ pub struct LaravelParser {
     migration_parser: MigrationParser,
     blade_parser: BladeParser,
     inertia_parser: InertiaParser,
+    graphql_parser: GraphqlParser,
+    livewire_parser: LivewireParser,
+    provider_parser: ProviderParser,
+    seeder_parser: SeederParser,
+    factory_parser: FactoryParser,
+    config_parser: ConfigParser,
+    command_parser: CommandParser,
+    mail_parser: MailParser,
 }
 
 impl LaravelParser {
@@ -41,6 +65,14 @@ impl LaravelParser {
             migration_parser: MigrationParser::new(),
             blade_parser: BladeParser::new(),
             inertia_parser: InertiaParser::new(),
+            graphql_parser: GraphqlParser::new(),
+            livewire_parser: LivewireParser::new(),
+            provider_parser: ProviderParser::new(),
+            seeder_parser: SeederParser::new(),
+            factory_parser: FactoryParser::new(),
+            config_parser: ConfigParser::new(),
+            command_parser: CommandParser::new(),
+            mail_parser: MailParser::new(),
         }
     }
 
@@ -50,7 +82,8 @@ impl LaravelParser {
         let name = &file.name;
 
         // Normalize path separators for cross-platform compatibility
-        let normalized_path = path.replace('\\', "/");
+        // (paths are relative to the root, the leading / lets top-level dirs like routes/ match)
+        let normalized_path = format!("/{}", path.replace('\\', "/"));
         let path_lower = normalized_path.to_lowercase();
 
         // Inertia pages (Vue, React, Svelte in resources/js/Pages)
@@ -60,6 +93,18 @@ impl LaravelParser {
             return LaravelFileType::InertiaPage;
         }
 
+        // Components, layouts, ... the pages import (resources/js/Components/Button.vue)
+        if path_lower.contains("/resources/js/")
+            && (name.ends_with(".vue") || name.ends_with(".jsx") || name.ends_with(".tsx") || name.ends_with(".svelte"))
+        {
+            return LaravelFileType::InertiaComponent;
+        }
+
+        // Lighthouse GraphQL schemas
+        if name.ends_with(".graphql") {
+            return LaravelFileType::GraphqlSchema;
+        }
+
         // Blade templates (check first, most specific)
         if name.ends_with(".blade.php") {
             return LaravelFileType::BladeView;
@@ -75,6 +120,13 @@ impl LaravelParser {
             return LaravelFileType::Migration;
         }
 
+        // Livewire components (app/Livewire, app/Http/Livewire in Livewire 2)
+        if (path_lower.contains("/app/livewire/") || path_lower.contains("/app/http/livewire/"))
+            && name.ends_with(".php")
+        {
+            return LaravelFileType::Livewire;
+        }
+
         // Controllers (check by path or name pattern)
         if path_lower.contains("/controllers/") || name.ends_with("Controller.php") {
             return LaravelFileType::Controller;
@@ -90,8 +142,8 @@ impl LaravelParser {
             return LaravelFileType::Request;
         }
 
-        // Resources (API Resources) - but not views/resources
-        if path_lower.contains("/resources/") && !path_lower.contains("/views/") && !path_lower.contains("resources/views") {
+        // API Resources, not the files of the top-level resources/ dir
+        if path_lower.contains("/app/http/resources/") {
             return LaravelFileType::Resource;
         }
 
@@ -236,6 +288,19 @@ impl LaravelParser {
             return initial_type;
         }
 
+        // Enum declared outside app/Enums
+        let declares_class = parsed_file
+            .symbols
+            .iter()
+            .any(|s| s.symbol_type == crate::models::SymbolType::Class);
+        let declares_enum = parsed_file
+            .symbols
+            .iter()
+            .any(|s| s.symbol_type == crate::models::SymbolType::Enum);
+        if declares_enum && !declares_class {
+            return LaravelFileType::Enum;
+        }
+
         // Look at the main class symbol to determine type
         for symbol in &parsed_file.symbols {
             if symbol.symbol_type != crate::models::SymbolType::Class {
@@ -244,6 +309,11 @@ impl LaravelParser {
 
             // Check extends
             if let Some(ref extends) = symbol.extends {
+                // Livewire components, whatever their folder
+                if expand_imported_name(&parsed_file.dependencies, extends) == "Livewire\\Component" {
+                    return LaravelFileType::Livewire;
+                }
+
                 let parent = extends.rsplit('\\').next().unwrap_or(extends);
 
                 match parent {
@@ -468,6 +538,9 @@ enum LaravelFileType {
     Factory,
     Test,
     InertiaPage,
+    InertiaComponent,
+    GraphqlSchema,
+    Livewire,
     // Additional types based on extends/implements/namespace
     Service,
     Repository,
@@ -487,6 +560,207 @@ enum LaravelFileType {
     Php,
 }
 
+/// `fills` edges from a view to the layouts yielding its `@section`s
+///
+/// A section fills the nearest layout up the `@extends` chain that yields it.
+/// There is one edge per layout, labelled with the sections it receives.
+fn section_edges(
+    source_id: &str,
+    parsed_file: &ParsedFile,
+    node_by_qualified: &HashMap<&str, &UnifiedNode>,
+) -> Vec<UnifiedEdge> {
+    let strings = |value: Option<&serde_json::Value>| -> Vec<String> {
+        value
+            .and_then(|v| v.as_array())
+            .map(|items| items.iter().filter_map(|i| i.as_str()).map(String::from).collect())
+            .unwrap_or_default()
+    };
+
+    let mut remaining = strings(parsed_file.metadata.get("sections"));
+    let mut edges = Vec::new();
+    let mut seen: HashSet<&str> = HashSet::new();
+    let mut parent = parsed_file.metadata.get("extends").and_then(|e| e.as_str());
+
+    while let Some(name) = parent {
+        let layout = match node_by_qualified.get(format!("view:{}", name).as_str()) {
+            Some(layout) if !remaining.is_empty() && seen.insert(layout.id.as_str()) => *layout,
+            _ => break,
+        };
+
+        let yields = strings(layout.metadata.extra.get("yields"));
+        let (filled, rest): (Vec<String>, Vec<String>) =
+            remaining.into_iter().partition(|section| yields.contains(section));
+        remaining = rest;
+        if !filled.is_empty() {
+            edges.push(
+                UnifiedEdge::new(
+                    source_id.to_string(),
+                    layout.id.clone(),
+                    UnifiedEdgeType::Custom("fills".to_string()),
+                )
+                .with_label(&filled.join(", ")),
+            );
+        }
+
+        parent = layout.metadata.extra.get("extends").and_then(|e| e.as_str());
+    }
+
+    edges
+}
+
+/// Class backing a Blade x-component (forms.user-card -> App\View\Components\Forms\UserCard)
+fn component_class_name(component: &str) -> String {
+    let segments: Vec<String> = component.split('.').map(studly_case).collect();
+
+    format!("App\\View\\Components\\{}", segments.join("\\"))
+}
+
+/// Map every declared route name to the path of the route file declaring it
+///
+/// Resource routes expand to their conventional names (photos -> photos.index, ...).
+fn route_files_by_name(parse_result: &ParseResult) -> HashMap<String, &str> {
+    let mut route_files = HashMap::new();
+
+    for parsed_file in &parse_result.files {
+        let path = parsed_file.source.path.as_str();
+
+        if let Some(routes) = parsed_file.metadata.get("routes").and_then(|r| r.as_array()) {
+            for name in routes.iter().filter_map(|r| r.get("name").and_then(|n| n.as_str())) {
+                route_files.entry(name.to_string()).or_insert(path);
+            }
+        }
+
+        for key in ["resource_routes", "api_resource_routes"] {
+            if let Some(resources) = parsed_file.metadata.get(key).and_then(|r| r.as_array()) {
+                for resource in resources {
+                    let name = match resource.get("name").and_then(|n| n.as_str()) {
+                        Some(name) if !name.is_empty() => name,
+                        _ => continue,
+                    };
+                    if let Some(actions) = resource.get("routes").and_then(|r| r.as_array()) {
+                        for action in actions.iter().filter_map(|a| a.as_str()) {
+                            route_files
+                                .entry(format!("{}.{}", name, action))
+                                .or_insert(path);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    route_files
+}
+
+/// Relation edge of a polymorphic relationship, labelled with the relationship
+fn polymorphic_edge(source: String, target: String, rel_type: &str) -> UnifiedEdge {
+    let mut edge = UnifiedEdge::new(source, target, UnifiedEdgeType::HasRelation).with_label(rel_type);
+    edge.metadata.polymorphic = Some(true);
+    edge
+}
+
+/// A model declaring a `morphOne`/`morphMany` relationship, a possible
+/// target of the `morphTo` relationships with the same morph name
+struct MorphParent<'a> {
+    /// Path of the declaring model
+    path: &'a str,
+    namespace: Option<&'a str>,
+    /// The morphed model, as written
+    related_model: Option<&'a str>,
+}
+
+/// Map every morph name to the models declaring a morphOne/morphMany with it
+fn morph_parents(parse_result: &ParseResult) -> HashMap<&str, Vec<MorphParent<'_>>> {
+    let mut parents: HashMap<&str, Vec<MorphParent>> = HashMap::new();
+
+    for parsed_file in &parse_result.files {
+        let relationships = match parsed_file.metadata.get("relationships").and_then(|r| r.as_array()) {
+            Some(relationships) => relationships,
+            None => continue,
+        };
+        for rel in relationships {
+            let rel_type = rel.get("type").and_then(|t| t.as_str());
+            if !matches!(rel_type, Some("morphOne") | Some("morphMany")) {
+                continue;
+            }
+            if let Some(morph_name) = rel.get("morph_name").and_then(|n| n.as_str()) {
+                parents.entry(morph_name).or_default().push(MorphParent {
+                    path: parsed_file.source.path.as_str(),
+                    namespace: parsed_file.metadata.get("namespace").and_then(|ns| ns.as_str()),
+                    related_model: rel.get("related_model").and_then(|m| m.as_str()),
+                });
+            }
+        }
+    }
+
+    parents
+}
+
+/// Lookup of class-like nodes by fully qualified name, with short-name fallback
+struct ClassIndex<'a> {
+    by_qualified: HashMap<&'a str, &'a UnifiedNode>,
+    by_name: HashMap<&'a str, Vec<&'a UnifiedNode>>,
+}
+
+impl<'a> ClassIndex<'a> {
+    fn new(nodes: &'a [UnifiedNode]) -> Self {
+        let mut by_qualified = HashMap::new();
+        let mut by_name: HashMap<&str, Vec<&UnifiedNode>> = HashMap::new();
+
+        for node in nodes {
+            let is_class_like = matches!(
+                node.node_type,
+                UnifiedNodeType::Class
+                    | UnifiedNodeType::Controller
+                    | UnifiedNodeType::Model
+                    | UnifiedNodeType::Interface
+                    | UnifiedNodeType::Trait
+                    | UnifiedNodeType::Enum
+            );
+            // File nodes share their namespace as qualified name, skip them
+            if is_class_like && !is_file_node(node) {
+                by_qualified.insert(node.qualified_name.as_str(), node);
+                by_name.entry(node.name.as_str()).or_default().push(node);
+            }
+        }
+
+        Self { by_qualified, by_name }
+    }
+
+    /// Look up a class by its exact fully qualified name
+    fn get(&self, qualified_name: &str) -> Option<&'a UnifiedNode> {
+        self.by_qualified.get(qualified_name).copied()
+    }
+
+    /// Resolve a class reference (short or fully qualified)
+    ///
+    /// Qualified names only resolve to the class they name: an unknown
+    /// `Illuminate\Foundation\Auth\User` isn't the project's `App\Models\User`.
+    /// Short names are tried relative to `namespace`, then fall back to the
+    /// class of that name when there is exactly one.
+    fn resolve(&self, name: &str, namespace: Option<&str>) -> Option<&'a UnifiedNode> {
+        let name = name.trim().trim_start_matches('\\');
+        if name.is_empty() {
+            return None;
+        }
+
+        if name.contains('\\') {
+            return self.by_qualified.get(name).copied();
+        }
+
+        if let Some(ns) = namespace {
+            if let Some(node) = self.by_qualified.get(format!("{}\\{}", ns, name).as_str()) {
+                return Some(node);
+            }
+        }
+
+        match self.by_name.get(name).map(|c| c.as_slice()) {
+            Some([single]) => Some(single),
+            _ => None,
+        }
+    }
+}
+
 impl Default for LaravelParser {
     fn default() -> Self {
         Self::new()
@@ -515,7 +789,7 @@ impl ProjectParser for LaravelParser {
 
     fn default_config(&self) -> ParserConfig {
         ParserConfig {
-            include_extensions: vec!["php".to_string()],
+            include_extensions: SCANNED_EXTENSIONS.iter().map(|e| e.to_string()).collect(),
             exclude_dirs: vec![
                 "vendor".to_string(),
                 "node_modules".to_string(),
@@ -523,10 +797,15 @@ impl ProjectParser for LaravelParser {
                 "bootstrap/cache".to_string(),
                 ".git".to_string(),
             ],
+            include_globs: Vec::new(),
+            exclude_globs: Vec::new(),
             encoding: "utf-8".to_string(),
             parse_external_deps: false,
             max_depth: None,
             language_options: Default::default(),
+            respect_gitignore: false,
+            max_file_size_bytes: None,
+            compute_hashes: false,
         }
     }
 
@@ -538,6 +817,7 @@ impl ProjectParser for LaravelParser {
                 "view".to_string(),
                 "route".to_string(),
                 "migration".to_string(),
+                "table".to_string(),
                 "middleware".to_string(),
                 "provider".to_string(),
                 "event".to_string(),
@@ -545,6 +825,7 @@ impl ProjectParser for LaravelParser {
                 "job".to_string(),
                 "policy".to_string(),
                 "command".to_string(),
+                "livewire".to_string(),
             ],
             edge_types: vec![
                 "uses".to_string(),
@@ -555,11 +836,34 @@ impl ProjectParser for LaravelParser {
                 "has_many".to_string(),
                 "belongs_to".to_string(),
                 "middleware".to_string(),
+                "binds".to_string(),
+                "resolves".to_string(),
+                "rendered_by".to_string(),
+                "casts_to".to_string(),
+                "uses_component".to_string(),
+                "references_route".to_string(),
+                "handles".to_string(),
+                "chained_with".to_string(),
+                "batched_with".to_string(),
+                "dispatches".to_string(),
+                "calls".to_string(),
+                "factory_for".to_string(),
+                "migrates".to_string(),
+                "maps_to".to_string(),
+                "has_relation".to_string(),
+                "guards".to_string(),
+                "observed_by".to_string(),
+                "fills".to_string(),
             ],
-            supports_incremental: false,
+            // Files parse on their own; what providers register (events,
+            // policies, bindings) is resolved across files by generate_edges,
+            // which re-runs over every file on incremental parses too
+            supports_incremental: true,
             supports_cancellation: true,
             available_metrics: vec![
                 "lines_of_code".to_string(),
+                "comment_lines".to_string(),
+                "blank_lines".to_string(),
                 "routes_count".to_string(),
                 "models_count".to_string(),
                 "controllers_count".to_string(),
@@ -567,19 +871,25 @@ impl ProjectParser for LaravelParser {
         }
     }
 
-    fn detect_confidence(&self, root_path: &Path) -> f32 {
+    fn detect_markers(&self, root_path: &Path) -> (f32, Vec<String>) {
         let mut score = 0.0f32;
+        let mut markers = Vec::new();
 
-        // Check for composer.json with laravel/framework
+        // Check for composer.json with laravel/framework (or Lumen's)
         if let Ok(content) = std::fs::read_to_string(root_path.join("composer.json")) {
             if content.contains("laravel/framework") {
                 score += 0.6;
+                markers.push("composer.json (laravel/framework)".to_string());
+            } else if content.contains("laravel/lumen-framework") {
+                score += 0.6;
+                markers.push("composer.json (laravel/lumen-framework)".to_string());
             }
         }
 
         // Check for artisan
         if root_path.join("artisan").exists() {
             score += 0.2;
+            markers.push("artisan".to_string());
         }
 
         // Check for Laravel directories
@@ -592,10 +902,15 @@ impl ProjectParser for LaravelParser {
         for dir in laravel_dirs {
             if root_path.join(dir).is_dir() {
                 score += 0.05;
+                markers.push(dir.to_string());
             }
         }
 
-        score.min(1.0)
+        (score.min(1.0), markers)
+    }
+
+    fn framework_version(&self, root_path: &Path) -> Option<u32> {
+        framework_major_version(root_path)
     }
 
     fn can_handle_file(&self, file_path: &Path) -> bool {
@@ -612,11 +927,13 @@ impl ProjectParser for LaravelParser {
         config: &ParserConfig,
         _progress: Option<ProgressCallback>,
     ) -> ParserResult<Vec<SourceFile>> {
-        // Include PHP and Inertia files (Vue, React, Svelte)
-        let extensions: Vec<&str> = vec!["php", "vue", "jsx", "tsx", "svelte"];
-        let exclude_dirs: Vec<&str> = config.exclude_dirs.iter().map(|s| s.as_str()).collect();
+        let extensions: Vec<&str> = SCANNED_EXTENSIONS
+            .iter()
+            .copied()
+            .filter(|ext| config.includes_extension(ext))
+            .collect();
 
-        Ok(scan_directory(root_path, &extensions, &exclude_dirs))
+        scan_directory(root_path, &extensions, config)
     }
 
     async fn parse_file(
@@ -633,10 +950,39 @@ impl ProjectParser for LaravelParser {
             LaravelFileType::BladeView => self.blade_parser.parse(file, config).await,
             LaravelFileType::Route => self.route_parser.parse(file, config).await,
             LaravelFileType::Migration => self.migration_parser.parse(file, config).await,
-            LaravelFileType::InertiaPage => self.inertia_parser.parse(file, config).await,
+            LaravelFileType::InertiaPage | LaravelFileType::InertiaComponent => {
+                self.inertia_parser.parse(file, config).await
+            }
+            LaravelFileType::GraphqlSchema => self.graphql_parser.parse(file, config).await,
+            LaravelFileType::Livewire => self.livewire_parser.parse(file, config).await,
             // For other file types, use the base PHP parser with type annotation
             _ => {
-                let mut parsed = self.php_parser.parse(file, config).await?;
+                let mut parsed = match file_type {
+                    LaravelFileType::Provider => self.provider_parser.parse(file, config).await?,
+                    LaravelFileType::Seeder => self.seeder_parser.parse(file, config).await?,
+                    LaravelFileType::Factory => self.factory_parser.parse(file, config).await?,
+                    LaravelFileType::Config => self.config_parser.parse(file, config).await?,
+                    LaravelFileType::Command => self.command_parser.parse(file, config).await?,
+                    LaravelFileType::Mailable | LaravelFileType::Notification => {
+                        self.mail_parser.parse(file, config).await?
+                    }
+                    _ => self.php_parser.parse(file, config).await?,
+                };
+                // Classes found by what they extend, outside their conventional folder
+                if file_type == LaravelFileType::Php {
+                    match self.refine_file_type(LaravelFileType::Php, &parsed) {
+                        LaravelFileType::Livewire => {
+                            return self.livewire_parser.parse(file, config).await;
+                        }
+                        LaravelFileType::Command => {
+                            parsed = self.command_parser.parse(file, config).await?;
+                        }
+                        LaravelFileType::Mailable | LaravelFileType::Notification => {
+                            parsed = self.mail_parser.parse(file, config).await?;
+                        }
+                        _ => {}
+                    }
+                }
                 parsed.metadata.insert(
                     "laravel_type".to_string(),
                     serde_json::Value::String(format!("{:?}", file_type)),
@@ -649,6 +995,13 @@ impl ProjectParser for LaravelParser {
     fn generate_nodes(&self, parse_result: &ParseResult) -> Vec<UnifiedNode> {
         let mut nodes = Vec::new();
 
+        // Scanned files, for the imports of Inertia pages and components
+        let file_paths: HashSet<&str> = parse_result
+            .files
+            .iter()
+            .map(|f| f.source.path.as_str())
+            .collect();
+
         for parsed_file in &parse_result.files {
             // Get initial type from path, then refine using extends/implements
             let initial_type = self.determine_file_type(&parsed_file.source);
@@ -675,6 +1028,9 @@ impl ProjectParser for LaravelParser {
                 LaravelFileType::Factory => UnifiedNodeType::Custom("factory".to_string()),
                 LaravelFileType::Test => UnifiedNodeType::Custom("test".to_string()),
                 LaravelFileType::InertiaPage => UnifiedNodeType::Component,
+                LaravelFileType::InertiaComponent => UnifiedNodeType::Component,
+                LaravelFileType::GraphqlSchema => UnifiedNodeType::Custom("graphql_schema".to_string()),
+                LaravelFileType::Livewire => UnifiedNodeType::Custom("livewire".to_string()),
                 // New types
                 LaravelFileType::Service => UnifiedNodeType::Custom("service".to_string()),
                 LaravelFileType::Repository => UnifiedNodeType::Custom("repository".to_string()),
@@ -694,15 +1050,25 @@ impl ProjectParser for LaravelParser {
                 LaravelFileType::Php => UnifiedNodeType::SourceFile,
             };
 
+            let language = match file_type {
+                LaravelFileType::GraphqlSchema => "graphql",
+                _ => "php",
+            };
+
             // Create file node
             let file_id = generate_id(&parsed_file.source.path);
             let mut file_node =
                 UnifiedNode::new(file_id.clone(), node_type.clone(), parsed_file.source.name.clone())
                     .with_file(parsed_file.source.path.clone())
-                    .with_language("php");
+                    .with_language(language);
 
             // Set qualified name from metadata if available
-            if let Some(namespace) = parsed_file.metadata.get("namespace") {
+            if let Some(command) = parsed_file.metadata.get("command_name") {
+                // Console commands go by their Artisan name
+                if let Some(name) = command.as_str() {
+                    file_node.qualified_name = format!("artisan:{}", name);
+                }
+            } else if let Some(namespace) = parsed_file.metadata.get("namespace") {
                 if let Some(ns) = namespace.as_str() {
                     file_node.qualified_name = ns.to_string();
                 }
@@ -727,6 +1093,9 @@ impl ProjectParser for LaravelParser {
                 LaravelFileType::Route => 6,
                 LaravelFileType::BladeView => 5,
                 LaravelFileType::InertiaPage => 6,
+                LaravelFileType::InertiaComponent => 4,
+                LaravelFileType::GraphqlSchema => 5,
+                LaravelFileType::Livewire => 6,
                 LaravelFileType::Migration => 5,
                 LaravelFileType::Middleware => 6,
                 LaravelFileType::Provider => 6,
@@ -764,14 +1133,52 @@ impl ProjectParser for LaravelParser {
                 .map(|(k, v)| (k.clone(), v.clone()))
                 .collect();
 
+            // Local imports of pages and components that match no scanned
+            // file, such as plain .ts/.js modules
+            if parsed_file.metadata.contains_key("is_inertia_page") {
+                let unresolved: Vec<&str> = parsed_file
+                    .dependencies
+                    .iter()
+                    .map(|dep| dep.target.as_str())
+                    .filter(|import| {
+                        resolve_import(&parsed_file.source.path, import, &file_paths).is_none()
+                    })
+                    .collect();
+                if !unresolved.is_empty() {
+                    file_node.metadata.extra.insert(
+                        "unresolved_imports".to_string(),
+                        serde_json::json!(unresolved),
+                    );
+                }
+            }
+
             nodes.push(file_node);
 
-            // Create nodes for classes/symbols within the file
+            // The class named after the file (else the first one) is the
+            // controller or model, other classes of the file are plain classes
+            let classes: Vec<&str> = parsed_file
+                .symbols
+                .iter()
+                .filter(|s| s.symbol_type == crate::models::SymbolType::Class)
+                .map(|s| s.name.as_str())
+                .collect();
+            let stem = parsed_file.source.name.split('.').next().unwrap_or_default();
+            let primary_class = classes
+                .iter()
+                .find(|name| **name == stem)
+                .or_else(|| classes.first())
+                .copied();
+
+            // Create nodes for classes/symbols within the file, a name declared
+            // twice (same method in two classes) gets a numbered ID
+            let mut declared: HashMap<&str, usize> = HashMap::new();
             for symbol in &parsed_file.symbols {
                 let symbol_node_type = match symbol.symbol_type {
                     crate::models::SymbolType::Class => {
                         // Refine based on Laravel context
-                        if file_type == LaravelFileType::Controller {
+                        if primary_class != Some(symbol.name.as_str()) {
+                            UnifiedNodeType::Class
+                        } else if file_type == LaravelFileType::Controller {
                             UnifiedNodeType::Controller
                         } else if file_type == LaravelFileType::Model {
                             UnifiedNodeType::Model
@@ -781,12 +1188,18 @@ impl ProjectParser for LaravelParser {
                     }
                     crate::models::SymbolType::Interface => UnifiedNodeType::Interface,
                     crate::models::SymbolType::Trait => UnifiedNodeType::Trait,
+                    crate::models::SymbolType::Enum => UnifiedNodeType::Enum,
                     crate::models::SymbolType::Method => UnifiedNodeType::Method,
                     crate::models::SymbolType::Function => UnifiedNodeType::Function,
                     _ => continue, // Skip other symbols for now
                 };
 
-                let symbol_id = generate_id(&format!("{}::{}", parsed_file.source.path, symbol.name));
+                let occurrence = declared.entry(symbol.name.as_str()).or_insert(0);
+                *occurrence += 1;
+                let symbol_id = match *occurrence {
+                    1 => generate_id(&format!("{}::{}", parsed_file.source.path, symbol.name)),
+                    n => generate_id(&format!("{}::{}#{}", parsed_file.source.path, symbol.name, n)),
+                };
 
                 let mut symbol_node = UnifiedNode::new(
                     symbol_id,
@@ -810,9 +1223,84 @@ impl ProjectParser for LaravelParser {
                 }
 
                 symbol_node.metadata.implements = symbol.implements.clone();
+                symbol_node.metadata.parameters = symbol.parameters.clone();
+                symbol_node.metadata.return_type = symbol.return_type.clone();
+                symbol_node.metadata.attributes = symbol.attributes.clone();
+                symbol_node.metadata.backing_type = symbol.backing_type.clone();
+                symbol_node.metadata.enum_cases = symbol.enum_cases.clone();
 
                 nodes.push(symbol_node);
             }
+
+            // Create nodes for GraphQL operations (Query.users, Mutation.createUser)
+            if let Some(operations) = parsed_file.metadata.get("graphql_operations") {
+                if let Some(operation_list) = operations.as_array() {
+                    for operation in operation_list {
+                        if let (Some(type_name), Some(field)) = (
+                            operation.get("type").and_then(|t| t.as_str()),
+                            operation.get("field").and_then(|f| f.as_str()),
+                        ) {
+                            let operation_name = format!("{}.{}", type_name, field);
+
+                            let mut operation_node = UnifiedNode::new(
+                                generate_id(&format!("{}::{}", parsed_file.source.path, operation_name)),
+                                UnifiedNodeType::Custom("graphql_operation".to_string()),
+                                operation_name.clone(),
+                            )
+                            .with_file(parsed_file.source.path.clone())
+                            .with_language("graphql");
+
+                            operation_node.qualified_name = format!("graphql:{}", operation_name);
+                            if let Some(extra) = operation.as_object() {
+                                operation_node.metadata.extra =
+                                    extra.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+                            }
+
+                            nodes.push(operation_node);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Final table schemas, folded across every migration, then the tables
+        // of models no migration creates (schema managed elsewhere)
+        let mut schemas = table_schemas(parse_result);
+        for parsed_file in &parse_result.files {
+            if !parsed_file.metadata.contains_key("table_source") {
+                continue;
+            }
+            if let Some(table) = parsed_file.metadata.get("table").and_then(|t| t.as_str()) {
+                if !schemas.iter().any(|s| s.name == table) {
+                    schemas.push(TableSchema {
+                        name: table.to_string(),
+                        columns: Vec::new(),
+                        migrations: Vec::new(),
+                    });
+                }
+            }
+        }
+
+        for schema in schemas {
+            let qualified_name = format!("table:{}", schema.name);
+            let mut table_node = UnifiedNode::new(
+                generate_id(&qualified_name),
+                UnifiedNodeType::Table,
+                schema.name.clone(),
+            )
+            .with_language("php");
+
+            table_node.qualified_name = qualified_name;
+            table_node.metadata.extra.insert(
+                "columns".to_string(),
+                serde_json::json!(schema.columns),
+            );
+            table_node.metadata.extra.insert(
+                "migrations".to_string(),
+                serde_json::json!(schema.migrations),
+            );
+
+            nodes.push(table_node);
         }
 
         nodes
@@ -826,25 +1314,102 @@ impl ProjectParser for LaravelParser {
         let mut edges = Vec::new();
 
         // Build lookup maps for faster edge creation
-        let node_by_name: HashMap<&str, &UnifiedNode> = nodes
-            .iter()
-            .map(|n| (n.name.as_str(), n))
-            .collect();
+        let classes = ClassIndex::new(nodes);
 
         let node_by_qualified: HashMap<&str, &UnifiedNode> = nodes
             .iter()
             .map(|n| (n.qualified_name.as_str(), n))
             .collect();
 
+        // Inertia pages by normalized name, controllers spell them loosely
+        let pages_by_key: HashMap<String, &UnifiedNode> = nodes
+            .iter()
+            .filter_map(|n| {
+                n.qualified_name
+                    .strip_prefix("inertia:")
+                    .map(|page_name| (normalize_page_name(page_name), n))
+            })
+            .collect();
+
+        // Livewire components by the name Blade uses (<livewire:forms.user-card>)
+        let livewire_by_name: HashMap<&str, &UnifiedNode> = nodes
+            .iter()
+            .filter_map(|n| {
+                n.metadata
+                    .extra
+                    .get("livewire_name")
+                    .and_then(|name| name.as_str())
+                    .map(|name| (name, n))
+            })
+            .collect();
+
+        // Policies registered by a provider, the others are discovered by name
+        let mut registered_policies: HashSet<&str> = HashSet::new();
+
+        // Job chains and batches can be dispatched from several places
+        let mut workflow_edges: HashSet<String> = HashSet::new();
+
+        // Route files by the route names they declare (users.index -> routes/web.php)
+        let route_files_by_name = route_files_by_name(parse_result);
+
+        // morphTo targets, and the model class of each model file
+        let morph_parents = morph_parents(parse_result);
+        let models_by_file: HashMap<&str, &UnifiedNode> = nodes
+            .iter()
+            .filter(|n| n.node_type == UnifiedNodeType::Model && !is_file_node(n))
+            .filter_map(|n| n.file_path.as_deref().map(|path| (path, n)))
+            .collect();
+        // Non-polymorphic relationships, for the inverses of one-sided ones
+        let mut declared_relations: Vec<DeclaredRelation> = Vec::new();
+
+        // Scanned files, for the imports of Inertia pages and components
+        let file_paths: HashSet<&str> = parse_result
+            .files
+            .iter()
+            .map(|f| f.source.path.as_str())
+            .collect();
+
         for parsed_file in &parse_result.files {
             let source_id = generate_id(&parsed_file.source.path);
-
-            // Create edges from dependencies (use statements)
+            let namespace = parsed_file
+                .metadata
+                .get("namespace")
+                .and_then(|ns| ns.as_str());
+
+            // Create edges from dependencies (use statements), or from the
+            // local imports of Inertia pages and components: `contains` for
+            // components they render, `uses` for the rest
+            let is_frontend = parsed_file.metadata.contains_key("is_inertia_page");
+            let children: Vec<&str> = parsed_file
+                .metadata
+                .get("child_components")
+                .and_then(|c| c.as_array())
+                .map(|c| c.iter().filter_map(|name| name.as_str()).collect())
+                .unwrap_or_default();
             for dep in &parsed_file.dependencies {
-                // Try to find target node by qualified name
-                let target_name = dep.target.rsplit('\\').next().unwrap_or(&dep.target);
+                if is_frontend {
+                    let target = resolve_import(&parsed_file.source.path, &dep.target, &file_paths);
+                    if let Some(target) = target {
+                        let rendered = dep
+                            .alias
+                            .as_deref()
+                            .is_some_and(|name| children.contains(&name));
+                        let edge_type = if rendered {
+                            UnifiedEdgeType::Contains
+                        } else {
+                            UnifiedEdgeType::Uses
+                        };
+                        let mut edge =
+                            UnifiedEdge::new(source_id.clone(), generate_id(&target), edge_type);
+                        if let Some(name) = &dep.alias {
+                            edge = edge.with_label(name);
+                        }
+                        edges.push(edge);
+                    }
+                    continue;
+                }
 
-                if let Some(target_node) = node_by_name.get(target_name) {
+                if let Some(target_node) = classes.resolve(&dep.target, None) {
                     edges.push(UnifiedEdge::new(
                         source_id.clone(),
                         target_node.id.clone(),
@@ -857,38 +1422,139 @@ impl ProjectParser for LaravelParser {
             if let Some(relationships) = parsed_file.metadata.get("relationships") {
                 if let Some(rels) = relationships.as_array() {
                     for rel in rels {
-                        if let (Some(rel_type), Some(related_model)) =
-                            (rel.get("type"), rel.get("related_model"))
-                        {
-                            let rel_type_str = rel_type.as_str().unwrap_or("");
-                            let model_name = related_model.as_str().unwrap_or("");
-
-                            if let Some(target_node) = node_by_name.get(model_name) {
-                                let edge_type = match rel_type_str {
-                                    "hasMany" | "hasManyThrough" => {
-                                        UnifiedEdgeType::Custom("has_many".to_string())
-                                    }
-                                    "hasOne" | "hasOneThrough" => {
-                                        UnifiedEdgeType::Custom("has_one".to_string())
-                                    }
-                                    "belongsTo" => {
-                                        UnifiedEdgeType::Custom("belongs_to".to_string())
-                                    }
-                                    "belongsToMany" => {
-                                        UnifiedEdgeType::Custom("belongs_to_many".to_string())
+                        let rel_type_str = rel.get("type").and_then(|t| t.as_str()).unwrap_or("");
+                        let model_name = rel.get("related_model").and_then(|m| m.as_str());
+                        let morph_name = rel.get("morph_name").and_then(|n| n.as_str());
+
+                        // morphTo resolves to every model declaring a morphOne/morphMany
+                        // of this model under the same morph name
+                        if rel_type_str == "morphTo" {
+                            let parents = morph_name
+                                .and_then(|name| morph_parents.get(name))
+                                .map(|parents| parents.as_slice())
+                                .unwrap_or(&[]);
+                            for parent in parents {
+                                let morphs_this_model = match parent
+                                    .related_model
+                                    .and_then(|m| classes.resolve(m, parent.namespace))
+                                {
+                                    Some(related) => {
+                                        related.file_path.as_deref()
+                                            == Some(parsed_file.source.path.as_str())
                                     }
-                                    "morphTo" | "morphOne" | "morphMany" => {
-                                        UnifiedEdgeType::Custom("morph".to_string())
-                                    }
-                                    _ => UnifiedEdgeType::Uses,
+                                    None => true,
                                 };
+                                let target_node = models_by_file.get(parent.path);
+                                if let Some(target_node) = target_node.filter(|_| morphs_this_model) {
+                                    edges.push(polymorphic_edge(
+                                        source_id.clone(),
+                                        target_node.id.clone(),
+                                        rel_type_str,
+                                    ));
+                                }
+                            }
+                            continue;
+                        }
 
-                                edges.push(UnifiedEdge::new(
-                                    source_id.clone(),
-                                    target_node.id.clone(),
-                                    edge_type,
-                                ));
+                        if let Some(target_node) =
+                            model_name.and_then(|m| classes.resolve(m, namespace))
+                        {
+                            let edge_type = match rel_type_str {
+                                "hasMany" | "hasManyThrough" => {
+                                    UnifiedEdgeType::Custom("has_many".to_string())
+                                }
+                                "hasOne" | "hasOneThrough" => {
+                                    UnifiedEdgeType::Custom("has_one".to_string())
+                                }
+                                "belongsTo" => UnifiedEdgeType::Custom("belongs_to".to_string()),
+                                "belongsToMany" => {
+                                    UnifiedEdgeType::Custom("belongs_to_many".to_string())
+                                }
+                                "morphOne" | "morphMany" | "morphToMany" | "morphedByMany" => {
+                                    edges.push(polymorphic_edge(
+                                        source_id.clone(),
+                                        target_node.id.clone(),
+                                        rel_type_str,
+                                    ));
+                                    continue;
+                                }
+                                _ => UnifiedEdgeType::Uses,
+                            };
+
+                            if let Some(model) = models_by_file.get(parsed_file.source.path.as_str()) {
+                                declared_relations.push(DeclaredRelation {
+                                    model,
+                                    related: target_node,
+                                    rel_type: rel_type_str,
+                                    method: rel.get("method").and_then(|m| m.as_str()).unwrap_or(""),
+                                    raw_args: rel.get("raw_args").and_then(|a| a.as_str()).unwrap_or(""),
+                                });
                             }
+
+                            edges.push(UnifiedEdge::new(
+                                source_id.clone(),
+                                target_node.id.clone(),
+                                edge_type,
+                            ));
+                        }
+                    }
+                }
+            }
+
+            // Create edges from models to the classes their attributes are cast to
+            if let Some(cast_classes) = parsed_file.metadata.get("cast_classes") {
+                if let Some(class_list) = cast_classes.as_array() {
+                    for class_name in class_list.iter().filter_map(|c| c.as_str()) {
+                        if let Some(target_node) = classes.resolve(class_name, namespace) {
+                            edges.push(UnifiedEdge::new(
+                                source_id.clone(),
+                                target_node.id.clone(),
+                                UnifiedEdgeType::Custom("casts_to".to_string()),
+                            ));
+                        }
+                    }
+                }
+            }
+
+            // Create edges from models to the events their lifecycle events fire
+            if let Some(dispatched) = parsed_file.metadata.get("dispatched_events") {
+                if let Some(event_list) = dispatched.as_array() {
+                    let model_node = models_by_file.get(parsed_file.source.path.as_str());
+                    for entry in event_list {
+                        let lifecycle_event =
+                            entry.get("event").and_then(|e| e.as_str()).unwrap_or("");
+                        let class_name = entry.get("class").and_then(|c| c.as_str());
+                        if let (Some(model_node), Some(target_node)) = (
+                            model_node,
+                            class_name.and_then(|c| classes.resolve(c, namespace)),
+                        ) {
+                            edges.push(
+                                UnifiedEdge::new(
+                                    model_node.id.clone(),
+                                    target_node.id.clone(),
+                                    UnifiedEdgeType::Custom("dispatches".to_string()),
+                                )
+                                .with_label(lifecycle_event),
+                            );
+                        }
+                    }
+                }
+            }
+
+            // Create edges from models to the observers they register
+            // (#[ObservedBy] or static::observe())
+            if let Some(observers) = parsed_file.metadata.get("observed_by") {
+                if let Some(observer_list) = observers.as_array() {
+                    let model_node = models_by_file.get(parsed_file.source.path.as_str());
+                    for observer_name in observer_list.iter().filter_map(|o| o.as_str()) {
+                        if let (Some(model_node), Some(observer_node)) =
+                            (model_node, classes.resolve(observer_name, namespace))
+                        {
+                            edges.push(UnifiedEdge::new(
+                                model_node.id.clone(),
+                                observer_node.id.clone(),
+                                UnifiedEdgeType::Custom("observed_by".to_string()),
+                            ));
                         }
                     }
                 }
@@ -916,16 +1582,24 @@ impl ProjectParser for LaravelParser {
             // Create edges from controller to Inertia pages
             if let Some(pages) = parsed_file.metadata.get("inertia_pages") {
                 if let Some(page_list) = pages.as_array() {
+                    // Different spellings of the same page share one edge
+                    let mut rendered_pages = HashSet::new();
                     for page in page_list {
                         if let Some(page_name) = page.as_str() {
                             // Try to find the Inertia page node
-                            let page_path = format!("inertia:{}", page_name);
-                            if let Some(target_node) = node_by_qualified.get(page_path.as_str()) {
+                            let target = pages_by_key.get(&normalize_page_name(page_name));
+                            if let Some(target_node) = target.filter(|n| rendered_pages.insert(n.id.as_str())) {
                                 edges.push(UnifiedEdge::new(
                                     source_id.clone(),
                                     target_node.id.clone(),
                                     UnifiedEdgeType::Custom("renders".to_string()),
                                 ));
+                                // Back-reference so the page knows its controllers
+                                edges.push(UnifiedEdge::new(
+                                    target_node.id.clone(),
+                                    source_id.clone(),
+                                    UnifiedEdgeType::Custom("rendered_by".to_string()),
+                                ));
                             }
                         }
                     }
@@ -939,12 +1613,30 @@ impl ProjectParser for LaravelParser {
                         if let Some(action) = route.get("action") {
                             if let Some(controller) = action.get("controller") {
                                 if let Some(controller_name) = controller.as_str() {
-                                    if let Some(target_node) = node_by_name.get(controller_name) {
-                                        edges.push(UnifiedEdge::new(
+                                    // An imported controller is matched exactly, never by short name
+                                    let target = match action.get("controller_fqn").and_then(|f| f.as_str()) {
+                                        Some(fqn) => classes.get(fqn),
+                                        None => classes.resolve(controller_name, namespace),
+                                    };
+                                    if let Some(target_node) = target {
+                                        let method = route
+                                            .get("method")
+                                            .and_then(|m| m.as_str())
+                                            .unwrap_or("ANY");
+
+                                        // Label with the HTTP method so exports can badge it
+                                        let mut edge = UnifiedEdge::new(
                                             source_id.clone(),
                                             target_node.id.clone(),
                                             UnifiedEdgeType::Custom("routes_to".to_string()),
-                                        ));
+                                        )
+                                        .with_label(method);
+                                        edge.detail = route
+                                            .get("uri")
+                                            .and_then(|u| u.as_str())
+                                            .map(|u| u.to_string());
+
+                                        edges.push(edge);
                                     }
                                 }
                             }
@@ -953,6 +1645,262 @@ impl ProjectParser for LaravelParser {
                 }
             }
 
+            // Create edges from routes to the views they render, through
+            // Route::view() or a closure returning view()
+            let route_list = parsed_file.metadata.get("routes").and_then(|r| r.as_array());
+            if let Some(route_list) = route_list {
+                for route in route_list {
+                    let action = match route.get("action") {
+                        Some(action) => action,
+                        None => continue,
+                    };
+                    let views: Vec<&str> = match action.get("type").and_then(|t| t.as_str()) {
+                        Some("View") => {
+                            action.get("view").and_then(|v| v.as_str()).into_iter().collect()
+                        }
+                        Some("Closure") => action
+                            .get("views")
+                            .and_then(|v| v.as_array())
+                            .map(|views| views.iter().filter_map(|v| v.as_str()).collect())
+                            .unwrap_or_default(),
+                        _ => Vec::new(),
+                    };
+
+                    for view_name in views {
+                        let view_path = format!("view:{}", view_name);
+                        if let Some(target_node) = node_by_qualified.get(view_path.as_str()) {
+                            let method =
+                                route.get("method").and_then(|m| m.as_str()).unwrap_or("ANY");
+                            let mut edge = UnifiedEdge::new(
+                                source_id.clone(),
+                                target_node.id.clone(),
+                                UnifiedEdgeType::Custom("renders".to_string()),
+                            )
+                            .with_label(method);
+                            edge.detail = route
+                                .get("uri")
+                                .and_then(|u| u.as_str())
+                                .map(|u| u.to_string());
+
+                            edges.push(edge);
+                        }
+                    }
+                }
+            }
+
+            // Create edges from container bindings (service providers)
+            if let Some(bindings) = parsed_file.metadata.get("bindings") {
+                if let Some(binding_list) = bindings.as_array() {
+                    for binding in binding_list {
+                        let abstract_name = binding.get("abstract").and_then(|a| a.as_str());
+                        let concrete_name = binding.get("concrete").and_then(|c| c.as_str());
+
+                        if let (Some(abstract_name), Some(concrete_name)) = (abstract_name, concrete_name) {
+                            if let (Some(abstract_node), Some(concrete_node)) = (
+                                classes.resolve(abstract_name, namespace),
+                                classes.resolve(concrete_name, namespace),
+                            ) {
+                                // singleton(Foo::class) binds Foo to itself, nothing to draw
+                                if abstract_node.id == concrete_node.id {
+                                    continue;
+                                }
+
+                                let binding_type = binding
+                                    .get("type")
+                                    .and_then(|t| t.as_str())
+                                    .unwrap_or("bind");
+
+                                edges.push(
+                                    UnifiedEdge::new(
+                                        abstract_node.id.clone(),
+                                        concrete_node.id.clone(),
+                                        UnifiedEdgeType::Custom("binds".to_string()),
+                                    )
+                                    .with_label(binding_type),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Create edges from registered policies to the models they guard
+            if let Some(policies) = parsed_file.metadata.get("policies") {
+                if let Some(policy_list) = policies.as_array() {
+                    for registration in policy_list {
+                        let model_name = registration.get("model").and_then(|m| m.as_str());
+                        let policy_name = registration.get("policy").and_then(|p| p.as_str());
+
+                        if let (Some(model_name), Some(policy_name)) = (model_name, policy_name) {
+                            if let (Some(policy_node), Some(model_node)) = (
+                                classes.resolve(policy_name, namespace),
+                                classes.resolve(model_name, namespace),
+                            ) {
+                                registered_policies.insert(policy_node.id.as_str());
+                                edges.push(UnifiedEdge::new(
+                                    policy_node.id.clone(),
+                                    model_node.id.clone(),
+                                    UnifiedEdgeType::Custom("guards".to_string()),
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Create edges from listeners to the events they handle
+            if let Some(listeners) = parsed_file.metadata.get("event_listeners") {
+                if let Some(listener_list) = listeners.as_array() {
+                    for registration in listener_list {
+                        let event_name = registration.get("event").and_then(|e| e.as_str());
+                        let listener_name = registration.get("listener").and_then(|l| l.as_str());
+
+                        if let (Some(event_name), Some(listener_name)) = (event_name, listener_name) {
+                            if let (Some(listener_node), Some(event_node)) = (
+                                classes.resolve(listener_name, namespace),
+                                classes.resolve(event_name, namespace),
+                            ) {
+                                edges.push(UnifiedEdge::new(
+                                    listener_node.id.clone(),
+                                    event_node.id.clone(),
+                                    UnifiedEdgeType::Custom("handles".to_string()),
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Create edges from models to the observers a provider registers
+            if let Some(observers) = parsed_file.metadata.get("model_observers") {
+                if let Some(observer_list) = observers.as_array() {
+                    for registration in observer_list {
+                        let model_name = registration.get("model").and_then(|m| m.as_str());
+                        let observer_name = registration.get("observer").and_then(|o| o.as_str());
+
+                        if let (Some(model_name), Some(observer_name)) = (model_name, observer_name) {
+                            if let (Some(model_node), Some(observer_node)) = (
+                                classes.resolve(model_name, namespace),
+                                classes.resolve(observer_name, namespace),
+                            ) {
+                                edges.push(UnifiedEdge::new(
+                                    model_node.id.clone(),
+                                    observer_node.id.clone(),
+                                    UnifiedEdgeType::Custom("observed_by".to_string()),
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Create edges from seeders to the seeders they call
+            if let Some(seeders) = parsed_file.metadata.get("seeders_called") {
+                if let Some(seeder_list) = seeders.as_array() {
+                    for seeder_name in seeder_list.iter().filter_map(|s| s.as_str()) {
+                        if let Some(target_node) = classes.resolve(seeder_name, namespace) {
+                            edges.push(UnifiedEdge::new(
+                                source_id.clone(),
+                                target_node.id.clone(),
+                                UnifiedEdgeType::Calls,
+                            ));
+                        }
+                    }
+                }
+            }
+
+            // Create edge from a factory to the model it creates
+            if let Some(model) = parsed_file.metadata.get("factory_model") {
+                if let Some(target_node) =
+                    model.as_str().and_then(|name| classes.resolve(name, namespace))
+                {
+                    edges.push(UnifiedEdge::new(
+                        source_id.clone(),
+                        target_node.id.clone(),
+                        UnifiedEdgeType::Custom("factory_for".to_string()),
+                    ));
+                }
+            }
+
+            // Create edges to the jobs dispatched and events fired by this file
+            for key in ["dispatched_jobs", "fired_events"] {
+                if let Some(class_list) = parsed_file.metadata.get(key).and_then(|c| c.as_array()) {
+                    for class_name in class_list.iter().filter_map(|c| c.as_str()) {
+                        if let Some(target_node) = classes.resolve(class_name, namespace) {
+                            edges.push(UnifiedEdge::new(
+                                source_id.clone(),
+                                target_node.id.clone(),
+                                UnifiedEdgeType::Custom("dispatches".to_string()),
+                            ));
+                        }
+                    }
+                }
+            }
+
+            // Create edges between consecutive jobs of chains and batches
+            if let Some(workflows) = parsed_file.metadata.get("job_workflows") {
+                if let Some(workflow_list) = workflows.as_array() {
+                    for workflow in workflow_list {
+                        let edge_kind = match workflow.get("type").and_then(|t| t.as_str()) {
+                            Some("batch") => "batched_with",
+                            _ => "chained_with",
+                        };
+                        let jobs: Vec<&UnifiedNode> = workflow
+                            .get("jobs")
+                            .and_then(|j| j.as_array())
+                            .map(|jobs| {
+                                jobs.iter()
+                                    .filter_map(|job| job.as_str())
+                                    .filter_map(|job| classes.resolve(job, namespace))
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+
+                        for pair in jobs.windows(2) {
+                            let edge = UnifiedEdge::new(
+                                pair[0].id.clone(),
+                                pair[1].id.clone(),
+                                UnifiedEdgeType::Custom(edge_kind.to_string()),
+                            );
+                            if pair[0].id != pair[1].id && workflow_edges.insert(edge.id.clone()) {
+                                edges.push(edge);
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Create edges from GraphQL operations to their resolver classes
+            if let Some(operations) = parsed_file.metadata.get("graphql_operations") {
+                if let Some(operation_list) = operations.as_array() {
+                    for operation in operation_list {
+                        let type_name = operation.get("type").and_then(|t| t.as_str()).unwrap_or("");
+                        let field = operation.get("field").and_then(|f| f.as_str()).unwrap_or("");
+
+                        if let Some(resolver) = operation.get("resolver").and_then(|r| r.as_str()) {
+                            if let Some(target_node) = classes.resolve(resolver, None) {
+                                let operation_id = generate_id(&format!(
+                                    "{}::{}.{}",
+                                    parsed_file.source.path, type_name, field
+                                ));
+
+                                let mut edge = UnifiedEdge::new(
+                                    operation_id,
+                                    target_node.id.clone(),
+                                    UnifiedEdgeType::Custom("resolves".to_string()),
+                                );
+                                edge.detail = operation
+                                    .get("resolver_method")
+                                    .and_then(|m| m.as_str())
+                                    .map(|m| m.to_string());
+
+                                edges.push(edge);
+                            }
+                        }
+                    }
+                }
+            }
+
             // Create edges from Blade extends
             if let Some(extends) = parsed_file.metadata.get("extends") {
                 if let Some(parent_view) = extends.as_str() {
@@ -965,26 +1913,146 @@ impl ProjectParser for LaravelParser {
                         ));
                     }
                 }
+
+                // Link the sections to the layouts yielding them
+                edges.extend(section_edges(&source_id, parsed_file, &node_by_qualified));
+            }
+
+            // Create edges from Blade views to the components they use
+            if let Some(components) = parsed_file.metadata.get("components") {
+                if let Some(component_list) = components.as_array() {
+                    let mut used_components = HashSet::new();
+                    for component in component_list {
+                        let name = component.get("name").and_then(|n| n.as_str()).unwrap_or("");
+                        let kind = component.get("type").and_then(|t| t.as_str()).unwrap_or("");
+
+                        let target = match kind {
+                            // <x-forms.input>: App\View\Components\Forms\Input or components.forms.input
+                            "anonymous" => classes
+                                .get(&component_class_name(name))
+                                .or_else(|| {
+                                    node_by_qualified
+                                        .get(format!("view:components.{}", name).as_str())
+                                        .copied()
+                                })
+                                .or_else(|| {
+                                    node_by_qualified
+                                        .get(format!("view:components.{}.index", name).as_str())
+                                        .copied()
+                                }),
+                            // @component('components.alert') names the view directly
+                            "class" => node_by_qualified.get(format!("view:{}", name).as_str()).copied(),
+                            _ => None,
+                        };
+
+                        if let Some(target_node) = target {
+                            if used_components.insert(target_node.id.as_str()) {
+                                edges.push(UnifiedEdge::new(
+                                    source_id.clone(),
+                                    target_node.id.clone(),
+                                    UnifiedEdgeType::Custom("uses_component".to_string()),
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Create edges from Blade views to the Livewire components they embed
+            if let Some(components) = parsed_file.metadata.get("livewire_components") {
+                if let Some(component_list) = components.as_array() {
+                    let mut used_components = HashSet::new();
+                    for name in component_list.iter().filter_map(|c| c.as_str()) {
+                        if let Some(target_node) = livewire_by_name.get(name) {
+                            if used_components.insert(target_node.id.as_str()) {
+                                edges.push(UnifiedEdge::new(
+                                    source_id.clone(),
+                                    target_node.id.clone(),
+                                    UnifiedEdgeType::Custom("uses_component".to_string()),
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Create edges from Blade views and Inertia pages (Ziggy's route())
+            // to the route files declaring the routes they link to
+            if let Some(routes_referenced) = parsed_file.metadata.get("routes_referenced") {
+                if let Some(route_names) = routes_referenced.as_array() {
+                    let mut names_per_file: Vec<(&str, Vec<&str>)> = Vec::new();
+                    for route_name in route_names.iter().filter_map(|r| r.as_str()) {
+                        if let Some(route_file) = route_files_by_name.get(route_name) {
+                            match names_per_file.iter_mut().find(|(path, _)| path == route_file) {
+                                Some((_, names)) => names.push(route_name),
+                                None => names_per_file.push((route_file, vec![route_name])),
+                            }
+                        }
+                    }
+
+                    for (route_file, names) in names_per_file {
+                        let mut edge = UnifiedEdge::new(
+                            source_id.clone(),
+                            generate_id(route_file),
+                            UnifiedEdgeType::Custom("references_route".to_string()),
+                        );
+                        edge.detail = Some(names.join(", "));
+                        edges.push(edge);
+                    }
+                }
             }
 
             // Create edges from Blade includes
             if let Some(includes) = parsed_file.metadata.get("includes") {
                 if let Some(include_list) = includes.as_array() {
+                    let include_base = parsed_file
+                        .metadata
+                        .get("include_base")
+                        .and_then(|b| b.as_str());
+
+                    let mut included = HashSet::new();
                     for include in include_list {
                         if let Some(include_name) = include.as_str() {
-                            let include_path = format!("view:{}", include_name);
-                            if let Some(target_node) = node_by_qualified.get(include_path.as_str()) {
-                                edges.push(UnifiedEdge::new(
-                                    source_id.clone(),
-                                    target_node.id.clone(),
-                                    UnifiedEdgeType::Custom("includes".to_string()),
-                                ));
+                            // Absolute from the views root first, then relative to
+                            // the including view (.partials.row is always relative)
+                            let absolute = (!include_name.starts_with('.'))
+                                .then(|| format!("view:{}", include_name));
+                            let relative = include_base.map(|base| {
+                                format!("view:{}.{}", base, include_name.trim_start_matches('.'))
+                            });
+                            let target = absolute
+                                .into_iter()
+                                .chain(relative)
+                                .find_map(|path| node_by_qualified.get(path.as_str()).copied());
+
+                            if let Some(target_node) = target {
+                                if included.insert(target_node.id.as_str()) {
+                                    edges.push(UnifiedEdge::new(
+                                        source_id.clone(),
+                                        target_node.id.clone(),
+                                        UnifiedEdgeType::Custom("includes".to_string()),
+                                    ));
+                                }
                             }
                         }
                     }
                 }
             }
 
+            // Create edges from models to their table
+            if parsed_file.metadata.contains_key("table_source") {
+                let table = parsed_file.metadata.get("table").and_then(|t| t.as_str());
+                if let Some(table_node) =
+                    table.and_then(|t| node_by_qualified.get(format!("table:{}", t).as_str()))
+                {
+                    edges.push(UnifiedEdge::new(
+                        source_id.clone(),
+                        table_node.id.clone(),
+                        UnifiedEdgeType::Custom("maps_to".to_string()),
+                    ));
+                }
+            }
+
             // Create edges from foreign keys (migrations)
             if let Some(foreign_keys) = parsed_file.metadata.get("foreign_keys") {
                 if let Some(fk_list) = foreign_keys.as_array() {
@@ -1015,6 +2083,57 @@ impl ProjectParser for LaravelParser {
             }
         }
 
+        // Infer the inverses of relationships declared on one side only
+        edges.extend(infer_inverse_relationships(parse_result, &declared_relations));
+
+        // Create edges from the other policies to their model, by Laravel's
+        // discovery convention (PostPolicy guards App\Models\Post)
+        let policy_files: HashSet<&str> = nodes
+            .iter()
+            .filter(|n| n.node_type == UnifiedNodeType::Custom("policy".to_string()))
+            .filter_map(|n| n.file_path.as_deref())
+            .collect();
+        for policy_node in nodes.iter().filter(|n| {
+            n.node_type == UnifiedNodeType::Class
+                && n.file_path.as_deref().is_some_and(|path| policy_files.contains(path))
+                && !registered_policies.contains(n.id.as_str())
+        }) {
+            let model_node = policy_node
+                .name
+                .strip_suffix("Policy")
+                .filter(|model| !model.is_empty())
+                .and_then(|model| classes.resolve(model, Some("App\\Models")))
+                .filter(|n| n.node_type == UnifiedNodeType::Model);
+            if let Some(model_node) = model_node {
+                let mut edge = UnifiedEdge::new(
+                    policy_node.id.clone(),
+                    model_node.id.clone(),
+                    UnifiedEdgeType::Custom("guards".to_string()),
+                );
+                edge.detail = Some("discovered".to_string());
+                edges.push(edge);
+            }
+        }
+
+        // Create edges from migrations to the tables they shaped
+        for table_node in nodes.iter().filter(|n| n.node_type == UnifiedNodeType::Table) {
+            let migrations = table_node
+                .metadata
+                .extra
+                .get("migrations")
+                .and_then(|m| m.as_array())
+                .map(|m| m.as_slice())
+                .unwrap_or(&[]);
+
+            for path in migrations.iter().filter_map(|p| p.as_str()) {
+                edges.push(UnifiedEdge::new(
+                    generate_id(path),
+                    table_node.id.clone(),
+                    UnifiedEdgeType::Custom("migrates".to_string()),
+                ));
+            }
+        }
+
         edges
     }
 
@@ -1024,3 +2143,533 @@ impl ProjectParser for LaravelParser {
         Vec::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{EnumCase, UnifiedGraph};
+    use crate::parsers::common::fixtures::project_dir;
+    use crate::parsers::laravel::fixtures::{graph_of, graph_with, parse_with};
+    use crate::parsers::laravel::BLADE_RELATIVE_INCLUDES_OPTION;
+
+    /// The class-like node of a qualified name
+    fn class_node<'a>(graph: &'a UnifiedGraph, qualified_name: &str) -> &'a UnifiedNode {
+        graph
+            .nodes
+            .iter()
+            .find(|n| n.qualified_name == qualified_name && !is_file_node(n))
+            .unwrap_or_else(|| panic!("no node {}", qualified_name))
+    }
+
+    /// The file node of the file at `path`, relative to the project
+    fn file_node<'a>(graph: &'a UnifiedGraph, path: &str) -> &'a UnifiedNode {
+        graph
+            .nodes
+            .iter()
+            .find(|n| is_file_node(n) && n.file_path.as_deref().is_some_and(|p| p.ends_with(path)))
+            .unwrap_or_else(|| panic!("no file node {}", path))
+    }
+
+    #[tokio::test]
+    async fn qualified_names_dont_fall_back_to_short_names() {
+        let graph = graph_of(&[
+            (
+                "app/Models/User.php",
+                "<?php\n\nnamespace App\\Models;\n\n\
+                 use Illuminate\\Foundation\\Auth\\User as Authenticatable;\n\n\
+                 class User extends Authenticatable\n{\n}\n",
+            ),
+            (
+                "app/Http/Controllers/UserController.php",
+                "<?php\n\nnamespace App\\Http\\Controllers;\n\n\
+                 use App\\Models\\User;\n\n\
+                 class UserController extends Controller\n{\n}\n",
+            ),
+        ])
+        .await;
+
+        let user = class_node(&graph, "App\\Models\\User");
+        let controller = file_node(&graph, "app/Http/Controllers/UserController.php");
+        let uses: Vec<&UnifiedEdge> = graph
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == UnifiedEdgeType::Uses && e.target == user.id)
+            .collect();
+
+        assert_eq!(uses.len(), 1);
+        assert_eq!(uses[0].source, controller.id);
+    }
+
+    #[test]
+    fn only_app_http_resources_are_api_resources() {
+        let parser = LaravelParser::new();
+        let file_type = |path: &str| {
+            let name = path.rsplit('/').next().unwrap().to_string();
+            parser.determine_file_type(&SourceFile::new(name, path.to_string(), path.to_string()))
+        };
+
+        assert_eq!(
+            file_type("app/Http/Resources/UserResource.php"),
+            LaravelFileType::Resource
+        );
+        assert_ne!(
+            file_type("resources/lang/en/auth.php"),
+            LaravelFileType::Resource
+        );
+        assert_ne!(
+            file_type("resources/stubs/model.php"),
+            LaravelFileType::Resource
+        );
+    }
+
+    #[tokio::test]
+    async fn route_edges_export_their_http_method() {
+        let graph = graph_of(&[
+            (
+                "routes/web.php",
+                "<?php\n\nuse App\\Http\\Controllers\\UserController;\n\n\
+                 Route::post('/users', [UserController::class, 'store']);\n",
+            ),
+            (
+                "app/Http/Controllers/UserController.php",
+                "<?php\n\nnamespace App\\Http\\Controllers;\n\n\
+                 class UserController extends Controller\n{\n    public function store() {}\n}\n",
+            ),
+        ])
+        .await;
+
+        let routes = file_node(&graph, "routes/web.php");
+        let edge = graph
+            .edges
+            .iter()
+            .find(|e| e.source == routes.id && e.edge_type.as_key() == "custom:routes_to")
+            .unwrap();
+        assert_eq!(edge.label.as_deref(), Some("POST"));
+
+        let dot = crate::export::export_dot(&graph);
+        assert!(dot.contains("[label=\"POST /users\", type=\"custom:routes_to\"]"));
+        let graphml = crate::export::export_graphml(&graph);
+        assert!(graphml.contains("<data key=\"edge_label\">POST</data>"));
+        let cytoscape = crate::export::export_cytoscape(&graph);
+        assert!(cytoscape["elements"]["edges"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|e| e["data"]["label"] == "POST"));
+    }
+
+    /// Qualified names of the views `view` includes
+    fn included_views(graph: &UnifiedGraph, view: &str) -> Vec<String> {
+        let source = &file_node(graph, view).id;
+        let mut included: Vec<String> = graph
+            .edges
+            .iter()
+            .filter(|e| &e.source == source && e.edge_type.as_key() == "custom:includes")
+            .filter_map(|e| graph.nodes.iter().find(|n| n.id == e.target))
+            .map(|n| n.qualified_name.clone())
+            .collect();
+        included.sort();
+        included
+    }
+
+    const NESTED_VIEWS: &[(&str, &str)] = &[
+        (
+            "resources/views/admin/users/index.blade.php",
+            "@include('partials.nav')\n@include('partials.row')\n@include('.partials.row')\n",
+        ),
+        (
+            "resources/views/admin/users/partials/row.blade.php",
+            "<tr></tr>\n",
+        ),
+        ("resources/views/partials/nav.blade.php", "<nav></nav>\n"),
+    ];
+
+    #[tokio::test]
+    async fn includes_fall_back_to_the_including_views_directory() {
+        let graph = graph_of(NESTED_VIEWS).await;
+
+        assert_eq!(
+            included_views(&graph, "resources/views/admin/users/index.blade.php"),
+            ["view:admin.users.partials.row", "view:partials.nav"]
+        );
+    }
+
+    #[tokio::test]
+    async fn relative_includes_can_be_disabled() {
+        let graph = graph_with(NESTED_VIEWS, |config| {
+            config.language_options.insert(
+                BLADE_RELATIVE_INCLUDES_OPTION.to_string(),
+                serde_json::json!(false),
+            );
+        })
+        .await;
+
+        assert_eq!(
+            included_views(&graph, "resources/views/admin/users/index.blade.php"),
+            ["view:partials.nav"]
+        );
+    }
+
+    #[tokio::test]
+    async fn enums_carry_their_backing_type_and_cases() {
+        let graph = graph_of(&[
+            (
+                "app/Enums/Status.php",
+                "<?php\n\nnamespace App\\Enums;\n\n\
+                 enum Status: string implements HasLabel\n{\n\
+                 case Active = 'active';\ncase Archived = 'archived';\n}\n",
+            ),
+            (
+                "app/Support/Direction.php",
+                "<?php\n\nnamespace App\\Support;\n\n\
+                 enum Direction\n{\n    case Up;\n    case Down;\n}\n",
+            ),
+        ])
+        .await;
+
+        let status = class_node(&graph, "App\\Enums\\Status");
+        assert_eq!(status.node_type, UnifiedNodeType::Enum);
+        assert_eq!(status.metadata.backing_type.as_deref(), Some("string"));
+        assert_eq!(
+            status.metadata.enum_cases,
+            Some(vec![
+                EnumCase {
+                    name: "Active".to_string(),
+                    value: Some("active".to_string()),
+                },
+                EnumCase {
+                    name: "Archived".to_string(),
+                    value: Some("archived".to_string()),
+                },
+            ])
+        );
+
+        let direction = class_node(&graph, "App\\Support\\Direction");
+        assert_eq!(direction.metadata.backing_type, None);
+        let cases = direction.metadata.enum_cases.as_ref().unwrap();
+        assert_eq!(cases.len(), 2);
+        assert!(cases.iter().all(|case| case.value.is_none()));
+
+        // Declaring only an enum makes the file an enum file outside app/Enums too
+        let file = file_node(&graph, "app/Support/Direction.php");
+        assert_eq!(file.node_type, UnifiedNodeType::Custom("enum".to_string()));
+    }
+
+    #[tokio::test]
+    async fn default_config_scans_graphql_schemas() {
+        let dir = project_dir(&[("graphql/schema.graphql", "type Query { id: ID }")]);
+
+        let parser = LaravelParser::new();
+        let config = parser.default_config();
+        let files = parser.scan_files(dir.path(), &config, None).await.unwrap();
+
+        assert!(files.iter().any(|f| f.path.ends_with("schema.graphql")));
+    }
+
+    #[tokio::test]
+    async fn malformed_files_are_reported_as_warnings() {
+        let result = parse_with(
+            &[
+                (
+                    "app/Http/Controllers/HelperController.php",
+                    "<?php\nnamespace App\\Http\\Controllers;\n\nfunction helper() {}\n",
+                ),
+                (
+                    "database/migrations/2024_01_01_000000_create_users_table.php",
+                    "<?php\n\nreturn new class extends Migration\n{\n};\n",
+                ),
+                (
+                    "app/Models/User.php",
+                    "<?php\nnamespace App\\Models;\n\nclass User extends Model\n{\n\
+public function posts()\n{\n}\n",
+                ),
+            ],
+            |_| {},
+        )
+        .await;
+
+        let mut warnings = result.all_warnings();
+        warnings.sort();
+        assert_eq!(
+            warnings,
+            vec![
+                "Neither up() nor down() found in migration \
+database/migrations/2024_01_01_000000_create_users_table.php",
+                "No controller class found in app/Http/Controllers/HelperController.php",
+                "Unbalanced braces in app/Models/User.php: 2 opened, 1 closed",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn every_class_of_a_file_gets_its_node() {
+        let graph = graph_of(&[
+            (
+                "app/Support/Money.php",
+                "<?php
+namespace App\\Support;
+
+interface Currency
+{
+}
+
+class Money
+{
+}
+
+final class Euro implements Currency
+{
+}
+",
+            ),
+            (
+                "app/Legacy/helpers.php",
+                "<?php
+namespace App\\Legacy\\Old {
+    class Helper {}
+}
+
+namespace App\\Legacy\\New {
+    class Helper {}
+}
+",
+            ),
+        ])
+        .await;
+
+        assert_eq!(
+            class_node(&graph, "App\\Support\\Currency").node_type,
+            UnifiedNodeType::Interface
+        );
+        for qualified_name in [
+            "App\\Support\\Money",
+            "App\\Support\\Euro",
+            "App\\Legacy\\Old\\Helper",
+            "App\\Legacy\\New\\Helper",
+        ] {
+            assert_eq!(
+                class_node(&graph, qualified_name).node_type,
+                UnifiedNodeType::Class
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn unscanned_local_imports_are_recorded_as_unresolved() {
+        let page = "<script setup>
+import Form from './Partials/Form.vue';
+import { formatDate } from '@/lib/format';
+import Missing from '@/Components/Missing';
+import axios from 'axios';
+</script>
+
+<template>
+    <Form />
+</template>
+";
+        let graph = graph_of(&[
+            ("resources/js/Pages/Users/Edit.vue", page),
+            (
+                "resources/js/Pages/Users/Partials/Form.vue",
+                "<template><form /></template>\n",
+            ),
+            (
+                "resources/js/lib/format.ts",
+                "export const formatDate = (d) => d;\n",
+            ),
+        ])
+        .await;
+
+        let edit = file_node(&graph, "resources/js/Pages/Users/Edit.vue");
+        let form = file_node(&graph, "resources/js/Pages/Users/Partials/Form.vue");
+        assert!(graph.edges.iter().any(|e| {
+            e.edge_type == UnifiedEdgeType::Contains && e.source == edit.id && e.target == form.id
+        }));
+        assert_eq!(
+            edit.metadata.extra.get("unresolved_imports"),
+            Some(&serde_json::json!(["@/lib/format", "@/Components/Missing"]))
+        );
+        assert!(form.metadata.extra.get("unresolved_imports").is_none());
+    }
+
+    #[tokio::test]
+    async fn inertia_pages_reference_the_routes_they_call_through_ziggy() {
+        let routes = "<?php\n\nuse Illuminate\\Support\\Facades\\Route;\n\n\
+Route::get('/users/{user}', [UserController::class, 'show'])->name('users.show');\n\
+Route::get('/', [HomeController::class, 'index'])->name('home');\n\
+Route::get('/about', [HomeController::class, 'about'])->name('about');\n";
+        let page = "<script setup>
+import { router } from '@inertiajs/vue3';
+const open = (user) => router.visit(route('users.show', user.id));
+const active = route().current('users.*');
+router.route('about');
+</script>
+
+<template>
+    <a :href=\"this.route('home')\">Home</a>
+</template>
+";
+        let graph = graph_of(&[
+            ("routes/web.php", routes),
+            ("resources/js/Pages/Users/Index.vue", page),
+        ])
+        .await;
+
+        let index = file_node(&graph, "resources/js/Pages/Users/Index.vue");
+        assert_eq!(
+            index.metadata.extra.get("routes_referenced"),
+            Some(&serde_json::json!(["users.show", "home"]))
+        );
+
+        let web = file_node(&graph, "routes/web.php");
+        let references: Vec<_> = graph
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == UnifiedEdgeType::Custom("references_route".to_string()))
+            .map(|e| (&e.source, &e.target, e.detail.as_deref()))
+            .collect();
+        assert_eq!(references, [(&index.id, &web.id, Some("users.show, home"))]);
+    }
+
+    #[tokio::test]
+    async fn controllers_render_their_inertia_pages() {
+        let controller = "<?php
+
+namespace App\\Http\\Controllers;
+
+use Inertia\\Inertia;
+
+class UserController extends Controller
+{
+    public function index()
+    {
+        return Inertia::render('Pages/Users/Index');
+    }
+}
+";
+        let graph = graph_of(&[
+            ("app/Http/Controllers/UserController.php", controller),
+            (
+                "resources/js/Pages/Users/Index.vue",
+                "<template><h1>Users</h1></template>\n",
+            ),
+        ])
+        .await;
+
+        let controller = file_node(&graph, "app/Http/Controllers/UserController.php");
+        let page = file_node(&graph, "resources/js/Pages/Users/Index.vue");
+        let edges = |edge_type: &str| -> Vec<_> {
+            graph
+                .edges
+                .iter()
+                .filter(|e| e.edge_type == UnifiedEdgeType::Custom(edge_type.to_string()))
+                .map(|e| (&e.source, &e.target))
+                .collect()
+        };
+        assert_eq!(edges("renders"), [(&controller.id, &page.id)]);
+        assert_eq!(edges("rendered_by"), [(&page.id, &controller.id)]);
+    }
+
+    #[tokio::test]
+    async fn models_link_to_their_events_and_observers() {
+        let user = "<?php
+
+namespace App\\Models;
+
+use App\\Events\\UserSaved;
+use App\\Observers\\AuditObserver;
+use App\\Observers\\UserObserver;
+use Illuminate\\Database\\Eloquent\\Attributes\\ObservedBy;
+use Illuminate\\Database\\Eloquent\\Model;
+
+#[ObservedBy([UserObserver::class])]
+class User extends Model
+{
+    protected $dispatchesEvents = [
+        'saved' => UserSaved::class,
+    ];
+
+    protected static function booted(): void
+    {
+        static::observe(AuditObserver::class);
+    }
+}
+";
+        let provider = "<?php
+
+namespace App\\Providers;
+
+use App\\Models\\User;
+use App\\Observers\\LogObserver;
+use Illuminate\\Support\\ServiceProvider;
+
+class AppServiceProvider extends ServiceProvider
+{
+    public function boot(): void
+    {
+        User::observe(LogObserver::class);
+    }
+}
+";
+        let class = |namespace: &str, name: &str| {
+            format!(
+                "<?php\n\nnamespace {};\n\nclass {}\n{{\n}}\n",
+                namespace, name
+            )
+        };
+        let event = class("App\\Events", "UserSaved");
+        let observers: Vec<(String, String)> = ["UserObserver", "AuditObserver", "LogObserver"]
+            .iter()
+            .map(|name| {
+                (
+                    format!("app/Observers/{}.php", name),
+                    class("App\\Observers", name),
+                )
+            })
+            .collect();
+        let mut files = vec![
+            ("app/Models/User.php", user),
+            ("app/Providers/AppServiceProvider.php", provider),
+            ("app/Events/UserSaved.php", event.as_str()),
+        ];
+        files.extend(
+            observers
+                .iter()
+                .map(|(path, content)| (path.as_str(), content.as_str())),
+        );
+        let graph = graph_of(&files).await;
+
+        let names: HashMap<&str, &str> = graph
+            .nodes
+            .iter()
+            .map(|n| (n.id.as_str(), n.qualified_name.as_str()))
+            .collect();
+        let mut edges: Vec<String> = graph
+            .edges
+            .iter()
+            .filter(|e| {
+                e.edge_type == UnifiedEdgeType::Custom("dispatches".to_string())
+                    || e.edge_type == UnifiedEdgeType::Custom("observed_by".to_string())
+            })
+            .map(|e| {
+                format!(
+                    "{} {} {} {}",
+                    names[e.source.as_str()],
+                    e.edge_type.as_key(),
+                    names[e.target.as_str()],
+                    e.label.as_deref().unwrap_or("-")
+                )
+            })
+            .collect();
+        edges.sort();
+        assert_eq!(
+            edges,
+            [
+                "App\\Models\\User custom:dispatches App\\Events\\UserSaved saved",
+                "App\\Models\\User custom:observed_by App\\Observers\\AuditObserver -",
+                "App\\Models\\User custom:observed_by App\\Observers\\LogObserver -",
+                "App\\Models\\User custom:observed_by App\\Observers\\UserObserver -",
+            ]
+        );
+    }
+}
@@ -1,8 +1,35 @@
 use regex::Regex;
-use std::fs;
 
-use crate::models::{Dependency, ParsedFile, SourceFile, Symbol, SymbolType};
-use crate::parsers::{ParseError, ParserConfig, ParserResult};
+use crate::models::{
+    AttributeInfo, Dependency, EnumCase, ParameterInfo, ParsedFile, SourceFile, Symbol,
+    SymbolType,
+};
+use crate::parsers::common::{
+    blank_comments_and_strings, block_body, matching_bracket, matching_paren, read_source,
+    split_top_level, unbalanced_braces_warning,
+};
+use crate::parsers::{ParserConfig, ParserResult};
+
+lazy_static::lazy_static! {
+    // Match: namespace App\Models;  or  namespace App\Models {
+    static ref NAMESPACE_DECLARATION_REGEX: Regex = Regex::new(
+        r"(?m)^\s*namespace\s+([\w\\]+)\s*[;{]"
+    ).unwrap();
+
+    // Match: 'email' => 'required|email'  (one entry of an associative array)
+    pub(super) static ref ARRAY_ENTRY_REGEX: Regex = Regex::new(
+        r#"(?s)^\s*['"]([^'"]+)['"]\s*=>\s*(.+?)\s*$"#
+    ).unwrap();
+
+    // Match: return [  (an array returned by a file or method)
+    pub(super) static ref RETURN_ARRAY_REGEX: Regex = Regex::new(r"\breturn\s*\[").unwrap();
+
+    // Match: User::class  or  \App\Models\User::class
+    pub(super) static ref CLASS_REF_REGEX: Regex = Regex::new(r"(\\?[\w\\]+)::class").unwrap();
+
+    // Match: 'name'  or  "name"  (a quoted identifier)
+    pub(super) static ref QUOTED_NAME_REGEX: Regex = Regex::new(r#"['"](\w+)['"]"#).unwrap();
+}
 
 /// Base PHP parser with common regex patterns for Laravel
 pub struct PhpParser {
@@ -11,11 +38,22 @@ pub struct PhpParser {
     class_regex: Regex,
     interface_regex: Regex,
     trait_regex: Regex,
+    enum_regex: Regex,
+    enum_case_regex: Regex,
     trait_use_regex: Regex,
     function_regex: Regex,
     method_regex: Regex,
     property_regex: Regex,
     const_regex: Regex,
+    bus_workflow_regex: Regex,
+    with_chain_regex: Regex,
+    chain_call_regex: Regex,
+    dispatch_head_regex: Regex,
+    new_job_regex: Regex,
+    static_dispatch_regex: Regex,
+    dispatch_helper_regex: Regex,
+    rules_method_regex: Regex,
+    validate_call_regex: Regex,
 }
 
 impl PhpParser {
@@ -42,6 +80,14 @@ impl PhpParser {
             // Match: trait HasFactory
             trait_regex: Regex::new(r"(?m)^\s*trait\s+(\w+)").unwrap(),
 
+            // Match: enum Status: string implements HasLabel
+            enum_regex: Regex::new(
+                r"(?m)^\s*enum\s+(\w+)(?:\s*:\s*(\w+))?(?:\s+implements\s+([\w\\,\s]+?))?\s*\{"
+            ).unwrap(),
+
+            // Match: case Active = 'active';  or  case Pending;
+            enum_case_regex: Regex::new(r"(?m)^\s*case\s+(\w+)\s*(?:=\s*([^;]+?))?\s*;").unwrap(),
+
             // Match: use HasFactory, Notifiable;
             trait_use_regex: Regex::new(r"(?m)^\s*use\s+([\w\\]+(?:\s*,\s*[\w\\]+)*)\s*;").unwrap(),
 
@@ -50,9 +96,9 @@ impl PhpParser {
                 r"(?m)^\s*(?:(public|protected|private)\s+)?(?:(static)\s+)?function\s+(\w+)\s*\("
             ).unwrap(),
 
-            // Match: public function store(Request $request)
+            // Match: public function store(  or  #[Computed] public function total(  (up to the parenthesis)
             method_regex: Regex::new(
-                r"(?m)^\s*(public|protected|private)\s+(?:(static)\s+)?function\s+(\w+)\s*\(([^)]*)\)"
+                r"(?m)^\s*(?:#\[[^\n]*\]\s*)?(public|protected|private)\s+(?:(static)\s+)?function\s+(\w+)\s*\("
             ).unwrap(),
 
             // Match: public $name; or protected string $email;
@@ -64,6 +110,44 @@ impl PhpParser {
             const_regex: Regex::new(
                 r"(?m)^\s*(?:(public|protected|private)\s+)?const\s+(\w+)\s*="
             ).unwrap(),
+
+            // Match: Bus::chain([  or  Bus::batch([
+            bus_workflow_regex: Regex::new(r"Bus::(chain|batch)\s*\(\s*\[").unwrap(),
+
+            // Match: ProcessPodcast::withChain([
+            with_chain_regex: Regex::new(r"(\\?[\w\\]+)::withChain\s*\(\s*\[").unwrap(),
+
+            // Match: ->chain([
+            chain_call_regex: Regex::new(r"->\s*chain\s*\(\s*\[").unwrap(),
+
+            // Match: ProcessPodcast::dispatch(  or  dispatch(new ProcessPodcast
+            dispatch_head_regex: Regex::new(
+                r"(?:(\\?[\w\\]+)::dispatch\w*\s*\(|\bdispatch\s*\(\s*new\s+(\\?[\w\\]+))"
+            ).unwrap(),
+
+            // Match: new OptimizePodcast(  (a job instance in a chain or batch)
+            new_job_regex: Regex::new(r"^\s*new\s+(\\?[\w\\]+)").unwrap(),
+
+            // Match: ProcessPodcast::dispatch(  or  ::dispatchSync( / ::dispatchIf( ...
+            static_dispatch_regex: Regex::new(
+                r"(\\?[\w\\]+)::dispatch(?:Sync|Now|If|Unless|AfterResponse)?\s*\("
+            ).unwrap(),
+
+            // Match: dispatch(new ProcessPodcast  or  event(new OrderShipped
+            //    or  Bus::dispatch(new ...)  or  Event::dispatch(new ...)
+            dispatch_helper_regex: Regex::new(
+                r"(?:\b(event|Event::dispatch)|\b(dispatch(?:_sync|_now)?|Bus::dispatch\w*))\s*\(\s*new\s+(\\?[\w\\]+)"
+            ).unwrap(),
+
+            // Match: public function rules(): array {  (up to the opening brace)
+            rules_method_regex: Regex::new(
+                r"function\s+rules\s*\(\s*\)(?:\s*:\s*\??\w+)?\s*\{"
+            ).unwrap(),
+
+            // Match: $request->validate(  or  $this->validate(  or  Validator::make(
+            validate_call_regex: Regex::new(
+                r"(?:->\s*validate(?:WithBag)?|Validator::make)\s*\("
+            ).unwrap(),
         }
     }
 
@@ -71,15 +155,25 @@ impl PhpParser {
     pub async fn parse(
         &self,
         file: &SourceFile,
-        _config: &ParserConfig,
+        config: &ParserConfig,
     ) -> ParserResult<ParsedFile> {
-        let content = fs::read_to_string(&file.absolute_path)
-            .map_err(ParseError::Io)?;
+        let (content, decode_warnings) = read_source(file, config)?;
+
+        let mut parsed = self.parse_content(file, &content);
+        parsed.warnings.extend(decode_warnings);
+        Ok(parsed)
+    }
 
+    /// Parse already loaded PHP source
+    pub fn parse_content(&self, file: &SourceFile, content: &str) -> ParsedFile {
         let mut parsed = ParsedFile::new(file.clone());
 
+        // Declarations are matched on the code alone: a commented-out or
+        // quoted `class Foo` isn't a class
+        let code = blank_comments_and_strings(content);
+
         // Extract namespace
-        let namespace = self.extract_namespace(&content);
+        let namespace = self.extract_namespace(&code);
         if let Some(ref ns) = namespace {
             parsed.metadata.insert(
                 "namespace".to_string(),
@@ -88,30 +182,45 @@ impl PhpParser {
         }
 
         // Extract use statements (imports)
-        self.extract_use_statements(&content, &mut parsed);
+        self.extract_use_statements(&code, &mut parsed);
 
         // Extract class definitions
-        self.extract_classes(&content, &namespace, &mut parsed);
+        self.extract_classes(content, &code, &namespace, &mut parsed);
 
         // Extract interfaces
-        self.extract_interfaces(&content, &namespace, &mut parsed);
+        self.extract_interfaces(&code, &namespace, &mut parsed);
 
         // Extract traits
-        self.extract_traits(&content, &namespace, &mut parsed);
+        self.extract_traits(&code, &namespace, &mut parsed);
+
+        // Extract enums (PHP 8.1+)
+        self.extract_enums(content, &code, &namespace, &mut parsed);
 
         // Extract functions (standalone)
-        self.extract_functions(&content, &mut parsed);
+        self.extract_functions(content, &code, &mut parsed);
 
         // Extract methods
-        self.extract_methods(&content, &mut parsed);
+        self.extract_methods(content, &code, &mut parsed);
 
         // Extract properties
-        self.extract_properties(&content, &mut parsed);
+        self.extract_properties(&code, &mut parsed);
 
         // Extract constants
-        self.extract_constants(&content, &mut parsed);
+        self.extract_constants(&code, &mut parsed);
 
-        Ok(parsed)
+        // Extract job chains and batches
+        self.extract_job_workflows(content, &mut parsed);
+
+        // Extract dispatched jobs and fired events
+        self.extract_dispatches(content, &mut parsed);
+
+        // Extract validation rules (FormRequest::rules(), inline validate calls)
+        self.extract_validation_rules(content, &mut parsed);
+
+        // A brace off means the regexes likely matched the wrong blocks
+        parsed.warnings.extend(unbalanced_braces_warning(content, &file.path));
+
+        parsed
     }
 
     pub fn extract_namespace(&self, content: &str) -> Option<String> {
@@ -138,13 +247,15 @@ impl PhpParser {
         }
     }
 
+    /// Extract the classes declared in `code` (see [`PhpParser::parse_content`])
     pub fn extract_classes(
         &self,
         content: &str,
+        code: &str,
         namespace: &Option<String>,
         parsed: &mut ParsedFile,
     ) {
-        for caps in self.class_regex.captures_iter(content) {
+        for caps in self.class_regex.captures_iter(code) {
             let modifier = caps.get(1).map(|m| m.as_str());
             let class_name = caps.get(2).map(|m| m.as_str().to_string()).unwrap_or_default();
             let extends = caps.get(3).map(|m| m.as_str().to_string());
@@ -157,10 +268,10 @@ impl PhpParser {
             });
 
             if !class_name.is_empty() {
-                let qualified_name = match namespace {
-                    Some(ns) => format!("{}\\{}", ns, class_name),
-                    None => class_name.clone(),
-                };
+                let start = caps.get(0).map_or(0, |m| m.start());
+                let qualified_name = qualify_name(code, start, namespace, &class_name);
+
+                let attributes = attributes_before(content, start);
 
                 parsed.add_symbol(Symbol {
                     name: class_name,
@@ -171,8 +282,14 @@ impl PhpParser {
                     is_static: None,
                     extends,
                     implements,
+                    parameters: None,
+                    return_type: None,
+                    attributes,
                     line_start: None,
                     line_end: None,
+                    section: None,
+                    backing_type: None,
+                    enum_cases: None,
                 });
             }
         }
@@ -189,10 +306,8 @@ impl PhpParser {
             let extends = caps.get(2).map(|m| m.as_str().to_string());
 
             if !iface_name.is_empty() {
-                let qualified_name = match namespace {
-                    Some(ns) => format!("{}\\{}", ns, iface_name),
-                    None => iface_name.clone(),
-                };
+                let start = caps.get(0).map_or(0, |m| m.start());
+                let qualified_name = qualify_name(content, start, namespace, &iface_name);
 
                 parsed.add_symbol(Symbol {
                     name: iface_name,
@@ -203,8 +318,14 @@ impl PhpParser {
                     is_static: None,
                     extends,
                     implements: None,
+                    parameters: None,
+                    return_type: None,
+                    attributes: None,
                     line_start: None,
                     line_end: None,
+                    section: None,
+                    backing_type: None,
+                    enum_cases: None,
                 });
             }
         }
@@ -220,10 +341,8 @@ impl PhpParser {
             let trait_name = caps.get(1).map(|m| m.as_str().to_string()).unwrap_or_default();
 
             if !trait_name.is_empty() {
-                let qualified_name = match namespace {
-                    Some(ns) => format!("{}\\{}", ns, trait_name),
-                    None => trait_name.clone(),
-                };
+                let start = caps.get(0).map_or(0, |m| m.start());
+                let qualified_name = qualify_name(content, start, namespace, &trait_name);
 
                 parsed.add_symbol(Symbol {
                     name: trait_name,
@@ -234,17 +353,85 @@ impl PhpParser {
                     is_static: None,
                     extends: None,
                     implements: None,
+                    parameters: None,
+                    return_type: None,
+                    attributes: None,
                     line_start: None,
                     line_end: None,
+                    section: None,
+                    backing_type: None,
+                    enum_cases: None,
                 });
             }
         }
     }
 
-    pub fn extract_functions(&self, content: &str, parsed: &mut ParsedFile) {
+    /// Extract the enums declared in `code`, with their cases
+    pub fn extract_enums(
+        &self,
+        content: &str,
+        code: &str,
+        namespace: &Option<String>,
+        parsed: &mut ParsedFile,
+    ) {
+        for caps in self.enum_regex.captures_iter(code) {
+            let enum_name = caps.get(1).map(|m| m.as_str().to_string()).unwrap_or_default();
+
+            if !enum_name.is_empty() {
+                let start = caps.get(0).map_or(0, |m| m.start());
+                let qualified_name = qualify_name(code, start, namespace, &enum_name);
+
+                let implements = caps.get(3).map(|m| {
+                    m.as_str()
+                        .split(',')
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect()
+                });
+
+                // Backed enums (enum Status: string) give every case a value
+                let backing_type = caps.get(2).map(|m| m.as_str().to_string());
+                let open = caps.get(0).map(|m| m.end() - 1).unwrap_or(0);
+                let cases: Vec<EnumCase> = block_body(content, open)
+                    .map(|body| {
+                        self.enum_case_regex
+                            .captures_iter(body)
+                            .map(|case| EnumCase {
+                                name: case[1].to_string(),
+                                value: case.get(2).map(|v| {
+                                    v.as_str().trim_matches(|c| c == '\'' || c == '"').to_string()
+                                }),
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                parsed.add_symbol(Symbol {
+                    name: enum_name,
+                    qualified_name,
+                    symbol_type: SymbolType::Enum,
+                    visibility: Some("public".to_string()),
+                    is_abstract: None,
+                    is_static: None,
+                    extends: None,
+                    implements,
+                    parameters: None,
+                    return_type: None,
+                    attributes: None,
+                    line_start: None,
+                    line_end: None,
+                    section: None,
+                    backing_type,
+                    enum_cases: Some(cases),
+                });
+            }
+        }
+    }
+
+    pub fn extract_functions(&self, content: &str, code: &str, parsed: &mut ParsedFile) {
         // Only extract top-level functions (not methods inside classes)
         // This is a simplification - for standalone function files
-        for caps in self.function_regex.captures_iter(content) {
+        for caps in self.function_regex.captures_iter(code) {
             let visibility = caps.get(1).map(|m| m.as_str().to_string());
             let is_static = caps.get(2).is_some();
             let func_name = caps.get(3).map(|m| m.as_str().to_string()).unwrap_or_default();
@@ -255,6 +442,9 @@ impl PhpParser {
             }
 
             if !func_name.is_empty() {
+                let open = caps.get(0).map(|m| m.end() - 1).unwrap_or(0);
+                let (parameters, return_type) = function_signature(content, open);
+
                 parsed.add_symbol(Symbol {
                     name: func_name.clone(),
                     qualified_name: func_name,
@@ -264,20 +454,30 @@ impl PhpParser {
                     is_static: Some(is_static),
                     extends: None,
                     implements: None,
+                    parameters,
+                    return_type,
+                    attributes: None,
                     line_start: None,
                     line_end: None,
+                    section: None,
+                    backing_type: None,
+                    enum_cases: None,
                 });
             }
         }
     }
 
-    pub fn extract_methods(&self, content: &str, parsed: &mut ParsedFile) {
-        for caps in self.method_regex.captures_iter(content) {
+    pub fn extract_methods(&self, content: &str, code: &str, parsed: &mut ParsedFile) {
+        for caps in self.method_regex.captures_iter(code) {
             let visibility = caps.get(1).map(|m| m.as_str().to_string());
             let is_static = caps.get(2).is_some();
             let method_name = caps.get(3).map(|m| m.as_str().to_string()).unwrap_or_default();
 
             if !method_name.is_empty() {
+                let open = caps.get(0).map(|m| m.end() - 1).unwrap_or(0);
+                let (parameters, return_type) = function_signature(content, open);
+                let attributes = attributes_before(content, caps.get(1).map_or(0, |m| m.start()));
+
                 parsed.add_symbol(Symbol {
                     name: method_name.clone(),
                     qualified_name: method_name,
@@ -287,8 +487,14 @@ impl PhpParser {
                     is_static: Some(is_static),
                     extends: None,
                     implements: None,
+                    parameters,
+                    return_type,
+                    attributes,
                     line_start: None,
                     line_end: None,
+                    section: None,
+                    backing_type: None,
+                    enum_cases: None,
                 });
             }
         }
@@ -310,8 +516,14 @@ impl PhpParser {
                     is_static: Some(is_static),
                     extends: None,
                     implements: None,
+                    parameters: None,
+                    return_type: None,
+                    attributes: None,
                     line_start: None,
                     line_end: None,
+                    section: None,
+                    backing_type: None,
+                    enum_cases: None,
                 });
             }
         }
@@ -332,13 +544,230 @@ impl PhpParser {
                     is_static: Some(true),
                     extends: None,
                     implements: None,
+                    parameters: None,
+                    return_type: None,
+                    attributes: None,
                     line_start: None,
                     line_end: None,
+                    section: None,
+                    backing_type: None,
+                    enum_cases: None,
                 });
             }
         }
     }
 
+    /// Extract job chains and batches dispatched from this file
+    ///
+    /// Recorded as `job_workflows`: `[{type: chain|batch, jobs: [...]}]`, jobs
+    /// in dispatch order and expanded through the use statements. The job a
+    /// chain is attached to (`A::withChain([B])`, `A::dispatch()->chain([B])`)
+    /// comes first.
+    pub fn extract_job_workflows(&self, content: &str, parsed: &mut ParsedFile) {
+        let mut workflows = Vec::new();
+
+        for caps in self.bus_workflow_regex.captures_iter(content) {
+            let open = caps.get(0).map(|m| m.end() - 1).unwrap_or(0);
+            let jobs = self.workflow_jobs(content, open, None, parsed);
+            workflows.push((caps[1].to_string(), jobs));
+        }
+
+        for caps in self.with_chain_regex.captures_iter(content) {
+            let open = caps.get(0).map(|m| m.end() - 1).unwrap_or(0);
+            let start = caps.get(0).map_or(0, |m| m.start());
+            let head = self.dispatched_class(content, start, &caps[1], parsed);
+            let jobs = self.workflow_jobs(content, open, head, parsed);
+            workflows.push(("chain".to_string(), jobs));
+        }
+
+        for found in self.chain_call_regex.find_iter(content) {
+            // The job being dispatched earlier in the same statement, if any
+            let statement_start = content[..found.start()]
+                .rfind([';', '{', '}'])
+                .map(|p| p + 1)
+                .unwrap_or(0);
+            let head = self
+                .dispatch_head_regex
+                .captures(&content[statement_start..found.start()])
+                .and_then(|caps| caps.get(1).or_else(|| caps.get(2)))
+                .and_then(|m| self.dispatched_class(content, found.start(), m.as_str(), parsed));
+
+            let jobs = self.workflow_jobs(content, found.end() - 1, head, parsed);
+            workflows.push(("chain".to_string(), jobs));
+        }
+
+        let workflows: Vec<serde_json::Value> = workflows
+            .into_iter()
+            .filter(|(_, jobs)| jobs.len() > 1)
+            .map(|(kind, jobs)| serde_json::json!({ "type": kind, "jobs": jobs }))
+            .collect();
+
+        if !workflows.is_empty() {
+            parsed.metadata.insert(
+                "job_workflows".to_string(),
+                serde_json::json!(workflows),
+            );
+        }
+    }
+
+    /// Jobs instantiated in the `[...]` list opened at `open`, after `head`
+    fn workflow_jobs(
+        &self,
+        content: &str,
+        open: usize,
+        head: Option<String>,
+        parsed: &ParsedFile,
+    ) -> Vec<String> {
+        let list = match matching_bracket(content, open) {
+            Some(close) => &content[open + 1..close],
+            None => return Vec::new(),
+        };
+
+        head.into_iter()
+            .chain(split_top_level(list, b',').into_iter().filter_map(|item| {
+                self.new_job_regex
+                    .captures(item)
+                    .and_then(|caps| caps.get(1))
+                    .map(|m| expand_imported_name(&parsed.dependencies, m.as_str()))
+            }))
+            .collect()
+    }
+
+    /// Class dispatched as `name` at byte offset `position`
+    ///
+    /// `self` and `static` are the class declared around the call, `parent`
+    /// isn't known; other names are expanded through the use statements.
+    fn dispatched_class(
+        &self,
+        content: &str,
+        position: usize,
+        name: &str,
+        parsed: &ParsedFile,
+    ) -> Option<String> {
+        match name {
+            "self" | "static" => {
+                let caps = self.class_regex.captures_iter(&content[..position]).last()?;
+                let start = caps.get(0).map_or(0, |m| m.start());
+                let namespace = parsed
+                    .metadata
+                    .get("namespace")
+                    .and_then(|ns| ns.as_str())
+                    .map(|ns| ns.to_string());
+                Some(qualify_name(content, start, &namespace, &caps[2]))
+            }
+            "parent" => None,
+            _ => Some(expand_imported_name(&parsed.dependencies, name)),
+        }
+    }
+
+    /// Extract jobs and events dispatched from this file
+    ///
+    /// Recorded as `dispatched_jobs` and `fired_events`, class names expanded
+    /// through the use statements. `event(new X)` and `Event::dispatch(new X)`
+    /// fire events, `dispatch(new X)` and `Bus::dispatch(new X)` dispatch jobs.
+    /// `X::dispatch()` works for both (Dispatchable), so it counts as an event
+    /// when the class lives in an `Events` namespace. `self::dispatch()` and
+    /// `static::dispatch()` dispatch the enclosing class.
+    pub fn extract_dispatches(&self, content: &str, parsed: &mut ParsedFile) {
+        let mut jobs: Vec<String> = Vec::new();
+        let mut events: Vec<String> = Vec::new();
+
+        for caps in self.static_dispatch_regex.captures_iter(content) {
+            let class_name = &caps[1];
+            if matches!(class_name.trim_start_matches('\\'), "Bus" | "Event" | "Queue") {
+                continue;
+            }
+            let start = caps.get(0).map_or(0, |m| m.start());
+            let class_name = match self.dispatched_class(content, start, class_name, parsed) {
+                Some(class_name) => class_name,
+                None => continue,
+            };
+            let is_event = class_name.split('\\').any(|segment| segment == "Events");
+            let target = if is_event { &mut events } else { &mut jobs };
+            if !target.contains(&class_name) {
+                target.push(class_name);
+            }
+        }
+
+        for caps in self.dispatch_helper_regex.captures_iter(content) {
+            let class_name = expand_imported_name(&parsed.dependencies, &caps[3]);
+            let target = if caps.get(1).is_some() { &mut events } else { &mut jobs };
+            if !target.contains(&class_name) {
+                target.push(class_name);
+            }
+        }
+
+        if !jobs.is_empty() {
+            parsed.metadata.insert(
+                "dispatched_jobs".to_string(),
+                serde_json::json!(jobs),
+            );
+        }
+        if !events.is_empty() {
+            parsed.metadata.insert(
+                "fired_events".to_string(),
+                serde_json::json!(events),
+            );
+        }
+    }
+
+    /// Extract validation rules into a `validation_rules` object (field -> rules)
+    ///
+    /// Reads the array returned by a FormRequest `rules()` method and the rules
+    /// array of `->validate()`, `->validateWithBag()` and `Validator::make()`
+    /// calls. String rules are unquoted, other rule expressions kept as written.
+    /// The first declaration of a field wins.
+    pub fn extract_validation_rules(&self, content: &str, parsed: &mut ParsedFile) {
+        let mut rule_arrays: Vec<&str> = Vec::new();
+
+        let rules_body = self
+            .rules_method_regex
+            .find(content)
+            .and_then(|header| block_body(content, header.end() - 1));
+        if let Some(body) = rules_body {
+            if let Some(found) = RETURN_ARRAY_REGEX.find(body) {
+                let open = found.end() - 1;
+                if let Some(close) = matching_bracket(body, open) {
+                    rule_arrays.push(&body[open + 1..close]);
+                }
+            }
+        }
+
+        for found in self.validate_call_regex.find_iter(content) {
+            let open = found.end() - 1;
+            let arguments = match matching_paren(content, open) {
+                Some(close) => &content[open + 1..close],
+                None => continue,
+            };
+
+            // The rules are the first array argument (after $request, $data or a bag name)
+            let rules = split_top_level(arguments, b',')
+                .into_iter()
+                .map(|argument| argument.trim())
+                .find(|argument| argument.starts_with('[') && argument.ends_with(']'));
+            if let Some(rules) = rules {
+                rule_arrays.push(&rules[1..rules.len() - 1]);
+            }
+        }
+
+        let mut validation_rules = serde_json::Map::new();
+        for entry in rule_arrays.into_iter().flat_map(|rules| split_top_level(rules, b',')) {
+            if let Some(caps) = ARRAY_ENTRY_REGEX.captures(entry) {
+                let rule = unquote(caps[2].trim());
+                validation_rules
+                    .entry(caps[1].to_string())
+                    .or_insert_with(|| serde_json::Value::String(rule.to_string()));
+            }
+        }
+
+        if !validation_rules.is_empty() {
+            parsed.metadata.insert(
+                "validation_rules".to_string(),
+                serde_json::Value::Object(validation_rules),
+            );
+        }
+    }
+
     /// Extract traits used inside a class
     pub fn extract_trait_uses(&self, content: &str) -> Vec<String> {
         let mut traits = Vec::new();
@@ -367,8 +796,438 @@ impl PhpParser {
     }
 }
 
+/// Qualified name of a symbol declared at byte offset `offset`
+///
+/// A file may declare several namespaces: the last one declared before the
+/// symbol applies, `namespace` (the file's) when none is.
+pub fn qualify_name(
+    content: &str,
+    offset: usize,
+    namespace: &Option<String>,
+    name: &str,
+) -> String {
+    let declared = NAMESPACE_DECLARATION_REGEX
+        .captures_iter(&content[..offset.min(content.len())])
+        .last()
+        .map(|caps| caps[1].to_string());
+
+    match declared.as_ref().or(namespace.as_ref()) {
+        Some(ns) => format!("{}\\{}", ns, name),
+        None => name.to_string(),
+    }
+}
+
+/// Expand a class reference through a file's use statements
+///
+/// With `use App\Enums\Status;`, `Status` becomes `App\Enums\Status` (aliases and
+/// partially qualified names like `Enums\Status` work too). Names that aren't
+/// imported are returned as written, without a leading `\`.
+pub fn expand_imported_name(dependencies: &[Dependency], name: &str) -> String {
+    if let Some(qualified) = name.strip_prefix('\\') {
+        return qualified.to_string();
+    }
+
+    let (first, rest) = match name.split_once('\\') {
+        Some((first, rest)) => (first, Some(rest)),
+        None => (name, None),
+    };
+
+    let import = dependencies.iter().find(|dep| {
+        let short_name = dep
+            .alias
+            .as_deref()
+            .unwrap_or_else(|| dep.target.rsplit('\\').next().unwrap_or(&dep.target));
+        short_name == first
+    });
+
+    match (import, rest) {
+        (Some(dep), Some(rest)) => format!("{}\\{}", dep.target, rest),
+        (Some(dep), None) => dep.target.clone(),
+        (None, _) => name.to_string(),
+    }
+}
+
+/// Parameters and return type of the function whose `(` is at byte offset `open`
+///
+/// Handles union and nullable types (`int|string`, `?User`), by-reference and
+/// variadic parameters, and constructor property promotion
+/// (`public readonly int $id`). Returns `(None, None)` for an unclosed list.
+pub fn function_signature(
+    content: &str,
+    open: usize,
+) -> (Option<Vec<ParameterInfo>>, Option<String>) {
+    let close = match matching_paren(content, open) {
+        Some(close) => close,
+        None => return (None, None),
+    };
+
+    let parameters = split_top_level(&content[open + 1..close], b',')
+        .into_iter()
+        .filter_map(parse_parameter)
+        .collect();
+
+    // function index(): Response|View {  or  abstract function handle(): void;
+    let after = &content[close + 1..];
+    let declaration_end = after.find(['{', ';']).unwrap_or(after.len());
+    let return_type = after[..declaration_end]
+        .trim()
+        .strip_prefix(':')
+        .map(|ty| ty.split_whitespace().collect::<String>())
+        .filter(|ty| !ty.is_empty());
+
+    (Some(parameters), return_type)
+}
+
+/// Attributes (`#[...]` groups) written right before the declaration at `position`
+///
+/// Several attributes can share a group (`#[Get('/'), Middleware('auth')]`)
+/// and groups can span lines. Returns None when the declaration has none.
+pub fn attributes_before(content: &str, position: usize) -> Option<Vec<AttributeInfo>> {
+    let mut groups = Vec::new();
+    let mut end = content[..position].trim_end().len();
+
+    while content[..end].ends_with(']') {
+        let close = end - 1;
+
+        // The closest `#[` whose bracket closes here (arguments may hold arrays)
+        let mut search_end = close;
+        let mut open = None;
+        while let Some(candidate) = content[..search_end].rfind("#[") {
+            if matching_bracket(content, candidate + 1) == Some(close) {
+                open = Some(candidate);
+                break;
+            }
+            search_end = candidate;
+        }
+
+        match open {
+            Some(open) => {
+                groups.push(&content[open + 2..close]);
+                end = content[..open].trim_end().len();
+            }
+            None => break,
+        }
+    }
+
+    let attributes: Vec<AttributeInfo> = groups
+        .into_iter()
+        .rev()
+        .flat_map(|group| split_top_level(group, b','))
+        .filter_map(parse_attribute)
+        .collect();
+
+    if attributes.is_empty() {
+        None
+    } else {
+        Some(attributes)
+    }
+}
+
+/// Parse one attribute: `Route('/users', methods: ['GET'])`
+fn parse_attribute(raw: &str) -> Option<AttributeInfo> {
+    let raw = raw.trim();
+    let name_end = raw
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '\\'))
+        .unwrap_or(raw.len());
+    let name = raw[..name_end].trim_start_matches('\\');
+    if name.is_empty() {
+        return None;
+    }
+
+    let rest = raw[name_end..].trim_start();
+    let arguments = match matching_paren(rest, 0) {
+        Some(close) => split_top_level(&rest[1..close], b',')
+            .into_iter()
+            .map(|argument| argument.trim().to_string())
+            .filter(|argument| !argument.is_empty())
+            .collect(),
+        None => Vec::new(),
+    };
+
+    Some(AttributeInfo {
+        name: name.to_string(),
+        arguments,
+    })
+}
+
+/// Content of a single string literal ('required|email'), other expressions as written
+pub fn unquote(expression: &str) -> &str {
+    for quote in ['\'', '"'] {
+        let inner = expression
+            .strip_prefix(quote)
+            .and_then(|rest| rest.strip_suffix(quote));
+        if let Some(inner) = inner {
+            if !inner.contains(quote) {
+                return inner;
+            }
+        }
+    }
+    expression
+}
+
+/// Parse one parameter declaration: `#[Attr] public ?User &...$users = null`
+fn parse_parameter(raw: &str) -> Option<ParameterInfo> {
+    let mut declaration = raw.trim();
+
+    // Skip attributes (#[SensitiveParameter])
+    while declaration.starts_with("#[") {
+        let end = declaration.find(']')?;
+        declaration = declaration[end + 1..].trim_start();
+    }
+
+    let (declaration, default_value) = match default_value_position(declaration) {
+        Some(eq) => (
+            &declaration[..eq],
+            Some(declaration[eq + 1..].trim().to_string()),
+        ),
+        None => (declaration, None),
+    };
+
+    let dollar = declaration.find('$')?;
+    let name: String = declaration[dollar + 1..]
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+    if name.is_empty() {
+        return None;
+    }
+
+    let type_part = &declaration[..dollar];
+    let is_variadic = type_part.contains("...");
+
+    // Drop promotion modifiers, keep the type (union types may contain spaces)
+    let param_type: String = type_part
+        .trim_end_matches(|c: char| c == '.' || c == '&' || c.is_whitespace())
+        .split_whitespace()
+        .filter(|word| {
+            !matches!(*word, "public" | "protected" | "private" | "readonly")
+                && !word.ends_with("(set)")
+        })
+        .collect();
+
+    Some(ParameterInfo {
+        name,
+        param_type: if param_type.is_empty() { None } else { Some(param_type) },
+        is_optional: default_value.is_some() || is_variadic,
+        default_value,
+        modifier: None,
+    })
+}
+
+/// Byte offset of the `=` introducing a parameter's default value: the first
+/// one outside brackets and strings that isn't part of `==` or `=>`
+fn default_value_position(declaration: &str) -> Option<usize> {
+    let bytes = declaration.as_bytes();
+    let mut depth = 0usize;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth = depth.saturating_sub(1),
+            quote @ (b'\'' | b'"') => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    if bytes[i] == b'\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+            }
+            b'=' if depth == 0 => match bytes.get(i + 1) {
+                Some(b'=') | Some(b'>') => i += 1,
+                _ => return Some(i),
+            },
+            _ => {}
+        }
+        i += 1;
+    }
+
+    None
+}
+
 impl Default for PhpParser {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::is_file_node;
+    use crate::models::{UnifiedEdgeType, UnifiedGraph};
+    use crate::parsers::laravel::fixtures::graph_of;
+
+    fn signature(declaration: &str) -> (Vec<ParameterInfo>, Option<String>) {
+        let open = declaration.find('(').unwrap();
+        let (parameters, return_type) = function_signature(declaration, open);
+        (parameters.unwrap(), return_type)
+    }
+
+    #[test]
+    fn function_signature_reads_types_and_defaults() {
+        let (parameters, return_type) = signature(
+            "public function store(public readonly ?User &$user, int|string ...$ids): Response|View {",
+        );
+
+        assert_eq!(parameters.len(), 2);
+        assert_eq!(parameters[0].name, "user");
+        assert_eq!(parameters[0].param_type.as_deref(), Some("?User"));
+        assert!(!parameters[0].is_optional);
+        assert_eq!(parameters[1].name, "ids");
+        assert_eq!(parameters[1].param_type.as_deref(), Some("int|string"));
+        assert!(parameters[1].is_optional);
+        assert_eq!(return_type.as_deref(), Some("Response|View"));
+    }
+
+    #[test]
+    fn default_values_keep_their_operators() {
+        let (parameters, return_type) = signature(
+            "function compare($op = '==', array $map = ['a' => 1, 'b' => 2], $fn = null);",
+        );
+
+        let defaults: Vec<_> = parameters
+            .iter()
+            .map(|p| (p.name.as_str(), p.default_value.as_deref()))
+            .collect();
+        assert_eq!(
+            defaults,
+            [
+                ("op", Some("'=='")),
+                ("map", Some("['a' => 1, 'b' => 2]")),
+                ("fn", Some("null")),
+            ]
+        );
+        assert!(parameters.iter().all(|p| p.is_optional));
+        assert_eq!(return_type, None);
+    }
+
+    #[test]
+    fn self_dispatches_resolve_to_the_enclosing_class() {
+        let content = "<?php\n\nnamespace App\\Jobs;\n\n\
+                       use App\\Events\\PodcastQueued;\n\n\
+                       class ProcessPodcast extends BaseJob\n{\n\
+                       public function retry()\n{\n\
+                       self::dispatch($this->podcast);\n\
+                       static::dispatchIf(true, $this->podcast);\n\
+                       parent::dispatch();\n\
+                       PodcastQueued::dispatch($this->podcast);\n\
+                       static::withChain([new Notify])->dispatch();\n}\n}\n";
+        let file = SourceFile::new(
+            "ProcessPodcast.php".to_string(),
+            "app/Jobs/ProcessPodcast.php".to_string(),
+            "app/Jobs/ProcessPodcast.php".to_string(),
+        );
+
+        let parsed = PhpParser::new().parse_content(&file, content);
+
+        assert_eq!(
+            parsed.metadata.get("dispatched_jobs"),
+            Some(&serde_json::json!(["App\\Jobs\\ProcessPodcast"]))
+        );
+        assert_eq!(
+            parsed.metadata.get("fired_events"),
+            Some(&serde_json::json!(["App\\Events\\PodcastQueued"]))
+        );
+        assert_eq!(
+            parsed.metadata["job_workflows"][0]["jobs"],
+            serde_json::json!(["App\\Jobs\\ProcessPodcast", "Notify"])
+        );
+    }
+
+    #[test]
+    fn declarations_in_comments_and_strings_are_ignored() {
+        let content = r#"<?php
+namespace App\Services;
+
+// class CommentedOut {}
+/* interface Blocked { public function gone(); } */
+
+class Invoice
+{
+    private $template = 'class Quoted { function fake() {} }';
+
+    public function render()
+    {
+        return <<<PHP
+        trait Heredoc { function alsoFake() {} }
+        PHP;
+    }
+
+    # public function disabled() {}
+}
+"#;
+        let file = SourceFile::new(
+            "Invoice.php".to_string(),
+            "app/Services/Invoice.php".to_string(),
+            "app/Services/Invoice.php".to_string(),
+        );
+
+        let parsed = PhpParser::new().parse_content(&file, content);
+
+        let names: Vec<&str> = parsed.symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["Invoice", "render", "template"]);
+    }
+
+    #[tokio::test]
+    async fn bus_chains_link_their_jobs_in_order() {
+        let controller = "<?php
+
+namespace App\\Http\\Controllers;
+
+use App\\Jobs\\OptimizePodcast;
+use App\\Jobs\\ProcessPodcast;
+use App\\Jobs\\ReleasePodcast;
+use Illuminate\\Support\\Facades\\Bus;
+
+class PodcastController extends Controller
+{
+    public function store()
+    {
+        Bus::chain([
+            new ProcessPodcast,
+            new OptimizePodcast($podcast),
+            new ReleasePodcast,
+        ])->dispatch();
+    }
+}
+";
+        let job = |name: &str| {
+            format!(
+                "<?php\n\nnamespace App\\Jobs;\n\nclass {} implements ShouldQueue {{}}\n",
+                name
+            )
+        };
+        let (process, optimize, release) = (
+            job("ProcessPodcast"),
+            job("OptimizePodcast"),
+            job("ReleasePodcast"),
+        );
+        let graph = graph_of(&[
+            ("app/Http/Controllers/PodcastController.php", controller),
+            ("app/Jobs/ProcessPodcast.php", &process),
+            ("app/Jobs/OptimizePodcast.php", &optimize),
+            ("app/Jobs/ReleasePodcast.php", &release),
+        ])
+        .await;
+
+        let name_of = |graph: &UnifiedGraph, id: &str| {
+            let node = graph.nodes.iter().find(|n| n.id == id && !is_file_node(n));
+            node.map(|n| n.name.clone()).unwrap_or_default()
+        };
+        let chained: Vec<(String, String)> = graph
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == UnifiedEdgeType::Custom("chained_with".to_string()))
+            .map(|e| (name_of(&graph, &e.source), name_of(&graph, &e.target)))
+            .collect();
+        assert_eq!(
+            chained,
+            [
+                ("ProcessPodcast".to_string(), "OptimizePodcast".to_string()),
+                ("OptimizePodcast".to_string(), "ReleasePodcast".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,649 @@
+use regex::Regex;
+
+use crate::models::{ParsedFile, SourceFile};
+use crate::parsers::common::{
+    block_body, matching_bracket, matching_paren, read_source, split_top_level,
+};
+use crate::parsers::{ParserConfig, ParserResult};
+
+use super::php_parser::{expand_imported_name, unquote, PhpParser, CLASS_REF_REGEX};
+
+/// Parser for Laravel Service Providers
+pub struct ProviderParser {
+    php_parser: PhpParser,
+    register_method_regex: Regex,
+    binding_regex: Regex,
+    class_string_regex: Regex,
+    closure_regex: Regex,
+    listen_property_regex: Regex,
+    listen_entry_regex: Regex,
+    event_listen_regex: Regex,
+    policies_property_regex: Regex,
+    policy_entry_regex: Regex,
+    gate_policy_regex: Regex,
+    gate_define_regex: Regex,
+    observers_property_regex: Regex,
+    observers_entry_regex: Regex,
+    observe_call_regex: Regex,
+}
+
+impl ProviderParser {
+    pub fn new() -> Self {
+        Self {
+            php_parser: PhpParser::new(),
+
+            // Match: public function register(): void {  (up to the opening brace)
+            register_method_regex: Regex::new(
+                r"function\s+register\s*\(\s*\)(?:\s*:\s*\??\w+)?\s*\{"
+            ).unwrap(),
+
+            // Match: $this->app->bind(  or  $app->singleton(  or  app()->scoped(  (up to the parenthesis)
+            binding_regex: Regex::new(
+                r"(?:\$this\s*->\s*app|\$app|app\s*\(\s*\))\s*->\s*(bind|singleton|scoped|bindIf|singletonIf|scopedIf)\s*\("
+            ).unwrap(),
+
+            // Match: Contract::class  (a whole binding argument)
+            class_string_regex: Regex::new(r"^(\\?[\w\\]+)::class$").unwrap(),
+
+            // Match: function ($app) {  or  fn () =>  or  static fn () =>
+            closure_regex: Regex::new(r"^(?:static\s+)?(?:function|fn)\s*\(").unwrap(),
+
+            // Match: protected $listen = [  (up to the opening bracket)
+            listen_property_regex: Regex::new(
+                r"(?:protected|public)\s+(?:array\s+)?\$listen\s*=\s*\["
+            ).unwrap(),
+
+            // Match: OrderShipped::class => [  (up to the listener list)
+            listen_entry_regex: Regex::new(r"(\\?[\w\\]+)::class\s*=>\s*\[").unwrap(),
+
+            // Match: Event::listen(OrderShipped::class, SendNotification::class)
+            //    or  Event::listen(OrderShipped::class, [SendNotification::class, 'handle'])
+            event_listen_regex: Regex::new(
+                r"Event::listen\s*\(\s*(\\?[\w\\]+)::class\s*,\s*\[?\s*(\\?[\w\\]+)::class"
+            ).unwrap(),
+
+            // Match: protected $policies = [  (up to the opening bracket)
+            policies_property_regex: Regex::new(
+                r"(?:protected|public)\s+(?:array\s+)?\$policies\s*=\s*\["
+            ).unwrap(),
+
+            // Match: Post::class => PostPolicy::class
+            policy_entry_regex: Regex::new(
+                r"(\\?[\w\\]+)::class\s*=>\s*(\\?[\w\\]+)::class"
+            ).unwrap(),
+
+            // Match: Gate::policy(Post::class, PostPolicy::class)
+            gate_policy_regex: Regex::new(
+                r"Gate::policy\s*\(\s*(\\?[\w\\]+)::class\s*,\s*(\\?[\w\\]+)::class"
+            ).unwrap(),
+
+            // Match: Gate::define('update-post'
+            gate_define_regex: Regex::new(r#"Gate::define\s*\(\s*['"]([^'"]+)['"]"#).unwrap(),
+
+            // Match: protected $observers = [  (up to the opening bracket)
+            observers_property_regex: Regex::new(
+                r"(?:protected|public)\s+(?:array\s+)?\$observers\s*=\s*\["
+            ).unwrap(),
+
+            // Match: User::class =>  (up to the observer or observer list)
+            observers_entry_regex: Regex::new(r"(\\?[\w\\]+)::class\s*=>\s*").unwrap(),
+
+            // Match: User::observe(  (up to the parenthesis)
+            observe_call_regex: Regex::new(r"(\\?[\w\\]+)::observe\s*\(").unwrap(),
+        }
+    }
+
+    /// Parse a service provider file
+    pub async fn parse(
+        &self,
+        file: &SourceFile,
+        config: &ParserConfig,
+    ) -> ParserResult<ParsedFile> {
+        let (content, decode_warnings) = read_source(file, config)?;
+
+        let mut parsed = self.php_parser.parse_content(file, &content);
+        parsed.warnings.extend(decode_warnings);
+
+        // Extract container bindings declared in register()
+        let bindings = self.extract_bindings(&content, &parsed);
+        if !bindings.is_empty() {
+            parsed.metadata.insert(
+                "bindings".to_string(),
+                serde_json::json!(bindings),
+            );
+        }
+
+        // Extract event -> listener registrations
+        let listeners = self.extract_event_listeners(&content, &parsed);
+        if !listeners.is_empty() {
+            parsed.metadata.insert(
+                "event_listeners".to_string(),
+                serde_json::json!(listeners),
+            );
+        }
+
+        // Extract model -> policy registrations
+        let policies = self.extract_policies(&content, &parsed);
+        if !policies.is_empty() {
+            parsed.metadata.insert(
+                "policies".to_string(),
+                serde_json::json!(policies),
+            );
+        }
+
+        // Extract model -> observer registrations
+        let observers = self.extract_observers(&content, &parsed);
+        if !observers.is_empty() {
+            parsed.metadata.insert(
+                "model_observers".to_string(),
+                serde_json::json!(observers),
+            );
+        }
+
+        // Extract the abilities defined through Gate::define()
+        let mut gates: Vec<&str> = Vec::new();
+        for caps in self.gate_define_regex.captures_iter(&content) {
+            let ability = caps.get(1).map(|m| m.as_str()).unwrap_or("");
+            if !gates.contains(&ability) {
+                gates.push(ability);
+            }
+        }
+        if !gates.is_empty() {
+            parsed.metadata.insert(
+                "gates".to_string(),
+                serde_json::json!(gates),
+            );
+        }
+
+        Ok(parsed)
+    }
+
+    /// Extract the container bindings of the register() method
+    ///
+    /// Class names are expanded through the file's use statements so they
+    /// can be matched against qualified names. A closure concrete can't be
+    /// resolved statically: it is recorded with `concrete: null` and
+    /// `closure: true`. Without a concrete the abstract binds to itself.
+    fn extract_bindings(&self, content: &str, parsed: &ParsedFile) -> Vec<serde_json::Value> {
+        let register_body = match self
+            .register_method_regex
+            .find(content)
+            .and_then(|header| block_body(content, header.end() - 1))
+        {
+            Some(body) => body,
+            None => return Vec::new(),
+        };
+
+        let mut bindings = Vec::new();
+        for caps in self.binding_regex.captures_iter(register_body) {
+            let open = caps.get(0).map(|m| m.end() - 1).unwrap_or(0);
+            let arguments = match matching_paren(register_body, open) {
+                Some(close) => split_top_level(&register_body[open + 1..close], b','),
+                None => continue,
+            };
+
+            let abstract_name = match self.class_name(arguments[0], parsed) {
+                Some(name) => name,
+                None => continue,
+            };
+            let concrete = arguments.get(1).map(|argument| argument.trim());
+            let binding = match concrete {
+                Some(closure) if self.closure_regex.is_match(closure) => serde_json::json!({
+                    "type": &caps[1],
+                    "abstract": abstract_name,
+                    "concrete": null,
+                    "closure": true,
+                }),
+                Some(concrete) => match self.class_name(concrete, parsed) {
+                    Some(concrete_name) => serde_json::json!({
+                        "type": &caps[1],
+                        "abstract": abstract_name,
+                        "concrete": concrete_name,
+                    }),
+                    None => continue,
+                },
+                None => serde_json::json!({
+                    "type": &caps[1],
+                    "abstract": abstract_name,
+                    "concrete": abstract_name,
+                }),
+            };
+            bindings.push(binding);
+        }
+
+        bindings
+    }
+
+    /// Class named by a binding argument: `Foo::class` or a `'App\Foo'` string
+    fn class_name(&self, argument: &str, parsed: &ParsedFile) -> Option<String> {
+        let argument = argument.trim();
+        if let Some(caps) = self.class_string_regex.captures(argument) {
+            return Some(expand_imported_name(&parsed.dependencies, &caps[1]));
+        }
+
+        // 'App\\Services\\Stripe' escapes its backslashes
+        let name = unquote(argument);
+        if name.len() == argument.len() || name.is_empty() {
+            return None;
+        }
+        Some(name.replace("\\\\", "\\").trim_start_matches('\\').to_string())
+    }
+
+    /// Extract policies from the `$policies` array and `Gate::policy()` calls
+    fn extract_policies(&self, content: &str, parsed: &ParsedFile) -> Vec<serde_json::Value> {
+        let mut pairs: Vec<(&str, &str)> = Vec::new();
+
+        let policies_array = self
+            .policies_property_regex
+            .find(content)
+            .and_then(|header| {
+                let open = header.end() - 1;
+                matching_bracket(content, open).map(|close| &content[open + 1..close])
+            });
+        if let Some(policies_array) = policies_array {
+            for caps in self.policy_entry_regex.captures_iter(policies_array) {
+                if let (Some(model), Some(policy)) = (caps.get(1), caps.get(2)) {
+                    pairs.push((model.as_str(), policy.as_str()));
+                }
+            }
+        }
+
+        for caps in self.gate_policy_regex.captures_iter(content) {
+            if let (Some(model), Some(policy)) = (caps.get(1), caps.get(2)) {
+                pairs.push((model.as_str(), policy.as_str()));
+            }
+        }
+
+        let mut policies: Vec<serde_json::Value> = Vec::new();
+        for (model, policy) in pairs {
+            let entry = serde_json::json!({
+                "model": expand_imported_name(&parsed.dependencies, model),
+                "policy": expand_imported_name(&parsed.dependencies, policy),
+            });
+            if !policies.contains(&entry) {
+                policies.push(entry);
+            }
+        }
+
+        policies
+    }
+
+    /// Extract observers from the `$observers` array and `Model::observe()` calls
+    ///
+    /// Both take one observer or a list of them.
+    fn extract_observers(&self, content: &str, parsed: &ParsedFile) -> Vec<serde_json::Value> {
+        let mut pairs: Vec<(&str, &str)> = Vec::new();
+
+        let observers_array = self
+            .observers_property_regex
+            .find(content)
+            .and_then(|header| {
+                let open = header.end() - 1;
+                matching_bracket(content, open).map(|close| &content[open + 1..close])
+            });
+        if let Some(observers_array) = observers_array {
+            for caps in self.observers_entry_regex.captures_iter(observers_array) {
+                let model = caps.get(1).map(|m| m.as_str()).unwrap_or("");
+                let rest = &observers_array[caps.get(0).map_or(0, |m| m.end())..];
+                let observers = if rest.starts_with('[') {
+                    match matching_bracket(rest, 0) {
+                        Some(close) => &rest[1..close],
+                        None => continue,
+                    }
+                } else {
+                    rest.split(',').next().unwrap_or("")
+                };
+                for observer in CLASS_REF_REGEX.captures_iter(observers) {
+                    pairs.push((model, observer.get(1).map(|m| m.as_str()).unwrap_or("")));
+                }
+            }
+        }
+
+        for caps in self.observe_call_regex.captures_iter(content) {
+            let model = caps.get(1).map(|m| m.as_str()).unwrap_or("");
+            if matches!(model, "static" | "self" | "parent") {
+                continue;
+            }
+            let open = caps.get(0).map(|m| m.end() - 1).unwrap_or(0);
+            let observers = match matching_paren(content, open) {
+                Some(close) => &content[open + 1..close],
+                None => continue,
+            };
+            for observer in CLASS_REF_REGEX.captures_iter(observers) {
+                pairs.push((model, observer.get(1).map(|m| m.as_str()).unwrap_or("")));
+            }
+        }
+
+        let mut observers: Vec<serde_json::Value> = Vec::new();
+        for (model, observer) in pairs {
+            let entry = serde_json::json!({
+                "model": expand_imported_name(&parsed.dependencies, model),
+                "observer": expand_imported_name(&parsed.dependencies, observer),
+            });
+            if !observers.contains(&entry) {
+                observers.push(entry);
+            }
+        }
+
+        observers
+    }
+
+    /// Extract listeners from the `$listen` array and `Event::listen()` calls
+    fn extract_event_listeners(
+        &self,
+        content: &str,
+        parsed: &ParsedFile,
+    ) -> Vec<serde_json::Value> {
+        let mut pairs: Vec<(&str, &str)> = Vec::new();
+
+        let listen_array = self
+            .listen_property_regex
+            .find(content)
+            .and_then(|header| {
+                let open = header.end() - 1;
+                matching_bracket(content, open).map(|close| &content[open + 1..close])
+            });
+        if let Some(listen_array) = listen_array {
+            for caps in self.listen_entry_regex.captures_iter(listen_array) {
+                let event = caps.get(1).map(|m| m.as_str()).unwrap_or("");
+                let open = caps.get(0).map(|m| m.end() - 1).unwrap_or(0);
+                let listeners = match matching_bracket(listen_array, open) {
+                    Some(close) => &listen_array[open + 1..close],
+                    None => continue,
+                };
+                for listener in CLASS_REF_REGEX.captures_iter(listeners) {
+                    pairs.push((event, listener.get(1).map(|m| m.as_str()).unwrap_or("")));
+                }
+            }
+        }
+
+        for caps in self.event_listen_regex.captures_iter(content) {
+            if let (Some(event), Some(listener)) = (caps.get(1), caps.get(2)) {
+                pairs.push((event.as_str(), listener.as_str()));
+            }
+        }
+
+        let mut listeners: Vec<serde_json::Value> = Vec::new();
+        for (event, listener) in pairs {
+            let entry = serde_json::json!({
+                "event": expand_imported_name(&parsed.dependencies, event),
+                "listener": expand_imported_name(&parsed.dependencies, listener),
+            });
+            if !listeners.contains(&entry) {
+                listeners.push(entry);
+            }
+        }
+
+        listeners
+    }
+}
+
+impl Default for ProviderParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::UnifiedEdgeType;
+    use crate::parsers::common::generate_id;
+    use crate::parsers::laravel::fixtures::{graph_of, parse_with};
+
+    const AUTH_PROVIDER: &str = r#"<?php
+namespace App\Providers;
+
+use App\Models\Post;
+use App\Models\Comment;
+use App\Policies\PostPolicy;
+use App\Policies\CommentPolicy;
+
+class AuthServiceProvider extends ServiceProvider
+{
+    protected $policies = [
+        Post::class => PostPolicy::class,
+        Comment::class => CommentPolicy::class,
+    ];
+
+    public function boot(): void
+    {
+        Gate::define('publish-post', fn ($user) => $user->isEditor());
+        Gate::define('moderate', [ModerationPolicy::class, 'moderate']);
+    }
+}
+"#;
+
+    fn class(namespace: &str, name: &str) -> String {
+        format!(
+            "<?php\nnamespace {};\n\nclass {}\n{{\n}}\n",
+            namespace, name
+        )
+    }
+
+    #[tokio::test]
+    async fn registered_policies_guard_their_models() {
+        let files = [
+            ("app/Models/Post.php", class("App\\Models", "Post")),
+            ("app/Models/Comment.php", class("App\\Models", "Comment")),
+            (
+                "app/Policies/PostPolicy.php",
+                class("App\\Policies", "PostPolicy"),
+            ),
+            (
+                "app/Policies/CommentPolicy.php",
+                class("App\\Policies", "CommentPolicy"),
+            ),
+            (
+                "app/Providers/AuthServiceProvider.php",
+                AUTH_PROVIDER.to_string(),
+            ),
+        ];
+        let files: Vec<(&str, &str)> = files.iter().map(|(p, c)| (*p, c.as_str())).collect();
+        let graph = graph_of(&files).await;
+
+        let name = |id: &str| graph.find_node(id).unwrap().qualified_name.clone();
+        let mut guarded: Vec<(String, String)> = graph
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == UnifiedEdgeType::Custom("guards".to_string()))
+            .map(|e| (name(&e.source), name(&e.target)))
+            .collect();
+        guarded.sort();
+        assert_eq!(
+            guarded,
+            vec![
+                (
+                    "App\\Policies\\CommentPolicy".to_string(),
+                    "App\\Models\\Comment".to_string()
+                ),
+                (
+                    "App\\Policies\\PostPolicy".to_string(),
+                    "App\\Models\\Post".to_string()
+                ),
+            ]
+        );
+        // Registered policies aren't discovered again by convention
+        assert!(graph
+            .edges
+            .iter()
+            .all(|e| e.detail.as_deref() != Some("discovered")));
+
+        let provider = graph
+            .find_node(&generate_id("app/Providers/AuthServiceProvider.php"))
+            .unwrap();
+        assert_eq!(
+            provider.metadata.extra["gates"],
+            serde_json::json!(["publish-post", "moderate"])
+        );
+    }
+
+    #[tokio::test]
+    async fn bindings_link_interfaces_to_their_implementations() {
+        let provider = r#"<?php
+namespace App\Providers;
+
+use App\Contracts\PaymentGateway;
+use App\Services\StripeGateway;
+
+class AppServiceProvider extends ServiceProvider
+{
+    public function register(): void
+    {
+        $this->app->bind(PaymentGateway::class, StripeGateway::class);
+    }
+}
+"#;
+        let gateway = "<?php\nnamespace App\\Contracts;\n\ninterface PaymentGateway\n{\n}\n";
+        let stripe = class("App\\Services", "StripeGateway");
+        let graph = graph_of(&[
+            ("app/Contracts/PaymentGateway.php", gateway),
+            ("app/Services/StripeGateway.php", &stripe),
+            ("app/Providers/AppServiceProvider.php", provider),
+        ])
+        .await;
+
+        let name = |id: &str| graph.find_node(id).unwrap().qualified_name.clone();
+        let binds: Vec<(String, String, Option<&str>)> = graph
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == UnifiedEdgeType::Custom("binds".to_string()))
+            .map(|e| (name(&e.source), name(&e.target), e.label.as_deref()))
+            .collect();
+        assert_eq!(
+            binds,
+            vec![(
+                "App\\Contracts\\PaymentGateway".to_string(),
+                "App\\Services\\StripeGateway".to_string(),
+                Some("bind")
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn closure_string_and_self_bindings_are_extracted() {
+        let provider = r#"<?php
+namespace App\Providers;
+
+use App\Contracts\PaymentGateway;
+use App\Contracts\Reporter;
+use App\Services\StripeGateway;
+use App\Support\Clock;
+
+class AppServiceProvider extends ServiceProvider
+{
+    public function register(): void
+    {
+        $this->app->bind(PaymentGateway::class, StripeGateway::class);
+        $this->app->singleton(Reporter::class, function ($app) {
+            return new SlackReporter($app['config']['services.slack']);
+        });
+        $this->app->scoped(Clock::class);
+        app()->singleton('App\\Services\\Cache', 'App\\Services\\RedisCache');
+    }
+
+    public function boot(): void
+    {
+        $this->app->bind(Ignored::class, Other::class);
+    }
+}
+"#;
+        let result = parse_with(
+            &[("app/Providers/AppServiceProvider.php", provider)],
+            |_| {},
+        )
+        .await;
+
+        assert_eq!(
+            result.files[0].metadata["bindings"],
+            serde_json::json!([
+                {
+                    "type": "bind",
+                    "abstract": "App\\Contracts\\PaymentGateway",
+                    "concrete": "App\\Services\\StripeGateway",
+                },
+                {
+                    "type": "singleton",
+                    "abstract": "App\\Contracts\\Reporter",
+                    "concrete": null,
+                    "closure": true,
+                },
+                {
+                    "type": "scoped",
+                    "abstract": "App\\Support\\Clock",
+                    "concrete": "App\\Support\\Clock",
+                },
+                {
+                    "type": "singleton",
+                    "abstract": "App\\Services\\Cache",
+                    "concrete": "App\\Services\\RedisCache",
+                },
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn listeners_handle_the_events_they_are_registered_for() {
+        let provider = r#"<?php
+namespace App\Providers;
+
+use App\Events\OrderShipped;
+use App\Events\UserRegistered;
+use App\Listeners\CreateDefaultTeam;
+use App\Listeners\SendShipmentNotification;
+use App\Listeners\SendWelcomeEmail;
+use App\Listeners\UpdateInventory;
+
+class EventServiceProvider extends ServiceProvider
+{
+    protected $listen = [
+        OrderShipped::class => [
+            SendShipmentNotification::class,
+            UpdateInventory::class,
+        ],
+        UserRegistered::class => [
+            SendWelcomeEmail::class,
+            CreateDefaultTeam::class,
+        ],
+    ];
+}
+"#;
+        let mut files = vec![(
+            "app/Providers/EventServiceProvider.php".to_string(),
+            provider.to_string(),
+        )];
+        for (namespace, name) in [
+            ("App\\Events", "OrderShipped"),
+            ("App\\Events", "UserRegistered"),
+            ("App\\Listeners", "CreateDefaultTeam"),
+            ("App\\Listeners", "SendShipmentNotification"),
+            ("App\\Listeners", "SendWelcomeEmail"),
+            ("App\\Listeners", "UpdateInventory"),
+        ] {
+            let path = format!("app/{}/{}.php", &namespace[4..], name);
+            files.push((path, class(namespace, name)));
+        }
+        let files: Vec<(&str, &str)> = files
+            .iter()
+            .map(|(p, c)| (p.as_str(), c.as_str()))
+            .collect();
+        let graph = graph_of(&files).await;
+
+        let name = |id: &str| graph.find_node(id).unwrap().name.clone();
+        let mut handles: Vec<String> = graph
+            .edges
+            .iter()
+            .filter(|e| e.edge_type == UnifiedEdgeType::Custom("handles".to_string()))
+            .map(|e| format!("{} -> {}", name(&e.source), name(&e.target)))
+            .collect();
+        handles.sort();
+        assert_eq!(
+            handles,
+            [
+                "CreateDefaultTeam -> UserRegistered",
+                "SendShipmentNotification -> OrderShipped",
+                "SendWelcomeEmail -> UserRegistered",
+                "UpdateInventory -> OrderShipped",
+            ]
+        );
+    }
+}
@@ -0,0 +1,340 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::models::{ParseResult, UnifiedEdge, UnifiedEdgeType, UnifiedNode};
+use crate::parsers::common::{generate_id, snake_case, split_top_level};
+
+use super::table_schema::table_schemas;
+
+/// A non-polymorphic relationship a model declares
+pub(super) struct DeclaredRelation<'a> {
+    /// The declaring model
+    pub model: &'a UnifiedNode,
+    /// The related model
+    pub related: &'a UnifiedNode,
+    /// Eloquent relationship type (`belongsTo`, `hasMany`, ...)
+    pub rel_type: &'a str,
+    /// Name of the relationship method
+    pub method: &'a str,
+    /// Arguments of the relationship call, as written
+    pub raw_args: &'a str,
+}
+
+/// Foreign keys from migrations, by the table holding the column
+struct ForeignKeys<'a> {
+    /// Table -> (column, referenced table)
+    by_table: HashMap<String, Vec<(&'a str, &'a str)>>,
+    /// (table, column) of the columns declared `->unique()`
+    unique_columns: HashSet<(String, String)>,
+}
+
+impl<'a> ForeignKeys<'a> {
+    fn new(parse_result: &'a ParseResult) -> Self {
+        let mut by_table: HashMap<String, Vec<(&str, &str)>> = HashMap::new();
+
+        for parsed_file in &parse_result.files {
+            let foreign_keys = match parsed_file
+                .metadata
+                .get("foreign_keys")
+                .and_then(|f| f.as_array())
+            {
+                Some(foreign_keys) => foreign_keys,
+                None => continue,
+            };
+
+            // Foreign keys aren't tied to their table, only attribute them in
+            // migrations creating or altering a single one
+            let tables: HashSet<&str> = parsed_file
+                .metadata
+                .get("schema_changes")
+                .and_then(|c| c.as_array())
+                .into_iter()
+                .flatten()
+                .filter(|change| {
+                    matches!(
+                        change.get("type").and_then(|t| t.as_str()),
+                        Some("create") | Some("modify")
+                    )
+                })
+                .filter_map(|change| change.get("table").and_then(|t| t.as_str()))
+                .collect();
+            let table = match tables.into_iter().collect::<Vec<_>>().as_slice() {
+                [table] => table.to_string(),
+                _ => continue,
+            };
+
+            for fk in foreign_keys {
+                let column = fk.get("column").and_then(|c| c.as_str());
+                let on_table = fk.get("on_table").and_then(|t| t.as_str());
+                if let (Some(column), Some(on_table)) = (column, on_table) {
+                    by_table
+                        .entry(table.clone())
+                        .or_default()
+                        .push((column, on_table));
+                }
+            }
+        }
+
+        let unique_columns = table_schemas(parse_result)
+            .into_iter()
+            .flat_map(|schema| {
+                let table = schema.name;
+                schema.columns.into_iter().filter_map(move |column| {
+                    let unique = column
+                        .get("modifiers")
+                        .and_then(|m| m.as_array())
+                        .is_some_and(|m| m.iter().any(|m| m.as_str() == Some("unique")));
+                    let name = column.get("name").and_then(|n| n.as_str())?;
+                    unique.then(|| (table.clone(), name.to_string()))
+                })
+            })
+            .collect();
+
+        Self {
+            by_table,
+            unique_columns,
+        }
+    }
+
+    /// Columns of `table` referencing `referenced`
+    fn columns_referencing(&self, table: &str, referenced: &str) -> Vec<&'a str> {
+        self.by_table
+            .get(table)
+            .into_iter()
+            .flatten()
+            .filter(|(_, on_table)| *on_table == referenced)
+            .map(|(column, _)| *column)
+            .collect()
+    }
+
+    /// Whether the migrations put the key between the two tables on
+    /// `referenced`'s side only, contradicting a key on `table`
+    fn contradicts_key_on(&self, table: &str, referenced: &str) -> bool {
+        self.columns_referencing(table, referenced).is_empty()
+            && !self.columns_referencing(referenced, table).is_empty()
+    }
+
+    /// Whether the key of `table` to `referenced` is unique, making the
+    /// inverse a `hasOne`; the relationship's own column when it is a key
+    fn is_unique_key(&self, table: &str, referenced: &str, column: &str) -> bool {
+        let columns = self.columns_referencing(table, referenced);
+        let columns = if columns.contains(&column) {
+            vec![column]
+        } else {
+            columns
+        };
+
+        !columns.is_empty()
+            && columns.iter().all(|c| {
+                self.unique_columns
+                    .contains(&(table.to_string(), c.to_string()))
+            })
+    }
+}
+
+/// Edges for the inverses of relationships declared on one side only
+///
+/// `Post belongsTo User` without a `User` relationship back to `Post` infers
+/// `User hasMany Post` (`hasOne` when the migrations make the key unique),
+/// `hasOne`/`hasMany` infer `belongsTo` and `belongsToMany` infers itself.
+/// Any declared relationship between the two models in the other direction
+/// wins, and none is inferred when migration foreign keys put the key on the
+/// other table. Inferred edges are marked `metadata.inferred`.
+pub(super) fn infer_inverse_relationships(
+    parse_result: &ParseResult,
+    declared: &[DeclaredRelation],
+) -> Vec<UnifiedEdge> {
+    let foreign_keys = ForeignKeys::new(parse_result);
+    let tables: HashMap<&str, &str> = parse_result
+        .files
+        .iter()
+        .filter_map(|f| {
+            let table = f.metadata.get("table")?.as_str()?;
+            Some((f.source.path.as_str(), table))
+        })
+        .collect();
+    let table_of = |node: &UnifiedNode| {
+        node.file_path
+            .as_deref()
+            .and_then(|path| tables.get(path).copied())
+    };
+
+    let related_pairs: HashSet<(&str, &str)> = declared
+        .iter()
+        .map(|rel| (rel.model.id.as_str(), rel.related.id.as_str()))
+        .collect();
+
+    let mut seen = HashSet::new();
+    let mut edges = Vec::new();
+    for rel in declared {
+        if related_pairs.contains(&(rel.related.id.as_str(), rel.model.id.as_str())) {
+            continue;
+        }
+        let model_table = table_of(rel.model);
+        let related_table = table_of(rel.related);
+
+        let inverse = match rel.rel_type {
+            "belongsTo" => match (model_table, related_table) {
+                (Some(table), Some(related_table)) => {
+                    if foreign_keys.contradicts_key_on(table, related_table) {
+                        continue;
+                    }
+                    let column = foreign_key_argument(rel.raw_args)
+                        .unwrap_or_else(|| format!("{}_id", snake_case(rel.method)));
+                    if foreign_keys.is_unique_key(table, related_table, &column) {
+                        "has_one"
+                    } else {
+                        "has_many"
+                    }
+                }
+                _ => "has_many",
+            },
+            "hasOne" | "hasMany" => {
+                if let (Some(table), Some(related_table)) = (model_table, related_table) {
+                    if foreign_keys.contradicts_key_on(related_table, table) {
+                        continue;
+                    }
+                }
+                "belongs_to"
+            }
+            "belongsToMany" => "belongs_to_many",
+            _ => continue,
+        };
+
+        // The inverse goes from the related model's file, like declared ones
+        let source = match rel.related.file_path.as_deref() {
+            Some(path) => generate_id(path),
+            None => continue,
+        };
+        if !seen.insert((source.clone(), rel.model.id.clone(), inverse)) {
+            continue;
+        }
+
+        let mut edge = UnifiedEdge::new(
+            source,
+            rel.model.id.clone(),
+            UnifiedEdgeType::Custom(inverse.to_string()),
+        );
+        edge.detail = Some(format!(
+            "Inverse of {}::{}() ({})",
+            rel.model.name, rel.method, rel.rel_type
+        ));
+        edge.metadata.inferred = Some(true);
+        edges.push(edge);
+    }
+
+    edges
+}
+
+/// Foreign key column given as second argument: `belongsTo(User::class, 'author_id')`
+fn foreign_key_argument(raw_args: &str) -> Option<String> {
+    let argument = split_top_level(raw_args, b',').into_iter().nth(1)?.trim();
+    let column = argument.trim_matches(|c| c == '\'' || c == '"');
+    let is_literal = column.len() + 2 == argument.len()
+        && !column.is_empty()
+        && column
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_');
+    is_literal.then(|| column.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::laravel::fixtures::graph_of;
+
+    fn model(name: &str, body: &str) -> (String, String) {
+        (
+            format!("app/Models/{}.php", name),
+            format!(
+                "<?php\n\nnamespace App\\Models;\n\n\
+use Illuminate\\Database\\Eloquent\\Model;\n\n\
+class {} extends Model\n{{\n{}}}\n",
+                name, body
+            ),
+        )
+    }
+
+    fn migration(table: &str, columns: &str) -> (String, String) {
+        (
+            format!(
+                "database/migrations/2024_01_01_000000_create_{}_table.php",
+                table
+            ),
+            format!(
+                "<?php\n\nuse Illuminate\\Database\\Migrations\\Migration;\n\
+use Illuminate\\Database\\Schema\\Blueprint;\n\
+use Illuminate\\Support\\Facades\\Schema;\n\n\
+return new class extends Migration\n{{\n    public function up(): void\n    {{\n\
+        Schema::create('{}', function (Blueprint $table) {{\n\
+            $table->id();\n{}        }});\n    }}\n}};\n",
+                table, columns
+            ),
+        )
+    }
+
+    #[test]
+    fn foreign_key_is_the_second_top_level_argument() {
+        assert_eq!(
+            foreign_key_argument("User::class, 'owner_id'"),
+            Some("owner_id".to_string())
+        );
+        assert_eq!(
+            foreign_key_argument("config('auth.model', User::class), 'owner_id'"),
+            Some("owner_id".to_string())
+        );
+        assert_eq!(foreign_key_argument("User::class, $column"), None);
+        assert_eq!(foreign_key_argument("User::class"), None);
+    }
+
+    #[tokio::test]
+    async fn unique_foreign_keys_infer_has_one() {
+        let files = [
+            model("User", ""),
+            model(
+                "Profile",
+                "    public function owner()\n    {\n\
+        return $this->belongsTo(User::class, 'owner_id');\n    }\n",
+            ),
+            model(
+                "Post",
+                "    public function author()\n    {\n\
+        return $this->belongsTo(User::class);\n    }\n",
+            ),
+            migration("users", ""),
+            migration(
+                "profiles",
+                "            $table->foreignId('owner_id')->unique()->constrained('users');\n",
+            ),
+            migration(
+                "posts",
+                "            $table->foreignId('author_id')->constrained('users');\n",
+            ),
+        ];
+        let files: Vec<(&str, &str)> = files
+            .iter()
+            .map(|(path, content)| (path.as_str(), content.as_str()))
+            .collect();
+        let graph = graph_of(&files).await;
+
+        let mut inferred: Vec<String> = graph
+            .edges
+            .iter()
+            .filter(|e| e.metadata.inferred == Some(true))
+            .map(|e| {
+                format!(
+                    "{} {}",
+                    e.edge_type.as_key(),
+                    e.detail.as_deref().unwrap_or("")
+                )
+            })
+            .collect();
+        inferred.sort();
+        assert_eq!(
+            inferred,
+            [
+                "custom:has_many Inverse of Post::author() (belongsTo)",
+                "custom:has_one Inverse of Profile::owner() (belongsTo)",
+            ]
+        );
+    }
+}
@@ -1,30 +1,103 @@
 use regex::Regex;
-use std::fs;
 
 use crate::models::{Dependency, ParsedFile, SourceFile, Symbol, SymbolType};
-use crate::parsers::{ParseError, ParserConfig, ParserResult};
+use crate::parsers::common::{
+    blank_regions, block_body, matching_bracket, matching_paren, php_regions, read_source,
+    split_top_level, unbalanced_braces_warning, PhpRegionKind,
+};
+use crate::parsers::{ParserConfig, ParserResult};
+
+use super::php_parser::expand_imported_name;
 
 /// Represents a parsed Laravel route
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct RouteDefinition {
     pub method: String,
     pub uri: String,
+    pub full_uri: String,
     pub action: RouteAction,
     pub name: Option<String>,
     pub middleware: Vec<String>,
+    pub rate_limit: Option<RateLimit>,
     pub prefix: Option<String>,
 }
 
+/// Rate limit applied by the `throttle` middleware
+///
+/// `throttle:60,1` allows 60 requests per minute, `throttle:uploads` defers to
+/// a limiter registered with `RateLimiter::for()`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct RateLimit {
+    pub limit: Option<u32>,
+    pub minutes: Option<u32>,
+    pub limiter: Option<String>,
+}
+
+impl RateLimit {
+    /// Parse the rate limit of the first `throttle` entry of a middleware list
+    pub fn from_middleware(middleware: &[String]) -> Option<Self> {
+        let parameters = middleware
+            .iter()
+            .find_map(|m| m.trim().strip_prefix("throttle:"))?;
+        let mut parameters = parameters.split(',').map(|p| p.trim());
+        let first = parameters.next().unwrap_or("");
+
+        match first.parse::<u32>() {
+            Ok(limit) => Some(Self {
+                limit: Some(limit),
+                // Laravel's decay defaults to one minute
+                minutes: parameters.next().and_then(|m| m.parse().ok()).or(Some(1)),
+                limiter: None,
+            }),
+            Err(_) if !first.is_empty() => Some(Self {
+                limit: None,
+                minutes: None,
+                limiter: Some(first.to_string()),
+            }),
+            Err(_) => None,
+        }
+    }
+}
+
 /// Route action - controller method or closure
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(tag = "type")]
 pub enum RouteAction {
-    Controller { controller: String, method: String },
-    Closure,
+    Controller {
+        controller: String,
+        controller_fqn: Option<String>,
+        method: String,
+    },
+    Closure {
+        /// Views the closure renders (`return view('welcome')`)
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        views: Vec<String>,
+    },
     View { view: String },
     Redirect { to: String },
 }
 
+/// A route group and the byte range of its body (the `group(...)` arguments)
+struct RouteGroup {
+    start: usize,
+    end: usize,
+    prefix: Option<String>,
+    name_prefix: Option<String>,
+    namespace: Option<String>,
+    controller: Option<String>,
+    middleware: Vec<String>,
+}
+
+/// Prefix, name prefix, controller and middleware a route inherits from its groups
+#[derive(Default)]
+struct GroupScope {
+    prefix: Option<String>,
+    name_prefix: String,
+    controller: Option<String>,
+    middleware: Vec<String>,
+    depth: usize,
+}
+
 /// Parser for Laravel route files (web.php, api.php, etc.)
 pub struct RouteParser {
     // Basic route patterns
@@ -33,12 +106,19 @@ pub struct RouteParser {
     resource_regex: Regex,
     // API resource route pattern
     api_resource_regex: Regex,
-    // Route group pattern
-    group_regex: Regex,
+    // Route group patterns Route::group([...]) and Route::prefix(...)->group(...)
+    group_call_regex: Regex,
+    fluent_group_regex: Regex,
+    group_name_regex: Regex,
+    group_controller_regex: Regex,
     // Controller action pattern [Controller::class, 'method']
     controller_action_regex: Regex,
     // String controller pattern 'Controller@method'
     string_controller_regex: Regex,
+    // Single action controller pattern Controller::class
+    invokable_action_regex: Regex,
+    // Method of the group's controller pattern 'method'
+    group_method_action_regex: Regex,
     // Route name pattern ->name('xxx')
     route_name_regex: Regex,
     // Middleware pattern ->middleware(['xxx'])
@@ -47,8 +127,22 @@ pub struct RouteParser {
     prefix_regex: Regex,
     // View route
     view_route_regex: Regex,
+    // Closure action, up to its body or arrow
+    closure_regex: Regex,
+    // View rendered by a closure action
+    view_call_regex: Regex,
     // Redirect route
     redirect_route_regex: Regex,
+    // Group option pattern 'key' => 'value'
+    group_option_regex: Regex,
+    // Group middleware patterns 'middleware' => [...] or 'middleware' => 'xxx'
+    group_middleware_array_regex: Regex,
+    group_middleware_single_regex: Regex,
+    group_middleware_regex: Regex,
+    // Any Xxx::class reference
+    class_reference_regex: Regex,
+    // Use statements
+    use_regex: Regex,
 }
 
 impl RouteParser {
@@ -72,8 +166,19 @@ impl RouteParser {
             )
             .unwrap(),
 
-            // Match: Route::group(['prefix' => 'admin', ...], function() { ... })
-            group_regex: Regex::new(r"Route::group\s*\(\s*\[([^\]]*)\]").unwrap(),
+            // Match: Route::group(  (options array and routes closure follow)
+            group_call_regex: Regex::new(r"Route::group\s*\(").unwrap(),
+
+            // Match: Route::prefix('admin')->middleware('auth')->group(  (up to the paren)
+            fluent_group_regex: Regex::new(
+                r#"Route::(?:prefix|middleware|name|as|domain|namespace|controller)\s*\((?:[^;{'"]|'[^']*'|"[^"]*")*?->\s*group\s*\("#
+            ).unwrap(),
+
+            // Match: ->name('admin.')  or  ->as('admin.')  (group name prefix)
+            group_name_regex: Regex::new(r#"->\s*(?:name|as)\s*\(\s*['"]([^'"]+)['"]"#).unwrap(),
+
+            // Match: ->controller(PhotoController::class)  (controller of the group's routes)
+            group_controller_regex: Regex::new(r"->\s*controller\s*\(\s*([A-Z]\w+)::class").unwrap(),
 
             // Match: [UserController::class, 'index']
             controller_action_regex: Regex::new(
@@ -84,6 +189,18 @@ impl RouteParser {
             // Match: 'UserController@index' (legacy style)
             string_controller_regex: Regex::new(r#"['"]([A-Z]\w+)@(\w+)['"]"#).unwrap(),
 
+            // Match: Route::get('/home', HomeController::class)  (calls __invoke)
+            invokable_action_regex: Regex::new(
+                r#"^Route::\w+\s*\(\s*['"][^'"]+['"]\s*,\s*([A-Z]\w+)::class\s*\)"#,
+            )
+            .unwrap(),
+
+            // Match: Route::get('/photos/{id}', 'show')  (inside a controller group)
+            group_method_action_regex: Regex::new(
+                r#"^Route::\w+\s*\(\s*['"][^'"]+['"]\s*,\s*['"](\w+)['"]\s*\)"#,
+            )
+            .unwrap(),
+
             // Match: ->name('users.index')
             route_name_regex: Regex::new(r#"->\s*name\s*\(\s*['"]([^'"]+)['"]"#).unwrap(),
 
@@ -102,22 +219,66 @@ impl RouteParser {
             )
             .unwrap(),
 
+            // Match: function () use ($x): View {  or  fn () =>
+            closure_regex: Regex::new(
+                r"\bfunction\s*\([^)]*\)\s*(?:use\s*\([^)]*\)\s*)?(?::\s*\??[\w\\]+\s*)?\{|\bfn\s*\([^)]*\)\s*(?::\s*\??[\w\\]+\s*)?=>",
+            )
+            .unwrap(),
+
+            // Match: view('welcome') or View::make('welcome')
+            view_call_regex: Regex::new(
+                r#"(?:\bview|View::make)\s*\(\s*['"]([^'"]+)['"]"#,
+            )
+            .unwrap(),
+
             // Match: Route::redirect('/here', '/there')
             redirect_route_regex: Regex::new(
                 r#"Route::redirect\s*\(\s*['"]([^'"]+)['"]\s*,\s*['"]([^'"]+)['"]"#,
             )
             .unwrap(),
+
+            // Match: 'prefix' => 'admin'
+            group_option_regex: Regex::new(r#"['"](\w+)['"]\s*=>\s*['"]([^'"]+)['"]"#).unwrap(),
+
+            // Match: 'middleware' => ['auth', 'admin']
+            group_middleware_array_regex: Regex::new(
+                r#"['"]middleware['"]\s*=>\s*\[([^\]]+)\]"#,
+            )
+            .unwrap(),
+
+            // Match: 'middleware' => 'auth'
+            group_middleware_single_regex: Regex::new(
+                r#"['"]middleware['"]\s*=>\s*['"]([^'"]+)['"]"#,
+            )
+            .unwrap(),
+
+            // Match: 'middleware' => ['auth'] or 'middleware' => 'auth'
+            group_middleware_regex: Regex::new(
+                r#"['"]middleware['"]\s*=>\s*(?:\[([^\]]+)\]|['"]([^'"]+)['"])"#,
+            )
+            .unwrap(),
+
+            // Match: PhotoController::class
+            class_reference_regex: Regex::new(r"([A-Z]\w+)::class").unwrap(),
+
+            // Match: use App\Http\Controllers\UserController;
+            use_regex: Regex::new(r"(?m)^\s*use\s+([\w\\]+)(?:\s+as\s+(\w+))?\s*;").unwrap(),
         }
     }
 
     pub async fn parse(
         &self,
         file: &SourceFile,
-        _config: &ParserConfig,
+        config: &ParserConfig,
     ) -> ParserResult<ParsedFile> {
-        let content = fs::read_to_string(&file.absolute_path).map_err(ParseError::Io)?;
+        let (content, decode_warnings) = read_source(file, config)?;
+
+        // Commented-out routes and examples quoted in strings aren't routes
+        let content = self.blank_inert_code(&content);
 
         let mut parsed = ParsedFile::new(file.clone());
+        parsed.warnings.extend(decode_warnings);
+        parsed.warnings.extend(unbalanced_braces_warning(&content, &file.path));
 
         // Determine route file type (web, api, channels, console)
         let route_type = self.detect_route_type(&file.name);
@@ -129,8 +290,11 @@ impl RouteParser {
         // Extract use statements for controller references
         self.extract_use_statements(&content, &mut parsed);
 
-        // Extract all routes
-        let routes = self.extract_routes(&content);
+        // Route groups, routes inherit the options of the groups they're nested in
+        let groups = self.find_route_groups(&content);
+
+        // Extract all routes, controllers resolved through the use statements
+        let routes = self.extract_routes(&content, &groups, &parsed.dependencies);
         if !routes.is_empty() {
             parsed
                 .metadata
@@ -139,6 +303,17 @@ impl RouteParser {
             parsed
                 .metadata
                 .insert("route_count".to_string(), serde_json::json!(routes.len()));
+
+            // Distinct HTTP methods served by this file (for method badges)
+            let mut methods: Vec<&str> = routes
+                .iter()
+                .filter_map(|r| r.get("method").and_then(|m| m.as_str()))
+                .collect();
+            methods.sort_unstable();
+            methods.dedup();
+            parsed
+                .metadata
+                .insert("http_methods".to_string(), serde_json::json!(methods));
         }
 
         // Extract resource routes
@@ -159,11 +334,11 @@ impl RouteParser {
         }
 
         // Extract route groups
-        let groups = self.extract_route_groups(&content);
-        if !groups.is_empty() {
+        let route_groups = self.extract_route_groups(&groups);
+        if !route_groups.is_empty() {
             parsed
                 .metadata
-                .insert("route_groups".to_string(), serde_json::json!(groups));
+                .insert("route_groups".to_string(), serde_json::json!(route_groups));
         }
 
         // Extract all referenced controllers
@@ -194,8 +369,14 @@ impl RouteParser {
             is_static: None,
             extends: None,
             implements: None,
+            parameters: None,
+            return_type: None,
+            attributes: None,
             line_start: None,
             line_end: None,
+            section: None,
+            backing_type: None,
+            enum_cases: None,
         });
 
         Ok(parsed)
@@ -211,10 +392,23 @@ impl RouteParser {
         }
     }
 
-    fn extract_use_statements(&self, content: &str, parsed: &mut ParsedFile) {
-        let use_regex = Regex::new(r"(?m)^\s*use\s+([\w\\]+)(?:\s+as\s+(\w+))?\s*;").unwrap();
+    /// Blank comments, and string literals that quote route code themselves
+    ///
+    /// URIs, names and actions are string arguments, so other strings stay.
+    fn blank_inert_code(&self, content: &str) -> String {
+        let inert: Vec<_> = php_regions(content)
+            .into_iter()
+            .filter(|region| match region.kind {
+                PhpRegionKind::Comment => true,
+                PhpRegionKind::String => content[region.start..region.end].contains("Route::"),
+            })
+            .collect();
+
+        blank_regions(content, &inert)
+    }
 
-        for caps in use_regex.captures_iter(content) {
+    fn extract_use_statements(&self, content: &str, parsed: &mut ParsedFile) {
+        for caps in self.use_regex.captures_iter(content) {
             let target = caps
                 .get(1)
                 .map(|m| m.as_str().to_string())
@@ -233,7 +427,16 @@ impl RouteParser {
         }
     }
 
-    fn extract_routes(&self, content: &str) -> Vec<serde_json::Value> {
+    /// Extract routes, with the prefix, name prefix and middleware of their groups
+    ///
+    /// `uri` is the URI as declared, `full_uri` includes the group prefixes.
+    /// `name` is the full route name, as used by `route()`.
+    fn extract_routes(
+        &self,
+        content: &str,
+        groups: &[RouteGroup],
+        dependencies: &[Dependency],
+    ) -> Vec<serde_json::Value> {
         let mut routes = Vec::new();
 
         // Find basic routes (get, post, put, etc.)
@@ -251,16 +454,30 @@ impl RouteParser {
             let match_start = caps.get(0).map(|m| m.start()).unwrap_or(0);
             let context = self.get_route_context(content, match_start);
 
-            let action = self.extract_route_action(&context);
-            let name = self.extract_route_name(&context);
-            let middleware = self.extract_middleware(&context);
+            let scope = self.group_scope(groups, match_start);
+            let mut action =
+                self.extract_route_action(&context, scope.controller.as_deref(), dependencies);
+            if action["type"] == "Closure" {
+                let views = self.closure_views(content, match_start, &context);
+                if !views.is_empty() {
+                    action["views"] = serde_json::json!(views);
+                }
+            }
+            let name = self
+                .extract_route_name(&context)
+                .map(|name| format!("{}{}", scope.name_prefix, name));
+            let middleware = merge_middleware(&scope.middleware, self.extract_middleware(&context));
+            let rate_limit = RateLimit::from_middleware(&middleware);
 
             routes.push(serde_json::json!({
                 "method": method,
                 "uri": uri,
+                "full_uri": join_uri(scope.prefix.as_deref(), &uri),
+                "prefix": scope.prefix,
                 "action": action,
                 "name": name,
-                "middleware": middleware
+                "middleware": middleware,
+                "rate_limit": rate_limit
             }));
         }
 
@@ -274,16 +491,20 @@ impl RouteParser {
                 .get(2)
                 .map(|m| m.as_str().to_string())
                 .unwrap_or_default();
+            let scope = self.group_scope(groups, caps.get(0).map(|m| m.start()).unwrap_or(0));
 
             routes.push(serde_json::json!({
                 "method": "GET",
                 "uri": uri,
+                "full_uri": join_uri(scope.prefix.as_deref(), &uri),
+                "prefix": scope.prefix,
                 "action": {
                     "type": "View",
                     "view": view
                 },
                 "name": null,
-                "middleware": []
+                "rate_limit": RateLimit::from_middleware(&scope.middleware),
+                "middleware": scope.middleware
             }));
         }
 
@@ -297,16 +518,20 @@ impl RouteParser {
                 .get(2)
                 .map(|m| m.as_str().to_string())
                 .unwrap_or_default();
+            let scope = self.group_scope(groups, caps.get(0).map(|m| m.start()).unwrap_or(0));
 
             routes.push(serde_json::json!({
                 "method": "GET",
                 "uri": from,
+                "full_uri": join_uri(scope.prefix.as_deref(), &from),
+                "prefix": scope.prefix,
                 "action": {
                     "type": "Redirect",
                     "to": to
                 },
                 "name": null,
-                "middleware": []
+                "rate_limit": RateLimit::from_middleware(&scope.middleware),
+                "middleware": scope.middleware
             }));
         }
 
@@ -324,17 +549,47 @@ impl RouteParser {
         remaining[..end].to_string()
     }
 
-    fn extract_route_action(&self, context: &str) -> serde_json::Value {
+    /// Action of a route
+    ///
+    /// `group_controller` is the controller of an enclosing `Route::controller()`
+    /// group, whose routes only name the method. `controller_fqn` is the
+    /// controller class as imported by the route file, null when it isn't
+    /// imported (legacy `'Controller@method'` strings never are).
+    fn extract_route_action(
+        &self,
+        context: &str,
+        group_controller: Option<&str>,
+        dependencies: &[Dependency],
+    ) -> serde_json::Value {
+        let controller_action = |controller: &str, method: &str| {
+            let controller_fqn = expand_imported_name(dependencies, controller);
+
+            serde_json::json!({
+                "type": "Controller",
+                "controller": controller,
+                "controller_fqn": (controller_fqn != controller).then_some(controller_fqn),
+                "method": method
+            })
+        };
+
         // Try controller class syntax first: [Controller::class, 'method']
         if let Some(caps) = self.controller_action_regex.captures(context) {
             let controller = caps.get(1).map(|m| m.as_str()).unwrap_or("");
             let method = caps.get(2).map(|m| m.as_str()).unwrap_or("");
 
-            return serde_json::json!({
-                "type": "Controller",
-                "controller": controller,
-                "method": method
-            });
+            return controller_action(controller, method);
+        }
+
+        // Single action controller: Controller::class
+        if let Some(caps) = self.invokable_action_regex.captures(context) {
+            return controller_action(&caps[1], "__invoke");
+        }
+
+        // Method of the group's controller: 'method'
+        if let Some(controller) = group_controller {
+            if let Some(caps) = self.group_method_action_regex.captures(context) {
+                return controller_action(controller, &caps[1]);
+            }
         }
 
         // Try legacy string syntax: 'Controller@method'
@@ -345,12 +600,13 @@ impl RouteParser {
             return serde_json::json!({
                 "type": "Controller",
                 "controller": controller,
+                "controller_fqn": null,
                 "method": method
             });
         }
 
         // Check for closure
-        if context.contains("function") && context.contains('{') {
+        if self.closure_regex.is_match(context) {
             return serde_json::json!({
                 "type": "Closure"
             });
@@ -361,6 +617,31 @@ impl RouteParser {
         })
     }
 
+    /// Views rendered by the closure action of the route declared at `start`
+    ///
+    /// A `function` body is read whole, it may run several statements before
+    /// returning the view; an arrow function ends with the route statement.
+    fn closure_views(&self, content: &str, start: usize, context: &str) -> Vec<String> {
+        let closure = match self.closure_regex.find(context) {
+            Some(closure) => closure,
+            None => return Vec::new(),
+        };
+        let body = if closure.as_str().ends_with('{') {
+            block_body(content, start + closure.end() - 1).unwrap_or("")
+        } else {
+            &context[closure.end()..]
+        };
+
+        let mut views = Vec::new();
+        for caps in self.view_call_regex.captures_iter(body) {
+            let view = caps[1].to_string();
+            if !views.contains(&view) {
+                views.push(view);
+            }
+        }
+        views
+    }
+
     fn extract_route_name(&self, context: &str) -> Option<String> {
         self.route_name_regex
             .captures(context)
@@ -374,7 +655,7 @@ impl RouteParser {
         if let Some(caps) = self.middleware_regex.captures(context) {
             // Array format: ['auth', 'admin']
             if let Some(array_list) = caps.get(1) {
-                for item in array_list.as_str().split(',') {
+                for item in split_top_level(array_list.as_str(), b',') {
                     let cleaned = item.trim().trim_matches(|c| c == '\'' || c == '"');
                     if !cleaned.is_empty() && !middlewares.contains(&cleaned.to_string()) {
                         middlewares.push(cleaned.to_string());
@@ -446,37 +727,125 @@ impl RouteParser {
         resources
     }
 
-    fn extract_route_groups(&self, content: &str) -> Vec<serde_json::Value> {
+    /// Find `Route::group([...], ...)` and fluent `Route::prefix(...)->group(...)` groups
+    ///
+    /// `Route::controller(PhotoController::class)->group(...)` is a fluent group too.
+    ///
+    /// Sorted by position, so outer groups come before the groups they contain.
+    fn find_route_groups(&self, content: &str) -> Vec<RouteGroup> {
         let mut groups = Vec::new();
 
-        for caps in self.group_regex.captures_iter(content) {
-            if let Some(options) = caps.get(1) {
-                let options_str = options.as_str();
-
-                let prefix = self.extract_group_option(options_str, "prefix");
-                let middleware = self.extract_group_middleware(options_str);
-                let namespace = self.extract_group_option(options_str, "namespace");
-                let name_prefix = self.extract_group_option(options_str, "as");
-
-                groups.push(serde_json::json!({
-                    "prefix": prefix,
-                    "middleware": middleware,
-                    "namespace": namespace,
-                    "name_prefix": name_prefix
-                }));
+        for found in self.group_call_regex.find_iter(content) {
+            let open = found.end() - 1;
+            let close = match matching_paren(content, open) {
+                Some(close) => close,
+                None => continue,
+            };
+
+            // Options are the leading array argument
+            let options_start = content[open + 1..close]
+                .find(|c: char| !c.is_whitespace())
+                .map(|p| open + 1 + p)
+                .filter(|&p| content.as_bytes()[p] == b'[');
+            let options = options_start
+                .and_then(|start| matching_bracket(content, start).map(|end| &content[start..=end]))
+                .unwrap_or("");
+
+            groups.push(RouteGroup {
+                start: open,
+                end: close,
+                prefix: self.extract_group_option(options, "prefix"),
+                name_prefix: self.extract_group_option(options, "as"),
+                namespace: self.extract_group_option(options, "namespace"),
+                controller: None,
+                middleware: self.extract_group_middleware(options),
+            });
+        }
+
+        for found in self.fluent_group_regex.find_iter(content) {
+            let open = found.end() - 1;
+            let close = match matching_paren(content, open) {
+                Some(close) => close,
+                None => continue,
+            };
+
+            // Route::prefix('a')->... reads like the ->prefix('a') of a chain
+            let chain = format!("->{}", &found.as_str()["Route::".len()..]);
+            let option = |regex: &Regex| {
+                regex
+                    .captures(&chain)
+                    .and_then(|caps| caps.get(1))
+                    .map(|m| m.as_str().to_string())
+            };
+
+            groups.push(RouteGroup {
+                start: open,
+                end: close,
+                prefix: option(&self.prefix_regex),
+                name_prefix: option(&self.group_name_regex),
+                namespace: None,
+                controller: option(&self.group_controller_regex),
+                middleware: self.extract_middleware(&chain),
+            });
+        }
+
+        groups.sort_by_key(|group| group.start);
+        groups
+    }
+
+    /// Combined options of the groups enclosing `position`, outermost first
+    fn group_scope(&self, groups: &[RouteGroup], position: usize) -> GroupScope {
+        let mut scope = GroupScope::default();
+        let mut prefixes: Vec<&str> = Vec::new();
+
+        for group in groups.iter().filter(|g| g.start < position && position < g.end) {
+            if let Some(prefix) = group.prefix.as_deref() {
+                prefixes.push(prefix.trim_matches('/'));
             }
+            if let Some(name_prefix) = group.name_prefix.as_deref() {
+                scope.name_prefix.push_str(name_prefix);
+            }
+            if group.controller.is_some() {
+                scope.controller = group.controller.clone();
+            }
+            scope.middleware = merge_middleware(&scope.middleware, group.middleware.clone());
+            scope.depth += 1;
+        }
+
+        prefixes.retain(|p| !p.is_empty());
+        if !prefixes.is_empty() {
+            scope.prefix = Some(prefixes.join("/"));
         }
+        scope
+    }
 
+    fn extract_route_groups(&self, groups: &[RouteGroup]) -> Vec<serde_json::Value> {
         groups
+            .iter()
+            .map(|group| {
+                // Just inside its body a group is in scope along with its parents
+                let scope = self.group_scope(groups, group.start + 1);
+                let rate_limit = RateLimit::from_middleware(&group.middleware);
+
+                serde_json::json!({
+                    "prefix": group.prefix,
+                    "full_prefix": scope.prefix,
+                    "middleware": group.middleware,
+                    "rate_limit": rate_limit,
+                    "namespace": group.namespace,
+                    "controller": group.controller,
+                    "name_prefix": group.name_prefix,
+                    "depth": scope.depth - 1
+                })
+            })
+            .collect()
     }
 
     fn extract_group_option(&self, options: &str, key: &str) -> Option<String> {
-        let pattern = format!(r#"['"]{}['"]\s*=>\s*['"]([^'"]+)['"]"#, key);
-        let regex = Regex::new(&pattern).ok()?;
-
-        regex
-            .captures(options)
-            .and_then(|caps| caps.get(1))
+        self.group_option_regex
+            .captures_iter(options)
+            .find(|caps| caps.get(1).map(|m| m.as_str()) == Some(key))
+            .and_then(|caps| caps.get(2))
             .map(|m| m.as_str().to_string())
     }
 
@@ -484,10 +853,9 @@ impl RouteParser {
         let mut middlewares = Vec::new();
 
         // Try array format: 'middleware' => ['auth', 'admin']
-        let array_regex = Regex::new(r#"['"]middleware['"]\s*=>\s*\[([^\]]+)\]"#).unwrap();
-        if let Some(caps) = array_regex.captures(options) {
+        if let Some(caps) = self.group_middleware_array_regex.captures(options) {
             if let Some(list) = caps.get(1) {
-                for item in list.as_str().split(',') {
+                for item in split_top_level(list.as_str(), b',') {
                     let cleaned = item.trim().trim_matches(|c| c == '\'' || c == '"');
                     if !cleaned.is_empty() {
                         middlewares.push(cleaned.to_string());
@@ -498,8 +866,7 @@ impl RouteParser {
         }
 
         // Try single value: 'middleware' => 'auth'
-        let single_regex = Regex::new(r#"['"]middleware['"]\s*=>\s*['"]([^'"]+)['"]"#).unwrap();
-        if let Some(caps) = single_regex.captures(options) {
+        if let Some(caps) = self.group_middleware_single_regex.captures(options) {
             if let Some(mw) = caps.get(1) {
                 middlewares.push(mw.as_str().to_string());
             }
@@ -532,8 +899,7 @@ impl RouteParser {
         }
 
         // From resource routes
-        let resource_controller_regex = Regex::new(r"([A-Z]\w+)::class").unwrap();
-        for caps in resource_controller_regex.captures_iter(content) {
+        for caps in self.class_reference_regex.captures_iter(content) {
             if let Some(controller) = caps.get(1) {
                 let name = controller.as_str().to_string();
                 if !controllers.contains(&name) && name.ends_with("Controller") {
@@ -551,7 +917,7 @@ impl RouteParser {
         // From ->middleware() calls
         for caps in self.middleware_regex.captures_iter(content) {
             if let Some(array_list) = caps.get(1) {
-                for item in array_list.as_str().split(',') {
+                for item in split_top_level(array_list.as_str(), b',') {
                     let cleaned = item.trim().trim_matches(|c| c == '\'' || c == '"');
                     if !cleaned.is_empty() && !middlewares.contains(&cleaned.to_string()) {
                         middlewares.push(cleaned.to_string());
@@ -566,11 +932,9 @@ impl RouteParser {
         }
 
         // From group options
-        let group_mw_regex =
-            Regex::new(r#"['"]middleware['"]\s*=>\s*(?:\[([^\]]+)\]|['"]([^'"]+)['"])"#).unwrap();
-        for caps in group_mw_regex.captures_iter(content) {
+        for caps in self.group_middleware_regex.captures_iter(content) {
             if let Some(list) = caps.get(1) {
-                for item in list.as_str().split(',') {
+                for item in split_top_level(list.as_str(), b',') {
                     let cleaned = item.trim().trim_matches(|c| c == '\'' || c == '"');
                     if !cleaned.is_empty() && !middlewares.contains(&cleaned.to_string()) {
                         middlewares.push(cleaned.to_string());
@@ -593,3 +957,327 @@ impl Default for RouteParser {
         Self::new()
     }
 }
+
+/// Group middleware followed by the route's own, without duplicates
+fn merge_middleware(inherited: &[String], own: Vec<String>) -> Vec<String> {
+    let mut middleware = inherited.to_vec();
+    for name in own {
+        if !middleware.contains(&name) {
+            middleware.push(name);
+        }
+    }
+    middleware
+}
+
+/// URI of a route under a group prefix (`admin/users` + `/{id}` -> `/admin/users/{id}`)
+fn join_uri(prefix: Option<&str>, uri: &str) -> String {
+    let parts: Vec<&str> = prefix
+        .into_iter()
+        .chain(std::iter::once(uri))
+        .map(|part| part.trim_matches('/'))
+        .filter(|part| !part.is_empty())
+        .collect();
+    format!("/{}", parts.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parse `content` as the project's routes/web.php
+    async fn parse_routes(content: &str) -> ParsedFile {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("web.php");
+        std::fs::write(&path, content).unwrap();
+        let file = SourceFile::new(
+            "web.php".to_string(),
+            "routes/web.php".to_string(),
+            path.to_string_lossy().to_string(),
+        );
+
+        RouteParser::new()
+            .parse(&file, &ParserConfig::new())
+            .await
+            .unwrap()
+    }
+
+    fn middleware(entries: &[&str]) -> Vec<String> {
+        entries.iter().map(|e| e.to_string()).collect()
+    }
+
+    #[test]
+    fn throttle_middleware_gives_the_rate_limit() {
+        assert_eq!(
+            RateLimit::from_middleware(&middleware(&["auth", "throttle:60,5"])),
+            Some(RateLimit {
+                limit: Some(60),
+                minutes: Some(5),
+                limiter: None,
+            })
+        );
+        assert_eq!(
+            RateLimit::from_middleware(&middleware(&["throttle:30"])),
+            Some(RateLimit {
+                limit: Some(30),
+                minutes: Some(1),
+                limiter: None,
+            })
+        );
+        assert_eq!(
+            RateLimit::from_middleware(&middleware(&["throttle:uploads"])),
+            Some(RateLimit {
+                limit: None,
+                minutes: None,
+                limiter: Some("uploads".to_string()),
+            })
+        );
+        assert_eq!(RateLimit::from_middleware(&middleware(&["auth"])), None);
+    }
+
+    #[tokio::test]
+    async fn routes_carry_their_rate_limits() {
+        let parsed = parse_routes(
+            "<?php\n\n\
+             Route::middleware(['auth', 'throttle:uploads'])->group(function () {\n\
+             Route::post('/upload', [UploadController::class, 'store']);\n\
+             });\n\
+             Route::get('/search', [SearchController::class, 'index'])\n\
+             ->middleware('throttle:60,5');\n\
+             Route::get('/about', [PageController::class, 'about']);\n",
+        )
+        .await;
+
+        let rate_limits: Vec<(&str, &serde_json::Value)> = parsed.metadata["routes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|route| (route["uri"].as_str().unwrap(), &route["rate_limit"]))
+            .collect();
+        assert_eq!(
+            rate_limits,
+            [
+                (
+                    "/upload",
+                    &serde_json::json!({ "limit": null, "minutes": null, "limiter": "uploads" })
+                ),
+                (
+                    "/search",
+                    &serde_json::json!({ "limit": 60, "minutes": 5, "limiter": null })
+                ),
+                ("/about", &serde_json::Value::Null),
+            ]
+        );
+    }
+
+    const VIEW_ROUTES: &str = "<?php
+
+Route::get('/', function () {
+    $posts = Post::latest()->get();
+
+    return view('welcome', ['posts' => $posts]);
+})->name('home');
+Route::get('/terms', fn () => View::make('pages.terms'));
+Route::get('/ping', function () {
+    return 'pong';
+});
+Route::view('/about', 'pages.about');
+";
+
+    #[tokio::test]
+    async fn view_routes_and_closures_record_their_views() {
+        let parsed = parse_routes(VIEW_ROUTES).await;
+
+        let actions: Vec<(&str, &serde_json::Value)> = parsed.metadata["routes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|route| (route["uri"].as_str().unwrap(), &route["action"]))
+            .collect();
+        assert_eq!(
+            actions,
+            [
+                (
+                    "/",
+                    &serde_json::json!({ "type": "Closure", "views": ["welcome"] })
+                ),
+                (
+                    "/terms",
+                    &serde_json::json!({ "type": "Closure", "views": ["pages.terms"] })
+                ),
+                ("/ping", &serde_json::json!({ "type": "Closure" })),
+                (
+                    "/about",
+                    &serde_json::json!({ "type": "View", "view": "pages.about" })
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn routes_render_the_views_of_their_actions() {
+        let graph = crate::parsers::laravel::fixtures::graph_of(&[
+            ("routes/web.php", VIEW_ROUTES),
+            ("resources/views/welcome.blade.php", "<h1>Welcome</h1>"),
+            ("resources/views/pages/about.blade.php", "<h1>About</h1>"),
+            ("resources/views/pages/terms.blade.php", "<h1>Terms</h1>"),
+        ])
+        .await;
+
+        let names: std::collections::HashMap<&str, &str> = graph
+            .nodes
+            .iter()
+            .map(|n| (n.id.as_str(), n.qualified_name.as_str()))
+            .collect();
+        let mut renders: Vec<String> = graph
+            .edges
+            .iter()
+            .filter(|e| e.edge_type.as_key() == "custom:renders")
+            .map(|e| {
+                format!(
+                    "{} {}",
+                    e.detail.as_deref().unwrap_or("-"),
+                    names[e.target.as_str()]
+                )
+            })
+            .collect();
+        renders.sort();
+        assert_eq!(
+            renders,
+            [
+                "/ view:welcome",
+                "/about view:pages.about",
+                "/terms view:pages.terms"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn commented_out_routes_are_not_counted() {
+        let parsed = parse_routes(
+            "<?php\n\n\
+             // Route::get('/legacy', [LegacyController::class, 'index']);\n\
+             /*\n\
+             Route::post('/import', [ImportController::class, 'store']);\n\
+             */\n\
+             Route::get('/users', [UserController::class, 'index']);\n\
+             # Route::delete('/users/{user}', [UserController::class, 'destroy']);\n\
+             $example = \"Route::get('/docs', DocsController::class)\";\n",
+        )
+        .await;
+
+        let uris: Vec<&str> = parsed.metadata["routes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|route| route["uri"].as_str().unwrap())
+            .collect();
+        assert_eq!(uris, ["/users"]);
+        assert_eq!(parsed.metadata["route_count"], 1);
+        assert_eq!(parsed.metadata["http_methods"], serde_json::json!(["GET"]));
+        assert_eq!(
+            parsed.metadata["controllers_referenced"],
+            serde_json::json!(["UserController"])
+        );
+    }
+
+    #[tokio::test]
+    async fn nested_groups_combine_their_prefixes_names_and_middleware() {
+        let parsed = parse_routes(
+            "<?php
+
+Route::group(['prefix' => 'admin', 'as' => 'admin.', 'middleware' => ['web', 'auth']], \
+function () {
+    Route::prefix('users')->name('users.')->middleware('verified')->group(function () {
+        Route::get('/{user}', [UserController::class, 'show'])
+            ->name('show')
+            ->middleware(['auth', 'can:view,user']);
+    });
+    Route::get('/dashboard', [DashboardController::class, 'index'])->name('dashboard');
+});
+Route::get('/', [HomeController::class, 'index'])->name('home');
+",
+        )
+        .await;
+
+        let routes: Vec<(&str, &str, &serde_json::Value)> = parsed.metadata["routes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|route| {
+                (
+                    route["full_uri"].as_str().unwrap(),
+                    route["name"].as_str().unwrap(),
+                    &route["middleware"],
+                )
+            })
+            .collect();
+        assert_eq!(
+            routes,
+            [
+                (
+                    "/admin/users/{user}",
+                    "admin.users.show",
+                    &serde_json::json!(["web", "auth", "verified", "can:view,user"])
+                ),
+                (
+                    "/admin/dashboard",
+                    "admin.dashboard",
+                    &serde_json::json!(["web", "auth"])
+                ),
+                ("/", "home", &serde_json::json!([])),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn controller_groups_and_invokable_controllers_resolve_their_actions() {
+        let parsed = parse_routes(
+            "<?php
+
+use App\\Http\\Controllers\\OrderController;
+use App\\Http\\Controllers\\ShowDashboard;
+
+Route::controller(OrderController::class)->group(function () {
+    Route::get('/orders/{id}', 'show');
+    Route::post('/orders', 'store');
+});
+Route::get('/dashboard', ShowDashboard::class);
+",
+        )
+        .await;
+
+        let actions: Vec<(&str, &str, &str)> = parsed.metadata["routes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|route| {
+                let action = &route["action"];
+                (
+                    route["uri"].as_str().unwrap(),
+                    action["controller_fqn"].as_str().unwrap(),
+                    action["method"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            actions,
+            [
+                (
+                    "/orders/{id}",
+                    "App\\Http\\Controllers\\OrderController",
+                    "show"
+                ),
+                (
+                    "/orders",
+                    "App\\Http\\Controllers\\OrderController",
+                    "store"
+                ),
+                (
+                    "/dashboard",
+                    "App\\Http\\Controllers\\ShowDashboard",
+                    "__invoke"
+                ),
+            ]
+        );
+    }
+}
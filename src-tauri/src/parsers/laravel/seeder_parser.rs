@@ -0,0 +1,124 @@
+use regex::Regex;
+
+use crate::models::{ParsedFile, SourceFile};
+use crate::parsers::common::{matching_paren, read_source};
+use crate::parsers::{ParserConfig, ParserResult};
+
+use super::php_parser::{expand_imported_name, PhpParser, CLASS_REF_REGEX};
+
+/// Parser for Laravel database seeders
+pub struct SeederParser {
+    php_parser: PhpParser,
+    call_regex: Regex,
+    factory_regex: Regex,
+    count_regex: Regex,
+}
+
+impl SeederParser {
+    pub fn new() -> Self {
+        Self {
+            php_parser: PhpParser::new(),
+
+            // Match: $this->call(  or  $this->callSilent( / $this->callWith(
+            call_regex: Regex::new(r"\$this\s*->\s*call(?:Silent|With|Once)?\s*\(").unwrap(),
+
+            // Match: User::factory()  or  User::factory(10)
+            factory_regex: Regex::new(r"(\\?[\w\\]+)::factory\s*\(\s*(\d+)?\s*\)").unwrap(),
+
+            // Match: ->count(10)  (later in the factory chain, after simple calls)
+            count_regex: Regex::new(
+                r"^\s*(?:->\s*\w+\s*\([^;()]*\)\s*)*?->\s*count\s*\(\s*(\d+)\s*\)"
+            ).unwrap(),
+        }
+    }
+
+    /// Parse a seeder file
+    pub async fn parse(
+        &self,
+        file: &SourceFile,
+        config: &ParserConfig,
+    ) -> ParserResult<ParsedFile> {
+        let (content, decode_warnings) = read_source(file, config)?;
+
+        let mut parsed = self.php_parser.parse_content(file, &content);
+        parsed.warnings.extend(decode_warnings);
+
+        // Extract seeders run through $this->call()
+        let seeders = self.extract_called_seeders(&content, &parsed);
+        if !seeders.is_empty() {
+            parsed.metadata.insert(
+                "seeders_called".to_string(),
+                serde_json::json!(seeders),
+            );
+        }
+
+        // Extract models populated through their factories
+        let factories = self.extract_factories(&content, &parsed);
+        if !factories.is_empty() {
+            parsed.metadata.insert(
+                "factories".to_string(),
+                serde_json::json!(factories),
+            );
+        }
+
+        Ok(parsed)
+    }
+
+    /// Seeder classes passed to `$this->call()`, alone or as a list
+    fn extract_called_seeders(&self, content: &str, parsed: &ParsedFile) -> Vec<String> {
+        let mut seeders: Vec<String> = Vec::new();
+
+        for found in self.call_regex.find_iter(content) {
+            let open = found.end() - 1;
+            let arguments = match matching_paren(content, open) {
+                Some(close) => &content[open + 1..close],
+                None => continue,
+            };
+            for caps in CLASS_REF_REGEX.captures_iter(arguments) {
+                let seeder = expand_imported_name(&parsed.dependencies, &caps[1]);
+                if !seeders.contains(&seeder) {
+                    seeders.push(seeder);
+                }
+            }
+        }
+
+        seeders
+    }
+
+    /// Models created through `Model::factory()`, with the count when given
+    ///
+    /// The count comes from `factory(n)` or a `->count(n)` later in the chain.
+    fn extract_factories(&self, content: &str, parsed: &ParsedFile) -> Vec<serde_json::Value> {
+        let mut factories: Vec<serde_json::Value> = Vec::new();
+
+        for caps in self.factory_regex.captures_iter(content) {
+            let chain_start = caps.get(0).map(|m| m.end()).unwrap_or(0);
+            let count = caps
+                .get(2)
+                .map(|m| m.as_str())
+                .or_else(|| {
+                    self.count_regex
+                        .captures(&content[chain_start..])
+                        .and_then(|c| c.get(1))
+                        .map(|m| m.as_str())
+                })
+                .and_then(|n| n.parse::<u64>().ok());
+
+            let entry = serde_json::json!({
+                "model": expand_imported_name(&parsed.dependencies, &caps[1]),
+                "count": count,
+            });
+            if !factories.contains(&entry) {
+                factories.push(entry);
+            }
+        }
+
+        factories
+    }
+}
+
+impl Default for SeederParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,140 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::models::ParseResult;
+
+/// Final schema of a table, with every migration that shaped it
+#[derive(Debug, Clone, Serialize)]
+pub struct TableSchema {
+    /// Table name (after any `Schema::rename`)
+    pub name: String,
+
+    /// Column definitions, in declaration order
+    pub columns: Vec<serde_json::Value>,
+
+    /// Paths of the migrations that created or altered the table, in run order
+    pub migrations: Vec<String>,
+}
+
+impl TableSchema {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            columns: Vec::new(),
+            migrations: Vec::new(),
+        }
+    }
+
+    fn touched_by(&mut self, migration: &str) {
+        if !self.migrations.iter().any(|m| m == migration) {
+            self.migrations.push(migration.to_string());
+        }
+    }
+
+    fn position(&self, column: &str) -> Option<usize> {
+        self.columns
+            .iter()
+            .position(|c| c.get("name").and_then(|n| n.as_str()) == Some(column))
+    }
+
+    /// Apply the column operations of a `Schema::create` or `Schema::table`
+    fn apply_columns(&mut self, operations: &[serde_json::Value]) {
+        for operation in operations {
+            match operation.get("op").and_then(|o| o.as_str()) {
+                Some("add") => {
+                    let column = match operation.get("column") {
+                        Some(column) => column.clone(),
+                        None => continue,
+                    };
+                    // A ->change()d column replaces its previous definition in place
+                    let name = column.get("name").and_then(|n| n.as_str()).unwrap_or("");
+                    match self.position(name) {
+                        Some(index) => self.columns[index] = column,
+                        None => self.columns.push(column),
+                    }
+                }
+                Some("drop") => {
+                    let name = operation.get("name").and_then(|n| n.as_str()).unwrap_or("");
+                    if let Some(index) = self.position(name) {
+                        self.columns.remove(index);
+                    }
+                }
+                Some("rename") => {
+                    let from = operation.get("from").and_then(|f| f.as_str()).unwrap_or("");
+                    let to = operation.get("to").and_then(|t| t.as_str()).unwrap_or("");
+                    if let Some(index) = self.position(from) {
+                        self.columns[index]["name"] = serde_json::Value::String(to.to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Fold the schema changes of every migration into the final table schemas
+///
+/// Migrations run in `migration_timestamp` order (path order for ties or
+/// files without one), like `php artisan migrate` would. Only `up()` counts.
+/// Tables altered without being created by a migration still get a schema
+/// with the columns seen. Sorted by table name.
+pub fn table_schemas(parse_result: &ParseResult) -> Vec<TableSchema> {
+    let mut migrations: Vec<(&str, &str, &Vec<serde_json::Value>)> = parse_result
+        .files
+        .iter()
+        .filter_map(|file| {
+            let changes = file.metadata.get("schema_changes")?.as_array()?;
+            let timestamp = file
+                .metadata
+                .get("migration_timestamp")
+                .and_then(|t| t.as_str())
+                .unwrap_or("");
+            Some((timestamp, file.source.path.as_str(), changes))
+        })
+        .collect();
+    migrations.sort_by_key(|(timestamp, path, _)| (*timestamp, *path));
+
+    let mut tables: BTreeMap<String, TableSchema> = BTreeMap::new();
+
+    for (_, path, changes) in migrations {
+        for change in changes {
+            let table = change.get("table").and_then(|t| t.as_str()).unwrap_or("");
+            let columns = change
+                .get("columns")
+                .and_then(|c| c.as_array())
+                .map(|c| c.as_slice())
+                .unwrap_or(&[]);
+
+            match change.get("type").and_then(|t| t.as_str()) {
+                Some("create") => {
+                    let mut schema = TableSchema::new(table);
+                    schema.touched_by(path);
+                    schema.apply_columns(columns);
+                    tables.insert(table.to_string(), schema);
+                }
+                Some("modify") => {
+                    let schema = tables
+                        .entry(table.to_string())
+                        .or_insert_with(|| TableSchema::new(table));
+                    schema.touched_by(path);
+                    schema.apply_columns(columns);
+                }
+                Some("drop") => {
+                    tables.remove(table);
+                }
+                Some("rename") => {
+                    let from = change.get("from").and_then(|f| f.as_str()).unwrap_or("");
+                    let to = change.get("to").and_then(|t| t.as_str()).unwrap_or("");
+                    if let Some(mut schema) = tables.remove(from) {
+                        schema.name = to.to_string();
+                        schema.touched_by(path);
+                        tables.insert(to.to_string(), schema);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    tables.into_values().collect()
+}
@@ -5,6 +5,7 @@ use thiserror::Error;
 
 use crate::core::{ParserInfo, ProjectType};
 use crate::models::{ParseResult, ParsedFile, SourceFile, UnifiedEdge, UnifiedGraph, UnifiedNode};
+use crate::parsers::common::{catch_unwind, file_content_hash, find_orphan_symbols};
 
 /// Parser error types
 #[derive(Error, Debug)]
@@ -21,6 +22,9 @@ pub enum ParseError {
     #[error("Configuration error: {0}")]
     Config(String),
 
+    #[error("Parser panicked on {file}: {message}")]
+    Panic { file: String, message: String },
+
     #[error("Cancelled")]
     Cancelled,
 }
@@ -36,6 +40,12 @@ pub struct ParserConfig {
     /// Directories to exclude from scanning
     pub exclude_dirs: Vec<String>,
 
+    /// Only scan paths matching one of these globs, relative to the root (empty = all)
+    pub include_globs: Vec<String>,
+
+    /// Globs of paths to exclude from scanning, relative to the root (`app/Legacy/**`)
+    pub exclude_globs: Vec<String>,
+
     /// File encoding (default: utf-8)
     pub encoding: String,
 
@@ -47,6 +57,15 @@ pub struct ParserConfig {
 
     /// Language-specific options
     pub language_options: HashMap<String, serde_json::Value>,
+
+    /// Also skip paths ignored by `.gitignore` files
+    pub respect_gitignore: bool,
+
+    /// Files larger than this are listed but not parsed (None = no limit)
+    pub max_file_size_bytes: Option<u64>,
+
+    /// Hash file contents while scanning (reads every file)
+    pub compute_hashes: bool,
 }
 
 impl ParserConfig {
@@ -61,6 +80,20 @@ impl ParserConfig {
         self.exclude_dirs = dirs;
         self
     }
+
+    pub fn with_respect_gitignore(mut self, respect_gitignore: bool) -> Self {
+        self.respect_gitignore = respect_gitignore;
+        self
+    }
+
+    /// Check if files with this extension should be scanned
+    pub fn includes_extension(&self, extension: &str) -> bool {
+        self.include_extensions.is_empty()
+            || self
+                .include_extensions
+                .iter()
+                .any(|e| e.trim_start_matches('.').eq_ignore_ascii_case(extension))
+    }
 }
 
 /// Parse progress information
@@ -85,7 +118,12 @@ pub struct ParserCapabilities {
     /// Edge types this parser can generate
     pub edge_types: Vec<String>,
 
-    /// Supports incremental parsing
+    /// Supports incremental parsing: `parse_file` only depends on the file's
+    /// path and content, so the parse of an unchanged file can be reused
+    ///
+    /// Cross-file work belongs in `generate_nodes`/`generate_edges`, which
+    /// always run over the whole parse result. Without it,
+    /// `incremental_parse` parses every file again.
     pub supports_incremental: bool,
 
     /// Supports cancellation
@@ -123,7 +161,24 @@ pub trait ProjectParser: Send + Sync {
     // ============================================
 
     /// Calculate confidence (0.0 - 1.0) that the directory is this project type
-    fn detect_confidence(&self, root_path: &Path) -> f32;
+    fn detect_confidence(&self, root_path: &Path) -> f32 {
+        self.detect_markers(root_path).0
+    }
+
+    /// Confidence with the marker files and directories it was computed from
+    ///
+    /// `ProjectDetector` relies on it, so a parser takes part in project
+    /// detection just by being registered.
+    fn detect_markers(&self, root_path: &Path) -> (f32, Vec<String>);
+
+    /// Major version of the framework the project is built on, when the
+    /// parser knows how to read it
+    ///
+    /// Recorded in the detection result and the graph metadata, parsers may
+    /// branch on it where framework versions lay projects out differently.
+    fn framework_version(&self, _root_path: &Path) -> Option<u32> {
+        None
+    }
 
     /// Check if parser can handle a specific file
     fn can_handle_file(&self, file_path: &Path) -> bool;
@@ -148,8 +203,25 @@ pub trait ProjectParser: Send + Sync {
     async fn parse_file(&self, file: &SourceFile, config: &ParserConfig)
         -> ParserResult<ParsedFile>;
 
+    /// Parse a single file, reporting a parser panic as an error
+    async fn parse_file_guarded(
+        &self,
+        file: &SourceFile,
+        config: &ParserConfig,
+    ) -> ParserResult<ParsedFile> {
+        catch_unwind(self.parse_file(file, config))
+            .await
+            .unwrap_or_else(|message| {
+                Err(ParseError::Panic {
+                    file: file.path.clone(),
+                    message,
+                })
+            })
+    }
+
     /// Parse complete project
-    /// Default implementation calls parse_file for each file
+    /// Default implementation calls parse_file for each file, a file whose
+    /// parse fails or panics ends up in the result's errors
     async fn parse_project(
         &self,
         root_path: &Path,
@@ -158,6 +230,7 @@ pub trait ProjectParser: Send + Sync {
         progress: Option<ProgressCallback>,
     ) -> ParserResult<ParseResult> {
         let mut result = ParseResult::new();
+        result.root_path = root_path.to_string_lossy().to_string();
         let total = files.len();
 
         for (index, file) in files.iter().enumerate() {
@@ -171,7 +244,84 @@ pub trait ProjectParser: Send + Sync {
                 });
             }
 
-            match self.parse_file(file, config).await {
+            if let Some(ref reason) = file.skip_reason {
+                result.add_warning(format!("Skipped {}: {}", file.path, reason));
+                continue;
+            }
+
+            match self.parse_file_guarded(file, config).await {
+                Ok(parsed) => result.add_parsed_file(parsed),
+                Err(e) => result.add_error(file.path.clone(), e.to_string()),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Re-parse a project reusing a previous result
+    /// Files whose content hash is unchanged keep their previous ParsedFile,
+    /// deleted files are dropped and new or modified files are parsed.
+    /// Parsers whose capabilities don't support incremental parsing parse
+    /// every file again
+    async fn incremental_parse(
+        &self,
+        root_path: &Path,
+        previous: ParseResult,
+        config: &ParserConfig,
+        progress: Option<ProgressCallback>,
+    ) -> ParserResult<ParseResult> {
+        let files = self.scan_files(root_path, config, None).await?;
+        if !self.capabilities().supports_incremental {
+            return self.parse_project(root_path, &files, config, progress).await;
+        }
+
+        let mut cached: HashMap<String, ParsedFile> = previous
+            .files
+            .into_iter()
+            .map(|f| (f.source.path.clone(), f))
+            .collect();
+
+        let mut result = ParseResult::new();
+        result.root_path = root_path.to_string_lossy().to_string();
+        let total = files.len();
+
+        for (index, file) in files.into_iter().enumerate() {
+            if let Some(ref reason) = file.skip_reason {
+                result.add_warning(format!("Skipped {}: {}", file.path, reason));
+                continue;
+            }
+
+            let file = match file.hash {
+                Some(_) => file,
+                None => match file_content_hash(Path::new(&file.absolute_path)) {
+                    Ok(hash) => file.with_hash(hash),
+                    Err(e) => {
+                        result.add_error(file.path.clone(), e.to_string());
+                        continue;
+                    }
+                },
+            };
+
+            // Reuse the previous parse when the content did not change
+            if let Some(mut cached_file) = cached.remove(&file.path) {
+                if cached_file.source.hash.is_some() && cached_file.source.hash == file.hash {
+                    cached_file.source = file;
+                    result.add_parsed_file(cached_file);
+                    continue;
+                }
+            }
+
+            if let Some(ref callback) = progress {
+                callback(ParseProgress {
+                    phase: "parsing".to_string(),
+                    current: index,
+                    total,
+                    current_file: Some(file.path.clone()),
+                    message: format!("Parsing {}", file.name),
+                });
+            }
+
+            match self.parse_file_guarded(&file, config).await {
                 Ok(parsed) => result.add_parsed_file(parsed),
                 Err(e) => result.add_error(file.path.clone(), e.to_string()),
             }
@@ -193,6 +343,16 @@ pub trait ProjectParser: Send + Sync {
     /// Build complete graph from parse result
     fn build_graph(&self, parse_result: &ParseResult) -> UnifiedGraph {
         let nodes = self.generate_nodes(parse_result);
+
+        debug_assert!(
+            find_orphan_symbols(&nodes).is_empty(),
+            "symbol nodes without a containing file node: {:?}",
+            find_orphan_symbols(&nodes)
+                .iter()
+                .map(|n| &n.qualified_name)
+                .collect::<Vec<_>>()
+        );
+
         let edges = self.generate_edges(parse_result, &nodes);
 
         UnifiedGraph {
@@ -209,3 +369,99 @@ pub trait ProjectParser: Send + Sync {
     /// Detect related file pairs (e.g., .pas <-> .dfm)
     fn detect_file_pairs(&self, files: &[SourceFile]) -> Vec<(String, String)>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::common::fixtures::project_dir;
+    use crate::parsers::laravel::LaravelParser;
+
+    /// Laravel parser reading a file's first byte as its first character, a
+    /// slicing bug that panics on files starting with a multi-byte character
+    struct FirstByteParser(LaravelParser);
+
+    #[async_trait]
+    impl ProjectParser for FirstByteParser {
+        fn info(&self) -> ParserInfo {
+            self.0.info()
+        }
+
+        fn default_config(&self) -> ParserConfig {
+            self.0.default_config()
+        }
+
+        fn capabilities(&self) -> ParserCapabilities {
+            self.0.capabilities()
+        }
+
+        fn detect_markers(&self, root_path: &Path) -> (f32, Vec<String>) {
+            self.0.detect_markers(root_path)
+        }
+
+        fn can_handle_file(&self, file_path: &Path) -> bool {
+            self.0.can_handle_file(file_path)
+        }
+
+        async fn scan_files(
+            &self,
+            root_path: &Path,
+            config: &ParserConfig,
+            progress: Option<ProgressCallback>,
+        ) -> ParserResult<Vec<SourceFile>> {
+            self.0.scan_files(root_path, config, progress).await
+        }
+
+        async fn parse_file(
+            &self,
+            file: &SourceFile,
+            config: &ParserConfig,
+        ) -> ParserResult<ParsedFile> {
+            let content = std::fs::read_to_string(&file.absolute_path)?;
+            let _first = &content[..1];
+            self.0.parse_file(file, config).await
+        }
+
+        fn generate_nodes(&self, parse_result: &ParseResult) -> Vec<UnifiedNode> {
+            self.0.generate_nodes(parse_result)
+        }
+
+        fn generate_edges(
+            &self,
+            parse_result: &ParseResult,
+            nodes: &[UnifiedNode],
+        ) -> Vec<UnifiedEdge> {
+            self.0.generate_edges(parse_result, nodes)
+        }
+
+        fn detect_file_pairs(&self, files: &[SourceFile]) -> Vec<(String, String)> {
+            self.0.detect_file_pairs(files)
+        }
+    }
+
+    #[tokio::test]
+    async fn a_panic_on_one_file_becomes_its_parse_error() {
+        let dir = project_dir(&[
+            (
+                "app/Models/User.php",
+                "<?php\nnamespace App\\Models;\n\nclass User {}\n",
+            ),
+            (
+                "app/Models/Café.php",
+                "é<?php\nnamespace App\\Models;\n\nclass Café {}\n",
+            ),
+        ]);
+        let parser = FirstByteParser(LaravelParser::new());
+        let config = parser.default_config();
+        let files = parser.scan_files(dir.path(), &config, None).await.unwrap();
+
+        let result = parser
+            .parse_project(dir.path(), &files, &config, None)
+            .await
+            .unwrap();
+
+        assert_eq!(result.files.len(), 1);
+        assert_eq!(result.errors.len(), 1);
+        let error = &result.errors[&files.iter().find(|f| f.name == "Café.php").unwrap().path];
+        assert!(error.starts_with("Parser panicked on"), "{}", error);
+    }
+}
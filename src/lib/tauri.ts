@@ -1,17 +1,28 @@
 // Tauri IPC wrapper
 
 import { invoke } from "@tauri-apps/api/core";
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
 import type {
+  DetectionOutcome,
   DetectionResult,
+  GraphFilter,
+  GraphUpdate,
+  NodeDetails,
+  ParseResult,
   ParserInfo,
+  ProjectAnalysis,
   SourceFile,
   UnifiedGraph,
+  UnifiedNode,
 } from "@/types/unified";
 
 export const tauriCommands = {
   detectProjectType: (path: string): Promise<DetectionResult> =>
     invoke("detect_project_type", { path }),
 
+  detectProjectTypes: (paths: string[]): Promise<DetectionOutcome[]> =>
+    invoke("detect_project_types", { paths }),
+
   listParsers: (): Promise<ParserInfo[]> => invoke("list_parsers"),
 
   scanDirectory: (path: string, parserId?: string): Promise<SourceFile[]> =>
@@ -19,6 +30,53 @@ export const tauriCommands = {
 
   parseProject: (path: string, parserId?: string): Promise<UnifiedGraph> =>
     invoke("parse_project", { path, parserId }),
+
+  analyze: (path: string, parserId?: string): Promise<ProjectAnalysis> =>
+    invoke("analyze", { path, parserId }),
+
+  rebuildGraph: (
+    parseResult: ParseResult,
+    parserId: string,
+  ): Promise<UnifiedGraph> =>
+    invoke("rebuild_graph", { parseResult, parserId }),
+
+  scanMonorepo: (path: string, layout?: boolean): Promise<UnifiedGraph> =>
+    invoke("scan_monorepo", { path, layout }),
+
+  searchNodes: (
+    graph: UnifiedGraph,
+    query: string,
+    nodeType?: string,
+    limit?: number,
+  ): Promise<UnifiedNode[]> =>
+    invoke("search_nodes", { graph, query, nodeType, limit }),
+
+  filterGraph: (
+    graph: UnifiedGraph,
+    options: GraphFilter,
+  ): Promise<UnifiedGraph> => invoke("filter_graph", { graph, options }),
+
+  getNodeDetails: (graph: UnifiedGraph, nodeId: string): Promise<NodeDetails> =>
+    invoke("get_node_details", { graph, nodeId }),
+
+  findPath: (
+    graph: UnifiedGraph,
+    fromId: string,
+    toId: string,
+  ): Promise<string[] | null> => invoke("find_path", { graph, fromId, toId }),
+
+  watchDirectory: (path: string, parserId?: string): Promise<void> =>
+    invoke("watch_directory", { path, parserId }),
+
+  stopWatch: (path: string): Promise<void> => invoke("stop_watch", { path }),
 };
 
+// Event emitted by the backend after a watched project changed
+export const GRAPH_UPDATED_EVENT = "graph-updated";
+
+export const onGraphUpdated = (
+  handler: (update: GraphUpdate) => void,
+): Promise<UnlistenFn> =>
+  listen<GraphUpdate>(GRAPH_UPDATED_EVENT, (event) => handler(event.payload));
+
 export default tauriCommands;
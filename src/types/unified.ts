@@ -51,6 +51,7 @@ export type UnifiedEdgeType =
 export type ProjectType =
   | "delphi"
   | "laravel"
+  | "lumen"
   | "nodejs"
   | "php"
   | "csharp"
@@ -102,6 +103,8 @@ export interface EdgeMetadata {
   is_conditional?: boolean;
   is_dev_dependency?: boolean;
   version_constraint?: string;
+  polymorphic?: boolean;
+  inferred?: boolean;
 }
 
 export interface UnifiedEdge {
@@ -124,6 +127,8 @@ export interface GraphMetadata {
   total_lines?: number;
   scanned_at?: string;
   parser_version: string;
+  framework_version?: number;
+  warnings?: string[];
 }
 
 export interface UnifiedGraph {
@@ -139,8 +144,12 @@ export interface DetectionResult {
   marker_files_found: string[];
   is_multi_language: boolean;
   secondary_types: [ProjectType, number][];
+  framework_version?: number;
 }
 
+/** Detection of one path of a batch, serialized from a Rust `Result` */
+export type DetectionOutcome = { Ok: DetectionResult } | { Err: string };
+
 export interface ParserInfo {
   id: string;
   display_name: string;
@@ -162,4 +171,48 @@ export interface SourceFile {
   size_bytes: number;
   hash?: string;
   modified_at?: string;
+  skip_reason?: string;
+}
+
+// Kept as returned by the backend, only sent back to rebuild_graph
+export interface ParseResult {
+  files: unknown[];
+  errors: Record<string, string>;
+  total_processed: number;
+  total_errors: number;
+  warnings: string[];
+  root_path: string;
+}
+
+export interface ProjectAnalysis {
+  parser_id: string;
+  parse_result: ParseResult;
+  graph: UnifiedGraph;
+}
+
+export interface GraphUpdate {
+  path: string;
+  changed_node_ids: string[];
+  graph: UnifiedGraph;
+}
+
+export interface GraphFilter {
+  node_types?: string[];
+  edge_types?: string[];
+  projects?: string[];
+  focus?: string[];
+  depth?: number;
+}
+
+export interface NodeNeighbor {
+  edge: UnifiedEdge;
+  node?: UnifiedNode;
+}
+
+export interface NodeDetails {
+  node: UnifiedNode;
+  file_path?: string;
+  outgoing: NodeNeighbor[];
+  incoming: NodeNeighbor[];
+  snippet?: string;
 }